@@ -50,6 +50,8 @@ pub struct ApiGitHubConfig {
     pub app_installation_id: u64,
     /// GitHub App private key.
     pub app_private_key: String,
+    /// Team members cache duration, in seconds.
+    pub team_members_cache_ttl: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -94,6 +96,32 @@ pub struct ServerConfig {
     pub disable_webhook_signature: bool,
     /// Enable welcome coments.
     pub enable_welcome_comments: bool,
+    /// Admin API token (admin endpoints are disabled if empty).
+    pub admin_token: String,
+    /// Webhook deliveries retention (in days).
+    pub webhook_deliveries_retention_days: u64,
+    /// Duration during which a webhook delivery is considered as already processed (in seconds).
+    pub webhook_deduplication_ttl: u64,
+    /// Background job workers count.
+    pub jobs_workers_count: u64,
+    /// Attempts before a background job is moved to the dead-letter state.
+    pub jobs_max_attempts: u64,
+    /// Base delay between two attempts of a background job (in seconds), doubled on each failure.
+    pub jobs_backoff_base: u64,
+    /// Lease duration of a running background job (in seconds), renewed while it runs.
+    pub jobs_lease_duration: u64,
+    /// Interval between two synchronizations of open pull requests (in seconds, 0 to disable).
+    pub scheduler_sync_interval: u64,
+    /// Interval between two merge retries of automerge pull requests (in seconds, 0 to disable).
+    pub scheduler_automerge_interval: u64,
+    /// Interval between two purges of closed pull requests (in seconds, 0 to disable).
+    pub scheduler_purge_interval: u64,
+    /// Closed pull requests retention (in days).
+    pub scheduler_purge_closed_after_days: u64,
+    /// Interval between two purges of old webhook deliveries (in seconds, 0 to disable).
+    pub scheduler_webhook_deliveries_purge_interval: u64,
+    /// Interval between two checks for stale pull requests (in seconds, 0 to disable).
+    pub scheduler_stale_interval: u64,
 }
 
 /// Bot configuration.
@@ -157,6 +185,10 @@ impl Config {
                     app_id: env_to_u64("BOT_API_GITHUB_APP_ID", 0),
                     app_installation_id: env_to_u64("BOT_API_GITHUB_APP_INSTALLATION_ID", 0),
                     app_private_key: env_to_str("BOT_API_GITHUB_APP_PRIVATE_KEY", ""),
+                    team_members_cache_ttl: env_to_u64(
+                        "BOT_API_GITHUB_TEAM_MEMBERS_CACHE_TTL",
+                        300,
+                    ),
                 },
             },
             logging: LoggingConfig {
@@ -182,6 +214,40 @@ impl Config {
                     false,
                 ),
                 enable_welcome_comments: env_to_bool("BOT_SERVER_ENABLE_WELCOME_COMMENTS", false),
+                admin_token: env_to_str("BOT_SERVER_ADMIN_TOKEN", ""),
+                webhook_deliveries_retention_days: env_to_u64(
+                    "BOT_SERVER_WEBHOOK_DELIVERIES_RETENTION_DAYS",
+                    30,
+                ),
+                webhook_deduplication_ttl: env_to_u64(
+                    "BOT_SERVER_WEBHOOK_DEDUPLICATION_TTL",
+                    24 * 60 * 60,
+                ),
+                jobs_workers_count: env_to_u64("BOT_SERVER_JOBS_WORKERS_COUNT", 2),
+                jobs_max_attempts: env_to_u64("BOT_SERVER_JOBS_MAX_ATTEMPTS", 5),
+                jobs_backoff_base: env_to_u64("BOT_SERVER_JOBS_BACKOFF_BASE", 10),
+                jobs_lease_duration: env_to_u64("BOT_SERVER_JOBS_LEASE_DURATION", 60),
+                scheduler_sync_interval: env_to_u64("BOT_SERVER_SCHEDULER_SYNC_INTERVAL", 60 * 60),
+                scheduler_automerge_interval: env_to_u64(
+                    "BOT_SERVER_SCHEDULER_AUTOMERGE_INTERVAL",
+                    5 * 60,
+                ),
+                scheduler_purge_interval: env_to_u64(
+                    "BOT_SERVER_SCHEDULER_PURGE_INTERVAL",
+                    24 * 60 * 60,
+                ),
+                scheduler_purge_closed_after_days: env_to_u64(
+                    "BOT_SERVER_SCHEDULER_PURGE_CLOSED_AFTER_DAYS",
+                    30,
+                ),
+                scheduler_webhook_deliveries_purge_interval: env_to_u64(
+                    "BOT_SERVER_SCHEDULER_WEBHOOK_DELIVERIES_PURGE_INTERVAL",
+                    60 * 60,
+                ),
+                scheduler_stale_interval: env_to_u64(
+                    "BOT_SERVER_SCHEDULER_STALE_INTERVAL",
+                    60 * 60,
+                ),
             },
             tenor_api_key: env_to_str("BOT_TENOR_API_KEY", ""),
             test_debug_mode: env_to_bool("BOT_TEST_DEBUG_MODE", false),
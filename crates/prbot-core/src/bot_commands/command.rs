@@ -1,7 +1,10 @@
+use std::str::FromStr;
+
 use prbot_config::Config;
 use prbot_ghapi_interface::types::GhReactionType;
-use prbot_models::{MergeStrategy, RuleBranch};
+use prbot_models::{parse_deadline, MergeStrategy, Repository, RuleBranch};
 use thiserror::Error;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
 const MAX_REVIEWERS_PER_COMMAND: usize = 16;
 
@@ -68,6 +71,16 @@ pub enum UserCommand {
     Gif(String),
     /// Merge pull request.
     Merge(Option<MergeStrategy>),
+    /// Add to/Remove from/Show merge queue.
+    Queue(Option<bool>),
+    /// Explain pull request rules.
+    Explain,
+    /// Pause stale reminders until a date.
+    Snooze(OffsetDateTime),
+    /// Mark/Unmark the comment author as away from automatic reviewer assignment.
+    Away(bool),
+    /// Backport pull request on target branches, once merged.
+    Backport(Vec<String>),
     /// Ping the bot.
     Ping,
     /// Show help message.
@@ -105,6 +118,20 @@ pub enum AdminCommand {
     SetDefaultChecksStatus(bool),
     /// Set needed reviewers count.
     SetNeededReviewers(u64),
+    /// Set CI app slugs.
+    SetCiApps(Vec<String>),
+    /// Set required check patterns.
+    SetRequiredChecks(Vec<String>),
+    /// Set optional check patterns.
+    SetOptionalChecks(Vec<String>),
+    /// Set native merge queue usage.
+    SetNativeMergeQueue(bool),
+    /// Set code owners required reviewers.
+    SetCodeOwnersReviewers(bool),
+    /// Freeze merges on a base branch until a date, with an optional reason.
+    Freeze(RuleBranch, OffsetDateTime, Option<String>),
+    /// Unfreeze merges on a base branch, or on all base branches.
+    Unfreeze(Option<RuleBranch>),
 }
 
 /// Command.
@@ -226,8 +253,19 @@ impl Command {
             "merge" => Self::User(UserCommand::Merge(Self::parse_optional_merge_strategy(
                 args,
             )?)),
+            "queue+" => Self::User(UserCommand::Queue(Some(true))),
+            "queue-" => Self::User(UserCommand::Queue(Some(false))),
+            "queue?" => Self::User(UserCommand::Queue(None)),
             "labels+" => Self::User(UserCommand::SetLabels(Self::parse_labels(args)?)),
             "labels-" => Self::User(UserCommand::UnsetLabels(Self::parse_labels(args)?)),
+            "explain" => Self::User(UserCommand::Explain),
+            "snooze" => Self::User(UserCommand::Snooze(Self::parse_snooze(
+                args,
+                OffsetDateTime::now_utc(),
+            )?)),
+            "away+" => Self::User(UserCommand::Away(true)),
+            "away-" => Self::User(UserCommand::Away(false)),
+            "backport" => Self::User(UserCommand::Backport(Self::parse_labels(args)?)),
             "ping" => Self::User(UserCommand::Ping),
             "is-admin" => Self::User(UserCommand::IsAdmin),
             "help" => Self::User(UserCommand::Help),
@@ -263,6 +301,34 @@ impl Command {
             "admin-set-needed-reviewers" => {
                 Self::Admin(AdminCommand::SetNeededReviewers(Self::parse_u64(args)?))
             }
+            "admin-set-ci-apps" => {
+                let apps = Self::parse_check_patterns(args)?;
+                if apps.is_empty() {
+                    return Err(CommandError::IncompleteCommand);
+                }
+                Self::Admin(AdminCommand::SetCiApps(apps))
+            }
+            "admin-set-required-checks" => Self::Admin(AdminCommand::SetRequiredChecks(
+                Self::parse_check_patterns(args)?,
+            )),
+            "admin-set-optional-checks" => Self::Admin(AdminCommand::SetOptionalChecks(
+                Self::parse_check_patterns(args)?,
+            )),
+            "admin-set-native-merge-queue+" => Self::Admin(AdminCommand::SetNativeMergeQueue(true)),
+            "admin-set-native-merge-queue-" => {
+                Self::Admin(AdminCommand::SetNativeMergeQueue(false))
+            }
+            "admin-set-codeowners-reviewers+" => {
+                Self::Admin(AdminCommand::SetCodeOwnersReviewers(true))
+            }
+            "admin-set-codeowners-reviewers-" => {
+                Self::Admin(AdminCommand::SetCodeOwnersReviewers(false))
+            }
+            "admin-freeze" => {
+                let (branch, until, reason) = Self::parse_freeze(args, OffsetDateTime::now_utc())?;
+                Self::Admin(AdminCommand::Freeze(branch, until, reason))
+            }
+            "admin-unfreeze" => Self::Admin(AdminCommand::Unfreeze(Self::parse_unfreeze(args)?)),
             // Unknown command
             unknown => {
                 return Err(CommandError::UnknownCommand {
@@ -321,6 +387,42 @@ impl Command {
                 AdminCommand::SetDefaultAutomerge(status) => {
                     format!("admin-set-default-automerge{}", Self::plus_minus(*status))
                 }
+                AdminCommand::SetCiApps(apps) => {
+                    format!("admin-set-ci-apps {}", apps.join(", "))
+                }
+                AdminCommand::SetRequiredChecks(patterns) => {
+                    format!("admin-set-required-checks {}", patterns.join(", "))
+                }
+                AdminCommand::SetOptionalChecks(patterns) => {
+                    format!("admin-set-optional-checks {}", patterns.join(", "))
+                }
+                AdminCommand::SetNativeMergeQueue(status) => {
+                    format!("admin-set-native-merge-queue{}", Self::plus_minus(*status))
+                }
+                AdminCommand::SetCodeOwnersReviewers(status) => {
+                    format!(
+                        "admin-set-codeowners-reviewers{}",
+                        Self::plus_minus(*status)
+                    )
+                }
+                AdminCommand::Freeze(branch, until, reason) => {
+                    let mut freeze = format!(
+                        "admin-freeze {} {}",
+                        branch,
+                        until.format(&Rfc3339).unwrap_or_default()
+                    );
+                    if let Some(reason) = reason {
+                        freeze = format!("{} {}", freeze, reason);
+                    }
+                    freeze
+                }
+                AdminCommand::Unfreeze(branch) => {
+                    if let Some(branch) = branch {
+                        format!("admin-unfreeze {}", branch)
+                    } else {
+                        "admin-unfreeze".into()
+                    }
+                }
                 AdminCommand::Synchronize => "admin-sync".into(),
                 AdminCommand::ResetSummary => "admin-reset-summary".into(),
             },
@@ -334,6 +436,7 @@ impl Command {
                 UserCommand::Automerge(status) => {
                     format!("automerge{}", Self::plus_minus(*status))
                 }
+                UserCommand::Explain => "explain".into(),
                 UserCommand::Gif(search) => format!("gif {}", search),
                 UserCommand::Help => "help".into(),
                 UserCommand::IsAdmin => "is-admin".into(),
@@ -358,7 +461,15 @@ impl Command {
                 UserCommand::SetLabels(labels) => format!("labels+ {}", labels.join(" ")),
                 UserCommand::UnsetLabels(labels) => format!("labels- {}", labels.join(" ")),
                 UserCommand::Ping => "ping".into(),
+                UserCommand::Snooze(until) => {
+                    format!("snooze {}", until.format(&Rfc3339).unwrap_or_default())
+                }
+                UserCommand::Away(status) => format!("away{}", Self::plus_minus(*status)),
+                UserCommand::Backport(branches) => format!("backport {}", branches.join(" ")),
                 UserCommand::QaStatus(status) => format!("qa{}", Self::plus_minus_option(*status)),
+                UserCommand::Queue(status) => {
+                    format!("queue{}", Self::plus_minus_option(*status))
+                }
                 UserCommand::SkipQaStatus(status) => {
                     format!("noqa{}", Self::plus_minus(*status))
                 }
@@ -439,19 +550,84 @@ impl Command {
         }
     }
 
+    fn parse_check_patterns(args: &[&str]) -> CommandResult<Vec<String>> {
+        let patterns: Vec<String> = args
+            .join(" ")
+            .split(',')
+            .map(|x| x.trim().to_string())
+            .filter(|x| !x.is_empty())
+            .collect();
+
+        for pattern in &patterns {
+            Repository::validate_check_pattern(pattern)
+                .map_err(|usage| CommandError::InvalidUsage { usage })?;
+        }
+
+        Ok(patterns)
+    }
+
     fn parse_merge_rule(args: &[&str]) -> CommandResult<(RuleBranch, RuleBranch, MergeStrategy)> {
         if args.len() != 3 {
             return Err(CommandError::IncompleteCommand);
         }
 
-        let base = RuleBranch::from(args[0]);
-        let head = RuleBranch::from(args[1]);
+        let base = RuleBranch::from_str(args[0]).map_err(|_| CommandError::ArgumentParsingError)?;
+        let head = RuleBranch::from_str(args[1]).map_err(|_| CommandError::ArgumentParsingError)?;
         let strategy =
             MergeStrategy::try_from(args[2]).map_err(|_| CommandError::ArgumentParsingError)?;
 
         Ok((base, head, strategy))
     }
 
+    fn parse_freeze(
+        args: &[&str],
+        now: OffsetDateTime,
+    ) -> CommandResult<(RuleBranch, OffsetDateTime, Option<String>)> {
+        if args.len() < 2 {
+            return Err(CommandError::IncompleteCommand);
+        }
+
+        let branch =
+            RuleBranch::from_str(args[0]).map_err(|_| CommandError::ArgumentParsingError)?;
+        let until = parse_deadline(args[1], now).map_err(|usage| CommandError::InvalidUsage {
+            usage: format!("{usage}. Use a duration (`2h`, `3d`), a date or a RFC 3339 datetime."),
+        })?;
+        if until <= now {
+            return Err(CommandError::InvalidUsage {
+                usage: "The freeze end date must be in the future.".into(),
+            });
+        }
+
+        Ok((branch, until, Self::parse_message(&args[2..])))
+    }
+
+    fn parse_snooze(args: &[&str], now: OffsetDateTime) -> CommandResult<OffsetDateTime> {
+        let [duration] = args else {
+            return Err(CommandError::IncompleteCommand);
+        };
+
+        let until = parse_deadline(duration, now).map_err(|usage| CommandError::InvalidUsage {
+            usage: format!("{usage}. Use a duration (`2h`, `3d`), a date or a RFC 3339 datetime."),
+        })?;
+        if until <= now {
+            return Err(CommandError::InvalidUsage {
+                usage: "The snooze end date must be in the future.".into(),
+            });
+        }
+
+        Ok(until)
+    }
+
+    fn parse_unfreeze(args: &[&str]) -> CommandResult<Option<RuleBranch>> {
+        match args {
+            [] => Ok(None),
+            [branch] => Ok(Some(
+                RuleBranch::from_str(branch).map_err(|_| CommandError::ArgumentParsingError)?,
+            )),
+            _ => Err(CommandError::ArgumentParsingError),
+        }
+    }
+
     /// Convert to bot string.
     pub fn to_bot_string(&self, config: &Config) -> String {
         format!(
@@ -536,6 +712,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_check_patterns() {
+        assert_eq!(
+            Command::parse_check_patterns(&["test", "(*),", "lint,build"]).unwrap(),
+            vec![
+                "test (*)".to_string(),
+                "lint".to_string(),
+                "build".to_string()
+            ]
+        );
+        assert_eq!(
+            Command::parse_check_patterns(&[]).unwrap(),
+            Vec::<String>::new()
+        );
+        assert!(matches!(
+            Command::parse_check_patterns(&["test", "["]),
+            Err(CommandError::InvalidUsage { usage: _ })
+        ));
+    }
+
     #[test]
     fn test_parse_merge_rule() {
         assert_eq!(
@@ -545,6 +741,87 @@ mod tests {
                 RuleBranch::Named("stable".into()),
                 MergeStrategy::Squash
             )
-        )
+        );
+        assert_eq!(
+            Command::parse_merge_rule(&["release/*", "re:^renovate/", "squash"]).unwrap(),
+            (
+                RuleBranch::Glob("release/*".into()),
+                RuleBranch::Regex("^renovate/".into()),
+                MergeStrategy::Squash
+            )
+        );
+        assert!(matches!(
+            Command::parse_merge_rule(&["re:(", "*", "squash"]),
+            Err(CommandError::ArgumentParsingError)
+        ));
+    }
+
+    #[test]
+    fn test_parse_freeze() {
+        let now = OffsetDateTime::parse("2026-10-17T10:00:00Z", &Rfc3339).unwrap();
+
+        assert_eq!(
+            Command::parse_freeze(&["main", "2d", "Release", "1.0"], now).unwrap(),
+            (
+                RuleBranch::Named("main".into()),
+                OffsetDateTime::parse("2026-10-19T10:00:00Z", &Rfc3339).unwrap(),
+                Some("Release 1.0".into())
+            )
+        );
+        assert_eq!(
+            Command::parse_freeze(&["release/*", "2026-10-20"], now).unwrap(),
+            (
+                RuleBranch::Glob("release/*".into()),
+                OffsetDateTime::parse("2026-10-20T00:00:00Z", &Rfc3339).unwrap(),
+                None
+            )
+        );
+        assert!(matches!(
+            Command::parse_freeze(&["main"], now),
+            Err(CommandError::IncompleteCommand)
+        ));
+        assert!(matches!(
+            Command::parse_freeze(&["main", "tomorrow"], now),
+            Err(CommandError::InvalidUsage { usage: _ })
+        ));
+        assert!(matches!(
+            Command::parse_freeze(&["main", "2026-10-01"], now),
+            Err(CommandError::InvalidUsage { usage: _ })
+        ));
+    }
+
+    #[test]
+    fn test_parse_snooze() {
+        let now = OffsetDateTime::parse("2026-10-17T10:00:00Z", &Rfc3339).unwrap();
+
+        assert_eq!(
+            Command::parse_snooze(&["3d"], now).unwrap(),
+            OffsetDateTime::parse("2026-10-20T10:00:00Z", &Rfc3339).unwrap()
+        );
+        assert!(matches!(
+            Command::parse_snooze(&[], now),
+            Err(CommandError::IncompleteCommand)
+        ));
+        assert!(matches!(
+            Command::parse_snooze(&["later"], now),
+            Err(CommandError::InvalidUsage { usage: _ })
+        ));
+        assert!(matches!(
+            Command::parse_snooze(&["2026-10-01"], now),
+            Err(CommandError::InvalidUsage { usage: _ })
+        ));
+    }
+
+    #[test]
+    fn test_parse_unfreeze() {
+        assert_eq!(Command::parse_unfreeze(&[]).unwrap(), None);
+        assert_eq!(
+            Command::parse_unfreeze(&["main"]).unwrap(),
+            Some(RuleBranch::Named("main".into()))
+        );
+        assert!(matches!(
+            Command::parse_unfreeze(&["main", "dev"]),
+            Err(CommandError::ArgumentParsingError)
+        ));
     }
 }
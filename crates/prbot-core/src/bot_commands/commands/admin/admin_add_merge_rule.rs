@@ -1,11 +1,11 @@
 use async_trait::async_trait;
 use prbot_ghapi_interface::types::GhReactionType;
-use prbot_models::{MergeStrategy, RuleBranch};
+use prbot_models::{MergeRule, MergeStrategy, RuleBranch};
 use shaku::HasComponent;
 
 use crate::{
     bot_commands::{BotCommand, CommandContext, CommandExecutionResult, ResultAction},
-    use_cases::repositories::AddMergeRuleInterface,
+    use_cases::{audit::record_audit_entry, repositories::AddMergeRuleInterface},
     Result,
 };
 
@@ -24,16 +24,43 @@ impl BotCommand for AdminAddMergeRuleCommand {
             .await?
             .unwrap();
 
+        let old_strategy = if self.base == RuleBranch::Wildcard && self.head == RuleBranch::Wildcard
+        {
+            Some(repository.default_strategy)
+        } else {
+            ctx.db_service
+                .merge_rules_get(
+                    ctx.repo_owner,
+                    ctx.repo_name,
+                    self.base.clone(),
+                    self.head.clone(),
+                )
+                .await?
+                .map(|r| r.strategy)
+        };
+
         let uc: &dyn AddMergeRuleInterface = ctx.core_module.resolve_ref();
         uc.run(
             &ctx.as_core_context(),
             &repository,
-            self.base.clone(),
-            self.head.clone(),
-            self.strategy,
+            MergeRule {
+                repository_id: repository.id,
+                base_branch: self.base.clone(),
+                head_branch: self.head.clone(),
+                strategy: self.strategy,
+                ..Default::default()
+            },
         )
         .await?;
 
+        let mut entry = ctx
+            .audit_repository("add_merge_rule")
+            .with_new_value(format!("{} <- {}: {}", self.base, self.head, self.strategy));
+        if let Some(strategy) = old_strategy {
+            entry = entry.with_old_value(format!("{} <- {}: {}", self.base, self.head, strategy));
+        }
+        record_audit_entry(ctx.db_service, entry).await?;
+
         let comment = if self.base == RuleBranch::Wildcard && self.head == RuleBranch::Wildcard {
             format!(
                 "Default strategy updated to '{}' for repository '{}'",
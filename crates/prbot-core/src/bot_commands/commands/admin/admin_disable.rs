@@ -7,7 +7,7 @@ use crate::{
         command::{CommandExecutionResult, ResultAction},
         BotCommand, CommandContext,
     },
-    use_cases::status::DisablePullRequestStatusInterface,
+    use_cases::{audit::record_audit_entry, status::DisablePullRequestStatusInterface},
     Result,
 };
 
@@ -39,6 +39,8 @@ impl BotCommand for AdminDisableCommand {
                 .pull_requests_delete(ctx.repo_owner, ctx.repo_name, ctx.pr_number)
                 .await?;
 
+            record_audit_entry(ctx.db_service, ctx.audit_pull_request("disable_bot")).await?;
+
             let comment = "Bot disabled on this PR. Bye!";
             Ok(CommandExecutionResult::builder()
                 .with_status_update(false)
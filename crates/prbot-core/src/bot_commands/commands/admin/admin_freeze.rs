@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+use prbot_models::{format_datetime, MergeFreeze, RuleBranch};
+use time::OffsetDateTime;
+
+use crate::{
+    bot_commands::{BotCommand, CommandContext, CommandExecutionResult, ResultAction},
+    use_cases::audit::record_audit_entry,
+    Result,
+};
+
+pub struct AdminFreezeCommand {
+    pub branch: RuleBranch,
+    pub until: OffsetDateTime,
+    pub reason: Option<String>,
+}
+
+#[async_trait]
+impl BotCommand for AdminFreezeCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
+        ctx.db_service
+            .merge_freezes_set(
+                MergeFreeze {
+                    base_branch: self.branch.clone(),
+                    until: self.until,
+                    reason: self.reason.clone(),
+                    created_by: ctx.comment_author.into(),
+                    ..Default::default()
+                }
+                .with_repository(&repository),
+            )
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_repository("freeze_merges")
+                .with_new_value(format!(
+                    "{} until {}",
+                    self.branch,
+                    format_datetime(self.until)
+                )),
+        )
+        .await?;
+
+        let comment = format!(
+            "Merges frozen until **{}** for repository '{}' and base branches '{}'.",
+            format_datetime(self.until),
+            ctx.repository_path(),
+            self.branch
+        );
+
+        Ok(CommandExecutionResult::builder()
+            .with_status_update(true)
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::{Repository, RuleBranch};
+    use time::{Duration, OffsetDateTime};
+
+    use super::AdminFreezeCommand;
+    use crate::bot_commands::{BotCommand, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let mut ctx = CommandContextTest::new();
+        ctx.repo_owner = "owner".into();
+        ctx.repo_name = "name".into();
+        ctx.comment_author = "me".into();
+        ctx.db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        let cmd = AdminFreezeCommand {
+            branch: RuleBranch::Named("main".into()),
+            until: OffsetDateTime::now_utc() + Duration::days(1),
+            reason: Some("Release".into()),
+        };
+
+        let result = cmd.handle(&ctx.as_context()).await?;
+        assert!(result.should_update_status);
+
+        let freezes = ctx.db_service.merge_freezes_list("owner", "name").await?;
+        assert_eq!(freezes.len(), 1);
+        assert_eq!(freezes[0].reason, Some("Release".into()));
+        assert_eq!(freezes[0].created_by, "me");
+
+        Ok(())
+    }
+}
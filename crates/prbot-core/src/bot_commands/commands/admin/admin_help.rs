@@ -38,6 +38,15 @@ impl BotCommand for AdminHelpCommand {
             - `admin-set-default-qa-status-`: _Disable QA validation by default for this repository_\n\
             - `admin-set-default-checks-status+`: _Enable checks validation by default for this repository_\n\
             - `admin-set-default-checks-status-`: _Disable checks validation by default for this repository_\n\
+            - `admin-set-ci-apps <slug, ...>`: _Set app slugs whose check runs are used for this repository_\n\
+            - `admin-set-required-checks <pattern, ...>`: _Set required check name patterns for this repository (empty to unset)_\n\
+            - `admin-set-optional-checks <pattern, ...>`: _Set optional check name patterns for this repository (empty to unset)_\n\
+            - `admin-set-native-merge-queue+`: _Merge pull requests through the GitHub native merge queue for this repository_\n\
+            - `admin-set-native-merge-queue-`: _Merge pull requests directly for this repository_\n\
+            - `admin-set-codeowners-reviewers+`: _Register code owners of changed files as required reviewers for this repository_\n\
+            - `admin-set-codeowners-reviewers-`: _Stop registering code owners as required reviewers for this repository_\n\
+            - `admin-freeze <branch> <until> <reason?>`: _Freeze merges on matching base branches until a duration (`2h`, `3d`), a date or a datetime_\n\
+            - `admin-unfreeze <branch?>`: _Unfreeze merges on matching base branches, or on all branches_\n\
             - `admin-set-needed-reviewers <count>`: _Set needed reviewers count for this PR_\n\
             - `admin-reset-reviewers`: _Reset and update reviews on pull request (maintenance-type command)_\n\
             - `admin-reset-summary`: _Create a new summary message (maintenance-type command)_\n\
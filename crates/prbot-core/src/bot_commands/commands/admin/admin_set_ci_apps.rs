@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+
+use crate::{
+    bot_commands::{
+        command::{CommandExecutionResult, ResultAction},
+        BotCommand, CommandContext,
+    },
+    use_cases::audit::record_audit_entry,
+    Result,
+};
+
+pub struct AdminSetCiAppsCommand {
+    apps: Vec<String>,
+}
+
+impl AdminSetCiAppsCommand {
+    pub fn new(apps: Vec<String>) -> Self {
+        Self { apps }
+    }
+}
+
+#[async_trait]
+impl BotCommand for AdminSetCiAppsCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
+        ctx.db_service
+            .repositories_set_ci_app_slugs(ctx.repo_owner, ctx.repo_name, &self.apps)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_repository("set_ci_app_slugs")
+                .with_old_value(repository.ci_app_slugs.join(", "))
+                .with_new_value(self.apps.join(", ")),
+        )
+        .await?;
+
+        let comment = format!(
+            "CI apps set to {} for this repository.",
+            self.apps
+                .iter()
+                .map(|x| format!("`{x}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        Ok(CommandExecutionResult::builder()
+            .with_status_update(true)
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+}
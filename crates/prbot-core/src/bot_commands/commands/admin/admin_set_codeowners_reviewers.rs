@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+
+use crate::{
+    bot_commands::{
+        command::{CommandExecutionResult, ResultAction},
+        BotCommand, CommandContext,
+    },
+    use_cases::audit::record_audit_entry,
+    Result,
+};
+
+pub struct AdminSetCodeOwnersReviewersCommand {
+    enabled: bool,
+}
+
+impl AdminSetCodeOwnersReviewersCommand {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+#[async_trait]
+impl BotCommand for AdminSetCodeOwnersReviewersCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
+        ctx.db_service
+            .repositories_set_codeowners_reviewers(ctx.repo_owner, ctx.repo_name, self.enabled)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_repository("set_codeowners_reviewers")
+                .with_old_value(repository.codeowners_reviewers)
+                .with_new_value(self.enabled),
+        )
+        .await?;
+
+        let comment = format!(
+            "Code owners required reviewers set to **{}** for this repository.",
+            self.enabled
+        );
+        Ok(CommandExecutionResult::builder()
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+}
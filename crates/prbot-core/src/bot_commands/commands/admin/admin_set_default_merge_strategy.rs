@@ -7,6 +7,7 @@ use crate::{
         command::{CommandExecutionResult, ResultAction},
         BotCommand, CommandContext,
     },
+    use_cases::audit::record_audit_entry,
     Result,
 };
 
@@ -23,10 +24,24 @@ impl AdminSetDefaultMergeStrategyCommand {
 #[async_trait]
 impl BotCommand for AdminSetDefaultMergeStrategyCommand {
     async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
         ctx.db_service
             .repositories_set_default_strategy(ctx.repo_owner, ctx.repo_name, self.strategy)
             .await?;
 
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_repository("set_default_merge_strategy")
+                .with_old_value(repository.default_strategy)
+                .with_new_value(self.strategy),
+        )
+        .await?;
+
         let comment = format!(
             "Merge strategy set to **{}** for this repository.",
             self.strategy
@@ -6,6 +6,7 @@ use crate::{
         command::{CommandExecutionResult, ResultAction},
         BotCommand, CommandContext,
     },
+    use_cases::audit::record_audit_entry,
     Result,
 };
 
@@ -22,10 +23,24 @@ impl AdminSetDefaultPrTitleRegexCommand {
 #[async_trait]
 impl BotCommand for AdminSetDefaultPrTitleRegexCommand {
     async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
         ctx.db_service
             .repositories_set_pr_title_validation_regex(ctx.repo_owner, ctx.repo_name, &self.regex)
             .await?;
 
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_repository("set_pr_title_validation_regex")
+                .with_old_value(&repository.pr_title_validation_regex)
+                .with_new_value(&self.regex),
+        )
+        .await?;
+
         let comment = if self.regex.is_empty() {
             "Pull request title regex **unset** for this repository.".into()
         } else {
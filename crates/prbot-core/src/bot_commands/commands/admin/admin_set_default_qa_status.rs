@@ -6,6 +6,7 @@ use crate::{
         command::{CommandExecutionResult, ResultAction},
         BotCommand, CommandContext,
     },
+    use_cases::audit::record_audit_entry,
     Result,
 };
 
@@ -22,10 +23,24 @@ impl AdminSetDefaultQaStatusCommand {
 #[async_trait]
 impl BotCommand for AdminSetDefaultQaStatusCommand {
     async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
         ctx.db_service
             .repositories_set_default_enable_qa(ctx.repo_owner, ctx.repo_name, self.enabled)
             .await?;
 
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_repository("set_default_enable_qa")
+                .with_old_value(repository.default_enable_qa)
+                .with_new_value(self.enabled),
+        )
+        .await?;
+
         let comment = if self.enabled {
             "QA status check **enabled** for this repository."
         } else {
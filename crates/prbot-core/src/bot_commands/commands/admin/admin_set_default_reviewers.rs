@@ -6,6 +6,7 @@ use crate::{
         command::{CommandExecutionResult, ResultAction},
         BotCommand, CommandContext,
     },
+    use_cases::audit::record_audit_entry,
     Result,
 };
 
@@ -22,6 +23,12 @@ impl AdminSetDefaultReviewersCommand {
 #[async_trait]
 impl BotCommand for AdminSetDefaultReviewersCommand {
     async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
         ctx.db_service
             .repositories_set_default_needed_reviewers_count(
                 ctx.repo_owner,
@@ -30,6 +37,14 @@ impl BotCommand for AdminSetDefaultReviewersCommand {
             )
             .await?;
 
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_repository("set_default_needed_reviewers_count")
+                .with_old_value(repository.default_needed_reviewers_count)
+                .with_new_value(self.count),
+        )
+        .await?;
+
         let comment = format!(
             "Needed reviewers count set to **{}** for this repository.",
             self.count
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+
+use crate::{
+    bot_commands::{
+        command::{CommandExecutionResult, ResultAction},
+        BotCommand, CommandContext,
+    },
+    use_cases::audit::record_audit_entry,
+    Result,
+};
+
+pub struct AdminSetNativeMergeQueueCommand {
+    enabled: bool,
+}
+
+impl AdminSetNativeMergeQueueCommand {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+#[async_trait]
+impl BotCommand for AdminSetNativeMergeQueueCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
+        ctx.db_service
+            .repositories_set_native_merge_queue(ctx.repo_owner, ctx.repo_name, self.enabled)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_repository("set_native_merge_queue")
+                .with_old_value(repository.native_merge_queue)
+                .with_new_value(self.enabled),
+        )
+        .await?;
+
+        let comment = format!(
+            "Native merge queue usage set to **{}** for this repository.",
+            self.enabled
+        );
+        Ok(CommandExecutionResult::builder()
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+}
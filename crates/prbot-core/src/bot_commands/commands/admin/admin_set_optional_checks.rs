@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+
+use crate::{
+    bot_commands::{
+        command::{CommandExecutionResult, ResultAction},
+        BotCommand, CommandContext,
+    },
+    use_cases::audit::record_audit_entry,
+    Result,
+};
+
+pub struct AdminSetOptionalChecksCommand {
+    patterns: Vec<String>,
+}
+
+impl AdminSetOptionalChecksCommand {
+    pub fn new(patterns: Vec<String>) -> Self {
+        Self { patterns }
+    }
+}
+
+#[async_trait]
+impl BotCommand for AdminSetOptionalChecksCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
+        ctx.db_service
+            .repositories_set_optional_checks(ctx.repo_owner, ctx.repo_name, &self.patterns)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_repository("set_optional_checks")
+                .with_old_value(repository.optional_checks.join(", "))
+                .with_new_value(self.patterns.join(", ")),
+        )
+        .await?;
+
+        let comment = if self.patterns.is_empty() {
+            "Optional checks **unset** for this repository.".into()
+        } else {
+            format!(
+                "Optional checks set to {} for this repository.",
+                self.patterns
+                    .iter()
+                    .map(|x| format!("`{x}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+        Ok(CommandExecutionResult::builder()
+            .with_status_update(true)
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+}
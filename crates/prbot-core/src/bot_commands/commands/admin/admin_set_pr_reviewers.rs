@@ -6,6 +6,7 @@ use crate::{
         command::{CommandExecutionResult, ResultAction},
         BotCommand, CommandContext,
     },
+    use_cases::audit::record_audit_entry,
     Result,
 };
 
@@ -22,6 +23,11 @@ impl AdminSetPrReviewersCommand {
 #[async_trait]
 impl BotCommand for AdminSetPrReviewersCommand {
     async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let pr_model = ctx
+            .db_service
+            .pull_requests_get_expect(ctx.repo_owner, ctx.repo_name, ctx.pr_number)
+            .await?;
+
         ctx.db_service
             .pull_requests_set_needed_reviewers_count(
                 ctx.repo_owner,
@@ -31,6 +37,14 @@ impl BotCommand for AdminSetPrReviewersCommand {
             )
             .await?;
 
+        record_audit_entry(
+            ctx.db_service,
+            ctx.audit_pull_request("set_needed_reviewers_count")
+                .with_old_value(pr_model.needed_reviewers_count)
+                .with_new_value(self.count),
+        )
+        .await?;
+
         let comment = format!(
             "Needed reviewers count set to **{}** for this pull request.",
             self.count
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+use prbot_models::RuleBranch;
+use shaku::HasComponent;
+
+use crate::{
+    bot_commands::{BotCommand, CommandContext, CommandExecutionResult, ResultAction},
+    use_cases::{audit::record_audit_entry, repositories::UnfreezeMergesInterface},
+    Result,
+};
+
+pub struct AdminUnfreezeCommand {
+    pub branch: Option<RuleBranch>,
+}
+
+#[async_trait]
+impl BotCommand for AdminUnfreezeCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let repository = ctx
+            .db_service
+            .repositories_get(ctx.repo_owner, ctx.repo_name)
+            .await?
+            .unwrap();
+
+        let uc: &dyn UnfreezeMergesInterface = ctx.core_module.resolve_ref();
+        let count = uc
+            .run(&ctx.as_core_context(), &repository, self.branch.clone())
+            .await?;
+
+        if count > 0 {
+            let branch = self
+                .branch
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_else(|| "all".into());
+            record_audit_entry(
+                ctx.db_service,
+                ctx.audit_repository("unfreeze_merges")
+                    .with_old_value(branch),
+            )
+            .await?;
+        }
+
+        let comment = match (&self.branch, count) {
+            (_, 0) => format!(
+                "No merge freeze to remove for repository '{}'.",
+                ctx.repository_path()
+            ),
+            (Some(branch), _) => format!(
+                "Merges unfrozen for repository '{}' and base branches '{}'.",
+                ctx.repository_path(),
+                branch
+            ),
+            (None, _) => format!(
+                "Merges unfrozen for repository '{}'.",
+                ctx.repository_path()
+            ),
+        };
+
+        Ok(CommandExecutionResult::builder()
+            .with_status_update(true)
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+}
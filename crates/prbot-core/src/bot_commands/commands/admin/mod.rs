@@ -1,25 +1,39 @@
 mod admin_add_merge_rule;
 mod admin_disable;
+mod admin_freeze;
 mod admin_help;
 mod admin_reset_summary;
+mod admin_set_ci_apps;
+mod admin_set_codeowners_reviewers;
 mod admin_set_default_automerge;
 mod admin_set_default_checks_status;
 mod admin_set_default_merge_strategy;
 mod admin_set_default_pr_title_regex;
 mod admin_set_default_qa_status;
 mod admin_set_default_reviewers;
+mod admin_set_native_merge_queue;
+mod admin_set_optional_checks;
 mod admin_set_pr_reviewers;
+mod admin_set_required_checks;
 mod admin_sync;
+mod admin_unfreeze;
 
 pub use admin_add_merge_rule::AdminAddMergeRuleCommand;
 pub use admin_disable::AdminDisableCommand;
+pub use admin_freeze::AdminFreezeCommand;
 pub use admin_help::AdminHelpCommand;
 pub use admin_reset_summary::AdminResetSummaryCommand;
+pub use admin_set_ci_apps::AdminSetCiAppsCommand;
+pub use admin_set_codeowners_reviewers::AdminSetCodeOwnersReviewersCommand;
 pub use admin_set_default_automerge::AdminSetDefaultAutomergeCommand;
 pub use admin_set_default_checks_status::AdminSetDefaultChecksStatusCommand;
 pub use admin_set_default_merge_strategy::AdminSetDefaultMergeStrategyCommand;
 pub use admin_set_default_pr_title_regex::AdminSetDefaultPrTitleRegexCommand;
 pub use admin_set_default_qa_status::AdminSetDefaultQaStatusCommand;
 pub use admin_set_default_reviewers::AdminSetDefaultReviewersCommand;
+pub use admin_set_native_merge_queue::AdminSetNativeMergeQueueCommand;
+pub use admin_set_optional_checks::AdminSetOptionalChecksCommand;
 pub use admin_set_pr_reviewers::AdminSetPrReviewersCommand;
+pub use admin_set_required_checks::AdminSetRequiredChecksCommand;
 pub use admin_sync::AdminSyncCommand;
+pub use admin_unfreeze::AdminUnfreezeCommand;
@@ -2,7 +2,7 @@ use prbot_config::Config;
 use prbot_database_interface::DbService;
 use prbot_ghapi_interface::{types::GhPullRequest, ApiService};
 use prbot_lock_interface::LockService;
-use prbot_models::{PullRequestHandle, RepositoryPath};
+use prbot_models::{AuditActor, AuditEntry, AuditSource, PullRequestHandle, RepositoryPath};
 
 use crate::{CoreContext, CoreModule};
 
@@ -29,6 +29,25 @@ impl<'a> CommandContext<'a> {
         (self.repo_owner, self.repo_name, self.pr_number).into()
     }
 
+    pub fn audit_actor(&self) -> AuditActor {
+        AuditActor::new(AuditSource::Comment, self.comment_author)
+    }
+
+    /// Creates an audit entry on the repository.
+    pub fn audit_repository(&self, action: &str) -> AuditEntry {
+        AuditEntry::on_repository(&self.audit_actor(), action, &self.repository_path())
+    }
+
+    /// Creates an audit entry on the pull request.
+    pub fn audit_pull_request(&self, action: &str) -> AuditEntry {
+        AuditEntry::on_pull_request(
+            &self.audit_actor(),
+            action,
+            &self.repository_path(),
+            self.pr_number,
+        )
+    }
+
     pub fn as_core_context(&self) -> CoreContext<'a> {
         CoreContext {
             config: self.config,
@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+use shaku::HasComponent;
+
+use crate::{
+    bot_commands::{
+        command::{CommandExecutionResult, ResultAction},
+        BotCommand, CommandContext,
+    },
+    use_cases::pulls::{BackportPullRequestInterface, BACKPORT_LABEL_PREFIX},
+    Result,
+};
+
+pub struct BackportCommand {
+    branches: Vec<String>,
+}
+
+impl BackportCommand {
+    pub fn new(branches: Vec<String>) -> Self {
+        Self { branches }
+    }
+}
+
+#[async_trait]
+impl BotCommand for BackportCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        // Merged pull requests are backported right away
+        if ctx.upstream_pr.merge_commit_sha.is_some() && ctx.upstream_pr.merged_at.is_some() {
+            let backport_pull_request: &dyn BackportPullRequestInterface =
+                ctx.core_module.resolve_ref();
+            backport_pull_request
+                .run(
+                    &ctx.as_core_context(),
+                    &ctx.pr_handle(),
+                    ctx.upstream_pr,
+                    &self.branches,
+                )
+                .await?;
+
+            return Ok(CommandExecutionResult::builder()
+                .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+                .build());
+        }
+
+        let labels: Vec<_> = self
+            .branches
+            .iter()
+            .map(|b| format!("{BACKPORT_LABEL_PREFIX}{b}"))
+            .collect();
+        ctx.api_service
+            .issue_labels_add(ctx.repo_owner, ctx.repo_name, ctx.pr_number, &labels)
+            .await?;
+
+        let branches: Vec<_> = self.branches.iter().map(|b| format!("`{b}`")).collect();
+        Ok(CommandExecutionResult::builder()
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(format!(
+                "Pull request will be backported to {} once merged.",
+                branches.join(", ")
+            )))
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_ghapi_interface::types::GhPullRequest;
+    use time::OffsetDateTime;
+
+    use super::BackportCommand;
+    use crate::{
+        bot_commands::{BotCommand, CommandContextTest, ResultAction},
+        use_cases::pulls::{BackportPullRequestInterface, MockBackportPullRequestInterface},
+        CoreModule,
+    };
+
+    #[tokio::test]
+    async fn run_not_merged() -> Result<(), Box<dyn Error>> {
+        let mut ctx = CommandContextTest::new();
+        ctx.api_service
+            .expect_issue_labels_add()
+            .once()
+            .withf(|_, _, _, labels| labels == ["backport/stable", "backport/v1"])
+            .return_once(|_, _, _, _| Ok(()));
+
+        let result = BackportCommand::new(vec!["stable".into(), "v1".into()])
+            .handle(&ctx.as_context())
+            .await?;
+
+        assert_eq!(
+            result.result_actions[1],
+            ResultAction::PostComment(
+                "Pull request will be backported to `stable`, `v1` once merged.".into()
+            )
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn run_merged() -> Result<(), Box<dyn Error>> {
+        let mut ctx = CommandContextTest::new();
+        ctx.upstream_pr = GhPullRequest {
+            merged_at: Some(OffsetDateTime::now_utc()),
+            merge_commit_sha: Some("merge".into()),
+            ..Default::default()
+        };
+
+        let backport_pull_request = {
+            let mut mock = MockBackportPullRequestInterface::new();
+            mock.expect_run()
+                .once()
+                .withf(|_, _, _, branches| branches == ["stable"])
+                .return_once(|_, _, _, _| Ok(vec![]));
+            mock
+        };
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn BackportPullRequestInterface>(Box::new(
+                backport_pull_request,
+            ))
+            .build();
+
+        BackportCommand::new(vec!["stable".into()])
+            .handle(&ctx.as_context())
+            .await?;
+
+        Ok(())
+    }
+}
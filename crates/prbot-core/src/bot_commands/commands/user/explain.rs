@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+use shaku::HasComponent;
+
+use crate::{
+    bot_commands::{
+        command::{CommandExecutionResult, ResultAction},
+        BotCommand, CommandContext,
+    },
+    use_cases::pulls::ExplainPullRequestRulesInterface,
+    Result,
+};
+
+pub struct ExplainCommand;
+
+impl ExplainCommand {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl BotCommand for ExplainCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let explain_rules: &dyn ExplainPullRequestRulesInterface = ctx.core_module.resolve_ref();
+        let explanation = explain_rules
+            .run(
+                &ctx.as_core_context(),
+                ctx.pr_handle().repository_path(),
+                ctx.upstream_pr,
+            )
+            .await?;
+
+        Ok(CommandExecutionResult::builder()
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(explanation.to_markdown()))
+            .build())
+    }
+}
@@ -36,14 +36,22 @@ impl BotCommand for HelpCommand {
             - `automerge-`: _Disable auto-merge for this PR_\n\
             - `lock+ <reason?>`: _Lock a pull-request (block merge)_\n\
             - `lock- <reason?>`: _Unlock a pull-request (unblock merge)_\n\
-            - `r+ <reviewers>`: _Assign reviewers (you can assign multiple reviewers)_\n\
-            - `req+ <reviewers>`: _Assign required reviewers (you can assign multiple reviewers)_\n\
+            - `r+ <reviewers>`: _Assign reviewers (you can assign multiple reviewers, or teams with `@org/team`)_\n\
+            - `req+ <reviewers>`: _Assign required reviewers (you can assign multiple reviewers, or teams with `@org/team`)_\n\
             - `r- <reviewers>`: _Unassign reviewers (you can unassign multiple reviewers)_\n\
             - `strategy+ <strategy>`: _Override merge strategy for this pull request_\n\
             - `strategy-`: _Remove the overriden merge strategy for this pull request_\n\
             - `merge <merge|squash|rebase?>`: _Try merging the pull request with optional strategy_\n\
+            - `queue+`: _Add this PR to the merge queue of its base branch_\n\
+            - `queue-`: _Remove this PR from the merge queue_\n\
+            - `queue?`: _Show this PR position in the merge queue_\n\
             - `labels+ <label>`: _Set specific labels_\n\
             - `labels- <label>`: _Unset specific labels_\n\
+            - `explain`: _Explain which pull request rules matched, and which actions won_\n\
+            - `snooze <duration>`: _Pause stale reminders for this PR (e.g. `3d`, or a date)_\n\
+            - `away+`: _Stop being automatically assigned as reviewer_\n\
+            - `away-`: _Be automatically assigned as reviewer again_\n\
+            - `backport <branches>`: _Cherry-pick this PR on other branches once merged, with new pull requests_\n\
             - `ping`: _Ping me_\n\
             - `gif <search>`: _Post a random GIF with a tag_\n\
             - `is-admin`: _Check if you are admin_\n\
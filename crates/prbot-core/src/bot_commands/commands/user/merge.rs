@@ -72,6 +72,12 @@ impl BotCommand for MergeCommand {
                     ctx.comment_author, strategy
                 )));
             }
+            TryMergePullRequestState::Enqueued => {
+                actions.push(ResultAction::AddReaction(GhReactionType::PlusOne));
+                actions.push(ResultAction::PostComment(
+                    "Pull request added to the merge queue.".into(),
+                ));
+            }
             TryMergePullRequestState::Error => {
                 actions.push(ResultAction::AddReaction(GhReactionType::MinusOne));
                 actions.push(ResultAction::PostComment(
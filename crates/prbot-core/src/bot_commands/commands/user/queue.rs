@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+use prbot_models::StepLabel;
+use shaku::HasComponent;
+
+use crate::{
+    bot_commands::{
+        command::{CommandExecutionResult, ResultAction},
+        BotCommand, CommandContext,
+    },
+    use_cases::{
+        merge_queue::{
+            get_merge_queue_position, AddToMergeQueueInterface, RemoveFromMergeQueueInterface,
+        },
+        status::{BuildPullRequestStatusInterface, StepLabelChooser},
+    },
+    Result,
+};
+
+pub struct QueueCommand {
+    status: Option<bool>,
+}
+
+impl QueueCommand {
+    pub fn new(status: Option<bool>) -> Self {
+        Self { status }
+    }
+
+    async fn handle_add(&self, ctx: &CommandContext<'_>) -> Result<CommandExecutionResult> {
+        let build_pr_status: &dyn BuildPullRequestStatusInterface = ctx.core_module.resolve_ref();
+        let pr_status = build_pr_status
+            .run(&ctx.as_core_context(), &ctx.pr_handle(), ctx.upstream_pr)
+            .await?;
+
+        let step = StepLabelChooser::default().choose_from_status(&pr_status);
+        if step != StepLabel::AwaitingMerge {
+            return Ok(CommandExecutionResult::builder()
+                .with_action(ResultAction::AddReaction(GhReactionType::MinusOne))
+                .with_action(ResultAction::PostComment(
+                    "Pull request is not ready to be queued.".into(),
+                ))
+                .build());
+        }
+
+        let add_to_merge_queue: &dyn AddToMergeQueueInterface = ctx.core_module.resolve_ref();
+        add_to_merge_queue
+            .run(
+                &ctx.as_core_context(),
+                &ctx.pr_handle(),
+                &ctx.upstream_pr.base.reference,
+            )
+            .await?;
+
+        let mut comment = format!(
+            "Pull request added to the merge queue for `{}` by **{}**.",
+            ctx.upstream_pr.base.reference, ctx.comment_author
+        );
+        if let Some(position) =
+            get_merge_queue_position(&ctx.as_core_context(), &ctx.pr_handle()).await?
+        {
+            comment = format!(
+                "{comment}\nPosition: **{}** of {} (status: **{}**).",
+                position.position, position.count, position.entry.status
+            );
+        }
+
+        Ok(CommandExecutionResult::builder()
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+
+    async fn handle_remove(&self, ctx: &CommandContext<'_>) -> Result<CommandExecutionResult> {
+        let remove_from_merge_queue: &dyn RemoveFromMergeQueueInterface =
+            ctx.core_module.resolve_ref();
+        let removed = remove_from_merge_queue
+            .run(&ctx.as_core_context(), &ctx.pr_handle())
+            .await?;
+
+        let comment = if removed {
+            format!(
+                "Pull request removed from the merge queue by **{}**.",
+                ctx.comment_author
+            )
+        } else {
+            "Pull request is not in the merge queue.".into()
+        };
+
+        Ok(CommandExecutionResult::builder()
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+
+    async fn handle_show(&self, ctx: &CommandContext<'_>) -> Result<CommandExecutionResult> {
+        let comment = match get_merge_queue_position(&ctx.as_core_context(), &ctx.pr_handle())
+            .await?
+        {
+            Some(position) if position.entry.is_active() => format!(
+                "Pull request is in the merge queue for `{}`.\nPosition: **{}** of {} (status: **{}**).",
+                position.entry.base_branch, position.position, position.count, position.entry.status
+            ),
+            Some(position) => format!(
+                "Pull request failed in the merge queue for `{}`.\nUse `queue+` to queue it again.",
+                position.entry.base_branch
+            ),
+            None => "Pull request is not in the merge queue.".into(),
+        };
+
+        Ok(CommandExecutionResult::builder()
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+}
+
+#[async_trait]
+impl BotCommand for QueueCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        match self.status {
+            Some(true) => self.handle_add(ctx).await,
+            Some(false) => self.handle_remove(ctx).await,
+            None => self.handle_show(ctx).await,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+use prbot_models::AwayUser;
+use time::OffsetDateTime;
+
+use crate::{
+    bot_commands::{
+        command::{CommandExecutionResult, ResultAction},
+        BotCommand, CommandContext,
+    },
+    Result,
+};
+
+pub struct SetAwayCommand {
+    away: bool,
+}
+
+impl SetAwayCommand {
+    pub fn new(away: bool) -> Self {
+        Self { away }
+    }
+}
+
+#[async_trait]
+impl BotCommand for SetAwayCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        let comment = if self.away {
+            if ctx
+                .db_service
+                .away_users_get(ctx.comment_author)
+                .await?
+                .is_none()
+            {
+                ctx.db_service
+                    .away_users_create(AwayUser {
+                        username: ctx.comment_author.into(),
+                        created_at: OffsetDateTime::now_utc(),
+                    })
+                    .await?;
+            }
+
+            format!(
+                "**{}** is now away, and will not be automatically assigned as reviewer.",
+                ctx.comment_author
+            )
+        } else {
+            ctx.db_service.away_users_delete(ctx.comment_author).await?;
+
+            format!(
+                "**{}** is back, and can be automatically assigned as reviewer.",
+                ctx.comment_author
+            )
+        };
+
+        Ok(CommandExecutionResult::builder()
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+
+    use super::SetAwayCommand;
+    use crate::bot_commands::{BotCommand, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let mut ctx = CommandContextTest::new();
+        ctx.comment_author = "me".into();
+
+        SetAwayCommand::new(true).handle(&ctx.as_context()).await?;
+        // Setting away twice is harmless
+        SetAwayCommand::new(true).handle(&ctx.as_context()).await?;
+        assert!(ctx.db_service.away_users_get("me").await?.is_some());
+
+        SetAwayCommand::new(false).handle(&ctx.as_context()).await?;
+        assert!(ctx.db_service.away_users_get("me").await?.is_none());
+
+        Ok(())
+    }
+}
@@ -1,12 +1,13 @@
 use async_trait::async_trait;
 use prbot_ghapi_interface::types::GhReactionType;
-use prbot_models::QaStatus;
+use prbot_models::{PullRequestEventKind, QaStatus};
 
 use crate::{
     bot_commands::{
         command::{CommandExecutionResult, ResultAction},
         BotCommand, CommandContext,
     },
+    use_cases::stats::record_pull_request_event,
     Result,
 };
 
@@ -60,6 +61,14 @@ impl BotCommand for SetQaStatusCommand {
         ctx.db_service
             .pull_requests_set_qa_status(ctx.repo_owner, ctx.repo_name, ctx.pr_number, self.status)
             .await?;
+        record_pull_request_event(
+            ctx.db_service,
+            &ctx.pr_handle(),
+            PullRequestEventKind::QaStatusChanged,
+            Some(ctx.comment_author),
+            self.status.to_str(),
+        )
+        .await?;
 
         let comment = self._create_status_message(ctx);
 
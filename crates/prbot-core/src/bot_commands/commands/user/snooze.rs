@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhReactionType;
+use prbot_models::format_datetime;
+use time::OffsetDateTime;
+
+use crate::{
+    bot_commands::{
+        command::{CommandExecutionResult, ResultAction},
+        BotCommand, CommandContext,
+    },
+    Result,
+};
+
+pub struct SnoozeCommand {
+    until: OffsetDateTime,
+}
+
+impl SnoozeCommand {
+    pub fn new(until: OffsetDateTime) -> Self {
+        Self { until }
+    }
+}
+
+#[async_trait]
+impl BotCommand for SnoozeCommand {
+    async fn handle(&self, ctx: &CommandContext) -> Result<CommandExecutionResult> {
+        ctx.db_service
+            .pull_requests_set_snoozed_until(
+                ctx.repo_owner,
+                ctx.repo_name,
+                ctx.pr_number,
+                Some(self.until),
+            )
+            .await?;
+
+        let comment = format!(
+            "Stale reminders snoozed until **{}** by **{}**.",
+            format_datetime(self.until),
+            ctx.comment_author
+        );
+
+        Ok(CommandExecutionResult::builder()
+            .with_action(ResultAction::AddReaction(GhReactionType::Eyes))
+            .with_action(ResultAction::PostComment(comment))
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::{PullRequest, Repository};
+    use time::{Duration, OffsetDateTime};
+
+    use super::SnoozeCommand;
+    use crate::bot_commands::{BotCommand, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let mut ctx = CommandContextTest::new();
+        ctx.repo_owner = "owner".into();
+        ctx.repo_name = "name".into();
+        ctx.pr_number = 1;
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await?;
+        ctx.db_service
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                ..Default::default()
+            })
+            .await?;
+
+        let until = OffsetDateTime::now_utc() + Duration::days(2);
+        let result = SnoozeCommand::new(until).handle(&ctx.as_context()).await?;
+        assert!(!result.should_update_status);
+
+        let pr = ctx
+            .db_service
+            .pull_requests_get_expect("owner", "name", 1)
+            .await?;
+        assert_eq!(pr.snoozed_until, Some(until));
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use prbot_ghapi_interface::{
     comments::CommentApi,
     types::{GhReactionType, GhUserPermission},
 };
+use prbot_models::PullRequestEventKind;
 use shaku::{Component, HasComponent, Interface};
 
 use super::{
@@ -14,18 +15,23 @@ use super::{
 use crate::{
     bot_commands::{
         commands::{
-            AdminDisableCommand, AdminHelpCommand, AdminResetSummaryCommand,
+            AdminDisableCommand, AdminFreezeCommand, AdminHelpCommand, AdminResetSummaryCommand,
+            AdminSetCiAppsCommand, AdminSetCodeOwnersReviewersCommand,
             AdminSetDefaultAutomergeCommand, AdminSetDefaultChecksStatusCommand,
             AdminSetDefaultMergeStrategyCommand, AdminSetDefaultPrTitleRegexCommand,
             AdminSetDefaultQaStatusCommand, AdminSetDefaultReviewersCommand,
-            AdminSetPrReviewersCommand, AdminSyncCommand, GifCommand, HelpCommand, IsAdminCommand,
-            LockCommand, MergeCommand, PingCommand, SetAutomergeCommand, SetChecksStatusCommand,
-            SetLabelsCommand, SetMergeStrategyCommand, SetQaStatusCommand, SetReviewersCommand,
+            AdminSetNativeMergeQueueCommand, AdminSetOptionalChecksCommand,
+            AdminSetPrReviewersCommand, AdminSetRequiredChecksCommand, AdminSyncCommand,
+            AdminUnfreezeCommand, BackportCommand, ExplainCommand, GifCommand, HelpCommand,
+            IsAdminCommand, LockCommand, MergeCommand, PingCommand, QueueCommand,
+            SetAutomergeCommand, SetAwayCommand, SetChecksStatusCommand, SetLabelsCommand,
+            SetMergeStrategyCommand, SetQaStatusCommand, SetReviewersCommand, SnoozeCommand,
         },
         Command, CommandContext, CommandExecutionResult, CommandResult, ResultAction,
     },
     use_cases::{
         auth::{CheckIsAdmin, CheckWriteRight},
+        stats::record_pull_request_event,
         status::UpdatePullRequestStatusInterface,
     },
     CoreContext, Result,
@@ -242,6 +248,16 @@ impl CommandExecutor {
                 Command::Admin(cmd) => self._execute_admin_command(ctx, cmd).await?,
             };
 
+            // Failed commands are retried, so only successful ones are recorded
+            record_pull_request_event(
+                ctx.db_service,
+                &ctx.pr_handle(),
+                PullRequestEventKind::Command,
+                Some(ctx.comment_author),
+                &command.to_bot_string(ctx.config),
+            )
+            .await?;
+
             for action in &mut command_result.result_actions {
                 if let ResultAction::PostComment(comment) = action {
                     // Include command recap before comment
@@ -276,7 +292,14 @@ impl CommandExecutor {
             UserCommand::QaStatus(s) => SetQaStatusCommand::new_pass_or_fail(*s).handle(ctx).await,
             UserCommand::Lock(s, reason) => LockCommand::new(*s, reason.clone()).handle(ctx).await,
             UserCommand::Ping => PingCommand::new().handle(ctx).await,
+            UserCommand::Explain => ExplainCommand::new().handle(ctx).await,
+            UserCommand::Snooze(until) => SnoozeCommand::new(*until).handle(ctx).await,
+            UserCommand::Away(away) => SetAwayCommand::new(*away).handle(ctx).await,
+            UserCommand::Backport(branches) => {
+                BackportCommand::new(branches.clone()).handle(ctx).await
+            }
             UserCommand::Merge(strategy) => MergeCommand::new(*strategy).handle(ctx).await,
+            UserCommand::Queue(status) => QueueCommand::new(*status).handle(ctx).await,
             UserCommand::AssignReviewers(reviewers) => {
                 SetReviewersCommand::new_assign(reviewers.clone(), false)
                     .handle(ctx)
@@ -367,6 +390,45 @@ impl CommandExecutor {
             AdminCommand::SetNeededReviewers(count) => {
                 AdminSetPrReviewersCommand::new(*count).handle(ctx).await
             }
+            AdminCommand::SetCiApps(apps) => {
+                AdminSetCiAppsCommand::new(apps.clone()).handle(ctx).await
+            }
+            AdminCommand::SetRequiredChecks(patterns) => {
+                AdminSetRequiredChecksCommand::new(patterns.clone())
+                    .handle(ctx)
+                    .await
+            }
+            AdminCommand::SetOptionalChecks(patterns) => {
+                AdminSetOptionalChecksCommand::new(patterns.clone())
+                    .handle(ctx)
+                    .await
+            }
+            AdminCommand::SetNativeMergeQueue(value) => {
+                AdminSetNativeMergeQueueCommand::new(*value)
+                    .handle(ctx)
+                    .await
+            }
+            AdminCommand::SetCodeOwnersReviewers(value) => {
+                AdminSetCodeOwnersReviewersCommand::new(*value)
+                    .handle(ctx)
+                    .await
+            }
+            AdminCommand::Freeze(branch, until, reason) => {
+                AdminFreezeCommand {
+                    branch: branch.clone(),
+                    until: *until,
+                    reason: reason.clone(),
+                }
+                .handle(ctx)
+                .await
+            }
+            AdminCommand::Unfreeze(branch) => {
+                AdminUnfreezeCommand {
+                    branch: branch.clone(),
+                }
+                .handle(ctx)
+                .await
+            }
         }
     }
 
@@ -390,9 +452,13 @@ impl CommandExecutor {
 
 #[cfg(test)]
 mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::ApiError;
+    use prbot_models::{PullRequest, Repository};
     use pretty_assertions::assert_eq;
 
     use super::*;
+    use crate::bot_commands::CommandContextTest;
 
     #[test]
     fn test_merge_command_results() {
@@ -449,4 +515,63 @@ mod tests {
             }
         );
     }
+
+    #[tokio::test]
+    async fn test_execute_command_records_successful_commands() {
+        let mut ctx = CommandContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .pull_requests_create(
+                PullRequest {
+                    number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+        ctx.api_service
+            .expect_user_permissions_get()
+            .times(2)
+            .returning(|_, _, _| Ok(GhUserPermission::Write));
+        ctx.api_service
+            .expect_issue_labels_add()
+            .once()
+            .return_once(|_, _, _, _| {
+                Err(ApiError::ImplementationError {
+                    source: "Unavailable".into(),
+                })
+            });
+
+        let executor = CommandExecutor;
+        let failed = executor
+            .execute_command(
+                &ctx.as_context(),
+                Command::User(UserCommand::Backport(vec!["stable".into()])),
+            )
+            .await;
+        assert!(failed.is_err());
+
+        executor
+            .execute_command(&ctx.as_context(), Command::User(UserCommand::Ping))
+            .await
+            .unwrap();
+
+        let events = ctx
+            .db_service
+            .pull_request_events_list("owner", "name", 1)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, PullRequestEventKind::Command);
+        assert_eq!(events[0].details, format!("{} ping", ctx.config.name));
+    }
 }
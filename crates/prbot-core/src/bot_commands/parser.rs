@@ -122,6 +122,38 @@ mod tests {
             Command::from_comment("qa?", &[]),
             Ok(Some(Command::User(UserCommand::QaStatus(None))))
         ));
+        assert!(matches!(
+            Command::from_comment("queue+", &[]),
+            Ok(Some(Command::User(UserCommand::Queue(Some(true)))))
+        ));
+        assert!(matches!(
+            Command::from_comment("queue-", &[]),
+            Ok(Some(Command::User(UserCommand::Queue(Some(false)))))
+        ));
+        assert!(matches!(
+            Command::from_comment("queue?", &[]),
+            Ok(Some(Command::User(UserCommand::Queue(None))))
+        ));
+        assert!(matches!(
+            Command::from_comment("explain", &[]),
+            Ok(Some(Command::User(UserCommand::Explain)))
+        ));
+        assert!(matches!(
+            Command::from_comment("snooze", &["2d"]),
+            Ok(Some(Command::User(UserCommand::Snooze(_))))
+        ));
+        assert!(matches!(
+            Command::from_comment("away+", &[]),
+            Ok(Some(Command::User(UserCommand::Away(true))))
+        ));
+        assert!(matches!(
+            Command::from_comment("backport", &["stable", "v1"]),
+            Ok(Some(Command::User(UserCommand::Backport(branches)))) if branches == ["stable", "v1"]
+        ));
+        assert!(matches!(
+            Command::from_comment("backport", &[]),
+            Err(CommandError::IncompleteCommand)
+        ));
         assert!(matches!(
             Command::from_comment("automerge+", &[]),
             Ok(Some(Command::User(UserCommand::Automerge(true))))
@@ -147,5 +179,36 @@ mod tests {
             Command::from_comment("admin-set-needed-reviewers", &["toto"]),
             Err(CommandError::ArgumentParsingError)
         ));
+        assert!(matches!(
+            Command::from_comment("admin-set-ci-apps", &[]),
+            Err(CommandError::IncompleteCommand)
+        ));
+        assert_eq!(
+            Command::from_comment("admin-set-required-checks", &["build,", "test", "(*)"]).unwrap(),
+            Some(Command::Admin(AdminCommand::SetRequiredChecks(vec![
+                "build".into(),
+                "test (*)".into()
+            ])))
+        );
+        assert!(matches!(
+            Command::from_comment("admin-set-native-merge-queue+", &[]),
+            Ok(Some(Command::Admin(AdminCommand::SetNativeMergeQueue(
+                true
+            ))))
+        ));
+        assert!(matches!(
+            Command::from_comment("admin-set-codeowners-reviewers-", &[]),
+            Ok(Some(Command::Admin(AdminCommand::SetCodeOwnersReviewers(
+                false
+            ))))
+        ));
+        assert!(matches!(
+            Command::from_comment("admin-freeze", &["main", "2h", "Release"]),
+            Ok(Some(Command::Admin(AdminCommand::Freeze(_, _, Some(_)))))
+        ));
+        assert!(matches!(
+            Command::from_comment("admin-unfreeze", &[]),
+            Ok(Some(Command::Admin(AdminCommand::Unfreeze(None))))
+        ));
     }
 }
@@ -27,8 +27,14 @@ pub enum DomainError {
         source: prbot_lock_interface::LockError,
     },
 
+    #[error("Timeout while waiting for lock '{name}'")]
+    LockTimeout { name: String },
+
     #[error("Crypto error: {source}")]
     CryptoError { source: prbot_crypto::CryptoError },
+
+    #[error("Invalid pull request rule '{name}': {message}")]
+    InvalidPullRequestRule { name: String, message: String },
 }
 
 impl From<regex::Error> for DomainError {
@@ -16,7 +16,10 @@ use use_cases::{
     checks::{
         determine_check_status::DetermineChecksStatus,
         determine_commit_status::DetermineCommitStatus,
+        handle_check_run_event::HandleCheckRunEvent,
         handle_check_suite_event::HandleCheckSuiteEvent,
+        handle_merge_group_event::HandleMergeGroupEvent, handle_status_event::HandleStatusEvent,
+        handle_workflow_run_event::HandleWorkflowRunEvent,
     },
     comments::{
         generate_random_gif_comment::GenerateRandomGifComment,
@@ -24,25 +27,40 @@ use use_cases::{
         post_welcome_comment::PostWelcomeComment,
     },
     gifs::random_gif_from_query::RandomGifFromQuery,
+    merge_queue::{
+        add_to_merge_queue::AddToMergeQueue, handle_merge_queue_checks::HandleMergeQueueChecks,
+        process_merge_queue::ProcessMergeQueue, remove_from_merge_queue::RemoveFromMergeQueue,
+    },
     pulls::{
         add_pull_request_rule::AddPullRequestRule, apply_pull_request_rules::ApplyPullRequestRules,
-        automerge_pull_request::AutomergePullRequest,
+        automerge_pull_request::AutomergePullRequest, backport_pull_request::BackportPullRequest,
         determine_pull_request_merge_strategy::DeterminePullRequestMergeStrategy,
+        explain_pull_request_rules::ExplainPullRequestRules,
         get_or_create_repository::GetOrCreateRepository, merge_pull_request::MergePullRequest,
         process_pull_request_event::ProcessPullRequestEvent,
         process_pull_request_opened::ProcessPullRequestOpened,
+        purge_closed_pull_requests::PurgeClosedPullRequests,
+        remind_stale_pull_requests::RemindStalePullRequests,
         remove_pull_request_rule::RemovePullRequestRule,
         resolve_pull_request_rules::ResolvePullRequestRules, set_step_label::SetStepLabel,
+        simulate_pull_request_rule::SimulatePullRequestRule,
+        synchronize_open_pull_requests::SynchronizeOpenPullRequests,
         synchronize_pull_request::SynchronizePullRequest,
         synchronize_pull_request_and_update_status::SynchronizePullRequestAndUpdateStatus,
         try_merge_pull_request_from_status::TryMergePullRequestFromStatus,
         update_step_label_from_status::UpdateStepLabelFromStatus,
     },
-    repositories::{add_merge_rule::AddMergeRule, rename_repository::RenameRepository},
+    repositories::{
+        add_merge_rule::AddMergeRule, handle_push_event::HandlePushEvent,
+        rename_repository::RenameRepository, sync_repository_config::SyncRepositoryConfig,
+        unfreeze_merges::UnfreezeMerges,
+    },
     reviews::{
-        add_reviewers::AddReviewers, filter_reviewers::FilterReviewers,
+        add_reviewers::AddReviewers, assign_code_owners_reviewers::AssignCodeOwnersReviewers,
+        assign_pool_reviewers::AssignPoolReviewers, filter_reviewers::FilterReviewers,
         handle_review_event::HandleReviewEvent, remove_reviewers::RemoveReviewers,
     },
+    stats::compute_repository_stats::ComputeRepositoryStats,
     status::{
         build_pull_request_status::BuildPullRequestStatus,
         create_or_update_commit_status::CreateOrUpdateCommitStatus,
@@ -70,7 +88,13 @@ module! {
             HandleIssueCommentEvent, HandleReviewEvent, SetPullRequestQaStatus,
             UpdateStepLabelFromStatus, CreateOrUpdateCommitStatus, RenameRepository,
             DetermineCommitStatus, ResolvePullRequestRules, ApplyPullRequestRules,
-            AddPullRequestRule, RemovePullRequestRule
+            AddPullRequestRule, RemovePullRequestRule, HandleCheckRunEvent,
+            HandleWorkflowRunEvent, HandleStatusEvent, AddToMergeQueue,
+            RemoveFromMergeQueue, ProcessMergeQueue, HandleMergeQueueChecks,
+            HandleMergeGroupEvent, SyncRepositoryConfig, HandlePushEvent, ExplainPullRequestRules,
+            SimulatePullRequestRule, UnfreezeMerges, SynchronizeOpenPullRequests,
+            PurgeClosedPullRequests, RemindStalePullRequests, ComputeRepositoryStats,
+            AssignCodeOwnersReviewers, AssignPoolReviewers, BackportPullRequest
         ],
         providers = []
     }
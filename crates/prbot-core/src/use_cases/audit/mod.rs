@@ -0,0 +1,3 @@
+mod utils;
+
+pub use utils::record_audit_entry;
@@ -0,0 +1,19 @@
+use prbot_database_interface::DbService;
+use prbot_models::AuditEntry;
+use tracing::info;
+
+use crate::Result;
+
+/// Record an entry in the audit log.
+pub async fn record_audit_entry(db_service: &dyn DbService, entry: AuditEntry) -> Result<()> {
+    info!(
+        source = %entry.source,
+        actor = %entry.actor,
+        action = %entry.action,
+        target = %entry.target,
+        message = "Recording audit entry"
+    );
+
+    db_service.audit_entries_create(entry).await?;
+    Ok(())
+}
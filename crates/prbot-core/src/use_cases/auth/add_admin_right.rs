@@ -1,25 +1,40 @@
-use prbot_models::Account;
+use prbot_models::{Account, AuditActor, AuditEntry};
 
-use crate::{CoreContext, Result};
+use crate::{use_cases::audit::record_audit_entry, CoreContext, Result};
 
 pub struct AddAdminRight;
 
 impl AddAdminRight {
-    #[tracing::instrument(skip(self, ctx), fields(username))]
-    pub async fn run(&self, ctx: &CoreContext<'_>, username: &str) -> Result<()> {
-        match ctx.db_service.accounts_get(username).await? {
-            Some(_) => ctx.db_service.accounts_set_is_admin(username, true).await?,
+    #[tracing::instrument(skip(self, ctx, actor), fields(username))]
+    pub async fn run(
+        &self,
+        ctx: &CoreContext<'_>,
+        actor: &AuditActor,
+        username: &str,
+    ) -> Result<()> {
+        let was_admin = match ctx.db_service.accounts_get(username).await? {
+            Some(account) => {
+                ctx.db_service.accounts_set_is_admin(username, true).await?;
+                account.is_admin
+            }
             None => {
                 ctx.db_service
                     .accounts_create(Account {
                         username: username.into(),
                         is_admin: true,
                     })
-                    .await?
+                    .await?;
+                false
             }
         };
 
-        Ok(())
+        record_audit_entry(
+            ctx.db_service,
+            AuditEntry::new(actor, "set_admin_right", username)
+                .with_old_value(was_admin)
+                .with_new_value(true),
+        )
+        .await
     }
 }
 
@@ -28,6 +43,7 @@ mod tests {
     use std::error::Error;
 
     use prbot_database_interface::DbService;
+    use prbot_models::{AuditActor, AuditSource};
 
     use super::AddAdminRight;
     use crate::context::tests::CoreContextTest;
@@ -35,10 +51,19 @@ mod tests {
     #[tokio::test]
     async fn run() -> Result<(), Box<dyn Error>> {
         let ctx = CoreContextTest::new();
+        let actor = AuditActor::new(AuditSource::Cli, "admin");
 
-        AddAdminRight.run(&ctx.as_context(), "me").await?;
+        AddAdminRight.run(&ctx.as_context(), &actor, "me").await?;
         assert!(ctx.db_service.accounts_get_expect("me").await?.is_admin);
 
+        let entries = ctx.db_service.audit_entries_all().await?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].actor, "admin");
+        assert_eq!(entries[0].source, AuditSource::Cli);
+        assert_eq!(entries[0].target, "me");
+        assert_eq!(entries[0].old_value.as_deref(), Some("false"));
+        assert_eq!(entries[0].new_value.as_deref(), Some("true"));
+
         Ok(())
     }
 }
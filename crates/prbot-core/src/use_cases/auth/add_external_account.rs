@@ -1,12 +1,17 @@
-use prbot_models::ExternalAccount;
+use prbot_models::{AuditActor, AuditEntry, ExternalAccount};
 
-use crate::{CoreContext, Result};
+use crate::{use_cases::audit::record_audit_entry, CoreContext, Result};
 
 pub struct AddExternalAccount;
 
 impl AddExternalAccount {
-    #[tracing::instrument(skip(self, ctx), fields(username))]
-    pub async fn run(&self, ctx: &CoreContext<'_>, username: &str) -> Result<()> {
+    #[tracing::instrument(skip(self, ctx, actor), fields(username))]
+    pub async fn run(
+        &self,
+        ctx: &CoreContext<'_>,
+        actor: &AuditActor,
+        username: &str,
+    ) -> Result<()> {
         ctx.db_service
             .external_accounts_create(
                 ExternalAccount {
@@ -17,7 +22,11 @@ impl AddExternalAccount {
             )
             .await?;
 
-        Ok(())
+        record_audit_entry(
+            ctx.db_service,
+            AuditEntry::new(actor, "add_external_account", username),
+        )
+        .await
     }
 }
 
@@ -26,6 +35,7 @@ mod tests {
     use std::error::Error;
 
     use prbot_database_interface::DbService;
+    use prbot_models::{AuditActor, AuditSource};
 
     use super::AddExternalAccount;
     use crate::context::tests::CoreContextTest;
@@ -33,8 +43,11 @@ mod tests {
     #[tokio::test]
     async fn run() -> Result<(), Box<dyn Error>> {
         let ctx = CoreContextTest::new();
+        let actor = AuditActor::new(AuditSource::Cli, "admin");
 
-        AddExternalAccount.run(&ctx.as_context(), "me").await?;
+        AddExternalAccount
+            .run(&ctx.as_context(), &actor, "me")
+            .await?;
 
         assert!(ctx.db_service.external_accounts_get("me").await?.is_some());
 
@@ -1,14 +1,15 @@
-use prbot_models::{ExternalAccountRight, RepositoryPath};
+use prbot_models::{AuditActor, AuditEntry, ExternalAccountRight, RepositoryPath};
 
-use crate::{CoreContext, Result};
+use crate::{use_cases::audit::record_audit_entry, CoreContext, Result};
 
 pub struct AddExternalAccountRight;
 
 impl AddExternalAccountRight {
-    #[tracing::instrument(skip(self, ctx), fields(repository_path, username))]
+    #[tracing::instrument(skip(self, ctx, actor), fields(repository_path, username))]
     pub async fn run(
         &self,
         ctx: &CoreContext<'_>,
+        actor: &AuditActor,
         repository_path: &RepositoryPath,
         username: &str,
     ) -> Result<()> {
@@ -25,7 +26,12 @@ impl AddExternalAccountRight {
             })
             .await?;
 
-        Ok(())
+        record_audit_entry(
+            ctx.db_service,
+            AuditEntry::new(actor, "add_external_account_right", username)
+                .with_repository(repository_path),
+        )
+        .await
     }
 }
 
@@ -34,7 +40,7 @@ mod tests {
     use std::error::Error;
 
     use prbot_database_interface::DbService;
-    use prbot_models::{ExternalAccount, Repository};
+    use prbot_models::{AuditActor, AuditSource, ExternalAccount, Repository};
 
     use super::AddExternalAccountRight;
     use crate::context::tests::CoreContextTest;
@@ -42,6 +48,7 @@ mod tests {
     #[tokio::test]
     async fn run() -> Result<(), Box<dyn Error>> {
         let ctx = CoreContextTest::new();
+        let actor = AuditActor::new(AuditSource::Cli, "admin");
 
         let repository = ctx
             .db_service
@@ -59,7 +66,7 @@ mod tests {
             .await?;
 
         AddExternalAccountRight
-            .run(&ctx.as_context(), &repository.path(), "me")
+            .run(&ctx.as_context(), &actor, &repository.path(), "me")
             .await?;
 
         assert!(ctx
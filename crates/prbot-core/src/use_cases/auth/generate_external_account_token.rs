@@ -1,16 +1,32 @@
-use crate::{CoreContext, Result};
+use prbot_models::{AuditActor, AuditEntry};
+
+use crate::{use_cases::audit::record_audit_entry, CoreContext, Result};
 
 pub struct GenerateExternalAccountToken;
 
 impl GenerateExternalAccountToken {
-    #[tracing::instrument(skip(self, ctx), fields(username))]
-    pub async fn run(&self, ctx: &CoreContext<'_>, username: &str) -> Result<String> {
+    #[tracing::instrument(skip(self, ctx, actor), fields(username))]
+    pub async fn run(
+        &self,
+        ctx: &CoreContext<'_>,
+        actor: &AuditActor,
+        username: &str,
+    ) -> Result<String> {
         let exa = ctx
             .db_service
             .external_accounts_get(username)
             .await?
             .unwrap();
-        exa.generate_access_token().map_err(Into::into)
+        let token = exa.generate_access_token()?;
+
+        // Never store the token itself
+        record_audit_entry(
+            ctx.db_service,
+            AuditEntry::new(actor, "generate_external_account_token", username),
+        )
+        .await?;
+
+        Ok(token)
     }
 }
 
@@ -19,7 +35,7 @@ mod tests {
     use std::error::Error;
 
     use prbot_database_interface::DbService;
-    use prbot_models::ExternalAccount;
+    use prbot_models::{AuditActor, AuditSource, ExternalAccount};
 
     use super::GenerateExternalAccountToken;
     use crate::context::tests::CoreContextTest;
@@ -27,6 +43,7 @@ mod tests {
     #[tokio::test]
     async fn run() -> Result<(), Box<dyn Error>> {
         let ctx = CoreContextTest::new();
+        let actor = AuditActor::new(AuditSource::Cli, "admin");
 
         ctx.db_service
             .external_accounts_create(
@@ -39,7 +56,7 @@ mod tests {
             .await?;
 
         assert!(GenerateExternalAccountToken
-            .run(&ctx.as_context(), "me")
+            .run(&ctx.as_context(), &actor, "me")
             .await?
             .starts_with("ey"));
 
@@ -1,17 +1,23 @@
-use prbot_models::Account;
+use prbot_models::{Account, AuditActor, AuditEntry};
 
-use crate::{CoreContext, Result};
+use crate::{use_cases::audit::record_audit_entry, CoreContext, Result};
 
 pub struct RemoveAdminRight;
 
 impl RemoveAdminRight {
-    #[tracing::instrument(skip(self, ctx), fields(username))]
-    pub async fn run(&self, ctx: &CoreContext<'_>, username: &str) -> Result<()> {
-        match ctx.db_service.accounts_get(username).await? {
-            Some(_) => {
+    #[tracing::instrument(skip(self, ctx, actor), fields(username))]
+    pub async fn run(
+        &self,
+        ctx: &CoreContext<'_>,
+        actor: &AuditActor,
+        username: &str,
+    ) -> Result<()> {
+        let was_admin = match ctx.db_service.accounts_get(username).await? {
+            Some(account) => {
                 ctx.db_service
                     .accounts_set_is_admin(username, false)
-                    .await?
+                    .await?;
+                account.is_admin
             }
             None => {
                 ctx.db_service
@@ -19,11 +25,18 @@ impl RemoveAdminRight {
                         username: username.to_string(),
                         is_admin: false,
                     })
-                    .await?
+                    .await?;
+                false
             }
         };
 
-        Ok(())
+        record_audit_entry(
+            ctx.db_service,
+            AuditEntry::new(actor, "set_admin_right", username)
+                .with_old_value(was_admin)
+                .with_new_value(false),
+        )
+        .await
     }
 }
 
@@ -32,7 +45,7 @@ mod tests {
     use std::error::Error;
 
     use prbot_database_interface::DbService;
-    use prbot_models::Account;
+    use prbot_models::{Account, AuditActor, AuditSource};
 
     use super::RemoveAdminRight;
     use crate::context::tests::CoreContextTest;
@@ -40,6 +53,7 @@ mod tests {
     #[tokio::test]
     async fn run() -> Result<(), Box<dyn Error>> {
         let ctx = CoreContextTest::new();
+        let actor = AuditActor::new(AuditSource::Cli, "admin");
 
         ctx.db_service
             .accounts_create(Account {
@@ -48,7 +62,9 @@ mod tests {
             })
             .await?;
 
-        RemoveAdminRight.run(&ctx.as_context(), "acc").await?;
+        RemoveAdminRight
+            .run(&ctx.as_context(), &actor, "acc")
+            .await?;
 
         assert!(!ctx.db_service.accounts_get_expect("acc").await?.is_admin);
 
@@ -1,15 +1,26 @@
-use crate::{CoreContext, Result};
+use prbot_models::{AuditActor, AuditEntry};
+
+use crate::{use_cases::audit::record_audit_entry, CoreContext, Result};
 
 pub struct RemoveAllExternalAccountRights;
 
 impl RemoveAllExternalAccountRights {
-    #[tracing::instrument(skip(self, ctx), fields(username))]
-    pub async fn run(&self, ctx: &CoreContext<'_>, username: &str) -> Result<()> {
+    #[tracing::instrument(skip(self, ctx, actor), fields(username))]
+    pub async fn run(
+        &self,
+        ctx: &CoreContext<'_>,
+        actor: &AuditActor,
+        username: &str,
+    ) -> Result<()> {
         ctx.db_service
             .external_account_rights_delete_all(username)
             .await?;
 
-        Ok(())
+        record_audit_entry(
+            ctx.db_service,
+            AuditEntry::new(actor, "remove_all_external_account_rights", username),
+        )
+        .await
     }
 }
 
@@ -18,7 +29,9 @@ mod tests {
     use std::error::Error;
 
     use prbot_database_interface::DbService;
-    use prbot_models::{ExternalAccount, ExternalAccountRight, Repository};
+    use prbot_models::{
+        AuditActor, AuditSource, ExternalAccount, ExternalAccountRight, Repository,
+    };
 
     use super::RemoveAllExternalAccountRights;
     use crate::context::tests::CoreContextTest;
@@ -26,6 +39,7 @@ mod tests {
     #[tokio::test]
     async fn run() -> Result<(), Box<dyn Error>> {
         let ctx = CoreContextTest::new();
+        let actor = AuditActor::new(AuditSource::Cli, "admin");
 
         let repo = ctx
             .db_service
@@ -51,7 +65,7 @@ mod tests {
             .await?;
 
         RemoveAllExternalAccountRights
-            .run(&ctx.as_context(), "acc")
+            .run(&ctx.as_context(), &actor, "acc")
             .await?;
 
         assert_eq!(
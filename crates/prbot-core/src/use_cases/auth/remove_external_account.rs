@@ -1,13 +1,24 @@
-use crate::{CoreContext, Result};
+use prbot_models::{AuditActor, AuditEntry};
+
+use crate::{use_cases::audit::record_audit_entry, CoreContext, Result};
 
 pub struct RemoveExternalAccount;
 
 impl RemoveExternalAccount {
-    #[tracing::instrument(skip(self, ctx), fields(username))]
-    pub async fn run(&self, ctx: &CoreContext<'_>, username: &str) -> Result<()> {
+    #[tracing::instrument(skip(self, ctx, actor), fields(username))]
+    pub async fn run(
+        &self,
+        ctx: &CoreContext<'_>,
+        actor: &AuditActor,
+        username: &str,
+    ) -> Result<()> {
         ctx.db_service.external_accounts_delete(username).await?;
 
-        Ok(())
+        record_audit_entry(
+            ctx.db_service,
+            AuditEntry::new(actor, "remove_external_account", username),
+        )
+        .await
     }
 }
 
@@ -16,7 +27,7 @@ mod tests {
     use std::error::Error;
 
     use prbot_database_interface::DbService;
-    use prbot_models::ExternalAccount;
+    use prbot_models::{AuditActor, AuditSource, ExternalAccount};
 
     use super::RemoveExternalAccount;
     use crate::context::tests::CoreContextTest;
@@ -24,6 +35,7 @@ mod tests {
     #[tokio::test]
     async fn run() -> Result<(), Box<dyn Error>> {
         let ctx = CoreContextTest::new();
+        let actor = AuditActor::new(AuditSource::Cli, "admin");
 
         ctx.db_service
             .external_accounts_create(ExternalAccount {
@@ -32,7 +44,9 @@ mod tests {
             })
             .await?;
 
-        RemoveExternalAccount.run(&ctx.as_context(), "acc").await?;
+        RemoveExternalAccount
+            .run(&ctx.as_context(), &actor, "acc")
+            .await?;
 
         assert_eq!(ctx.db_service.external_accounts_get("acc").await?, None);
 
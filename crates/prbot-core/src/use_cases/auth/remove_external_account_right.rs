@@ -1,14 +1,15 @@
-use prbot_models::RepositoryPath;
+use prbot_models::{AuditActor, AuditEntry, RepositoryPath};
 
-use crate::{CoreContext, Result};
+use crate::{use_cases::audit::record_audit_entry, CoreContext, Result};
 
 pub struct RemoveExternalAccountRight;
 
 impl RemoveExternalAccountRight {
-    #[tracing::instrument(skip(self, ctx), fields(username, repository_path))]
+    #[tracing::instrument(skip(self, ctx, actor), fields(username, repository_path))]
     pub async fn run(
         &self,
         ctx: &CoreContext<'_>,
+        actor: &AuditActor,
         repository_path: &RepositoryPath,
         username: &str,
     ) -> Result<()> {
@@ -18,7 +19,12 @@ impl RemoveExternalAccountRight {
             .external_account_rights_delete(owner, name, username)
             .await?;
 
-        Ok(())
+        record_audit_entry(
+            ctx.db_service,
+            AuditEntry::new(actor, "remove_external_account_right", username)
+                .with_repository(repository_path),
+        )
+        .await
     }
 }
 
@@ -27,7 +33,9 @@ mod tests {
     use std::error::Error;
 
     use prbot_database_interface::DbService;
-    use prbot_models::{ExternalAccount, ExternalAccountRight, Repository};
+    use prbot_models::{
+        AuditActor, AuditSource, ExternalAccount, ExternalAccountRight, Repository,
+    };
 
     use super::RemoveExternalAccountRight;
     use crate::context::tests::CoreContextTest;
@@ -35,6 +43,7 @@ mod tests {
     #[tokio::test]
     async fn run() -> Result<(), Box<dyn Error>> {
         let ctx = CoreContextTest::new();
+        let actor = AuditActor::new(AuditSource::Cli, "admin");
 
         let repo = ctx
             .db_service
@@ -60,7 +69,7 @@ mod tests {
             .await?;
 
         RemoveExternalAccountRight
-            .run(&ctx.as_context(), &("owner", "name").into(), "acc")
+            .run(&ctx.as_context(), &actor, &("owner", "name").into(), "acc")
             .await?;
 
         assert_eq!(
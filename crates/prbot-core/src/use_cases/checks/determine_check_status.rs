@@ -1,22 +1,53 @@
 use std::collections::{hash_map::Entry, HashMap};
 
 use async_trait::async_trait;
-use prbot_ghapi_interface::types::{GhCheckConclusion, GhCheckRun};
-use prbot_models::{ChecksStatus, RepositoryPath};
+use prbot_ghapi_interface::types::{
+    GhCheckConclusion, GhCheckRun, GhCommitStatusItem, GhCommitStatusState,
+};
+use prbot_models::{ChecksStatus, Repository};
 use shaku::{Component, Interface};
 
+use super::determine_commit_status::filter_statuses;
 use crate::{CoreContext, Result};
 
+/// Required check status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiredCheckStatus {
+    /// Check name pattern.
+    pub name: String,
+    /// Check status.
+    pub status: ChecksStatus,
+}
+
+/// Checks status, with required checks details.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChecksReport {
+    /// Global checks status.
+    pub status: ChecksStatus,
+    /// Required checks statuses, in configuration order.
+    pub required_checks: Vec<RequiredCheckStatus>,
+}
+
+impl ChecksReport {
+    /// Create report from a status, without required checks.
+    pub fn from_status(status: ChecksStatus) -> Self {
+        Self {
+            status,
+            required_checks: vec![],
+        }
+    }
+}
+
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
 pub trait DetermineChecksStatusInterface: Interface {
     async fn run<'a>(
         &self,
         ctx: &CoreContext<'a>,
-        repository_path: &RepositoryPath,
+        repository: &Repository,
         commit_sha: &str,
         wait_for_initial_checks: bool,
-    ) -> Result<ChecksStatus>;
+    ) -> Result<ChecksReport>;
 }
 
 #[derive(Component)]
@@ -26,53 +57,84 @@ pub(crate) struct DetermineChecksStatus;
 #[async_trait]
 impl DetermineChecksStatusInterface for DetermineChecksStatus {
     #[tracing::instrument(
-        skip(self, ctx),
-        fields(api_service, repository_path, commit_sha, wait_for_initial_checks),
+        skip(self, ctx, repository),
+        fields(api_service, repository_path = %repository.path(), commit_sha, wait_for_initial_checks),
         ret
     )]
     async fn run<'a>(
         &self,
         ctx: &CoreContext<'a>,
-        repository_path: &RepositoryPath,
+        repository: &Repository,
         commit_sha: &str,
         wait_for_initial_checks: bool,
-    ) -> Result<ChecksStatus> {
+    ) -> Result<ChecksReport> {
         // Get upstream checks
         let check_runs = ctx
             .api_service
-            .check_runs_list(repository_path.owner(), repository_path.name(), commit_sha)
+            .check_runs_list(&repository.owner, &repository.name, commit_sha)
             .await?;
 
-        // Extract status
-        if check_runs.is_empty() {
-            if wait_for_initial_checks {
-                Ok(ChecksStatus::Waiting)
-            } else {
-                Ok(ChecksStatus::Skipped)
-            }
-        } else {
-            Ok(filter_and_merge_check_runs(
-                &check_runs,
-                wait_for_initial_checks,
-            ))
-        }
+        // Get upstream commit statuses, from external CI
+        let commit_statuses = ctx
+            .api_service
+            .commit_statuses_combined(&repository.owner, &repository.name, commit_sha)
+            .await?;
+
+        let mut checks = check_runs_to_checks(repository, &check_runs);
+        checks.extend(commit_statuses_to_checks(&commit_statuses.items));
+
+        Ok(evaluate_checks(
+            repository,
+            &checks,
+            wait_for_initial_checks,
+        ))
     }
 }
 
-/// Filter and merge check suites.
-fn filter_and_merge_check_runs(
+/// Convert check runs to named checks, using the last run for each name.
+fn check_runs_to_checks(
+    repository: &Repository,
     check_runs: &[GhCheckRun],
-    wait_for_initial_checks: bool,
-) -> ChecksStatus {
-    let filtered = filter_last_check_runs(check_runs);
-    marge_check_run_statuses(&filtered, wait_for_initial_checks)
+) -> Vec<(String, ChecksStatus)> {
+    filter_last_check_runs(repository, check_runs)
+        .into_iter()
+        .map(|run| {
+            let status = match run.conclusion {
+                Some(GhCheckConclusion::Failure) => ChecksStatus::Fail,
+                Some(GhCheckConclusion::Success) => ChecksStatus::Pass,
+                None => ChecksStatus::Waiting,
+                Some(_) => ChecksStatus::Skipped,
+            };
+
+            (run.name, status)
+        })
+        .collect()
+}
+
+/// Convert commit statuses to named checks, using the last status for each context.
+fn commit_statuses_to_checks(status_items: &[GhCommitStatusItem]) -> Vec<(String, ChecksStatus)> {
+    filter_statuses(status_items)
+        .into_iter()
+        .map(|item| {
+            let status = match item.state {
+                GhCommitStatusState::Error | GhCommitStatusState::Failure => ChecksStatus::Fail,
+                GhCommitStatusState::Pending => ChecksStatus::Waiting,
+                GhCommitStatusState::Success => ChecksStatus::Pass,
+            };
+
+            (item.context, status)
+        })
+        .collect()
 }
 
 /// Filter last check runs, using the name of the check run to dedupe.
-fn filter_last_check_runs(check_runs: &[GhCheckRun]) -> Vec<GhCheckRun> {
+fn filter_last_check_runs(repository: &Repository, check_runs: &[GhCheckRun]) -> Vec<GhCheckRun> {
     let mut map: HashMap<String, GhCheckRun> = HashMap::new();
-    // Only fetch GitHub Actions statuses
-    for check_run in check_runs.iter().filter(|s| s.app.slug == "github-actions") {
+    // Only fetch statuses from configured apps
+    for check_run in check_runs
+        .iter()
+        .filter(|s| repository.is_ci_app(&s.app.slug))
+    {
         if let Entry::Vacant(e) = map.entry(check_run.name.clone()) {
             e.insert(check_run.clone());
         } else {
@@ -86,39 +148,78 @@ fn filter_last_check_runs(check_runs: &[GhCheckRun]) -> Vec<GhCheckRun> {
     map.into_values().collect()
 }
 
-fn marge_check_run_statuses(
-    check_runs: &[GhCheckRun],
+/// Evaluate named checks using the repository required and optional checks.
+///
+/// Optional checks are ignored, and required checks which did not report yet are waiting.
+fn evaluate_checks(
+    repository: &Repository,
+    checks: &[(String, ChecksStatus)],
     wait_for_initial_checks: bool,
-) -> ChecksStatus {
-    let initial = if wait_for_initial_checks {
-        ChecksStatus::Waiting
-    } else {
-        ChecksStatus::Skipped
-    };
+) -> ChecksReport {
+    let checks: Vec<_> = checks
+        .iter()
+        .filter(|(name, _)| !repository.is_optional_check(name))
+        .collect();
 
-    check_runs
+    let required_checks: Vec<_> = repository
+        .required_checks
         .iter()
-        .fold(None, |acc, s| match (&acc, &s.conclusion) {
-            // Already failed, or current check suite is failing
-            (Some(ChecksStatus::Fail), _) | (_, Some(GhCheckConclusion::Failure)) => {
-                Some(ChecksStatus::Fail)
-            }
-            // No status or checks already pass, and current check suite pass
-            (None | Some(ChecksStatus::Pass), Some(GhCheckConclusion::Success)) => {
-                Some(ChecksStatus::Pass)
+        .map(|pattern| {
+            let matching: Vec<_> = checks
+                .iter()
+                .filter(|(name, _)| Repository::check_matches_pattern(pattern, name))
+                .map(|(_, status)| *status)
+                .collect();
+
+            let status = match merge_statuses(matching.iter().copied()) {
+                Some(status) => status,
+                None if matching.is_empty() => ChecksStatus::Waiting,
+                None => ChecksStatus::Skipped,
+            };
+
+            RequiredCheckStatus {
+                name: pattern.clone(),
+                status,
             }
-            // No conclusion for current check suite
-            (_, None) => Some(ChecksStatus::Waiting),
-            // Keep same status
-            (_, _) => acc,
         })
-        .unwrap_or(initial)
+        .collect();
+
+    let status = merge_statuses(
+        checks
+            .iter()
+            .map(|(_, status)| *status)
+            .chain(required_checks.iter().map(|c| c.status)),
+    )
+    .unwrap_or(if wait_for_initial_checks {
+        ChecksStatus::Waiting
+    } else {
+        ChecksStatus::Skipped
+    });
+
+    ChecksReport {
+        status,
+        required_checks,
+    }
+}
+
+/// Merge statuses: one failing status fails, then one waiting status waits.
+///
+/// Skipped statuses are ignored, so `None` is returned if there is nothing to merge.
+fn merge_statuses(statuses: impl Iterator<Item = ChecksStatus>) -> Option<ChecksStatus> {
+    statuses.fold(None, |acc, status| match (acc, status) {
+        (Some(ChecksStatus::Fail), _) | (_, ChecksStatus::Fail) => Some(ChecksStatus::Fail),
+        (Some(ChecksStatus::Waiting), _) | (_, ChecksStatus::Waiting) => {
+            Some(ChecksStatus::Waiting)
+        }
+        (_, ChecksStatus::Pass) => Some(ChecksStatus::Pass),
+        (acc, ChecksStatus::Skipped) => acc,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use prbot_ghapi_interface::{
-        types::{GhApplication, GhCheckStatus, GhUser},
+        types::{GhApplication, GhCheckStatus, GhCommitStatus, GhUser},
         MockApiService,
     };
     use time::{Duration, OffsetDateTime};
@@ -126,81 +227,228 @@ mod tests {
     use super::*;
     use crate::context::tests::CoreContextTest;
 
+    fn build_repository() -> Repository {
+        Repository {
+            owner: "me".into(),
+            name: "test".into(),
+            ..Default::default()
+        }
+    }
+
+    fn build_api_service(
+        check_runs: Vec<GhCheckRun>,
+        status_items: Vec<GhCommitStatusItem>,
+    ) -> MockApiService {
+        let mut svc = MockApiService::new();
+
+        svc.expect_check_runs_list()
+            .once()
+            .withf(|owner, name, sha| owner == "me" && name == "test" && sha == "abcdef")
+            .return_once(|_, _, _| Ok(check_runs));
+        svc.expect_commit_statuses_combined()
+            .once()
+            .withf(|owner, name, sha| owner == "me" && name == "test" && sha == "abcdef")
+            .return_once(|_, _, _| {
+                Ok(GhCommitStatus {
+                    state: GhCommitStatusState::Success,
+                    items: status_items,
+                })
+            });
+
+        svc
+    }
+
+    fn filter_and_merge_check_runs(
+        check_runs: &[GhCheckRun],
+        wait_for_initial_checks: bool,
+    ) -> ChecksStatus {
+        let repository = Repository::default();
+        let checks = check_runs_to_checks(&repository, check_runs);
+        evaluate_checks(&repository, &checks, wait_for_initial_checks).status
+    }
+
     #[tokio::test]
     async fn no_runs_and_wait() {
         let mut ctx = CoreContextTest::new();
-        ctx.api_service = {
-            let mut svc = MockApiService::new();
+        ctx.api_service = build_api_service(vec![], vec![]);
 
-            svc.expect_check_runs_list()
-                .once()
-                .withf(|owner, name, sha| owner == "me" && name == "test" && sha == "abcdef")
-                .return_once(|_, _, _| Ok(vec![]));
+        let report = DetermineChecksStatus
+            .run(&ctx.as_context(), &build_repository(), "abcdef", true)
+            .await
+            .unwrap();
 
-            svc
-        };
+        assert_eq!(report.status, ChecksStatus::Waiting);
+    }
+
+    #[tokio::test]
+    async fn no_runs_and_no_wait() {
+        let mut ctx = CoreContextTest::new();
+        ctx.api_service = build_api_service(vec![], vec![]);
 
-        let status = DetermineChecksStatus
-            .run(&ctx.as_context(), &("me", "test").into(), "abcdef", true)
+        let report = DetermineChecksStatus
+            .run(&ctx.as_context(), &build_repository(), "abcdef", false)
             .await
             .unwrap();
 
-        assert_eq!(status, ChecksStatus::Waiting);
+        assert_eq!(report.status, ChecksStatus::Skipped);
     }
 
     #[tokio::test]
-    async fn no_runs_and_no_wait() {
+    async fn runs() {
         let mut ctx = CoreContextTest::new();
-        ctx.api_service = {
-            let mut svc = MockApiService::new();
+        ctx.api_service = build_api_service(
+            vec![GhCheckRun {
+                name: "dummy".into(),
+                app: GhApplication {
+                    owner: GhUser {
+                        login: "github-actions".into(),
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            vec![],
+        );
 
-            svc.expect_check_runs_list()
-                .once()
-                .withf(|owner, name, sha| owner == "me" && name == "test" && sha == "abcdef")
-                .return_once(|_, _, _| Ok(vec![]));
+        let report = DetermineChecksStatus
+            .run(&ctx.as_context(), &build_repository(), "abcdef", false)
+            .await
+            .unwrap();
 
-            svc
-        };
+        assert_eq!(report.status, ChecksStatus::Skipped);
+    }
 
-        let status = DetermineChecksStatus
-            .run(&ctx.as_context(), &("me", "test").into(), "abcdef", false)
+    #[tokio::test]
+    async fn no_runs_and_commit_statuses() {
+        let mut ctx = CoreContextTest::new();
+        ctx.api_service = build_api_service(
+            vec![],
+            vec![GhCommitStatusItem {
+                context: "ci/jenkins".into(),
+                state: GhCommitStatusState::Failure,
+                ..Default::default()
+            }],
+        );
+
+        let report = DetermineChecksStatus
+            .run(&ctx.as_context(), &build_repository(), "abcdef", true)
             .await
             .unwrap();
 
-        assert_eq!(status, ChecksStatus::Skipped);
+        assert_eq!(report.status, ChecksStatus::Fail);
     }
 
     #[tokio::test]
-    async fn runs() {
+    async fn runs_from_custom_app() {
         let mut ctx = CoreContextTest::new();
-        ctx.api_service = {
-            let mut svc = MockApiService::new();
-
-            svc.expect_check_runs_list()
-                .once()
-                .withf(|owner, name, sha| owner == "me" && name == "test" && sha == "abcdef")
-                .return_once(|_, _, _| {
-                    Ok(vec![GhCheckRun {
-                        name: "dummy".into(),
-                        app: GhApplication {
-                            owner: GhUser {
-                                login: "github-actions".into(),
-                            },
-                            ..Default::default()
-                        },
-                        ..Default::default()
-                    }])
-                });
-
-            svc
-        };
+        ctx.api_service = build_api_service(
+            vec![GhCheckRun {
+                name: "build".into(),
+                conclusion: Some(GhCheckConclusion::Success),
+                app: GhApplication {
+                    slug: "circleci-checks".into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            vec![],
+        );
 
-        let status = DetermineChecksStatus
-            .run(&ctx.as_context(), &("me", "test").into(), "abcdef", false)
+        let report = DetermineChecksStatus
+            .run(
+                &ctx.as_context(),
+                &Repository {
+                    ci_app_slugs: vec!["circleci-checks".into()],
+                    ..build_repository()
+                },
+                "abcdef",
+                true,
+            )
             .await
             .unwrap();
 
-        assert_eq!(status, ChecksStatus::Skipped);
+        assert_eq!(report.status, ChecksStatus::Pass);
+    }
+
+    #[test]
+    fn required_and_optional_checks() {
+        let repository = Repository {
+            required_checks: vec!["test (*)".into(), "build".into()],
+            optional_checks: vec!["lint*".into()],
+            ..Default::default()
+        };
+
+        // Missing required checks
+        let report = evaluate_checks(
+            &repository,
+            &[("test (linux)".into(), ChecksStatus::Pass)],
+            false,
+        );
+        assert_eq!(report.status, ChecksStatus::Waiting);
+        assert_eq!(
+            report.required_checks,
+            vec![
+                RequiredCheckStatus {
+                    name: "test (*)".into(),
+                    status: ChecksStatus::Pass
+                },
+                RequiredCheckStatus {
+                    name: "build".into(),
+                    status: ChecksStatus::Waiting
+                }
+            ]
+        );
+
+        // Failing optional checks are ignored
+        let report = evaluate_checks(
+            &repository,
+            &[
+                ("test (linux)".into(), ChecksStatus::Pass),
+                ("test (macos)".into(), ChecksStatus::Pass),
+                ("build".into(), ChecksStatus::Pass),
+                ("lint".into(), ChecksStatus::Fail),
+            ],
+            false,
+        );
+        assert_eq!(report.status, ChecksStatus::Pass);
+
+        // Other checks still count
+        let report = evaluate_checks(
+            &repository,
+            &[
+                ("test (linux)".into(), ChecksStatus::Pass),
+                ("build".into(), ChecksStatus::Pass),
+                ("deploy".into(), ChecksStatus::Fail),
+            ],
+            false,
+        );
+        assert_eq!(report.status, ChecksStatus::Fail);
+
+        // One failing required check
+        let report = evaluate_checks(
+            &repository,
+            &[
+                ("test (linux)".into(), ChecksStatus::Pass),
+                ("test (macos)".into(), ChecksStatus::Fail),
+                ("build".into(), ChecksStatus::Skipped),
+            ],
+            false,
+        );
+        assert_eq!(report.status, ChecksStatus::Fail);
+        assert_eq!(report.required_checks[0].status, ChecksStatus::Fail);
+        assert_eq!(report.required_checks[1].status, ChecksStatus::Skipped);
+    }
+
+    #[test]
+    fn merge() {
+        use ChecksStatus::*;
+
+        assert_eq!(merge_statuses([].into_iter()), None);
+        assert_eq!(merge_statuses([Skipped].into_iter()), None);
+        assert_eq!(merge_statuses([Skipped, Pass].into_iter()), Some(Pass));
+        assert_eq!(merge_statuses([Pass, Waiting].into_iter()), Some(Waiting));
+        assert_eq!(merge_statuses([Waiting, Fail].into_iter()), Some(Fail));
+        assert_eq!(merge_statuses([Fail, Pass].into_iter()), Some(Fail));
     }
 
     #[test]
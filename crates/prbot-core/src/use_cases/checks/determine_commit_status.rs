@@ -5,7 +5,10 @@ use prbot_ghapi_interface::types::{GhCommitStatusItem, GhCommitStatusState};
 use prbot_models::{ChecksStatus, RepositoryPath};
 use shaku::{Component, Interface};
 
-use crate::{CoreContext, Result};
+use crate::{
+    use_cases::status::utils::{CONFIGURATION_STATUS_MESSAGE, VALIDATION_STATUS_MESSAGE},
+    CoreContext, Result,
+};
 
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
@@ -51,7 +54,6 @@ impl DetermineCommitStatusInterface for DetermineCommitStatus {
                 Ok(ChecksStatus::Skipped)
             }
         } else {
-            // TODO
             Ok(filter_and_merge_statuses(
                 &commit_statuses.items,
                 wait_for_initial_checks,
@@ -69,11 +71,13 @@ fn filter_and_merge_statuses(
     merge_statuses(&filtered, wait_for_initial_checks)
 }
 
-/// Filter last check runs, using the name of the check run to dedupe.
-fn filter_statuses(check_runs: &[GhCommitStatusItem]) -> Vec<GhCommitStatusItem> {
+/// Filter last statuses, using the context of the status to dedupe.
+pub(super) fn filter_statuses(status_items: &[GhCommitStatusItem]) -> Vec<GhCommitStatusItem> {
     let mut map: HashMap<String, GhCommitStatusItem> = HashMap::new();
-    // Ignore prbot status
-    for item in check_runs.iter().filter(|s| s.context != "Validation") {
+    // Ignore prbot statuses
+    for item in status_items.iter().filter(|s| {
+        s.context != VALIDATION_STATUS_MESSAGE && s.context != CONFIGURATION_STATUS_MESSAGE
+    }) {
         if let Entry::Vacant(e) = map.entry(item.context.clone()) {
             e.insert(item.clone());
         } else {
@@ -115,3 +119,73 @@ fn merge_statuses(
         })
         .unwrap_or(initial)
 }
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration, OffsetDateTime};
+
+    use super::*;
+
+    #[test]
+    fn merge_commit_statuses() {
+        // No status
+        assert_eq!(filter_and_merge_statuses(&[], false), ChecksStatus::Skipped);
+        assert_eq!(filter_and_merge_statuses(&[], true), ChecksStatus::Waiting);
+
+        // Ignore prbot status
+        assert_eq!(
+            filter_and_merge_statuses(
+                &[GhCommitStatusItem {
+                    context: VALIDATION_STATUS_MESSAGE.into(),
+                    state: GhCommitStatusState::Failure,
+                    ..Default::default()
+                }],
+                false
+            ),
+            ChecksStatus::Skipped
+        );
+
+        // Pending
+        assert_eq!(
+            filter_and_merge_statuses(
+                &[
+                    GhCommitStatusItem {
+                        context: "ci/jenkins".into(),
+                        state: GhCommitStatusState::Success,
+                        ..Default::default()
+                    },
+                    GhCommitStatusItem {
+                        context: "ci/other".into(),
+                        state: GhCommitStatusState::Pending,
+                        ..Default::default()
+                    }
+                ],
+                false
+            ),
+            ChecksStatus::Waiting
+        );
+
+        // Last status for a context wins
+        let now = OffsetDateTime::now_utc();
+        assert_eq!(
+            filter_and_merge_statuses(
+                &[
+                    GhCommitStatusItem {
+                        context: "ci/jenkins".into(),
+                        state: GhCommitStatusState::Success,
+                        updated_at: now + Duration::hours(1),
+                        ..Default::default()
+                    },
+                    GhCommitStatusItem {
+                        context: "ci/jenkins".into(),
+                        state: GhCommitStatusState::Error,
+                        updated_at: now,
+                        ..Default::default()
+                    }
+                ],
+                false
+            ),
+            ChecksStatus::Pass
+        );
+    }
+}
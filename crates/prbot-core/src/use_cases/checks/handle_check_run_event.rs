@@ -0,0 +1,210 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::{GhCheckRunAction, GhCheckRunEvent};
+use shaku::{Component, Interface};
+
+use super::utils::{
+    is_repository_ci_app, update_merge_queue_from_checks, update_pull_request_status_from_checks,
+};
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait HandleCheckRunEventInterface: Interface {
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhCheckRunEvent) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = HandleCheckRunEventInterface)]
+pub(crate) struct HandleCheckRunEvent;
+
+#[async_trait]
+impl HandleCheckRunEventInterface for HandleCheckRunEvent {
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            action = ?event.action,
+            repository_path = %event.repository.full_name,
+            name = %event.check_run.name,
+            head_sha = %event.check_run.head_sha,
+            app_slug = %event.check_run.app.slug,
+            status = ?event.check_run.status,
+            conclusion = ?event.check_run.conclusion
+        )
+    )]
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhCheckRunEvent) -> Result<()> {
+        // Skip checks from other apps
+        if !is_repository_ci_app(ctx, &event.repository, &event.check_run.app.slug).await? {
+            return Ok(());
+        }
+
+        // Requested actions do not change the check run status
+        if event.action == GhCheckRunAction::RequestedAction {
+            return Ok(());
+        }
+
+        // Only look for first PR
+        if let Some(gh_pr) = event.check_run.pull_requests.first() {
+            update_pull_request_status_from_checks(
+                ctx,
+                &event.repository,
+                gh_pr,
+                &event.check_run.head_sha,
+            )
+            .await?;
+        } else {
+            // Staging commits from the merge queue have no pull request
+            update_merge_queue_from_checks(ctx, &event.repository, &event.check_run.head_sha)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_database_memory::MemoryDb;
+    use prbot_ghapi_interface::{
+        types::{
+            GhApplication, GhBranch, GhBranchShort, GhCheckConclusion, GhCheckRun, GhPullRequest,
+            GhPullRequestShort, GhRepository, GhUser,
+        },
+        MockApiService,
+    };
+    use prbot_models::{PullRequest, Repository};
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::status::{
+            MockUpdatePullRequestStatusInterface, UpdatePullRequestStatusInterface,
+        },
+        CoreModule,
+    };
+
+    fn failed_check_run_event(app_slug: &str) -> GhCheckRunEvent {
+        GhCheckRunEvent {
+            check_run: GhCheckRun {
+                name: "lint".into(),
+                head_sha: "abcdef".into(),
+                conclusion: Some(GhCheckConclusion::Failure),
+                pull_requests: vec![GhPullRequestShort {
+                    number: 1,
+                    head: GhBranchShort {
+                        sha: "abcdef".into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }],
+                app: GhApplication {
+                    slug: app_slug.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            repository: GhRepository {
+                owner: GhUser { login: "me".into() },
+                name: "test".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn run_unknown_pr() {
+        let ctx = CoreContextTest::new();
+
+        HandleCheckRunEvent
+            .run(&ctx.as_context(), failed_check_run_event("github-actions"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_other_app() {
+        // No mock expectations: nothing should be called
+        let ctx = CoreContextTest::new();
+
+        HandleCheckRunEvent
+            .run(&ctx.as_context(), failed_check_run_event("other-ci"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_known_pr() {
+        let mut ctx = CoreContextTest::new();
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+
+            svc.expect_pulls_get()
+                .once()
+                .withf(|owner, name, number| owner == "me" && name == "test" && number == &1)
+                .return_once(|_, _, _| {
+                    Ok(GhPullRequest {
+                        number: 1,
+                        head: GhBranch {
+                            sha: "abcdef".into(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                });
+
+            svc
+        };
+
+        ctx.db_service = {
+            let svc = MemoryDb::new();
+
+            let repo = svc
+                .repositories_create(Repository {
+                    owner: "me".into(),
+                    name: "test".into(),
+                    default_enable_checks: true,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            svc.pull_requests_create(
+                PullRequest {
+                    number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+
+            svc
+        };
+
+        let update_pull_request_status = {
+            let mut mock = MockUpdatePullRequestStatusInterface::new();
+
+            mock.expect_run()
+                .once()
+                .withf(|_, pr_handle, upstream_pr| {
+                    pr_handle == &("me", "test", 1).into() && upstream_pr.number == 1
+                })
+                .return_once(|_, _, _| Ok(()));
+
+            mock
+        };
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn UpdatePullRequestStatusInterface>(Box::new(
+                update_pull_request_status,
+            ))
+            .build();
+
+        HandleCheckRunEvent
+            .run(&ctx.as_context(), failed_check_run_event("github-actions"))
+            .await
+            .unwrap();
+    }
+}
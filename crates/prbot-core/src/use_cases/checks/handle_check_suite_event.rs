@@ -1,8 +1,11 @@
 use async_trait::async_trait;
 use prbot_ghapi_interface::types::GhCheckSuiteEvent;
-use shaku::{Component, HasComponent, Interface};
+use shaku::{Component, Interface};
 
-use crate::{use_cases::status::UpdatePullRequestStatusInterface, CoreContext, Result};
+use super::utils::{
+    is_repository_ci_app, update_merge_queue_from_checks, update_pull_request_status_from_checks,
+};
+use crate::{CoreContext, Result};
 
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
@@ -29,49 +32,24 @@ impl HandleCheckSuiteEventInterface for HandleCheckSuiteEvent {
         )
     )]
     async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhCheckSuiteEvent) -> Result<()> {
-        let repo_owner = &event.repository.owner.login;
-        let repo_name = &event.repository.name;
+        // Skip checks from other apps
+        if !is_repository_ci_app(ctx, &event.repository, &event.check_suite.app.slug).await? {
+            return Ok(());
+        }
 
         // Only look for first PR
         if let Some(gh_pr) = event.check_suite.pull_requests.first() {
-            let pr_number = gh_pr.number;
-
-            if let Some(pr_model) = ctx
-                .db_service
-                .pull_requests_get(repo_owner, repo_name, pr_number)
-                .await?
-            {
-                // Skip non Github Actions checks
-                if event.check_suite.app.slug != "github-actions" {
-                    return Ok(());
-                }
-
-                // Skip non up-to-date checks
-                if event.check_suite.head_sha != gh_pr.head.sha {
-                    return Ok(());
-                }
-
-                // Skip if checks are skipped
-                if !pr_model.checks_enabled {
-                    return Ok(());
-                }
-
-                let upstream_pr = ctx
-                    .api_service
-                    .pulls_get(repo_owner, repo_name, pr_number)
-                    .await?;
-
-                // Update status
-                let update_pull_request_status: &dyn UpdatePullRequestStatusInterface =
-                    ctx.core_module.resolve_ref();
-                update_pull_request_status
-                    .run(
-                        ctx,
-                        &(repo_owner.as_str(), repo_name.as_str(), pr_number).into(),
-                        &upstream_pr,
-                    )
-                    .await?;
-            }
+            update_pull_request_status_from_checks(
+                ctx,
+                &event.repository,
+                gh_pr,
+                &event.check_suite.head_sha,
+            )
+            .await?;
+        } else {
+            // Staging commits from the merge queue have no pull request
+            update_merge_queue_from_checks(ctx, &event.repository, &event.check_suite.head_sha)
+                .await?;
         }
 
         Ok(())
@@ -93,7 +71,10 @@ mod tests {
 
     use super::*;
     use crate::{
-        context::tests::CoreContextTest, use_cases::status::MockUpdatePullRequestStatusInterface,
+        context::tests::CoreContextTest,
+        use_cases::status::{
+            MockUpdatePullRequestStatusInterface, UpdatePullRequestStatusInterface,
+        },
         CoreModule,
     };
 
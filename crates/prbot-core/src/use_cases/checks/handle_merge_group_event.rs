@@ -0,0 +1,229 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::{GhMergeGroupAction, GhMergeGroupEvent};
+use prbot_models::PullRequestHandle;
+use shaku::{Component, HasComponent, Interface};
+
+use crate::{
+    use_cases::status::{BuildPullRequestStatusInterface, StatusMessageGenerator},
+    CoreContext, Result,
+};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait HandleMergeGroupEventInterface: Interface {
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhMergeGroupEvent) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = HandleMergeGroupEventInterface)]
+pub(crate) struct HandleMergeGroupEvent;
+
+#[async_trait]
+impl HandleMergeGroupEventInterface for HandleMergeGroupEvent {
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            repository_path = %event.repository.full_name,
+            head_ref = %event.merge_group.head_ref,
+            head_sha = %event.merge_group.head_sha,
+            action = ?event.action
+        )
+    )]
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhMergeGroupEvent) -> Result<()> {
+        // Merged or invalidated groups do not need a status
+        if event.action != GhMergeGroupAction::ChecksRequested {
+            return Ok(());
+        }
+
+        let repo_owner = &event.repository.owner.login;
+        let repo_name = &event.repository.name;
+        let pr_number = match event.merge_group.pull_request_number() {
+            Some(n) => n,
+            None => return Ok(()),
+        };
+
+        if ctx
+            .db_service
+            .pull_requests_get(repo_owner, repo_name, pr_number)
+            .await?
+            .is_none()
+        {
+            return Ok(());
+        }
+
+        // The merge group commit needs the validation status to pass the required checks
+        let pr_handle: PullRequestHandle =
+            (repo_owner.as_str(), repo_name.as_str(), pr_number).into();
+        let upstream_pr = ctx
+            .api_service
+            .pulls_get(repo_owner, repo_name, pr_number)
+            .await?;
+
+        let build_pull_request_status: &dyn BuildPullRequestStatusInterface =
+            ctx.core_module.resolve_ref();
+        let pr_status = build_pull_request_status
+            .run(ctx, &pr_handle, &upstream_pr)
+            .await?;
+
+        let status_message = StatusMessageGenerator::default().generate(&pr_status)?;
+        ctx.api_service
+            .commit_statuses_update(
+                repo_owner,
+                repo_name,
+                &event.merge_group.head_sha,
+                status_message.state,
+                status_message.title,
+                &status_message.message,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::{
+        types::{GhCommitStatusState, GhMergeGroup, GhPullRequest, GhRepository, GhUser},
+        MockApiService,
+    };
+    use prbot_models::{PullRequest, QaStatus, Repository};
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::status::{MockBuildPullRequestStatusInterface, PullRequestStatus},
+        CoreModule,
+    };
+
+    fn merge_group_event(action: GhMergeGroupAction, head_ref: &str) -> GhMergeGroupEvent {
+        GhMergeGroupEvent {
+            action,
+            merge_group: GhMergeGroup {
+                head_sha: "abcdef".into(),
+                head_ref: head_ref.into(),
+                base_sha: "012345".into(),
+                base_ref: "refs/heads/main".into(),
+            },
+            repository: GhRepository {
+                owner: GhUser { login: "me".into() },
+                name: "test".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn run_destroyed() {
+        // No mock expectations: nothing should be called
+        let ctx = CoreContextTest::new();
+
+        HandleMergeGroupEvent
+            .run(
+                &ctx.as_context(),
+                merge_group_event(
+                    GhMergeGroupAction::Destroyed,
+                    "refs/heads/gh-readonly-queue/main/pr-1-abcdef",
+                ),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_unknown_pr() {
+        let ctx = CoreContextTest::new();
+
+        HandleMergeGroupEvent
+            .run(
+                &ctx.as_context(),
+                merge_group_event(
+                    GhMergeGroupAction::ChecksRequested,
+                    "refs/heads/gh-readonly-queue/main/pr-1-abcdef",
+                ),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_known_pr() {
+        let mut ctx = CoreContextTest::new();
+
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .pull_requests_create(
+                PullRequest {
+                    number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+
+            svc.expect_pulls_get()
+                .once()
+                .withf(|owner, name, number| owner == "me" && name == "test" && number == &1)
+                .return_once(|_, _, _| Ok(GhPullRequest::default()));
+            svc.expect_commit_statuses_update()
+                .once()
+                .withf(|owner, name, sha, state, _, _| {
+                    owner == "me"
+                        && name == "test"
+                        && sha == "abcdef"
+                        && *state == GhCommitStatusState::Success
+                })
+                .return_once(|_, _, _, _, _, _| Ok(()));
+
+            svc
+        };
+
+        let build_pull_request_status = {
+            let mut mock = MockBuildPullRequestStatusInterface::new();
+            mock.expect_run()
+                .once()
+                .withf(|_, pr_handle, _| pr_handle == &("me", "test", 1).into())
+                .return_once(|_, _, _| {
+                    Ok(PullRequestStatus {
+                        valid_pr_title: true,
+                        mergeable: true,
+                        qa_status: QaStatus::Skipped,
+                        ..Default::default()
+                    })
+                });
+
+            mock
+        };
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn BuildPullRequestStatusInterface>(Box::new(
+                build_pull_request_status,
+            ))
+            .build();
+
+        HandleMergeGroupEvent
+            .run(
+                &ctx.as_context(),
+                merge_group_event(
+                    GhMergeGroupAction::ChecksRequested,
+                    "refs/heads/gh-readonly-queue/main/pr-1-abcdef",
+                ),
+            )
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhStatusEvent;
+use shaku::{Component, Interface};
+
+use super::utils::{update_merge_queue_from_checks, update_pull_request_status_from_checks};
+use crate::{
+    use_cases::status::utils::{CONFIGURATION_STATUS_MESSAGE, VALIDATION_STATUS_MESSAGE},
+    CoreContext, Result,
+};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait HandleStatusEventInterface: Interface {
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhStatusEvent) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = HandleStatusEventInterface)]
+pub(crate) struct HandleStatusEvent;
+
+#[async_trait]
+impl HandleStatusEventInterface for HandleStatusEvent {
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            repository_path = %event.repository.full_name,
+            context = %event.context,
+            sha = %event.sha,
+            state = ?event.state
+        )
+    )]
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhStatusEvent) -> Result<()> {
+        // Skip our own statuses
+        if event.context == VALIDATION_STATUS_MESSAGE
+            || event.context == CONFIGURATION_STATUS_MESSAGE
+        {
+            return Ok(());
+        }
+
+        // Status events do not contain pull requests
+        let gh_prs = ctx
+            .api_service
+            .commit_pulls_list(
+                &event.repository.owner.login,
+                &event.repository.name,
+                &event.sha,
+            )
+            .await?;
+
+        // Staging commits from the merge queue have no pull request
+        if gh_prs.is_empty() {
+            return update_merge_queue_from_checks(ctx, &event.repository, &event.sha).await;
+        }
+
+        for gh_pr in gh_prs {
+            update_pull_request_status_from_checks(ctx, &event.repository, &gh_pr, &event.sha)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_database_memory::MemoryDb;
+    use prbot_ghapi_interface::{
+        types::{
+            GhBranch, GhBranchShort, GhCommitStatusState, GhPullRequest, GhPullRequestShort,
+            GhRepository, GhUser,
+        },
+        MockApiService,
+    };
+    use prbot_models::{PullRequest, Repository};
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::status::{
+            MockUpdatePullRequestStatusInterface, UpdatePullRequestStatusInterface,
+        },
+        CoreModule,
+    };
+
+    fn failed_status_event(context: &str) -> GhStatusEvent {
+        GhStatusEvent {
+            sha: "abcdef".into(),
+            context: context.into(),
+            state: GhCommitStatusState::Failure,
+            repository: GhRepository {
+                owner: GhUser { login: "me".into() },
+                name: "test".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn run_own_status() {
+        // No mock expectations: nothing should be called
+        let ctx = CoreContextTest::new();
+
+        HandleStatusEvent
+            .run(
+                &ctx.as_context(),
+                failed_status_event(VALIDATION_STATUS_MESSAGE),
+            )
+            .await
+            .unwrap();
+        HandleStatusEvent
+            .run(
+                &ctx.as_context(),
+                failed_status_event(CONFIGURATION_STATUS_MESSAGE),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_known_pr() {
+        let mut ctx = CoreContextTest::new();
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+
+            svc.expect_commit_pulls_list()
+                .once()
+                .withf(|owner, name, sha| owner == "me" && name == "test" && sha == "abcdef")
+                .return_once(|_, _, _| {
+                    Ok(vec![
+                        GhPullRequestShort {
+                            number: 1,
+                            head: GhBranchShort {
+                                sha: "abcdef".into(),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        GhPullRequestShort {
+                            number: 2,
+                            head: GhBranchShort {
+                                sha: "abcdef".into(),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                    ])
+                });
+
+            svc.expect_pulls_get()
+                .once()
+                .withf(|owner, name, number| owner == "me" && name == "test" && number == &1)
+                .return_once(|_, _, _| {
+                    Ok(GhPullRequest {
+                        number: 1,
+                        head: GhBranch {
+                            sha: "abcdef".into(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                });
+
+            svc
+        };
+
+        ctx.db_service = {
+            let svc = MemoryDb::new();
+
+            let repo = svc
+                .repositories_create(Repository {
+                    owner: "me".into(),
+                    name: "test".into(),
+                    default_enable_checks: true,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            svc.pull_requests_create(
+                PullRequest {
+                    number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+
+            svc
+        };
+
+        let update_pull_request_status = {
+            let mut mock = MockUpdatePullRequestStatusInterface::new();
+
+            mock.expect_run()
+                .once()
+                .withf(|_, pr_handle, upstream_pr| {
+                    pr_handle == &("me", "test", 1).into() && upstream_pr.number == 1
+                })
+                .return_once(|_, _, _| Ok(()));
+
+            mock
+        };
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn UpdatePullRequestStatusInterface>(Box::new(
+                update_pull_request_status,
+            ))
+            .build();
+
+        HandleStatusEvent
+            .run(&ctx.as_context(), failed_status_event("ci/jenkins"))
+            .await
+            .unwrap();
+    }
+}
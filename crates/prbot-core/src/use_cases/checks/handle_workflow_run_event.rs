@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhWorkflowRunEvent;
+use shaku::{Component, Interface};
+
+use super::utils::{update_merge_queue_from_checks, update_pull_request_status_from_checks};
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait HandleWorkflowRunEventInterface: Interface {
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhWorkflowRunEvent) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = HandleWorkflowRunEventInterface)]
+pub(crate) struct HandleWorkflowRunEvent;
+
+#[async_trait]
+impl HandleWorkflowRunEventInterface for HandleWorkflowRunEvent {
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            action = ?event.action,
+            repository_path = %event.repository.full_name,
+            name = %event.workflow_run.name,
+            head_branch = %event.workflow_run.head_branch,
+            head_sha = %event.workflow_run.head_sha,
+            status = ?event.workflow_run.status,
+            conclusion = ?event.workflow_run.conclusion
+        )
+    )]
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhWorkflowRunEvent) -> Result<()> {
+        // Only look for first PR
+        if let Some(gh_pr) = event.workflow_run.pull_requests.first() {
+            update_pull_request_status_from_checks(
+                ctx,
+                &event.repository,
+                gh_pr,
+                &event.workflow_run.head_sha,
+            )
+            .await?;
+        } else {
+            // Staging commits from the merge queue have no pull request
+            update_merge_queue_from_checks(ctx, &event.repository, &event.workflow_run.head_sha)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_database_memory::MemoryDb;
+    use prbot_ghapi_interface::types::{
+        GhBranchShort, GhPullRequestShort, GhRepository, GhUser, GhWorkflowRun,
+    };
+    use prbot_models::{PullRequest, Repository};
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    #[tokio::test]
+    async fn run_outdated_sha() {
+        // No mock expectations: status should not be updated
+        let mut ctx = CoreContextTest::new();
+
+        ctx.db_service = {
+            let svc = MemoryDb::new();
+
+            let repo = svc
+                .repositories_create(Repository {
+                    owner: "me".into(),
+                    name: "test".into(),
+                    default_enable_checks: true,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            svc.pull_requests_create(
+                PullRequest {
+                    number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+
+            svc
+        };
+
+        HandleWorkflowRunEvent
+            .run(
+                &ctx.as_context(),
+                GhWorkflowRunEvent {
+                    workflow_run: GhWorkflowRun {
+                        head_sha: "old".into(),
+                        pull_requests: vec![GhPullRequestShort {
+                            number: 1,
+                            head: GhBranchShort {
+                                sha: "abcdef".into(),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    },
+                    repository: GhRepository {
+                        owner: GhUser { login: "me".into() },
+                        name: "test".into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+    }
+}
@@ -1,14 +1,29 @@
 pub(crate) mod determine_check_status;
 pub(crate) mod determine_commit_status;
+pub(crate) mod handle_check_run_event;
 pub(crate) mod handle_check_suite_event;
+pub(crate) mod handle_merge_group_event;
+pub(crate) mod handle_status_event;
+pub(crate) mod handle_workflow_run_event;
+mod utils;
 
-pub use determine_check_status::DetermineChecksStatusInterface;
+pub use determine_check_status::{
+    ChecksReport, DetermineChecksStatusInterface, RequiredCheckStatus,
+};
 pub use determine_commit_status::DetermineCommitStatusInterface;
+pub use handle_check_run_event::HandleCheckRunEventInterface;
 pub use handle_check_suite_event::HandleCheckSuiteEventInterface;
+pub use handle_merge_group_event::HandleMergeGroupEventInterface;
+pub use handle_status_event::HandleStatusEventInterface;
+pub use handle_workflow_run_event::HandleWorkflowRunEventInterface;
 
 #[cfg(any(test, feature = "testkit"))]
 pub use self::{
     determine_check_status::MockDetermineChecksStatusInterface,
     determine_commit_status::MockDetermineCommitStatusInterface,
+    handle_check_run_event::MockHandleCheckRunEventInterface,
     handle_check_suite_event::MockHandleCheckSuiteEventInterface,
+    handle_merge_group_event::MockHandleMergeGroupEventInterface,
+    handle_status_event::MockHandleStatusEventInterface,
+    handle_workflow_run_event::MockHandleWorkflowRunEventInterface,
 };
@@ -0,0 +1,86 @@
+use prbot_ghapi_interface::types::{GhPullRequestShort, GhRepository};
+use shaku::HasComponent;
+
+use crate::{
+    use_cases::{
+        merge_queue::HandleMergeQueueChecksInterface, status::UpdatePullRequestStatusInterface,
+    },
+    CoreContext, Result,
+};
+
+/// Check if check runs from an app are taken into account for a repository.
+///
+/// Unknown repositories use no app.
+pub(crate) async fn is_repository_ci_app<'a>(
+    ctx: &CoreContext<'a>,
+    repository: &GhRepository,
+    app_slug: &str,
+) -> Result<bool> {
+    Ok(ctx
+        .db_service
+        .repositories_get(&repository.owner.login, &repository.name)
+        .await?
+        .map(|repo| repo.is_ci_app(app_slug))
+        .unwrap_or(false))
+}
+
+/// Update a pull request status after a checks change on a commit.
+///
+/// Unknown pull requests, outdated commits and pull requests with disabled checks are skipped.
+pub(crate) async fn update_pull_request_status_from_checks<'a>(
+    ctx: &CoreContext<'a>,
+    repository: &GhRepository,
+    gh_pr: &GhPullRequestShort,
+    head_sha: &str,
+) -> Result<()> {
+    let repo_owner = &repository.owner.login;
+    let repo_name = &repository.name;
+    let pr_number = gh_pr.number;
+
+    if let Some(pr_model) = ctx
+        .db_service
+        .pull_requests_get(repo_owner, repo_name, pr_number)
+        .await?
+    {
+        // Skip non up-to-date checks
+        if head_sha != gh_pr.head.sha {
+            return Ok(());
+        }
+
+        // Skip if checks are skipped
+        if !pr_model.checks_enabled {
+            return Ok(());
+        }
+
+        let upstream_pr = ctx
+            .api_service
+            .pulls_get(repo_owner, repo_name, pr_number)
+            .await?;
+
+        // Update status
+        let update_pull_request_status: &dyn UpdatePullRequestStatusInterface =
+            ctx.core_module.resolve_ref();
+        update_pull_request_status
+            .run(
+                ctx,
+                &(repo_owner.as_str(), repo_name.as_str(), pr_number).into(),
+                &upstream_pr,
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Update the merge queue after a checks change on a commit without pull request.
+pub(crate) async fn update_merge_queue_from_checks<'a>(
+    ctx: &CoreContext<'a>,
+    repository: &GhRepository,
+    head_sha: &str,
+) -> Result<()> {
+    let handle_merge_queue_checks: &dyn HandleMergeQueueChecksInterface =
+        ctx.core_module.resolve_ref();
+    handle_merge_queue_checks
+        .run(ctx, &repository.owner.login, &repository.name, head_sha)
+        .await
+}
@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use prbot_models::{MergeQueueEntry, MergeQueueStatus, PullRequestHandle};
+use shaku::{Component, HasComponent, Interface};
+use time::OffsetDateTime;
+
+use super::ProcessMergeQueueInterface;
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait AddToMergeQueueInterface: Interface {
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        base_branch: &str,
+    ) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = AddToMergeQueueInterface)]
+pub(crate) struct AddToMergeQueue;
+
+#[async_trait]
+impl AddToMergeQueueInterface for AddToMergeQueue {
+    #[tracing::instrument(skip(self, ctx), fields(pr_handle = %pr_handle))]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        base_branch: &str,
+    ) -> Result<()> {
+        let repository = ctx
+            .db_service
+            .repositories_get_expect(pr_handle.owner(), pr_handle.name())
+            .await?;
+
+        match ctx
+            .db_service
+            .merge_queue_entries_get(pr_handle.owner(), pr_handle.name(), pr_handle.number())
+            .await?
+        {
+            // Already queued
+            Some(entry) if entry.is_active() => return Ok(()),
+            // Failed entries go back at the end of the queue
+            Some(entry) => {
+                ctx.db_service
+                    .merge_queue_entries_update(MergeQueueEntry {
+                        base_branch: base_branch.into(),
+                        status: MergeQueueStatus::Queued,
+                        staging_sha: None,
+                        queued_at: OffsetDateTime::now_utc(),
+                        ..entry
+                    })
+                    .await?;
+            }
+            None => {
+                ctx.db_service
+                    .merge_queue_entries_create(
+                        MergeQueueEntry {
+                            pull_request_number: pr_handle.number(),
+                            base_branch: base_branch.into(),
+                            ..Default::default()
+                        }
+                        .with_repository(&repository),
+                    )
+                    .await?;
+            }
+        }
+
+        let process_merge_queue: &dyn ProcessMergeQueueInterface = ctx.core_module.resolve_ref();
+        process_merge_queue.run(ctx, &repository, base_branch).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_models::Repository;
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest, use_cases::merge_queue::MockProcessMergeQueueInterface,
+        CoreModule,
+    };
+
+    async fn arrange() -> CoreContextTest {
+        let mut ctx = CoreContextTest::new();
+        ctx.db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let mut process_merge_queue = MockProcessMergeQueueInterface::new();
+        process_merge_queue
+            .expect_run()
+            .once()
+            .withf(|_, repository, base| repository.name == "test" && base == "main")
+            .return_once(|_, _, _| Ok(()));
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn ProcessMergeQueueInterface>(Box::new(
+                process_merge_queue,
+            ))
+            .build();
+
+        ctx
+    }
+
+    #[tokio::test]
+    async fn run_new() {
+        let ctx = arrange().await;
+
+        AddToMergeQueue
+            .run(&ctx.as_context(), &("me", "test", 1).into(), "main")
+            .await
+            .unwrap();
+
+        let entry = ctx
+            .db_service
+            .merge_queue_entries_get_expect("me", "test", 1)
+            .await
+            .unwrap();
+        assert_eq!(entry.base_branch, "main");
+        assert_eq!(entry.status, MergeQueueStatus::Queued);
+    }
+
+    #[tokio::test]
+    async fn run_failed() {
+        let ctx = arrange().await;
+        let repository = ctx
+            .db_service
+            .repositories_get_expect("me", "test")
+            .await
+            .unwrap();
+        ctx.db_service
+            .merge_queue_entries_create(
+                MergeQueueEntry {
+                    pull_request_number: 1,
+                    base_branch: "main".into(),
+                    status: MergeQueueStatus::Failed,
+                    ..Default::default()
+                }
+                .with_repository(&repository),
+            )
+            .await
+            .unwrap();
+
+        AddToMergeQueue
+            .run(&ctx.as_context(), &("me", "test", 1).into(), "main")
+            .await
+            .unwrap();
+
+        let entry = ctx
+            .db_service
+            .merge_queue_entries_get_expect("me", "test", 1)
+            .await
+            .unwrap();
+        assert_eq!(entry.status, MergeQueueStatus::Queued);
+    }
+}
@@ -0,0 +1,470 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::comments::CommentApi;
+use prbot_lock_interface::{using_lock, UsingLockResult};
+use prbot_models::{ChecksStatus, MergeQueueEntry, MergeQueueStatus, Repository};
+use shaku::{Component, HasComponent, Interface};
+use tracing::warn;
+
+use super::{
+    utils::{merge_queue_lock_name, MERGE_QUEUE_LOCK_TIMEOUT_MS},
+    ProcessMergeQueueInterface,
+};
+use crate::{use_cases::checks::DetermineChecksStatusInterface, CoreContext, DomainError, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait HandleMergeQueueChecksInterface: Interface {
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repo_owner: &str,
+        repo_name: &str,
+        commit_sha: &str,
+    ) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = HandleMergeQueueChecksInterface)]
+pub(crate) struct HandleMergeQueueChecks;
+
+#[async_trait]
+impl HandleMergeQueueChecksInterface for HandleMergeQueueChecks {
+    #[tracing::instrument(skip(self, ctx))]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repo_owner: &str,
+        repo_name: &str,
+        commit_sha: &str,
+    ) -> Result<()> {
+        let repository = match ctx
+            .db_service
+            .repositories_get(repo_owner, repo_name)
+            .await?
+        {
+            Some(r) => r,
+            None => return Ok(()),
+        };
+
+        let base_branch = match ctx
+            .db_service
+            .merge_queue_entries_list(repo_owner, repo_name)
+            .await?
+            .into_iter()
+            .find(|e| {
+                e.status == MergeQueueStatus::Testing
+                    && e.staging_sha.as_deref() == Some(commit_sha)
+            }) {
+            Some(e) => e.base_branch,
+            // Not a staging commit
+            None => return Ok(()),
+        };
+
+        // Concurrent check events on the same staging commit must be applied once
+        let lock_name = merge_queue_lock_name(repo_owner, repo_name, &base_branch);
+        let output = using_lock(
+            ctx.lock_service,
+            &lock_name,
+            MERGE_QUEUE_LOCK_TIMEOUT_MS,
+            || self.apply_checks(ctx, &repository, &base_branch, commit_sha),
+        )
+        .await?;
+
+        let batch_done = match output {
+            UsingLockResult::AlreadyLocked => {
+                return Err(DomainError::LockTimeout { name: lock_name })
+            }
+            UsingLockResult::Locked(result) => result?,
+        };
+
+        if batch_done {
+            let process_merge_queue: &dyn ProcessMergeQueueInterface =
+                ctx.core_module.resolve_ref();
+            process_merge_queue
+                .run(ctx, &repository, &base_branch)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl HandleMergeQueueChecks {
+    /// Apply the checks status of a staging commit on its batch.
+    ///
+    /// Returns `false` if the batch is still being tested, or was already handled.
+    async fn apply_checks(
+        &self,
+        ctx: &CoreContext<'_>,
+        repository: &Repository,
+        base_branch: &str,
+        commit_sha: &str,
+    ) -> Result<bool> {
+        let (repo_owner, repo_name) = (repository.owner.as_str(), repository.name.as_str());
+        let entries = ctx
+            .db_service
+            .merge_queue_entries_list(repo_owner, repo_name)
+            .await?;
+        let batch: Vec<_> = entries
+            .iter()
+            .filter(|e| {
+                e.status == MergeQueueStatus::Testing
+                    && e.staging_sha.as_deref() == Some(commit_sha)
+            })
+            .cloned()
+            .collect();
+
+        if batch.is_empty() {
+            return Ok(false);
+        }
+
+        let determine_checks_status: &dyn DetermineChecksStatusInterface =
+            ctx.core_module.resolve_ref();
+        let report = determine_checks_status
+            .run(ctx, repository, commit_sha, true)
+            .await?;
+
+        match report.status {
+            ChecksStatus::Waiting => return Ok(false),
+            ChecksStatus::Pass | ChecksStatus::Skipped => {
+                let fast_forward = ctx
+                    .api_service
+                    .git_refs_update(
+                        repo_owner,
+                        repo_name,
+                        &format!("heads/{base_branch}"),
+                        commit_sha,
+                        false,
+                    )
+                    .await;
+
+                if let Err(e) = fast_forward {
+                    // Base branch moved since the batch was built: test it again
+                    warn!(
+                        repository_path = %repository.path(),
+                        base_branch = %base_branch,
+                        error = %e,
+                        message = "Could not fast-forward base branch from staging branch"
+                    );
+
+                    for entry in batch {
+                        ctx.db_service
+                            .merge_queue_entries_update(MergeQueueEntry {
+                                status: MergeQueueStatus::Queued,
+                                staging_sha: None,
+                                ..entry
+                            })
+                            .await?;
+                    }
+                } else {
+                    for entry in batch {
+                        ctx.db_service
+                            .merge_queue_entries_delete(
+                                repo_owner,
+                                repo_name,
+                                entry.pull_request_number,
+                            )
+                            .await?;
+
+                        CommentApi::post_comment(
+                            ctx.config,
+                            ctx.api_service,
+                            repo_owner,
+                            repo_name,
+                            entry.pull_request_number,
+                            &format!(
+                                "Pull request merged into `{base_branch}` from the merge queue."
+                            ),
+                        )
+                        .await?;
+                    }
+                }
+            }
+            ChecksStatus::Fail => {
+                // Previous suspects go back in the queue
+                for entry in entries.iter().filter(|e| {
+                    e.base_branch == base_branch && e.status == MergeQueueStatus::Suspect
+                }) {
+                    ctx.db_service
+                        .merge_queue_entries_update(MergeQueueEntry {
+                            status: MergeQueueStatus::Queued,
+                            ..entry.clone()
+                        })
+                        .await?;
+                }
+
+                if batch.len() == 1 {
+                    let entry = batch.into_iter().next().unwrap();
+                    let number = entry.pull_request_number;
+                    ctx.db_service
+                        .merge_queue_entries_update(MergeQueueEntry {
+                            status: MergeQueueStatus::Failed,
+                            staging_sha: None,
+                            ..entry
+                        })
+                        .await?;
+
+                    CommentApi::post_comment(
+                        ctx.config,
+                        ctx.api_service,
+                        repo_owner,
+                        repo_name,
+                        number,
+                        &format!(
+                            "Pull request removed from the merge queue: checks failed when merged with `{base_branch}`."
+                        ),
+                    )
+                    .await?;
+                } else {
+                    // Bisect the batch to find the culprit
+                    for entry in batch {
+                        ctx.db_service
+                            .merge_queue_entries_update(MergeQueueEntry {
+                                status: MergeQueueStatus::Suspect,
+                                staging_sha: None,
+                                ..entry
+                            })
+                            .await?;
+                    }
+                }
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::MockApiService;
+    use prbot_models::Repository;
+    use time::OffsetDateTime;
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::{
+            checks::{ChecksReport, MockDetermineChecksStatusInterface},
+            merge_queue::{utils::tests::expect_merge_queue_lock, MockProcessMergeQueueInterface},
+        },
+        CoreModule,
+    };
+
+    async fn arrange(ctx: &CoreContextTest, entries: &[(u64, MergeQueueStatus, Option<&str>)]) {
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        for (index, (number, status, sha)) in entries.iter().enumerate() {
+            ctx.db_service
+                .merge_queue_entries_create(
+                    MergeQueueEntry {
+                        pull_request_number: *number,
+                        base_branch: "main".into(),
+                        status: *status,
+                        staging_sha: sha.map(Into::into),
+                        queued_at: OffsetDateTime::from_unix_timestamp(index as i64).unwrap(),
+                        ..Default::default()
+                    }
+                    .with_repository(&repo),
+                )
+                .await
+                .unwrap();
+        }
+    }
+
+    fn core_module(status: ChecksStatus) -> CoreModule {
+        let mut determine_checks_status = MockDetermineChecksStatusInterface::new();
+        determine_checks_status
+            .expect_run()
+            .once()
+            .withf(|_, _, sha, wait| sha == "staging" && *wait)
+            .return_once(move |_, _, _, _| Ok(ChecksReport::from_status(status)));
+
+        let mut process_merge_queue = MockProcessMergeQueueInterface::new();
+        process_merge_queue
+            .expect_run()
+            .withf(|_, _, base| base == "main")
+            .returning(|_, _, _| Ok(()));
+
+        CoreModule::builder()
+            .with_component_override::<dyn DetermineChecksStatusInterface>(Box::new(
+                determine_checks_status,
+            ))
+            .with_component_override::<dyn ProcessMergeQueueInterface>(Box::new(
+                process_merge_queue,
+            ))
+            .build()
+    }
+
+    async fn statuses(ctx: &CoreContextTest) -> Vec<(u64, MergeQueueStatus)> {
+        ctx.db_service
+            .merge_queue_entries_list("me", "test")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| (e.pull_request_number, e.status))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn run_unknown_sha() {
+        let ctx = CoreContextTest::new();
+        arrange(&ctx, &[(1, MergeQueueStatus::Testing, Some("staging"))]).await;
+
+        HandleMergeQueueChecks
+            .run(&ctx.as_context(), "me", "test", "other")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_locked() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, false);
+        arrange(&ctx, &[(1, MergeQueueStatus::Testing, Some("staging"))]).await;
+
+        // The job will be retried
+        assert!(matches!(
+            HandleMergeQueueChecks
+                .run(&ctx.as_context(), "me", "test", "staging")
+                .await,
+            Err(DomainError::LockTimeout { name }) if name == "merge-queue-me-test-main"
+        ));
+        assert_eq!(statuses(&ctx).await, vec![(1, MergeQueueStatus::Testing)]);
+    }
+
+    #[tokio::test]
+    async fn run_pass() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, true);
+        arrange(
+            &ctx,
+            &[
+                (1, MergeQueueStatus::Testing, Some("staging")),
+                (2, MergeQueueStatus::Queued, None),
+            ],
+        )
+        .await;
+
+        ctx.core_module = core_module(ChecksStatus::Pass);
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_git_refs_update()
+                .once()
+                .withf(|_, _, reference, sha, force| {
+                    reference == "heads/main" && sha == "staging" && !force
+                })
+                .return_once(|_, _, _, _, _| Ok(()));
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, number, body| number == &1 && body.contains("merged"))
+                .return_once(|_, _, _, _| Ok(1));
+            svc
+        };
+
+        HandleMergeQueueChecks
+            .run(&ctx.as_context(), "me", "test", "staging")
+            .await
+            .unwrap();
+
+        assert_eq!(statuses(&ctx).await, vec![(2, MergeQueueStatus::Queued)]);
+    }
+
+    #[tokio::test]
+    async fn run_waiting() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, true);
+        arrange(&ctx, &[(1, MergeQueueStatus::Testing, Some("staging"))]).await;
+
+        let mut determine_checks_status = MockDetermineChecksStatusInterface::new();
+        determine_checks_status
+            .expect_run()
+            .once()
+            .return_once(|_, _, _, _| Ok(ChecksReport::from_status(ChecksStatus::Waiting)));
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn DetermineChecksStatusInterface>(Box::new(
+                determine_checks_status,
+            ))
+            .build();
+
+        HandleMergeQueueChecks
+            .run(&ctx.as_context(), "me", "test", "staging")
+            .await
+            .unwrap();
+
+        assert_eq!(statuses(&ctx).await, vec![(1, MergeQueueStatus::Testing)]);
+    }
+
+    #[tokio::test]
+    async fn run_fail_batch() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, true);
+        arrange(
+            &ctx,
+            &[
+                (1, MergeQueueStatus::Testing, Some("staging")),
+                (2, MergeQueueStatus::Testing, Some("staging")),
+                (3, MergeQueueStatus::Suspect, None),
+            ],
+        )
+        .await;
+
+        ctx.core_module = core_module(ChecksStatus::Fail);
+
+        HandleMergeQueueChecks
+            .run(&ctx.as_context(), "me", "test", "staging")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&ctx).await,
+            vec![
+                (1, MergeQueueStatus::Suspect),
+                (2, MergeQueueStatus::Suspect),
+                (3, MergeQueueStatus::Queued),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_fail_single() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, true);
+        arrange(
+            &ctx,
+            &[
+                (1, MergeQueueStatus::Testing, Some("staging")),
+                (2, MergeQueueStatus::Suspect, None),
+            ],
+        )
+        .await;
+
+        ctx.core_module = core_module(ChecksStatus::Fail);
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, number, body| number == &1 && body.contains("checks failed"))
+                .return_once(|_, _, _, _| Ok(1));
+            svc
+        };
+
+        HandleMergeQueueChecks
+            .run(&ctx.as_context(), "me", "test", "staging")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&ctx).await,
+            vec![(1, MergeQueueStatus::Failed), (2, MergeQueueStatus::Queued)]
+        );
+    }
+}
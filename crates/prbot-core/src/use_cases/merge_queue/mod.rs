@@ -0,0 +1,19 @@
+pub(crate) mod add_to_merge_queue;
+pub(crate) mod handle_merge_queue_checks;
+pub(crate) mod process_merge_queue;
+pub(crate) mod remove_from_merge_queue;
+mod utils;
+
+pub use add_to_merge_queue::AddToMergeQueueInterface;
+pub use handle_merge_queue_checks::HandleMergeQueueChecksInterface;
+pub use process_merge_queue::ProcessMergeQueueInterface;
+pub use remove_from_merge_queue::RemoveFromMergeQueueInterface;
+pub use utils::{get_merge_queue_position, staging_branch_name, MergeQueuePosition};
+
+#[cfg(any(test, feature = "testkit"))]
+pub use self::{
+    add_to_merge_queue::MockAddToMergeQueueInterface,
+    handle_merge_queue_checks::MockHandleMergeQueueChecksInterface,
+    process_merge_queue::MockProcessMergeQueueInterface,
+    remove_from_merge_queue::MockRemoveFromMergeQueueInterface,
+};
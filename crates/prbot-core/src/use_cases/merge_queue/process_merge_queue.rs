@@ -0,0 +1,531 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::{comments::CommentApi, types::GhPullRequestState, ApiError};
+use prbot_lock_interface::{using_lock, UsingLockResult};
+use prbot_models::{FrozenReason, MergeQueueEntry, MergeQueueStatus, Repository};
+use shaku::{Component, Interface};
+use time::OffsetDateTime;
+use tracing::warn;
+
+use super::utils::{merge_queue_lock_name, staging_branch_name, MERGE_QUEUE_LOCK_TIMEOUT_MS};
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait ProcessMergeQueueInterface: Interface {
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository: &Repository,
+        base_branch: &str,
+    ) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = ProcessMergeQueueInterface)]
+pub(crate) struct ProcessMergeQueue;
+
+#[async_trait]
+impl ProcessMergeQueueInterface for ProcessMergeQueue {
+    #[tracing::instrument(skip(self, ctx, repository), fields(repository_path = %repository.path()))]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository: &Repository,
+        base_branch: &str,
+    ) -> Result<()> {
+        let owner = &repository.owner;
+        let name = &repository.name;
+
+        // No new batch while merges are frozen
+        let freezes = ctx.db_service.merge_freezes_list(owner, name).await?;
+        let windows = ctx.db_service.merge_windows_list(owner, name).await?;
+        if FrozenReason::resolve(&freezes, &windows, base_branch, OffsetDateTime::now_utc())
+            .is_some()
+        {
+            return Ok(());
+        }
+
+        // Only one worker at a time can build batches on a base branch
+        let lock_name = merge_queue_lock_name(owner, name, base_branch);
+        let output = using_lock(
+            ctx.lock_service,
+            &lock_name,
+            MERGE_QUEUE_LOCK_TIMEOUT_MS,
+            || self.process_batches(ctx, repository, base_branch),
+        )
+        .await?;
+
+        match output {
+            UsingLockResult::AlreadyLocked => {
+                // The lock owner starts the next batch itself
+                warn!(
+                    repository_path = %repository.path(),
+                    base_branch = base_branch,
+                    message = "Merge queue is already being processed, skipping"
+                );
+                Ok(())
+            }
+            UsingLockResult::Locked(result) => result,
+        }
+    }
+}
+
+impl ProcessMergeQueue {
+    /// Start batches until one is being tested, or the queue is empty.
+    async fn process_batches(
+        &self,
+        ctx: &CoreContext<'_>,
+        repository: &Repository,
+        base_branch: &str,
+    ) -> Result<()> {
+        let owner = &repository.owner;
+        let name = &repository.name;
+
+        loop {
+            let entries: Vec<_> = ctx
+                .db_service
+                .merge_queue_entries_list(owner, name)
+                .await?
+                .into_iter()
+                .filter(|e| e.base_branch == base_branch)
+                .collect();
+
+            // Only one batch at a time
+            if entries
+                .iter()
+                .any(|e| e.status == MergeQueueStatus::Testing)
+            {
+                return Ok(());
+            }
+
+            let batch = select_batch(&entries);
+            if batch.is_empty() {
+                return Ok(());
+            }
+
+            let base_sha = match ctx
+                .api_service
+                .git_refs_get(owner, name, &format!("heads/{base_branch}"))
+                .await?
+            {
+                Some(r) => r.object.sha,
+                None => {
+                    warn!(
+                        repository_path = %repository.path(),
+                        base_branch = base_branch,
+                        message = "Unknown base branch for merge queue"
+                    );
+                    return Ok(());
+                }
+            };
+
+            // Reset staging branch on base
+            let staging_branch = staging_branch_name(base_branch);
+            let staging_ref = format!("heads/{staging_branch}");
+            if ctx
+                .api_service
+                .git_refs_get(owner, name, &staging_ref)
+                .await?
+                .is_some()
+            {
+                ctx.api_service
+                    .git_refs_update(owner, name, &staging_ref, &base_sha, true)
+                    .await?;
+            } else {
+                ctx.api_service
+                    .git_refs_create(owner, name, &staging_ref, &base_sha)
+                    .await?;
+            }
+
+            let mut staging_sha = base_sha;
+            let mut merged = vec![];
+            for entry in batch {
+                let upstream_pr = ctx
+                    .api_service
+                    .pulls_get(owner, name, entry.pull_request_number)
+                    .await?;
+
+                // Closed pull requests leave the queue
+                if upstream_pr.state != GhPullRequestState::Open {
+                    ctx.db_service
+                        .merge_queue_entries_delete(owner, name, entry.pull_request_number)
+                        .await?;
+                    continue;
+                }
+
+                match ctx
+                    .api_service
+                    .repository_merges_create(
+                        owner,
+                        name,
+                        &staging_branch,
+                        &upstream_pr.head.sha,
+                        &format!(
+                            "Merge pull request #{}: {}",
+                            upstream_pr.number, upstream_pr.title
+                        ),
+                    )
+                    .await
+                {
+                    Ok(sha) => {
+                        if let Some(sha) = sha {
+                            staging_sha = sha;
+                        }
+                        merged.push(entry);
+                    }
+                    Err(ApiError::MergeConflict { .. }) => {
+                        ctx.db_service
+                            .merge_queue_entries_update(MergeQueueEntry {
+                                status: MergeQueueStatus::Failed,
+                                staging_sha: None,
+                                ..entry
+                            })
+                            .await?;
+
+                        CommentApi::post_comment(
+                            ctx.config,
+                            ctx.api_service,
+                            owner,
+                            name,
+                            upstream_pr.number,
+                            &format!(
+                                "Pull request removed from the merge queue: it conflicts with `{base_branch}` or with other queued pull requests."
+                            ),
+                        )
+                        .await?;
+                    }
+                    Err(e) => return Err(e.into()),
+                }
+            }
+
+            if !merged.is_empty() {
+                for entry in merged {
+                    ctx.db_service
+                        .merge_queue_entries_update(MergeQueueEntry {
+                            status: MergeQueueStatus::Testing,
+                            staging_sha: Some(staging_sha.clone()),
+                            ..entry
+                        })
+                        .await?;
+                }
+
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Select the next batch to test.
+///
+/// Suspect entries come from a failed batch: test their first half to bisect.
+fn select_batch(entries: &[MergeQueueEntry]) -> Vec<MergeQueueEntry> {
+    let suspects: Vec<_> = entries
+        .iter()
+        .filter(|e| e.status == MergeQueueStatus::Suspect)
+        .cloned()
+        .collect();
+
+    if suspects.is_empty() {
+        entries
+            .iter()
+            .filter(|e| e.status == MergeQueueStatus::Queued)
+            .cloned()
+            .collect()
+    } else {
+        let size = suspects.len().div_ceil(2);
+        suspects.into_iter().take(size).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::{
+        types::{GhBranch, GhGitObject, GhGitRef, GhPullRequest},
+        MockApiService,
+    };
+    use prbot_models::MergeFreeze;
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::merge_queue::utils::tests::expect_merge_queue_lock,
+    };
+
+    fn git_ref(sha: &str) -> GhGitRef {
+        GhGitRef {
+            reference: "ref".into(),
+            object: GhGitObject { sha: sha.into() },
+        }
+    }
+
+    fn upstream_pr(number: u64) -> GhPullRequest {
+        GhPullRequest {
+            number,
+            title: format!("PR {number}"),
+            head: GhBranch {
+                sha: format!("head{number}"),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    async fn arrange(ctx: &CoreContextTest, entries: &[(u64, MergeQueueStatus)]) -> Repository {
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        for (index, (number, status)) in entries.iter().enumerate() {
+            ctx.db_service
+                .merge_queue_entries_create(
+                    MergeQueueEntry {
+                        pull_request_number: *number,
+                        base_branch: "main".into(),
+                        status: *status,
+                        queued_at: OffsetDateTime::from_unix_timestamp(index as i64).unwrap(),
+                        ..Default::default()
+                    }
+                    .with_repository(&repo),
+                )
+                .await
+                .unwrap();
+        }
+
+        repo
+    }
+
+    async fn statuses(ctx: &CoreContextTest) -> Vec<(u64, MergeQueueStatus, Option<String>)> {
+        ctx.db_service
+            .merge_queue_entries_list("me", "test")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| (e.pull_request_number, e.status, e.staging_sha))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn run_already_testing() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, true);
+        let repo = arrange(
+            &ctx,
+            &[
+                (1, MergeQueueStatus::Testing),
+                (2, MergeQueueStatus::Queued),
+            ],
+        )
+        .await;
+
+        ProcessMergeQueue
+            .run(&ctx.as_context(), &repo, "main")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_locked() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, false);
+        let repo = arrange(&ctx, &[(1, MergeQueueStatus::Queued)]).await;
+
+        // Another worker is building a batch
+        ProcessMergeQueue
+            .run(&ctx.as_context(), &repo, "main")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&ctx).await,
+            vec![(1, MergeQueueStatus::Queued, None)]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_frozen() {
+        let ctx = CoreContextTest::new();
+        let repo = arrange(&ctx, &[(1, MergeQueueStatus::Queued)]).await;
+        ctx.db_service
+            .merge_freezes_set(
+                MergeFreeze {
+                    base_branch: "main".into(),
+                    until: OffsetDateTime::now_utc() + time::Duration::hours(1),
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+
+        ProcessMergeQueue
+            .run(&ctx.as_context(), &repo, "main")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&ctx).await,
+            vec![(1, MergeQueueStatus::Queued, None)]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_batch() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, true);
+        let repo = arrange(
+            &ctx,
+            &[
+                (1, MergeQueueStatus::Queued),
+                (2, MergeQueueStatus::Queued),
+                (3, MergeQueueStatus::Failed),
+            ],
+        )
+        .await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_git_refs_get()
+                .withf(|_, _, reference| reference == "heads/main")
+                .return_once(|_, _, _| Ok(Some(git_ref("base"))));
+            svc.expect_git_refs_get()
+                .withf(|_, _, reference| reference == "heads/prbot-staging/main")
+                .return_once(|_, _, _| Ok(None));
+            svc.expect_git_refs_create()
+                .once()
+                .withf(|_, _, reference, sha| {
+                    reference == "heads/prbot-staging/main" && sha == "base"
+                })
+                .return_once(|_, _, _, _| Ok(()));
+            svc.expect_pulls_get()
+                .times(2)
+                .returning(|_, _, number| Ok(upstream_pr(number)));
+            svc.expect_repository_merges_create()
+                .once()
+                .withf(|_, _, base, head, message| {
+                    base == "prbot-staging/main"
+                        && head == "head1"
+                        && message == "Merge pull request #1: PR 1"
+                })
+                .return_once(|_, _, _, _, _| Ok(Some("merge1".into())));
+            svc.expect_repository_merges_create()
+                .once()
+                .withf(|_, _, _, head, _| head == "head2")
+                .return_once(|_, _, _, _, _| Ok(Some("merge2".into())));
+            svc
+        };
+
+        ProcessMergeQueue
+            .run(&ctx.as_context(), &repo, "main")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&ctx).await,
+            vec![
+                (1, MergeQueueStatus::Testing, Some("merge2".into())),
+                (2, MergeQueueStatus::Testing, Some("merge2".into())),
+                (3, MergeQueueStatus::Failed, None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_bisect_suspects() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, true);
+        let repo = arrange(
+            &ctx,
+            &[
+                (1, MergeQueueStatus::Suspect),
+                (2, MergeQueueStatus::Suspect),
+                (3, MergeQueueStatus::Suspect),
+                (4, MergeQueueStatus::Queued),
+            ],
+        )
+        .await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_git_refs_get()
+                .returning(|_, _, _| Ok(Some(git_ref("base"))));
+            svc.expect_git_refs_update()
+                .once()
+                .withf(|_, _, reference, sha, force| {
+                    reference == "heads/prbot-staging/main" && sha == "base" && *force
+                })
+                .return_once(|_, _, _, _, _| Ok(()));
+            svc.expect_pulls_get()
+                .times(2)
+                .returning(|_, _, number| Ok(upstream_pr(number)));
+            svc.expect_repository_merges_create()
+                .times(2)
+                .returning(|_, _, _, head, _| Ok(Some(format!("merge-{head}"))));
+            svc
+        };
+
+        ProcessMergeQueue
+            .run(&ctx.as_context(), &repo, "main")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&ctx).await,
+            vec![
+                (1, MergeQueueStatus::Testing, Some("merge-head2".into())),
+                (2, MergeQueueStatus::Testing, Some("merge-head2".into())),
+                (3, MergeQueueStatus::Suspect, None),
+                (4, MergeQueueStatus::Queued, None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_conflict() {
+        let mut ctx = CoreContextTest::new();
+        expect_merge_queue_lock(&mut ctx.lock_service, true);
+        let repo = arrange(&ctx, &[(1, MergeQueueStatus::Queued)]).await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_git_refs_get()
+                .returning(|_, _, _| Ok(Some(git_ref("base"))));
+            svc.expect_git_refs_update()
+                .once()
+                .return_once(|_, _, _, _, _| Ok(()));
+            svc.expect_pulls_get()
+                .once()
+                .return_once(|_, _, number| Ok(upstream_pr(number)));
+            svc.expect_repository_merges_create()
+                .once()
+                .return_once(|_, _, base, head, _| {
+                    Err(ApiError::MergeConflict {
+                        base: base.into(),
+                        head: head.into(),
+                        repository_path: "me/test".into(),
+                    })
+                });
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, number, body| number == &1 && body.contains("conflicts"))
+                .return_once(|_, _, _, _| Ok(1));
+            svc
+        };
+
+        ProcessMergeQueue
+            .run(&ctx.as_context(), &repo, "main")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            statuses(&ctx).await,
+            vec![(1, MergeQueueStatus::Failed, None)]
+        );
+    }
+}
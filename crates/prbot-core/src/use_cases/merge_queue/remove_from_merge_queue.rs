@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use prbot_models::{MergeQueueEntry, MergeQueueStatus, PullRequestHandle};
+use shaku::{Component, HasComponent, Interface};
+
+use super::ProcessMergeQueueInterface;
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait RemoveFromMergeQueueInterface: Interface {
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, pr_handle: &PullRequestHandle) -> Result<bool>;
+}
+
+#[derive(Component)]
+#[shaku(interface = RemoveFromMergeQueueInterface)]
+pub(crate) struct RemoveFromMergeQueue;
+
+#[async_trait]
+impl RemoveFromMergeQueueInterface for RemoveFromMergeQueue {
+    #[tracing::instrument(skip(self, ctx), fields(pr_handle = %pr_handle), ret)]
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, pr_handle: &PullRequestHandle) -> Result<bool> {
+        let (owner, name) = (pr_handle.owner(), pr_handle.name());
+        let entry = match ctx
+            .db_service
+            .merge_queue_entries_get(owner, name, pr_handle.number())
+            .await?
+        {
+            Some(e) => e,
+            None => return Ok(false),
+        };
+
+        ctx.db_service
+            .merge_queue_entries_delete(owner, name, pr_handle.number())
+            .await?;
+
+        // Cancel the running batch, without this pull request
+        if entry.status == MergeQueueStatus::Testing {
+            for other in ctx
+                .db_service
+                .merge_queue_entries_list(owner, name)
+                .await?
+                .into_iter()
+                .filter(|e| e.status == MergeQueueStatus::Testing)
+            {
+                ctx.db_service
+                    .merge_queue_entries_update(MergeQueueEntry {
+                        status: MergeQueueStatus::Queued,
+                        staging_sha: None,
+                        ..other
+                    })
+                    .await?;
+            }
+        }
+
+        let repository = ctx.db_service.repositories_get_expect(owner, name).await?;
+        let process_merge_queue: &dyn ProcessMergeQueueInterface = ctx.core_module.resolve_ref();
+        process_merge_queue
+            .run(ctx, &repository, &entry.base_branch)
+            .await?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_models::Repository;
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest, use_cases::merge_queue::MockProcessMergeQueueInterface,
+        CoreModule,
+    };
+
+    #[tokio::test]
+    async fn run_unknown() {
+        let ctx = CoreContextTest::new();
+
+        assert!(!RemoveFromMergeQueue
+            .run(&ctx.as_context(), &("me", "test", 1).into())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn run_testing() {
+        let mut ctx = CoreContextTest::new();
+        let repository = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        for number in [1, 2] {
+            ctx.db_service
+                .merge_queue_entries_create(
+                    MergeQueueEntry {
+                        pull_request_number: number,
+                        base_branch: "main".into(),
+                        status: MergeQueueStatus::Testing,
+                        staging_sha: Some("staging".into()),
+                        ..Default::default()
+                    }
+                    .with_repository(&repository),
+                )
+                .await
+                .unwrap();
+        }
+
+        let mut process_merge_queue = MockProcessMergeQueueInterface::new();
+        process_merge_queue
+            .expect_run()
+            .once()
+            .withf(|_, _, base| base == "main")
+            .return_once(|_, _, _| Ok(()));
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn ProcessMergeQueueInterface>(Box::new(
+                process_merge_queue,
+            ))
+            .build();
+
+        assert!(RemoveFromMergeQueue
+            .run(&ctx.as_context(), &("me", "test", 1).into())
+            .await
+            .unwrap());
+
+        let entries = ctx
+            .db_service
+            .merge_queue_entries_list("me", "test")
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].pull_request_number, 2);
+        assert_eq!(entries[0].status, MergeQueueStatus::Queued);
+        assert_eq!(entries[0].staging_sha, None);
+    }
+}
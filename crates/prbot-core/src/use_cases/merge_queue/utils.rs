@@ -0,0 +1,81 @@
+use prbot_models::{MergeQueueEntry, PullRequestHandle};
+
+use crate::{CoreContext, Result};
+
+/// Merge queue position for a pull request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeQueuePosition {
+    /// Queue entry.
+    pub entry: MergeQueueEntry,
+    /// Position in the base branch queue, starting at 1.
+    pub position: usize,
+    /// Active entries count in the base branch queue.
+    pub count: usize,
+}
+
+/// Timeout when waiting for the merge queue lock of a base branch.
+pub const MERGE_QUEUE_LOCK_TIMEOUT_MS: u64 = 10_000;
+
+/// Get the lock name serializing merge queue changes on a base branch.
+pub fn merge_queue_lock_name(owner: &str, name: &str, base_branch: &str) -> String {
+    format!("merge-queue-{owner}-{name}-{base_branch}")
+}
+
+/// Get the staging branch name used to test merges on a base branch.
+pub fn staging_branch_name(base_branch: &str) -> String {
+    format!("prbot-staging/{base_branch}")
+}
+
+/// Get the merge queue position of a pull request, if queued.
+pub async fn get_merge_queue_position<'a>(
+    ctx: &CoreContext<'a>,
+    pr_handle: &PullRequestHandle,
+) -> Result<Option<MergeQueuePosition>> {
+    let entries = ctx
+        .db_service
+        .merge_queue_entries_list(pr_handle.owner(), pr_handle.name())
+        .await?;
+
+    let entry = match entries
+        .iter()
+        .find(|e| e.pull_request_number == pr_handle.number())
+    {
+        Some(e) => e.clone(),
+        None => return Ok(None),
+    };
+
+    let base_entries: Vec<_> = entries
+        .iter()
+        .filter(|e| e.base_branch == entry.base_branch && e.is_active())
+        .collect();
+    let position = base_entries
+        .iter()
+        .position(|e| e.pull_request_number == entry.pull_request_number)
+        .map(|p| p + 1)
+        .unwrap_or(0);
+
+    Ok(Some(MergeQueuePosition {
+        entry,
+        position,
+        count: base_entries.len(),
+    }))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use prbot_lock_interface::{LockInstance, LockStatus, MockLockService};
+
+    /// Expect the merge queue lock of `me/test` on `main` to be requested.
+    pub fn expect_merge_queue_lock(lock_service: &mut MockLockService, available: bool) {
+        lock_service
+            .expect_wait_lock_resource()
+            .withf(|name, _| name == "merge-queue-me-test-main")
+            .returning(move |name, _| {
+                Ok(if available {
+                    LockStatus::SuccessfullyLocked(LockInstance::new_dummy(name))
+                } else {
+                    LockStatus::AlreadyLocked
+                })
+            });
+    }
+}
@@ -1,9 +1,12 @@
+pub mod audit;
 pub mod auth;
 pub mod checks;
 pub mod comments;
 pub mod gifs;
+pub mod merge_queue;
 pub mod pulls;
 pub mod repositories;
 pub mod reviews;
+pub mod stats;
 pub mod status;
 pub mod summary;
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use prbot_models::{PullRequestRule, Repository, RuleAction, RuleCondition};
 use shaku::{Component, Interface};
 
-use crate::{CoreContext, Result};
+use crate::{CoreContext, DomainError, Result};
 
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
@@ -14,6 +14,7 @@ pub trait AddPullRequestRuleInterface: Interface {
         name: String,
         conditions: Vec<RuleCondition>,
         actions: Vec<RuleAction>,
+        priority: i32,
     ) -> Result<PullRequestRule>;
 }
 
@@ -30,15 +31,113 @@ impl AddPullRequestRuleInterface for AddPullRequestRule {
         name: String,
         conditions: Vec<RuleCondition>,
         actions: Vec<RuleAction>,
+        priority: i32,
     ) -> Result<PullRequestRule> {
+        validate_rule_conditions(&name, &conditions)?;
+
         let rule = PullRequestRule {
             repository_id: repository.id,
             name,
             conditions,
             actions,
+            priority,
         };
 
         let rule = ctx.db_service.pull_request_rules_create(rule).await?;
         Ok(rule)
     }
 }
+
+/// Reject conditions with invalid patterns, as they would never match.
+pub(crate) fn validate_rule_conditions(name: &str, conditions: &[RuleCondition]) -> Result<()> {
+    for condition in conditions {
+        condition
+            .validate()
+            .map_err(|message| DomainError::InvalidPullRequestRule {
+                name: name.into(),
+                message,
+            })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    #[tokio::test]
+    async fn run_invalid_condition() {
+        let ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let result = AddPullRequestRule
+            .run(
+                &ctx.as_context(),
+                &repo,
+                "Invalid".into(),
+                vec![RuleCondition::Any(vec![RuleCondition::TitleMatches(
+                    "(".into(),
+                )])],
+                vec![RuleAction::SetAutomerge(true)],
+                0,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::InvalidPullRequestRule { name, .. }) if name == "Invalid"
+        ));
+        assert!(ctx
+            .db_service
+            .pull_request_rules_list("me", "test")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn run() {
+        let ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let rule = AddPullRequestRule
+            .run(
+                &ctx.as_context(),
+                &repo,
+                "Docs".into(),
+                vec![RuleCondition::ChangedFiles("docs/**".into())],
+                vec![RuleAction::SetAutomerge(true)],
+                0,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ctx.db_service
+                .pull_request_rules_list("me", "test")
+                .await
+                .unwrap(),
+            vec![rule]
+        );
+    }
+}
@@ -1,9 +1,12 @@
+use std::collections::HashSet;
+
 use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhPullRequest;
 use prbot_models::{PullRequestHandle, PullRequestRule, QaStatus, RuleAction};
-use shaku::{Component, Interface};
+use shaku::{Component, HasComponent, Interface};
 use tracing::info;
 
-use crate::{CoreContext, Result};
+use crate::{use_cases::reviews::AddReviewersInterface, CoreContext, Result};
 
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
@@ -12,6 +15,7 @@ pub trait ApplyPullRequestRulesInterface: Interface {
         &self,
         ctx: &CoreContext<'a>,
         pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
         rules: Vec<PullRequestRule>,
     ) -> Result<()>;
 }
@@ -26,12 +30,25 @@ impl ApplyPullRequestRulesInterface for ApplyPullRequestRules {
         &self,
         ctx: &CoreContext<'a>,
         pr_handle: &PullRequestHandle,
-        rules: Vec<PullRequestRule>,
+        upstream_pr: &GhPullRequest,
+        mut rules: Vec<PullRequestRule>,
     ) -> Result<()> {
-        for rule in rules {
-            for action in rule.actions {
-                self.apply_action(ctx, pr_handle, action).await?;
+        PullRequestRule::sort_by_priority(&mut rules);
+
+        for resolved in PullRequestRule::resolve_actions(&rules) {
+            if let Some(winner) = resolved.overridden_by {
+                info!(
+                    action = ?resolved.action,
+                    rule = %resolved.rule.name,
+                    winner = %winner,
+                    pr_handle = ?pr_handle,
+                    "Skipping overridden rule action"
+                );
+                continue;
             }
+
+            self.apply_action(ctx, pr_handle, upstream_pr, resolved.rule, resolved.action)
+                .await?;
         }
 
         Ok(())
@@ -43,7 +60,9 @@ impl ApplyPullRequestRules {
         &self,
         ctx: &CoreContext<'_>,
         pr_handle: &PullRequestHandle,
-        action: RuleAction,
+        upstream_pr: &GhPullRequest,
+        rule: &PullRequestRule,
+        action: &RuleAction,
     ) -> Result<()> {
         info!(
             action = ?action,
@@ -51,44 +70,31 @@ impl ApplyPullRequestRules {
             "Applying rule action"
         );
 
+        let (owner, name, number) = (pr_handle.owner(), pr_handle.name(), pr_handle.number());
+
         match action {
             RuleAction::SetAutomerge(value) => {
                 ctx.db_service
-                    .pull_requests_set_automerge(
-                        pr_handle.owner(),
-                        pr_handle.name(),
-                        pr_handle.number(),
-                        value,
-                    )
+                    .pull_requests_set_automerge(owner, name, number, *value)
                     .await?;
             }
             RuleAction::SetChecksEnabled(value) => {
                 ctx.db_service
-                    .pull_requests_set_checks_enabled(
-                        pr_handle.owner(),
-                        pr_handle.name(),
-                        pr_handle.number(),
-                        value,
-                    )
+                    .pull_requests_set_checks_enabled(owner, name, number, *value)
                     .await?;
             }
             RuleAction::SetNeededReviewers(value) => {
                 ctx.db_service
-                    .pull_requests_set_needed_reviewers_count(
-                        pr_handle.owner(),
-                        pr_handle.name(),
-                        pr_handle.number(),
-                        value,
-                    )
+                    .pull_requests_set_needed_reviewers_count(owner, name, number, *value)
                     .await?;
             }
             RuleAction::SetQaEnabled(value) => {
                 ctx.db_service
                     .pull_requests_set_qa_status(
-                        pr_handle.owner(),
-                        pr_handle.name(),
-                        pr_handle.number(),
-                        if value {
+                        owner,
+                        name,
+                        number,
+                        if *value {
                             QaStatus::Waiting
                         } else {
                             QaStatus::Skipped
@@ -96,19 +102,233 @@ impl ApplyPullRequestRules {
                     )
                     .await?;
             }
+            RuleAction::AddLabels(labels) => {
+                let missing: Vec<_> = labels
+                    .iter()
+                    .filter(|l| !upstream_pr.labels.iter().any(|u| &u.name == *l))
+                    .cloned()
+                    .collect();
+
+                if !missing.is_empty() {
+                    ctx.api_service
+                        .issue_labels_add(owner, name, number, &missing)
+                        .await?;
+                }
+            }
+            RuleAction::RemoveLabels(labels) => {
+                let present: Vec<_> = labels
+                    .iter()
+                    .filter(|l| upstream_pr.labels.iter().any(|u| &u.name == *l))
+                    .cloned()
+                    .collect();
+
+                if !present.is_empty() {
+                    ctx.api_service
+                        .issue_labels_remove(owner, name, number, &present)
+                        .await?;
+                }
+            }
+            RuleAction::RequestReviewers(reviewers) => {
+                let mut known: HashSet<_> = upstream_pr
+                    .requested_reviewers
+                    .iter()
+                    .map(|u| u.login.clone())
+                    .collect();
+                known.extend(
+                    ctx.api_service
+                        .pull_reviews_list(owner, name, number)
+                        .await?
+                        .into_iter()
+                        .map(|r| r.user.login),
+                );
+
+                let missing = self.missing_reviewers(upstream_pr, reviewers, &known);
+                self.add_reviewers(ctx, pr_handle, &missing, false).await?;
+            }
+            RuleAction::RequestRequiredReviewers(reviewers) => {
+                let known: HashSet<_> = ctx
+                    .db_service
+                    .required_reviewers_list(owner, name, number)
+                    .await?
+                    .into_iter()
+                    .map(|r| r.username)
+                    .collect();
+
+                let missing = self.missing_reviewers(upstream_pr, reviewers, &known);
+                self.add_reviewers(ctx, pr_handle, &missing, true).await?;
+            }
+            RuleAction::SetMergeStrategy(strategy) => {
+                ctx.db_service
+                    .pull_requests_set_strategy_override(owner, name, number, Some(*strategy))
+                    .await?;
+            }
+            RuleAction::Lock(reason) => {
+                let pr_model = ctx
+                    .db_service
+                    .pull_requests_get_expect(owner, name, number)
+                    .await?;
+
+                if !pr_model.locked {
+                    ctx.db_service
+                        .pull_requests_set_locked(owner, name, number, true)
+                        .await?;
+                    ctx.api_service
+                        .comments_post(
+                            owner,
+                            name,
+                            number,
+                            &format!(
+                                "Pull request **locked** by rule **{}**.\n**Reason**: {}.",
+                                rule.name, reason
+                            ),
+                        )
+                        .await?;
+                }
+            }
+            RuleAction::PostComment(template) => {
+                let marker = Self::comment_marker(rule);
+                let already_posted = ctx
+                    .api_service
+                    .comments_list(owner, name, number)
+                    .await?
+                    .iter()
+                    .any(|c| c.body.contains(&marker));
+
+                if !already_posted {
+                    let body = Self::render_comment(template, upstream_pr, rule);
+                    ctx.api_service
+                        .comments_post(owner, name, number, &format!("{body}\n\n{marker}"))
+                        .await?;
+                }
+            }
+            RuleAction::SetReviewerPool(_) => {
+                // Resolved when automatically assigning reviewers
+            }
         }
 
         Ok(())
     }
+
+    fn missing_reviewers(
+        &self,
+        upstream_pr: &GhPullRequest,
+        reviewers: &[String],
+        known: &HashSet<String>,
+    ) -> Vec<String> {
+        // The author cannot review its own pull request
+        reviewers
+            .iter()
+            .filter(|r| !known.contains(*r) && **r != upstream_pr.user.login)
+            .cloned()
+            .collect()
+    }
+
+    async fn add_reviewers(
+        &self,
+        ctx: &CoreContext<'_>,
+        pr_handle: &PullRequestHandle,
+        reviewers: &[String],
+        required: bool,
+    ) -> Result<()> {
+        if reviewers.is_empty() {
+            return Ok(());
+        }
+
+        let add_reviewers: &dyn AddReviewersInterface = ctx.core_module.resolve_ref();
+        add_reviewers
+            .run(ctx, pr_handle, reviewers, required)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Hidden marker used to post a rule comment only once.
+    fn comment_marker(rule: &PullRequestRule) -> String {
+        format!("<!-- prbot-rule: {} -->", rule.name)
+    }
+
+    fn render_comment(
+        template: &str,
+        upstream_pr: &GhPullRequest,
+        rule: &PullRequestRule,
+    ) -> String {
+        template
+            .replace("{author}", &upstream_pr.user.login)
+            .replace("{title}", &upstream_pr.title)
+            .replace("{number}", &upstream_pr.number.to_string())
+            .replace("{base}", &upstream_pr.base.reference)
+            .replace("{head}", &upstream_pr.head.reference)
+            .replace("{rule}", &rule.name)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use prbot_database_interface::DbService;
-    use prbot_models::{PullRequest, PullRequestRule, Repository, RuleAction, RuleCondition};
+    use prbot_ghapi_interface::{
+        review::GhReviewApi,
+        types::{GhIssueComment, GhLabel, GhPullRequest, GhUser},
+        MockApiService,
+    };
+    use prbot_models::{
+        MergeStrategy, PullRequest, PullRequestRule, Repository, RequiredReviewer, RuleAction,
+        RuleCondition,
+    };
 
     use super::{ApplyPullRequestRules, ApplyPullRequestRulesInterface};
-    use crate::context::tests::CoreContextTest;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::reviews::{AddReviewersInterface, FilteredReviewers, MockAddReviewersInterface},
+        CoreModule,
+    };
+
+    async fn create_pull_request(ctx: &CoreContextTest, locked: bool) -> PullRequest {
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        ctx.db_service
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                locked,
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+    }
+
+    fn rule(actions: Vec<RuleAction>) -> PullRequestRule {
+        PullRequestRule {
+            name: "Rule".into(),
+            actions,
+            ..Default::default()
+        }
+    }
+
+    fn upstream_pr() -> GhPullRequest {
+        GhPullRequest {
+            number: 1,
+            title: "Add feature".into(),
+            user: GhUser {
+                login: "author".into(),
+            },
+            labels: vec![GhLabel {
+                name: "a".into(),
+                ..Default::default()
+            }],
+            requested_reviewers: vec![GhUser {
+                login: "requested".into(),
+            }],
+            ..Default::default()
+        }
+    }
 
     #[tokio::test]
     async fn apply() {
@@ -142,6 +362,7 @@ mod tests {
                 name: "Rule 1".into(),
                 actions: vec![RuleAction::SetAutomerge(true)],
                 conditions: vec![RuleCondition::Author("me".into())],
+                ..Default::default()
             })
             .await
             .unwrap();
@@ -149,7 +370,12 @@ mod tests {
         let handle = ("me", "name", pr.number).into();
 
         ApplyPullRequestRules
-            .run(&ctx.as_context(), &handle, vec![rule1])
+            .run(
+                &ctx.as_context(),
+                &handle,
+                &GhPullRequest::default(),
+                vec![rule1],
+            )
             .await
             .unwrap();
 
@@ -162,4 +388,201 @@ mod tests {
 
         assert!(pr.automerge);
     }
+
+    #[tokio::test]
+    async fn apply_labels_and_strategy() {
+        let mut ctx = CoreContextTest::new();
+        create_pull_request(&ctx, false).await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_issue_labels_add()
+                .once()
+                .withf(|_, _, number, labels| number == &1 && labels == ["b".to_string()])
+                .return_once(|_, _, _, _| Ok(()));
+            svc.expect_issue_labels_remove()
+                .once()
+                .withf(|_, _, number, labels| number == &1 && labels == ["a".to_string()])
+                .return_once(|_, _, _, _| Ok(()));
+
+            svc
+        };
+
+        ApplyPullRequestRules
+            .run(
+                &ctx.as_context(),
+                &("me", "name", 1).into(),
+                &upstream_pr(),
+                vec![rule(vec![
+                    RuleAction::AddLabels(vec!["a".into(), "b".into()]),
+                    RuleAction::RemoveLabels(vec!["c".into(), "a".into()]),
+                    RuleAction::SetMergeStrategy(MergeStrategy::Squash),
+                ])],
+            )
+            .await
+            .unwrap();
+
+        let pr = ctx
+            .db_service
+            .pull_requests_get_expect("me", "name", 1)
+            .await
+            .unwrap();
+        assert_eq!(pr.strategy_override, Some(MergeStrategy::Squash));
+    }
+
+    #[tokio::test]
+    async fn apply_reviewers() {
+        let mut ctx = CoreContextTest::new();
+        let pr = create_pull_request(&ctx, false).await;
+        ctx.db_service
+            .required_reviewers_create(RequiredReviewer {
+                pull_request_id: pr.id,
+                username: "required".into(),
+            })
+            .await
+            .unwrap();
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_pull_reviews_list()
+                .once()
+                .return_once(|_, _, _| {
+                    Ok(vec![GhReviewApi {
+                        user: GhUser {
+                            login: "reviewed".into(),
+                        },
+                        ..Default::default()
+                    }])
+                });
+
+            svc
+        };
+
+        let add_reviewers = {
+            let mut mock = MockAddReviewersInterface::new();
+            mock.expect_run()
+                .once()
+                .withf(|_, _, reviewers, required| reviewers == ["new".to_string()] && !required)
+                .return_once(|_, _, reviewers, _| {
+                    Ok(FilteredReviewers {
+                        allowed: reviewers.to_vec(),
+                        rejected: vec![],
+                    })
+                });
+            mock.expect_run()
+                .once()
+                .withf(|_, _, reviewers, required| reviewers == ["other".to_string()] && *required)
+                .return_once(|_, _, reviewers, _| {
+                    Ok(FilteredReviewers {
+                        allowed: reviewers.to_vec(),
+                        rejected: vec![],
+                    })
+                });
+
+            mock
+        };
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn AddReviewersInterface>(Box::new(add_reviewers))
+            .build();
+
+        ApplyPullRequestRules
+            .run(
+                &ctx.as_context(),
+                &("me", "name", 1).into(),
+                &upstream_pr(),
+                vec![rule(vec![
+                    RuleAction::RequestReviewers(vec![
+                        "author".into(),
+                        "requested".into(),
+                        "reviewed".into(),
+                        "new".into(),
+                    ]),
+                    RuleAction::RequestRequiredReviewers(vec!["required".into(), "other".into()]),
+                    // Everyone is already requested, nothing is called
+                    RuleAction::RequestRequiredReviewers(vec!["required".into()]),
+                ])],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn apply_lock_and_comment() {
+        let mut ctx = CoreContextTest::new();
+        create_pull_request(&ctx, false).await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, _, body| {
+                    body == "Pull request **locked** by rule **Rule**.\n**Reason**: Release."
+                })
+                .return_once(|_, _, _, _| Ok(1));
+            svc.expect_comments_list()
+                .once()
+                .return_once(|_, _, _| Ok(vec![]));
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, _, body| {
+                    body == "Thanks **author** for #1 (Add feature)!\n\n<!-- prbot-rule: Rule -->"
+                })
+                .return_once(|_, _, _, _| Ok(2));
+
+            svc
+        };
+
+        ApplyPullRequestRules
+            .run(
+                &ctx.as_context(),
+                &("me", "name", 1).into(),
+                &upstream_pr(),
+                vec![rule(vec![
+                    RuleAction::Lock("Release".into()),
+                    RuleAction::PostComment("Thanks **{author}** for #{number} ({title})!".into()),
+                ])],
+            )
+            .await
+            .unwrap();
+
+        let pr = ctx
+            .db_service
+            .pull_requests_get_expect("me", "name", 1)
+            .await
+            .unwrap();
+        assert!(pr.locked);
+    }
+
+    #[tokio::test]
+    async fn apply_lock_and_comment_again() {
+        let mut ctx = CoreContextTest::new();
+        create_pull_request(&ctx, true).await;
+
+        // Already locked and commented: nothing is posted
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_comments_list().once().return_once(|_, _, _| {
+                Ok(vec![GhIssueComment {
+                    body: "Thanks!\n\n<!-- prbot-rule: Rule -->".into(),
+                    ..Default::default()
+                }])
+            });
+
+            svc
+        };
+
+        ApplyPullRequestRules
+            .run(
+                &ctx.as_context(),
+                &("me", "name", 1).into(),
+                &upstream_pr(),
+                vec![rule(vec![
+                    RuleAction::Lock("Release".into()),
+                    RuleAction::PostComment("Thanks!".into()),
+                ])],
+            )
+            .await
+            .unwrap();
+    }
 }
@@ -1,13 +1,18 @@
 use async_trait::async_trait;
 use prbot_ghapi_interface::{comments::CommentApi, types::GhPullRequest};
-use prbot_models::PullRequestHandle;
+use prbot_models::{Job, JobKind, JobStatus, PullRequestHandle, StepLabel};
 use shaku::{Component, HasComponent, Interface};
+use time::OffsetDateTime;
+use tracing::{error, info};
 
 use super::{
     try_merge_pull_request_from_status::TryMergePullRequestState,
     TryMergePullRequestFromStatusInterface,
 };
-use crate::{use_cases::status::PullRequestStatus, CoreContext, Result};
+use crate::{
+    use_cases::status::{PullRequestStatus, StepLabelChooser},
+    CoreContext, Result,
+};
 
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
@@ -25,6 +30,98 @@ pub trait AutomergePullRequestInterface: Interface {
 #[shaku(interface = AutomergePullRequestInterface)]
 pub(crate) struct AutomergePullRequest;
 
+impl AutomergePullRequest {
+    /// Retry the merge once merges are not frozen anymore.
+    async fn schedule_retry<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        run_at: OffsetDateTime,
+    ) -> Result<()> {
+        let payload = format!("{}/{}", pr_handle.repository_path(), pr_handle.number());
+        let already_scheduled = ctx
+            .db_service
+            .jobs_list(Some(JobStatus::Pending))
+            .await?
+            .into_iter()
+            .any(|j| {
+                j.kind == JobKind::RetryPullRequestMerge
+                    && j.payload == payload
+                    && j.run_at == run_at
+            });
+
+        if !already_scheduled {
+            info!(
+                pr_handle = %pr_handle,
+                run_at = %run_at,
+                message = "Merges are frozen, scheduling a merge retry"
+            );
+
+            ctx.db_service
+                .jobs_create(Job {
+                    kind: JobKind::RetryPullRequestMerge,
+                    payload,
+                    max_attempts: ctx.config.server.jobs_max_attempts,
+                    run_at,
+                    ..Default::default()
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn enqueue<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
+        pr_status: &PullRequestStatus,
+    ) -> Result<TryMergePullRequestState> {
+        let step = StepLabelChooser::default().choose_from_status(pr_status);
+        if step != StepLabel::AwaitingMerge || upstream_pr.merged == Some(true) {
+            return Ok(TryMergePullRequestState::NotReady);
+        }
+
+        let (comment, result) = match ctx
+            .api_service
+            .pulls_enqueue(pr_handle.owner(), pr_handle.name(), pr_handle.number())
+            .await
+        {
+            Ok(()) => (
+                "Pull request added to the merge queue.\nAuto-merge is now handled by the merge queue.",
+                TryMergePullRequestState::Enqueued,
+            ),
+            Err(e) => {
+                error!(
+                    owner = %pr_handle.owner(),
+                    name = %pr_handle.name(),
+                    pr_number = pr_handle.number(),
+                    error = %e,
+                    message = "Error while adding pull request to the merge queue"
+                );
+
+                (
+                    "Could not add this pull request to the merge queue because of an error.\nAuto-merge disabled.",
+                    TryMergePullRequestState::Error,
+                )
+            }
+        };
+
+        CommentApi::post_comment(
+            ctx.config,
+            ctx.api_service,
+            pr_handle.owner(),
+            pr_handle.name(),
+            pr_handle.number(),
+            comment,
+        )
+        .await?;
+
+        Ok(result)
+    }
+}
+
 #[async_trait]
 impl AutomergePullRequestInterface for AutomergePullRequest {
     async fn run<'a>(
@@ -34,6 +131,25 @@ impl AutomergePullRequestInterface for AutomergePullRequest {
         upstream_pr: &GhPullRequest,
         pr_status: &PullRequestStatus,
     ) -> Result<TryMergePullRequestState> {
+        // Frozen pull requests are merged once merges are allowed again
+        if let Some(frozen) = &pr_status.frozen {
+            let step = StepLabelChooser::default().choose_from_status(pr_status);
+            if let (StepLabel::Frozen, Some(run_at)) = (step, frozen.retry_at()) {
+                self.schedule_retry(ctx, pr_handle, run_at).await?;
+            }
+
+            return Ok(TryMergePullRequestState::NotReady);
+        }
+
+        // Repositories with a native merge queue merge through it
+        let repository = ctx
+            .db_service
+            .repositories_get_expect(pr_handle.owner(), pr_handle.name())
+            .await?;
+        if repository.native_merge_queue {
+            return self.enqueue(ctx, pr_handle, upstream_pr, pr_status).await;
+        }
+
         let try_merge_uc: &dyn TryMergePullRequestFromStatusInterface =
             ctx.core_module.resolve_ref();
         let result = try_merge_uc
@@ -79,7 +195,7 @@ impl AutomergePullRequestInterface for AutomergePullRequest {
 mod tests {
     use prbot_database_interface::DbService;
     use prbot_ghapi_interface::MockApiService;
-    use prbot_models::{MergeStrategy, PullRequest, Repository};
+    use prbot_models::{FrozenReason, MergeStrategy, PullRequest, QaStatus, Repository};
 
     use super::*;
     use crate::{
@@ -221,4 +337,92 @@ mod tests {
 
         arrange.check(TryMergePullRequestState::AlreadyLocked).await;
     }
+
+    #[tokio::test]
+    async fn run_native_merge_queue() {
+        let mut arrange = arrange().await;
+        arrange
+            .ctx
+            .db_service
+            .repositories_set_native_merge_queue("owner", "name", true)
+            .await
+            .unwrap();
+        arrange.pr_status = PullRequestStatus {
+            valid_pr_title: true,
+            mergeable: true,
+            qa_status: QaStatus::Skipped,
+            ..Default::default()
+        };
+
+        arrange.ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_pulls_enqueue()
+                .once()
+                .withf(|owner, name, number| owner == "owner" && name == "name" && number == &1)
+                .return_once(|_, _, _| Ok(()));
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, number, body| number == &1 && body.contains("merge queue"))
+                .return_once(|_, _, _, _| Ok(1));
+
+            svc
+        };
+
+        arrange.check(TryMergePullRequestState::Enqueued).await;
+    }
+
+    #[tokio::test]
+    async fn run_frozen() {
+        let mut arrange = arrange().await;
+        let run_at = OffsetDateTime::from_unix_timestamp(1_000).unwrap();
+        arrange.pr_status = PullRequestStatus {
+            valid_pr_title: true,
+            mergeable: true,
+            qa_status: QaStatus::Skipped,
+            frozen: Some(FrozenReason::OutsideMergeWindow {
+                opens_at: Some(run_at),
+            }),
+            ..Default::default()
+        };
+
+        // Only one retry is scheduled
+        arrange.check(TryMergePullRequestState::NotReady).await;
+        arrange.check(TryMergePullRequestState::NotReady).await;
+
+        let jobs = arrange.ctx.db_service.jobs_list(None).await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].kind, JobKind::RetryPullRequestMerge);
+        assert_eq!(jobs[0].payload, "owner/name/1");
+        assert_eq!(jobs[0].run_at, run_at);
+    }
+
+    #[tokio::test]
+    async fn run_frozen_not_ready() {
+        let mut arrange = arrange().await;
+        arrange.pr_status = PullRequestStatus {
+            frozen: Some(FrozenReason::OutsideMergeWindow {
+                opens_at: Some(OffsetDateTime::from_unix_timestamp(1_000).unwrap()),
+            }),
+            ..Default::default()
+        };
+
+        arrange.check(TryMergePullRequestState::NotReady).await;
+        assert_eq!(
+            arrange.ctx.db_service.jobs_list(None).await.unwrap(),
+            vec![]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_native_merge_queue_not_ready() {
+        let arrange = arrange().await;
+        arrange
+            .ctx
+            .db_service
+            .repositories_set_native_merge_queue("owner", "name", true)
+            .await
+            .unwrap();
+
+        arrange.check(TryMergePullRequestState::NotReady).await;
+    }
 }
@@ -0,0 +1,760 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::{comments::CommentApi, types::GhPullRequest, ApiError};
+use prbot_models::PullRequestHandle;
+use shaku::{Component, Interface};
+
+use crate::{CoreContext, Result};
+
+/// Label prefix used to request a backport on a target branch, e.g. `backport/release-1.0`.
+pub const BACKPORT_LABEL_PREFIX: &str = "backport/";
+
+/// Outcome of a backport on a target branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackportOutcome {
+    /// A backport pull request was created, or already exists.
+    Created { branch: String, number: u64 },
+    /// The cherry-pick conflicts with the target branch.
+    Conflict { branch: String },
+    /// The target branch does not exist.
+    UnknownBranch { branch: String },
+    /// The upstream commits could not be resolved from the merge commit.
+    Unsupported { branch: String },
+}
+
+/// Upstream changes applied by a backport.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PickedChanges {
+    /// Commit the changes were made on.
+    base_sha: String,
+    /// Backport commit message.
+    message: String,
+    /// `git cherry-pick` arguments to backport manually.
+    cherry_pick_args: String,
+}
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait BackportPullRequestInterface: Interface {
+    /// Cherry-pick the changes of a merged pull request on target branches,
+    /// and open a pull request for each of them.
+    ///
+    /// Outcomes are commented on the pull request.
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
+        branches: &[String],
+    ) -> Result<Vec<BackportOutcome>>;
+}
+
+#[derive(Component)]
+#[shaku(interface = BackportPullRequestInterface)]
+pub(crate) struct BackportPullRequest;
+
+#[async_trait]
+impl BackportPullRequestInterface for BackportPullRequest {
+    #[tracing::instrument(skip(self, ctx, upstream_pr), fields(pr_handle = %pr_handle), ret)]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
+        branches: &[String],
+    ) -> Result<Vec<BackportOutcome>> {
+        let Some(merge_sha) = upstream_pr.merge_commit_sha.as_deref() else {
+            return Ok(vec![]);
+        };
+        if branches.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let changes = Self::resolve_picked_changes(ctx, pr_handle, merge_sha).await?;
+        let mut outcomes = vec![];
+        for branch in branches {
+            let outcome = match &changes {
+                Some(changes) => {
+                    self.backport_on_branch(ctx, pr_handle, upstream_pr, merge_sha, changes, branch)
+                        .await?
+                }
+                None => BackportOutcome::Unsupported {
+                    branch: branch.clone(),
+                },
+            };
+            outcomes.push(outcome);
+        }
+
+        CommentApi::post_comment(
+            ctx.config,
+            ctx.api_service,
+            pr_handle.owner(),
+            pr_handle.name(),
+            pr_handle.number(),
+            &Self::build_comment(pr_handle, changes.as_ref(), &outcomes),
+        )
+        .await?;
+
+        Ok(outcomes)
+    }
+}
+
+impl BackportPullRequest {
+    /// Find the upstream changes from the merge commit.
+    ///
+    /// Merge and squash merges produce a single commit. Rebase merges produce one
+    /// commit per pull request commit, with the same messages: the whole range is
+    /// picked. Returns `None` when the range cannot be matched with the pull request commits.
+    async fn resolve_picked_changes(
+        ctx: &CoreContext<'_>,
+        pr_handle: &PullRequestHandle,
+        merge_sha: &str,
+    ) -> Result<Option<PickedChanges>> {
+        let (owner, name) = (pr_handle.owner(), pr_handle.name());
+
+        let commit = ctx
+            .api_service
+            .git_commits_get(owner, name, merge_sha)
+            .await?;
+        let Some(parent) = commit.parents.first() else {
+            return Ok(None);
+        };
+
+        if commit.parents.len() > 1 {
+            return Ok(Some(PickedChanges {
+                base_sha: parent.sha.clone(),
+                message: format!(
+                    "{}\n\n(cherry picked from commit {merge_sha})",
+                    commit.message
+                ),
+                cherry_pick_args: format!("-x -m 1 {merge_sha}"),
+            }));
+        }
+
+        let pr_commits = ctx
+            .api_service
+            .pull_commits_list(owner, name, pr_handle.number())
+            .await?;
+        let is_rebase =
+            pr_commits.len() > 1 && pr_commits.last().map(|c| &c.message) == Some(&commit.message);
+        if !is_rebase {
+            return Ok(Some(PickedChanges {
+                base_sha: parent.sha.clone(),
+                message: format!(
+                    "{}\n\n(cherry picked from commit {merge_sha})",
+                    commit.message
+                ),
+                cherry_pick_args: format!("-x {merge_sha}"),
+            }));
+        }
+
+        // Walk back the rebased commits, which must match the pull request commits
+        let mut current = commit;
+        for pr_commit in pr_commits.iter().rev().skip(1) {
+            let [parent] = current.parents.as_slice() else {
+                return Ok(None);
+            };
+            current = ctx
+                .api_service
+                .git_commits_get(owner, name, &parent.sha)
+                .await?;
+            if current.message != pr_commit.message {
+                return Ok(None);
+            }
+        }
+
+        let [base] = current.parents.as_slice() else {
+            return Ok(None);
+        };
+        let messages: Vec<_> = pr_commits
+            .iter()
+            .map(|c| format!("- {}", c.message.lines().next().unwrap_or_default()))
+            .collect();
+
+        Ok(Some(PickedChanges {
+            base_sha: base.sha.clone(),
+            message: format!(
+                "Backport #{}\n\n{}\n\n(cherry picked from commits {}..{merge_sha})",
+                pr_handle.number(),
+                messages.join("\n"),
+                base.sha
+            ),
+            cherry_pick_args: format!("-x {}..{merge_sha}", base.sha),
+        }))
+    }
+
+    /// Apply the upstream changes on a target branch.
+    ///
+    /// The GitHub API has no cherry-pick endpoint: the upstream changes are merged
+    /// into a temporary commit sharing their base with the target tree, then the
+    /// resulting tree is committed on top of the target branch.
+    async fn backport_on_branch(
+        &self,
+        ctx: &CoreContext<'_>,
+        pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
+        merge_sha: &str,
+        changes: &PickedChanges,
+        branch: &str,
+    ) -> Result<BackportOutcome> {
+        let (owner, name) = (pr_handle.owner(), pr_handle.name());
+
+        let Some(target_ref) = ctx
+            .api_service
+            .git_refs_get(owner, name, &format!("heads/{branch}"))
+            .await?
+        else {
+            return Ok(BackportOutcome::UnknownBranch {
+                branch: branch.into(),
+            });
+        };
+        let target_sha = target_ref.object.sha;
+
+        let target_commit = ctx
+            .api_service
+            .git_commits_get(owner, name, &target_sha)
+            .await?;
+
+        let backport_branch = backport_branch_name(pr_handle.number(), branch);
+        let backport_ref = format!("heads/{backport_branch}");
+        let base = ctx
+            .api_service
+            .git_commits_create(
+                owner,
+                name,
+                &format!("Backport base for #{}", pr_handle.number()),
+                &target_commit.tree.sha,
+                &[changes.base_sha.clone()],
+            )
+            .await?;
+
+        if ctx
+            .api_service
+            .git_refs_get(owner, name, &backport_ref)
+            .await?
+            .is_some()
+        {
+            ctx.api_service
+                .git_refs_update(owner, name, &backport_ref, &base.sha, true)
+                .await?;
+        } else {
+            ctx.api_service
+                .git_refs_create(owner, name, &backport_ref, &base.sha)
+                .await?;
+        }
+
+        let merged_sha = match ctx
+            .api_service
+            .repository_merges_create(
+                owner,
+                name,
+                &backport_branch,
+                merge_sha,
+                &format!("Backport #{}", pr_handle.number()),
+            )
+            .await
+        {
+            Ok(sha) => sha.unwrap_or(base.sha),
+            Err(ApiError::MergeConflict { .. }) => {
+                ctx.api_service
+                    .git_refs_delete(owner, name, &backport_ref)
+                    .await?;
+
+                return Ok(BackportOutcome::Conflict {
+                    branch: branch.into(),
+                });
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let merged_commit = ctx
+            .api_service
+            .git_commits_get(owner, name, &merged_sha)
+            .await?;
+        let picked = ctx
+            .api_service
+            .git_commits_create(
+                owner,
+                name,
+                &changes.message,
+                &merged_commit.tree.sha,
+                &[target_sha],
+            )
+            .await?;
+        ctx.api_service
+            .git_refs_update(owner, name, &backport_ref, &picked.sha, true)
+            .await?;
+
+        // A retried backport reuses the pull request opened by a previous attempt
+        let existing_pr = ctx
+            .api_service
+            .pulls_list(owner, name)
+            .await?
+            .into_iter()
+            .find(|pr| pr.head.reference == backport_branch && pr.base.reference == branch);
+        let number = match existing_pr {
+            Some(pr) => pr.number,
+            None => {
+                ctx.api_service
+                    .pulls_create(
+                        owner,
+                        name,
+                        &format!("[Backport {branch}] {}", upstream_pr.title),
+                        &format!("Backport of #{} to `{branch}`.", pr_handle.number()),
+                        &backport_branch,
+                        branch,
+                    )
+                    .await?
+                    .number
+            }
+        };
+
+        Ok(BackportOutcome::Created {
+            branch: branch.into(),
+            number,
+        })
+    }
+
+    fn build_comment(
+        pr_handle: &PullRequestHandle,
+        changes: Option<&PickedChanges>,
+        outcomes: &[BackportOutcome],
+    ) -> String {
+        let mut comment = "Backport results:\n".to_string();
+        for outcome in outcomes {
+            match outcome {
+                BackportOutcome::Created { branch, number } => {
+                    comment.push_str(&format!("- `{branch}`: #{number}\n"));
+                }
+                BackportOutcome::UnknownBranch { branch } => {
+                    comment.push_str(&format!("- `{branch}`: _unknown branch_\n"));
+                }
+                BackportOutcome::Unsupported { branch } => {
+                    comment.push_str(&format!(
+                        "- `{branch}`: _merged commits do not match the pull request commits, backport manually_\n"
+                    ));
+                }
+                BackportOutcome::Conflict { branch } => {
+                    let backport_branch = backport_branch_name(pr_handle.number(), branch);
+                    let cherry_pick_args = changes
+                        .map(|c| c.cherry_pick_args.as_str())
+                        .unwrap_or_default();
+                    comment.push_str(&format!(
+                        "- `{branch}`: _conflicts, backport manually with:_\n\
+                         ```\n\
+                         git fetch origin {branch}\n\
+                         git checkout -b {backport_branch} origin/{branch}\n\
+                         git cherry-pick {cherry_pick_args}\n\
+                         ```\n"
+                    ));
+                }
+            }
+        }
+
+        comment
+    }
+}
+
+/// Name of the branch holding the backport of a pull request on a target branch.
+fn backport_branch_name(number: u64, branch: &str) -> String {
+    format!("prbot-backport/{number}/{branch}")
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_ghapi_interface::{
+        types::{GhBranch, GhGitCommit, GhGitObject, GhGitRef, GhPullCommit},
+        MockApiService,
+    };
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    fn git_commit(sha: &str, tree: &str, parents: &[&str]) -> GhGitCommit {
+        GhGitCommit {
+            sha: sha.into(),
+            message: format!("Commit {sha}"),
+            tree: GhGitObject { sha: tree.into() },
+            parents: parents
+                .iter()
+                .map(|p| GhGitObject { sha: p.to_string() })
+                .collect(),
+        }
+    }
+
+    fn merged_pr() -> GhPullRequest {
+        GhPullRequest {
+            number: 1,
+            title: "Fix".into(),
+            merge_commit_sha: Some("merge".into()),
+            ..Default::default()
+        }
+    }
+
+    fn pull_commit(message: &str) -> GhPullCommit {
+        GhPullCommit {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    fn arrange_squash(svc: &mut MockApiService) {
+        svc.expect_git_commits_get()
+            .withf(|_, _, sha| sha == "merge")
+            .returning(|_, _, _| Ok(git_commit("merge", "merge-tree", &["parent"])));
+        svc.expect_pull_commits_list()
+            .once()
+            .return_once(|_, _, _| Ok(vec![pull_commit("WIP"), pull_commit("Fix typo")]));
+    }
+
+    fn arrange_target(svc: &mut MockApiService, base_sha: &'static str) {
+        svc.expect_git_refs_get()
+            .withf(|_, _, reference| reference == "heads/stable")
+            .return_once(|_, _, _| {
+                Ok(Some(GhGitRef {
+                    reference: "refs/heads/stable".into(),
+                    object: GhGitObject {
+                        sha: "stable".into(),
+                    },
+                }))
+            });
+        svc.expect_git_refs_get()
+            .withf(|_, _, reference| reference == "heads/prbot-backport/1/stable")
+            .return_once(|_, _, _| Ok(None));
+        svc.expect_git_commits_get()
+            .withf(|_, _, sha| sha == "stable")
+            .returning(|_, _, _| Ok(git_commit("stable", "stable-tree", &[])));
+        svc.expect_git_commits_create()
+            .once()
+            .withf(move |_, _, _, tree, parents| tree == "stable-tree" && parents == [base_sha])
+            .return_once(move |_, _, _, _, _| Ok(git_commit("base", "stable-tree", &[base_sha])));
+        svc.expect_git_refs_create()
+            .once()
+            .withf(|_, _, reference, sha| {
+                reference == "heads/prbot-backport/1/stable" && sha == "base"
+            })
+            .return_once(|_, _, _, _| Ok(()));
+    }
+
+    #[tokio::test]
+    async fn run_created() {
+        let mut ctx = CoreContextTest::new();
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            arrange_squash(&mut svc);
+            arrange_target(&mut svc, "parent");
+            svc.expect_repository_merges_create()
+                .once()
+                .withf(|_, _, base, head, _| base == "prbot-backport/1/stable" && head == "merge")
+                .return_once(|_, _, _, _, _| Ok(Some("merged".into())));
+            svc.expect_git_commits_get()
+                .withf(|_, _, sha| sha == "merged")
+                .return_once(|_, _, _| Ok(git_commit("merged", "picked-tree", &[])));
+            svc.expect_git_commits_create()
+                .once()
+                .withf(|_, _, message, tree, parents| {
+                    message == "Commit merge\n\n(cherry picked from commit merge)"
+                        && tree == "picked-tree"
+                        && parents == ["stable"]
+                })
+                .return_once(|_, _, _, _, _| Ok(git_commit("picked", "picked-tree", &[])));
+            svc.expect_git_refs_update()
+                .once()
+                .withf(|_, _, reference, sha, force| {
+                    reference == "heads/prbot-backport/1/stable" && sha == "picked" && *force
+                })
+                .return_once(|_, _, _, _, _| Ok(()));
+            svc.expect_pulls_list()
+                .once()
+                .return_once(|_, _| Ok(vec![]));
+            svc.expect_pulls_create()
+                .once()
+                .withf(|_, _, title, body, head, base| {
+                    title == "[Backport stable] Fix"
+                        && body == "Backport of #1 to `stable`."
+                        && head == "prbot-backport/1/stable"
+                        && base == "stable"
+                })
+                .return_once(|_, _, _, _, _, _| {
+                    Ok(GhPullRequest {
+                        number: 2,
+                        ..Default::default()
+                    })
+                });
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, number, body| *number == 1 && body.contains("- `stable`: #2"))
+                .return_once(|_, _, _, _| Ok(1));
+            svc
+        };
+
+        let outcomes = BackportPullRequest
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &merged_pr(),
+                &["stable".into()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![BackportOutcome::Created {
+                branch: "stable".into(),
+                number: 2
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_conflict() {
+        let mut ctx = CoreContextTest::new();
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            arrange_squash(&mut svc);
+            arrange_target(&mut svc, "parent");
+            svc.expect_repository_merges_create()
+                .once()
+                .return_once(|_, _, base, head, _| {
+                    Err(ApiError::MergeConflict {
+                        base: base.into(),
+                        head: head.into(),
+                        repository_path: "me/test".into(),
+                    })
+                });
+            svc.expect_git_refs_delete()
+                .once()
+                .withf(|_, _, reference| reference == "heads/prbot-backport/1/stable")
+                .return_once(|_, _, _| Ok(()));
+            svc.expect_git_refs_get()
+                .withf(|_, _, reference| reference == "heads/unknown")
+                .return_once(|_, _, _| Ok(None));
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, _, body| {
+                    body.contains("git cherry-pick -x merge\n")
+                        && body.contains("- `unknown`: _unknown branch_")
+                })
+                .return_once(|_, _, _, _| Ok(1));
+            svc
+        };
+
+        let outcomes = BackportPullRequest
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &merged_pr(),
+                &["stable".into(), "unknown".into()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                BackportOutcome::Conflict {
+                    branch: "stable".into()
+                },
+                BackportOutcome::UnknownBranch {
+                    branch: "unknown".into()
+                }
+            ]
+        );
+    }
+
+    /// Expect the backport to be picked and pushed, with its commit message.
+    fn arrange_picked(svc: &mut MockApiService, expected_message: &'static str) {
+        svc.expect_repository_merges_create()
+            .once()
+            .return_once(|_, _, _, _, _| Ok(Some("merged".into())));
+        svc.expect_git_commits_get()
+            .withf(|_, _, sha| sha == "merged")
+            .return_once(|_, _, _| Ok(git_commit("merged", "picked-tree", &[])));
+        svc.expect_git_commits_create()
+            .once()
+            .withf(move |_, _, message, _, _| message == expected_message)
+            .return_once(|_, _, _, _, _| Ok(git_commit("picked", "picked-tree", &[])));
+        svc.expect_git_refs_update()
+            .once()
+            .return_once(|_, _, _, _, _| Ok(()));
+    }
+
+    #[tokio::test]
+    async fn run_existing_pull_request() {
+        let mut ctx = CoreContextTest::new();
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            arrange_squash(&mut svc);
+            arrange_target(&mut svc, "parent");
+            arrange_picked(
+                &mut svc,
+                "Commit merge\n\n(cherry picked from commit merge)",
+            );
+            svc.expect_pulls_list().once().return_once(|_, _| {
+                Ok(vec![GhPullRequest {
+                    number: 3,
+                    head: GhBranch {
+                        reference: "prbot-backport/1/stable".into(),
+                        ..Default::default()
+                    },
+                    base: GhBranch {
+                        reference: "stable".into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }])
+            });
+            svc.expect_pulls_create().never();
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, _, body| body.contains("- `stable`: #3"))
+                .return_once(|_, _, _, _| Ok(1));
+            svc
+        };
+
+        let outcomes = BackportPullRequest
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &merged_pr(),
+                &["stable".into()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![BackportOutcome::Created {
+                branch: "stable".into(),
+                number: 3
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_rebase_merge() {
+        let mut ctx = CoreContextTest::new();
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_git_commits_get()
+                .withf(|_, _, sha| sha == "merge")
+                .returning(|_, _, _| {
+                    Ok(GhGitCommit {
+                        message: "Second".into(),
+                        ..git_commit("merge", "merge-tree", &["first"])
+                    })
+                });
+            svc.expect_git_commits_get()
+                .withf(|_, _, sha| sha == "first")
+                .returning(|_, _, _| {
+                    Ok(GhGitCommit {
+                        message: "First".into(),
+                        ..git_commit("first", "first-tree", &["upstream"])
+                    })
+                });
+            svc.expect_pull_commits_list()
+                .once()
+                .return_once(|_, _, _| Ok(vec![pull_commit("First"), pull_commit("Second")]));
+            arrange_target(&mut svc, "upstream");
+            arrange_picked(
+                &mut svc,
+                "Backport #1\n\n- First\n- Second\n\n(cherry picked from commits upstream..merge)",
+            );
+            svc.expect_pulls_list()
+                .once()
+                .return_once(|_, _| Ok(vec![]));
+            svc.expect_pulls_create()
+                .once()
+                .return_once(|_, _, _, _, _, _| {
+                    Ok(GhPullRequest {
+                        number: 2,
+                        ..Default::default()
+                    })
+                });
+            svc.expect_comments_post()
+                .once()
+                .return_once(|_, _, _, _| Ok(1));
+            svc
+        };
+
+        let outcomes = BackportPullRequest
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &merged_pr(),
+                &["stable".into()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![BackportOutcome::Created {
+                branch: "stable".into(),
+                number: 2
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_rebase_merge_unresolved() {
+        let mut ctx = CoreContextTest::new();
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_git_commits_get()
+                .withf(|_, _, sha| sha == "merge")
+                .returning(|_, _, _| {
+                    Ok(GhGitCommit {
+                        message: "Second".into(),
+                        ..git_commit("merge", "merge-tree", &["other"])
+                    })
+                });
+            svc.expect_git_commits_get()
+                .withf(|_, _, sha| sha == "other")
+                .returning(|_, _, _| Ok(git_commit("other", "other-tree", &["upstream"])));
+            svc.expect_pull_commits_list()
+                .once()
+                .return_once(|_, _, _| Ok(vec![pull_commit("First"), pull_commit("Second")]));
+            svc.expect_git_refs_get().never();
+            svc.expect_comments_post()
+                .once()
+                .withf(|_, _, _, body| body.contains("- `stable`: _merged commits do not match"))
+                .return_once(|_, _, _, _| Ok(1));
+            svc
+        };
+
+        let outcomes = BackportPullRequest
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &merged_pr(),
+                &["stable".into()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![BackportOutcome::Unsupported {
+                branch: "stable".into()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn run_not_merged() {
+        let ctx = CoreContextTest::new();
+
+        let outcomes = BackportPullRequest
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &GhPullRequest::default(),
+                &["stable".into()],
+            )
+            .await
+            .unwrap();
+
+        assert!(outcomes.is_empty());
+    }
+}
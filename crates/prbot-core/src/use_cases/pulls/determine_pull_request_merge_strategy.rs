@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use prbot_models::{MergeStrategy, RepositoryPath};
+use prbot_models::{MergeRule, MergeStrategy, RepositoryPath};
 use shaku::{Component, Interface};
 
 use crate::{CoreContext, Result};
@@ -36,16 +36,12 @@ impl DeterminePullRequestMergeStrategyInterface for DeterminePullRequestMergeStr
         head_branch: &str,
         default_strategy: MergeStrategy,
     ) -> Result<MergeStrategy> {
-        match ctx
+        let rules = ctx
             .db_service
-            .merge_rules_get(
-                repository_path.owner(),
-                repository_path.name(),
-                base_branch.into(),
-                head_branch.into(),
-            )
-            .await?
-        {
+            .merge_rules_list(repository_path.owner(), repository_path.name())
+            .await?;
+
+        match MergeRule::find_most_specific(&rules, base_branch, head_branch) {
             Some(r) => Ok(r.strategy),
             None => Ok(default_strategy),
         }
@@ -55,7 +51,7 @@ impl DeterminePullRequestMergeStrategyInterface for DeterminePullRequestMergeStr
 #[cfg(test)]
 mod tests {
     use prbot_database_interface::DbService;
-    use prbot_models::{MergeRule, Repository, RuleBranch};
+    use prbot_models::{Repository, RuleBranch};
 
     use super::*;
     use crate::context::tests::CoreContextTest;
@@ -96,6 +92,7 @@ mod tests {
                 base_branch: RuleBranch::Named("main".into()),
                 head_branch: RuleBranch::Named("abcd".into()),
                 strategy: MergeStrategy::Squash,
+                ..Default::default()
             })
             .await
             .unwrap();
@@ -113,4 +110,78 @@ mod tests {
 
         assert_eq!(strategy, MergeStrategy::Squash);
     }
+
+    #[tokio::test]
+    async fn most_specific_rule() {
+        let ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        for (base, head, strategy) in [
+            ("*", "renovate/*", MergeStrategy::Rebase),
+            ("release/*", "*", MergeStrategy::Squash),
+            ("re:^release/v\\d+$", "*", MergeStrategy::Rebase),
+            ("release/v2", "*", MergeStrategy::Merge),
+        ] {
+            ctx.db_service
+                .merge_rules_create(MergeRule {
+                    repository_id: repo.id,
+                    base_branch: base.into(),
+                    head_branch: head.into(),
+                    strategy,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        let determine = |base: &'static str, head: &'static str, default: MergeStrategy| {
+            let ctx = &ctx;
+            async move {
+                DeterminePullRequestMergeStrategy
+                    .run(
+                        &ctx.as_context(),
+                        &("me", "test").into(),
+                        base,
+                        head,
+                        default,
+                    )
+                    .await
+                    .unwrap()
+            }
+        };
+
+        assert_eq!(
+            determine("main", "feature", MergeStrategy::Merge).await,
+            MergeStrategy::Merge
+        );
+        assert_eq!(
+            determine("main", "renovate/x", MergeStrategy::Merge).await,
+            MergeStrategy::Rebase
+        );
+        assert_eq!(
+            determine("release/next", "a", MergeStrategy::Merge).await,
+            MergeStrategy::Squash
+        );
+        assert_eq!(
+            determine("release/v1", "a", MergeStrategy::Squash).await,
+            MergeStrategy::Rebase
+        );
+        assert_eq!(
+            determine("release/v2", "a", MergeStrategy::Squash).await,
+            MergeStrategy::Merge
+        );
+        // Base branch specificity comes first
+        assert_eq!(
+            determine("release/next", "renovate/x", MergeStrategy::Merge).await,
+            MergeStrategy::Squash
+        );
+    }
 }
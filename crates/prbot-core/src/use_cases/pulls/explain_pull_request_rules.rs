@@ -0,0 +1,308 @@
+use std::fmt::Write;
+
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhPullRequest;
+use prbot_models::{PullRequestRule, RepositoryPath, RuleAction, RuleCondition};
+use shaku::{Component, Interface};
+
+use super::resolve_pull_request_rules::RuleFacts;
+use crate::{CoreContext, Result};
+
+/// Rule evaluation result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleEvaluation {
+    /// All conditions matched.
+    Matched,
+    /// Some conditions did not match.
+    Failed { conditions: Vec<RuleCondition> },
+    /// Rules without conditions or actions are never applied.
+    Ignored,
+}
+
+/// Rule explanation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleExplanation {
+    pub rule: PullRequestRule,
+    pub evaluation: RuleEvaluation,
+}
+
+/// Action explanation, for matched rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionExplanation {
+    pub rule_name: String,
+    pub action: RuleAction,
+    pub overridden_by: Option<String>,
+}
+
+/// Pull request rules explanation, sorted by priority.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequestRulesExplanation {
+    pub rules: Vec<RuleExplanation>,
+    pub actions: Vec<ActionExplanation>,
+}
+
+impl PullRequestRulesExplanation {
+    /// Generate a Markdown comment.
+    pub fn to_markdown(&self) -> String {
+        if self.rules.is_empty() {
+            return "No pull request rule for this repository.".into();
+        }
+
+        let mut comment = String::from("**Pull request rules**\n\n");
+        for explanation in &self.rules {
+            let rule = &explanation.rule;
+            let result = match &explanation.evaluation {
+                RuleEvaluation::Matched => "_matched_ :heavy_check_mark:".into(),
+                RuleEvaluation::Failed { conditions } => format!(
+                    "_not matched_ :x: (failed: {})",
+                    conditions
+                        .iter()
+                        .map(|c| format!("`{c}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                RuleEvaluation::Ignored => "_ignored, no conditions or actions_".into(),
+            };
+
+            writeln!(
+                comment,
+                "- **{}** (priority {}): {result}",
+                rule.name, rule.priority
+            )
+            .unwrap();
+        }
+
+        if !self.actions.is_empty() {
+            comment.push_str("\n**Actions**\n\n");
+            for action in &self.actions {
+                match &action.overridden_by {
+                    Some(winner) => writeln!(
+                        comment,
+                        "- ~`{}`~ from **{}**, overridden by **{winner}**",
+                        action.action, action.rule_name
+                    ),
+                    None => writeln!(
+                        comment,
+                        "- `{}` from **{}**",
+                        action.action, action.rule_name
+                    ),
+                }
+                .unwrap();
+            }
+        }
+
+        comment
+    }
+}
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait ExplainPullRequestRulesInterface: Interface {
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository_path: &RepositoryPath,
+        upstream_pr: &GhPullRequest,
+    ) -> Result<PullRequestRulesExplanation>;
+}
+
+#[derive(Component)]
+#[shaku(interface = ExplainPullRequestRulesInterface)]
+pub(crate) struct ExplainPullRequestRules;
+
+#[async_trait]
+impl ExplainPullRequestRulesInterface for ExplainPullRequestRules {
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository_path: &RepositoryPath,
+        upstream_pr: &GhPullRequest,
+    ) -> Result<PullRequestRulesExplanation> {
+        let mut rules = ctx
+            .db_service
+            .pull_request_rules_list(repository_path.owner(), repository_path.name())
+            .await?;
+        PullRequestRule::sort_by_priority(&mut rules);
+
+        let facts = RuleFacts::fetch(ctx, repository_path, upstream_pr, &rules).await?;
+        let explanations: Vec<_> = rules
+            .into_iter()
+            .map(|rule| {
+                let evaluation = if rule.conditions.is_empty() || rule.actions.is_empty() {
+                    RuleEvaluation::Ignored
+                } else {
+                    let failed: Vec<_> = rule
+                        .conditions
+                        .iter()
+                        .filter(|c| !facts.matches(c))
+                        .cloned()
+                        .collect();
+
+                    if failed.is_empty() {
+                        RuleEvaluation::Matched
+                    } else {
+                        RuleEvaluation::Failed { conditions: failed }
+                    }
+                };
+
+                RuleExplanation { rule, evaluation }
+            })
+            .collect();
+
+        let matched: Vec<_> = explanations
+            .iter()
+            .filter(|e| e.evaluation == RuleEvaluation::Matched)
+            .map(|e| e.rule.clone())
+            .collect();
+        let actions = PullRequestRule::resolve_actions(&matched)
+            .into_iter()
+            .map(|resolved| ActionExplanation {
+                rule_name: resolved.rule.name.clone(),
+                action: resolved.action.clone(),
+                overridden_by: resolved.overridden_by.map(Into::into),
+            })
+            .collect();
+
+        Ok(PullRequestRulesExplanation {
+            rules: explanations,
+            actions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::types::{GhLabel, GhUser};
+    use prbot_models::Repository;
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    #[tokio::test]
+    async fn explain() {
+        let ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        for rule in [
+            PullRequestRule {
+                name: "Default".into(),
+                conditions: vec![RuleCondition::Author("bot".into())],
+                actions: vec![
+                    RuleAction::SetNeededReviewers(1),
+                    RuleAction::AddLabels(vec!["bot".into()]),
+                ],
+                ..Default::default()
+            },
+            PullRequestRule {
+                name: "Dependencies".into(),
+                conditions: vec![RuleCondition::Label("deps".into())],
+                actions: vec![RuleAction::SetNeededReviewers(0)],
+                priority: 10,
+                ..Default::default()
+            },
+            PullRequestRule {
+                name: "Drafts".into(),
+                conditions: vec![
+                    RuleCondition::Author("bot".into()),
+                    RuleCondition::Draft(true),
+                ],
+                actions: vec![RuleAction::SetAutomerge(false)],
+                ..Default::default()
+            },
+            PullRequestRule {
+                name: "Empty".into(),
+                ..Default::default()
+            },
+        ] {
+            ctx.db_service
+                .pull_request_rules_create(PullRequestRule {
+                    repository_id: repo.id,
+                    ..rule
+                })
+                .await
+                .unwrap();
+        }
+
+        let upstream_pr = GhPullRequest {
+            user: GhUser {
+                login: "bot".into(),
+            },
+            labels: vec![GhLabel {
+                name: "deps".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let explanation = ExplainPullRequestRules
+            .run(&ctx.as_context(), &repo.path(), &upstream_pr)
+            .await
+            .unwrap();
+
+        let evaluations: Vec<_> = explanation
+            .rules
+            .iter()
+            .map(|e| (e.rule.name.as_str(), e.evaluation.clone()))
+            .collect();
+        assert_eq!(
+            evaluations,
+            vec![
+                ("Dependencies", RuleEvaluation::Matched),
+                ("Default", RuleEvaluation::Matched),
+                (
+                    "Drafts",
+                    RuleEvaluation::Failed {
+                        conditions: vec![RuleCondition::Draft(true)]
+                    }
+                ),
+                ("Empty", RuleEvaluation::Ignored),
+            ]
+        );
+
+        assert_eq!(
+            explanation.actions,
+            vec![
+                ActionExplanation {
+                    rule_name: "Dependencies".into(),
+                    action: RuleAction::SetNeededReviewers(0),
+                    overridden_by: None,
+                },
+                ActionExplanation {
+                    rule_name: "Default".into(),
+                    action: RuleAction::SetNeededReviewers(1),
+                    overridden_by: Some("Dependencies".into()),
+                },
+                ActionExplanation {
+                    rule_name: "Default".into(),
+                    action: RuleAction::AddLabels(vec!["bot".into()]),
+                    overridden_by: None,
+                },
+            ]
+        );
+
+        assert_eq!(
+            explanation.to_markdown(),
+            "**Pull request rules**\n\
+            \n\
+            - **Dependencies** (priority 10): _matched_ :heavy_check_mark:\n\
+            - **Default** (priority 0): _matched_ :heavy_check_mark:\n\
+            - **Drafts** (priority 0): _not matched_ :x: (failed: `{\"draft\":true}`)\n\
+            - **Empty** (priority 0): _ignored, no conditions or actions_\n\
+            \n\
+            **Actions**\n\
+            \n\
+            - `{\"set_needed_reviewers\":0}` from **Dependencies**\n\
+            - ~`{\"set_needed_reviewers\":1}`~ from **Default**, overridden by **Dependencies**\n\
+            - `{\"add_labels\":[\"bot\"]}` from **Default**\n"
+        );
+    }
+}
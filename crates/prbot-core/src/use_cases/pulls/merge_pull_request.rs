@@ -1,12 +1,15 @@
 use async_trait::async_trait;
 use prbot_ghapi_interface::{
-    types::{GhMergeStrategy, GhPullRequest},
-    ApiError,
+    reviews::ReviewApi,
+    types::{GhMergeStrategy, GhPullRequest, GhReviewState},
+};
+use prbot_models::{
+    normalize_conventional_commit, MergeCommitVariables, MergeRule, MergeStrategy,
+    PullRequestHandle, DEFAULT_MERGE_COMMIT_TITLE_TEMPLATE,
 };
-use prbot_models::{MergeStrategy, PullRequestHandle};
 use shaku::{Component, Interface};
 
-use crate::CoreContext;
+use crate::{CoreContext, Result};
 
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
@@ -17,7 +20,7 @@ pub trait MergePullRequestInterface: Interface {
         pr_handle: &PullRequestHandle,
         merge_strategy: MergeStrategy,
         upstream_pr: &GhPullRequest,
-    ) -> Result<(), ApiError>;
+    ) -> Result<()>;
 }
 
 #[derive(Component)]
@@ -32,6 +35,97 @@ impl MergePullRequest {
             MergeStrategy::Squash => GhMergeStrategy::Squash,
         }
     }
+
+    /// Build commit variables, only calling the API for variables used in templates.
+    async fn build_variables(
+        ctx: &CoreContext<'_>,
+        pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
+        templates: &[&str],
+    ) -> Result<MergeCommitVariables> {
+        let owner = pr_handle.repository_path().owner();
+        let name = pr_handle.repository_path().name();
+        let is_used = |variable| {
+            templates
+                .iter()
+                .any(|t| MergeCommitVariables::is_used(t, variable))
+        };
+
+        let body = upstream_pr.body.clone().unwrap_or_default();
+        let mut variables = MergeCommitVariables {
+            title: upstream_pr.title.clone(),
+            number: upstream_pr.number,
+            author: upstream_pr.user.login.clone(),
+            issues: MergeCommitVariables::parse_linked_issues(&body),
+            body,
+            base: upstream_pr.base.reference.clone(),
+            head: upstream_pr.head.reference.clone(),
+            ..Default::default()
+        };
+
+        if is_used("approvers") {
+            // Last reviews are deduplicated without order, sort them for a stable message
+            let mut approvers: Vec<String> = ReviewApi::list_reviews_for_pull_request(
+                ctx.api_service,
+                owner,
+                name,
+                pr_handle.number(),
+            )
+            .await?
+            .into_iter()
+            .filter(|r| r.state == GhReviewState::Approved)
+            .map(|r| r.user.login)
+            .collect();
+            approvers.sort_unstable();
+            variables.approvers = approvers;
+        }
+
+        if is_used("co_authors") {
+            let author = &upstream_pr.user.login;
+            let noreply_email = format!("{author}@users.noreply.github.com").to_lowercase();
+
+            let mut co_authors: Vec<String> = vec![];
+            for commit in ctx
+                .api_service
+                .pull_commits_list(owner, name, pr_handle.number())
+                .await?
+            {
+                // The pull request author is already the squashed commit author
+                let email = commit.author_email.to_lowercase();
+                let is_author = commit.author_name == *author
+                    || email == noreply_email
+                    || email.ends_with(&format!("+{noreply_email}"));
+
+                let mut values = MergeCommitVariables::parse_co_authors(&commit.message);
+                if !is_author {
+                    values.insert(
+                        0,
+                        format!("{} <{}>", commit.author_name, commit.author_email),
+                    );
+                }
+
+                for value in values {
+                    if !co_authors.iter().any(|c| c.eq_ignore_ascii_case(&value)) {
+                        co_authors.push(value);
+                    }
+                }
+            }
+
+            variables.co_authors = co_authors;
+        }
+
+        if is_used("qa_status") {
+            if let Some(pr) = ctx
+                .db_service
+                .pull_requests_get(owner, name, pr_handle.number())
+                .await?
+            {
+                variables.qa_status = pr.qa_status;
+            }
+        }
+
+        Ok(variables)
+    }
 }
 
 #[async_trait]
@@ -43,26 +137,75 @@ impl MergePullRequestInterface for MergePullRequest {
         pr_handle: &PullRequestHandle,
         merge_strategy: MergeStrategy,
         upstream_pr: &GhPullRequest,
-    ) -> Result<(), ApiError> {
-        let commit_title = format!("{} (#{})", upstream_pr.title, upstream_pr.number);
+    ) -> Result<()> {
+        let owner = pr_handle.repository_path().owner();
+        let name = pr_handle.repository_path().name();
+
+        let repository = ctx
+            .db_service
+            .repositories_get(owner, name)
+            .await?
+            .unwrap_or_default();
+        let rules = ctx.db_service.merge_rules_list(owner, name).await?;
+        let rule = MergeRule::find_most_specific(
+            &rules,
+            &upstream_pr.base.reference,
+            &upstream_pr.head.reference,
+        );
+
+        // Merge rule templates override repository templates
+        let title_template = rule
+            .and_then(|r| r.commit_title_template.as_deref())
+            .unwrap_or(&repository.merge_commit_title_template);
+        let message_template = rule
+            .and_then(|r| r.commit_message_template.as_deref())
+            .unwrap_or(&repository.merge_commit_message_template);
+
+        let mut variables = Self::build_variables(
+            ctx,
+            pr_handle,
+            upstream_pr,
+            &[title_template, message_template],
+        )
+        .await?;
+        if repository.conventional_commits {
+            variables.title = normalize_conventional_commit(&variables.title);
+        }
+
+        // GitHub needs a title
+        let mut commit_title = variables.render(title_template);
+        if commit_title.is_empty() {
+            commit_title = variables.render(DEFAULT_MERGE_COMMIT_TITLE_TEMPLATE);
+        }
+        let commit_message = variables.render(message_template);
 
         ctx.api_service
             .pulls_merge(
-                pr_handle.repository_path().owner(),
-                pr_handle.repository_path().name(),
+                owner,
+                name,
                 pr_handle.number(),
                 &commit_title,
-                "",
+                &commit_message,
                 Self::convert_strategy_for_github(merge_strategy),
             )
-            .await
+            .await?;
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::{
+        review::{GhReviewApi, GhReviewStateApi},
+        types::{GhBranch, GhPullCommit, GhUser},
+        ApiError,
+    };
+    use prbot_models::{PullRequest, QaStatus, Repository, RuleBranch};
+
     use super::*;
-    use crate::context::tests::CoreContextTest;
+    use crate::{context::tests::CoreContextTest, DomainError};
 
     #[tokio::test]
     async fn merge_success() {
@@ -130,9 +273,12 @@ mod tests {
             .await;
 
         match result {
-            Err(ApiError::MergeError {
-                pr_number,
-                repository_path,
+            Err(DomainError::ApiError {
+                source:
+                    ApiError::MergeError {
+                        pr_number,
+                        repository_path,
+                    },
             }) => {
                 assert_eq!(pr_number, 1);
                 assert_eq!(repository_path, "me/test");
@@ -140,4 +286,139 @@ mod tests {
             _ => panic!("Should error"),
         }
     }
+
+    #[tokio::test]
+    async fn merge_templates() {
+        let mut ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                merge_commit_title_template: "{title}".into(),
+                merge_commit_message_template:
+                    "Closes {issues}\nApproved by {approvers}\nQA: {qa_status}\n\n{co_authors}"
+                        .into(),
+                conventional_commits: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                qa_status: QaStatus::Pass,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .merge_rules_create(MergeRule {
+                repository_id: repo.id,
+                base_branch: RuleBranch::Named("main".into()),
+                head_branch: RuleBranch::Wildcard,
+                strategy: MergeStrategy::Squash,
+                commit_title_template: Some("{title} (#{number})".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        ctx.api_service
+            .expect_pull_reviews_list()
+            .once()
+            .return_once(|_, _, _| {
+                Ok(vec![
+                    GhReviewApi {
+                        user: GhUser {
+                            login: "zed".into(),
+                        },
+                        state: GhReviewStateApi::Approved,
+                        ..Default::default()
+                    },
+                    GhReviewApi {
+                        user: GhUser {
+                            login: "one".into(),
+                        },
+                        state: GhReviewStateApi::Approved,
+                        ..Default::default()
+                    },
+                    GhReviewApi {
+                        user: GhUser {
+                            login: "two".into(),
+                        },
+                        state: GhReviewStateApi::ChangesRequested,
+                        ..Default::default()
+                    },
+                    GhReviewApi {
+                        user: GhUser {
+                            login: "alice".into(),
+                        },
+                        state: GhReviewStateApi::Approved,
+                        ..Default::default()
+                    },
+                ])
+            });
+        ctx.api_service
+            .expect_pull_commits_list()
+            .once()
+            .return_once(|_, _, _| {
+                Ok(vec![
+                    GhPullCommit {
+                        message: "First".into(),
+                        author_name: "Me".into(),
+                        author_email: "1+me@users.noreply.github.com".into(),
+                        ..Default::default()
+                    },
+                    GhPullCommit {
+                        message: "Second\n\nCo-authored-by: Three <three@example.com>".into(),
+                        author_name: "Two".into(),
+                        author_email: "two@example.com".into(),
+                        ..Default::default()
+                    },
+                    GhPullCommit {
+                        message: "Third".into(),
+                        author_name: "Two".into(),
+                        author_email: "two@example.com".into(),
+                        ..Default::default()
+                    },
+                ])
+            });
+        ctx.api_service
+            .expect_pulls_merge()
+            .once()
+            .withf(|_, _, _, title, body, strategy| {
+                title == "feat(api): add thing (#1)"
+                    && body
+                        == "Closes #3\n\
+                        Approved by @alice, @one, @zed\n\
+                        QA: pass\n\
+                        \n\
+                        Co-authored-by: Two <two@example.com>\n\
+                        Co-authored-by: Three <three@example.com>"
+                    && *strategy == GhMergeStrategy::Squash
+            })
+            .return_once(|_, _, _, _, _, _| Ok(()));
+
+        MergePullRequest
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                MergeStrategy::Squash,
+                &GhPullRequest {
+                    number: 1,
+                    title: "Feature(API): Add thing.".into(),
+                    body: Some("Fixes #3".into()),
+                    user: GhUser { login: "me".into() },
+                    base: GhBranch {
+                        reference: "main".into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+    }
 }
@@ -1,14 +1,20 @@
 pub(crate) mod add_pull_request_rule;
 pub(crate) mod apply_pull_request_rules;
 pub(crate) mod automerge_pull_request;
+pub(crate) mod backport_pull_request;
 pub(crate) mod determine_pull_request_merge_strategy;
+pub(crate) mod explain_pull_request_rules;
 pub(crate) mod get_or_create_repository;
 pub(crate) mod merge_pull_request;
 pub(crate) mod process_pull_request_event;
 pub(crate) mod process_pull_request_opened;
+pub(crate) mod purge_closed_pull_requests;
+pub(crate) mod remind_stale_pull_requests;
 pub(crate) mod remove_pull_request_rule;
 pub(crate) mod resolve_pull_request_rules;
 pub(crate) mod set_step_label;
+pub(crate) mod simulate_pull_request_rule;
+pub(crate) mod synchronize_open_pull_requests;
 pub(crate) mod synchronize_pull_request;
 pub(crate) mod synchronize_pull_request_and_update_status;
 pub(crate) mod try_merge_pull_request_from_status;
@@ -17,14 +23,27 @@ pub(crate) mod update_step_label_from_status;
 pub use add_pull_request_rule::AddPullRequestRuleInterface;
 pub use apply_pull_request_rules::ApplyPullRequestRulesInterface;
 pub use automerge_pull_request::AutomergePullRequestInterface;
+pub use backport_pull_request::{
+    BackportOutcome, BackportPullRequestInterface, BACKPORT_LABEL_PREFIX,
+};
 pub use determine_pull_request_merge_strategy::DeterminePullRequestMergeStrategyInterface;
+pub use explain_pull_request_rules::{
+    ActionExplanation, ExplainPullRequestRulesInterface, PullRequestRulesExplanation,
+    RuleEvaluation, RuleExplanation,
+};
 pub use get_or_create_repository::GetOrCreateRepositoryInterface;
 pub use merge_pull_request::MergePullRequestInterface;
 pub use process_pull_request_event::ProcessPullRequestEventInterface;
 pub use process_pull_request_opened::ProcessPullRequestOpenedInterface;
+pub use purge_closed_pull_requests::PurgeClosedPullRequestsInterface;
+pub use remind_stale_pull_requests::{RemindStalePullRequestsInterface, STALE_LABEL};
 pub use remove_pull_request_rule::RemovePullRequestRuleInterface;
 pub use resolve_pull_request_rules::ResolvePullRequestRulesInterface;
 pub use set_step_label::SetStepLabelInterface;
+pub use simulate_pull_request_rule::{
+    RuleSettingChange, SimulatePullRequestRuleInterface, SimulatedPullRequest,
+};
+pub use synchronize_open_pull_requests::SynchronizeOpenPullRequestsInterface;
 pub use synchronize_pull_request::SynchronizePullRequestInterface;
 pub use synchronize_pull_request_and_update_status::SynchronizePullRequestAndUpdateStatusInterface;
 pub use try_merge_pull_request_from_status::TryMergePullRequestFromStatusInterface;
@@ -35,13 +54,19 @@ pub use self::{
     add_pull_request_rule::MockAddPullRequestRuleInterface,
     apply_pull_request_rules::MockApplyPullRequestRulesInterface,
     automerge_pull_request::MockAutomergePullRequestInterface,
+    backport_pull_request::MockBackportPullRequestInterface,
     determine_pull_request_merge_strategy::MockDeterminePullRequestMergeStrategyInterface,
+    explain_pull_request_rules::MockExplainPullRequestRulesInterface,
     get_or_create_repository::MockGetOrCreateRepositoryInterface,
     merge_pull_request::MockMergePullRequestInterface,
     process_pull_request_event::MockProcessPullRequestEventInterface,
+    purge_closed_pull_requests::MockPurgeClosedPullRequestsInterface,
+    remind_stale_pull_requests::MockRemindStalePullRequestsInterface,
     remove_pull_request_rule::MockRemovePullRequestRuleInterface,
     resolve_pull_request_rules::MockResolvePullRequestRulesInterface,
     set_step_label::MockSetStepLabelInterface,
+    simulate_pull_request_rule::MockSimulatePullRequestRuleInterface,
+    synchronize_open_pull_requests::MockSynchronizeOpenPullRequestsInterface,
     synchronize_pull_request::MockSynchronizePullRequestInterface,
     synchronize_pull_request_and_update_status::MockSynchronizePullRequestAndUpdateStatusInterface,
     try_merge_pull_request_from_status::MockTryMergePullRequestFromStatusInterface,
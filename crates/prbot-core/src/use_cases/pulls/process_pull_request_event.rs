@@ -1,9 +1,20 @@
 use async_trait::async_trait;
 use prbot_ghapi_interface::types::{GhPullRequestAction, GhPullRequestEvent};
+use prbot_models::{PullRequestEventKind, PullRequestHandle};
 use shaku::{Component, HasComponent, Interface};
 
-use super::ProcessPullRequestOpenedInterface;
-use crate::{use_cases::status::UpdatePullRequestStatusInterface, CoreContext, Result};
+use super::{
+    BackportPullRequestInterface, ProcessPullRequestOpenedInterface,
+    ResolvePullRequestRulesInterface, BACKPORT_LABEL_PREFIX,
+};
+use crate::{
+    use_cases::{
+        reviews::{AssignCodeOwnersReviewersInterface, AssignPoolReviewersInterface},
+        stats::{has_pull_request_event, record_pull_request_event},
+        status::UpdatePullRequestStatusInterface,
+    },
+    CoreContext, Result,
+};
 
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
@@ -71,6 +82,33 @@ impl ProcessPullRequestEventInterface for ProcessPullRequestEvent {
             _ => (),
         }
 
+        let lifecycle_event = match event.action {
+            GhPullRequestAction::Closed if event.pull_request.merged_at.is_some() => {
+                Some(PullRequestEventKind::Merged)
+            }
+            GhPullRequestAction::Closed => Some(PullRequestEventKind::Closed),
+            GhPullRequestAction::Reopened => Some(PullRequestEventKind::Reopened),
+            _ => None,
+        };
+
+        if let Some(kind) = lifecycle_event {
+            let pr_handle = (repo_owner.as_str(), repo_name.as_str(), pr_number).into();
+
+            // A pull request is merged only once, even if the event is delivered again
+            let already_merged = kind == PullRequestEventKind::Merged
+                && has_pull_request_event(ctx.db_service, &pr_handle, kind).await?;
+            if !already_merged {
+                record_pull_request_event(
+                    ctx.db_service,
+                    &pr_handle,
+                    kind,
+                    Some(&event.sender.login),
+                    "",
+                )
+                .await?;
+            }
+        }
+
         if let GhPullRequestAction::Edited = event.action {
             // Update PR title
             status_changed = true;
@@ -82,6 +120,35 @@ impl ProcessPullRequestEventInterface for ProcessPullRequestEvent {
                 .pulls_get(repo_owner, repo_name, pr_number)
                 .await?;
 
+            // New commits may change files owned by other people
+            if event.action == GhPullRequestAction::Synchronize {
+                let assign_code_owners: &dyn AssignCodeOwnersReviewersInterface =
+                    ctx.core_module.resolve_ref();
+                assign_code_owners
+                    .run(
+                        ctx,
+                        &(repo_owner.as_str(), repo_name.as_str(), pr_number).into(),
+                        &upstream_pr,
+                    )
+                    .await?;
+            }
+
+            if event.action == GhPullRequestAction::ReadyForReview {
+                let resolve_rules: &dyn ResolvePullRequestRulesInterface =
+                    ctx.core_module.resolve_ref();
+                let pr_handle: PullRequestHandle =
+                    (repo_owner.as_str(), repo_name.as_str(), pr_number).into();
+                let rules = resolve_rules
+                    .run(ctx, pr_handle.repository_path(), &upstream_pr)
+                    .await?;
+
+                let assign_pool_reviewers: &dyn AssignPoolReviewersInterface =
+                    ctx.core_module.resolve_ref();
+                assign_pool_reviewers
+                    .run(ctx, &pr_handle, &upstream_pr, &rules)
+                    .await?;
+            }
+
             let update_pull_request_status: &dyn UpdatePullRequestStatusInterface =
                 ctx.core_module.resolve_ref();
             update_pull_request_status
@@ -91,6 +158,29 @@ impl ProcessPullRequestEventInterface for ProcessPullRequestEvent {
                     &upstream_pr,
                 )
                 .await?;
+
+            // Backport labels are handled once merged
+            if lifecycle_event == Some(PullRequestEventKind::Merged) {
+                let branches: Vec<_> = upstream_pr
+                    .labels
+                    .iter()
+                    .filter_map(|l| l.name.strip_prefix(BACKPORT_LABEL_PREFIX))
+                    .map(String::from)
+                    .collect();
+
+                if !branches.is_empty() {
+                    let backport_pull_request: &dyn BackportPullRequestInterface =
+                        ctx.core_module.resolve_ref();
+                    backport_pull_request
+                        .run(
+                            ctx,
+                            &(repo_owner.as_str(), repo_name.as_str(), pr_number).into(),
+                            &upstream_pr,
+                            &branches,
+                        )
+                        .await?;
+                }
+            }
         }
 
         Ok(())
@@ -251,4 +341,87 @@ mod tests {
             .await
             .unwrap()
     }
+
+    #[tokio::test]
+    async fn merged_event_should_record_merge_once() {
+        let mut ctx = CoreContextTest::new();
+
+        ctx.api_service
+            .expect_pulls_get()
+            .times(2)
+            .returning(|_, _, _| {
+                Ok(GhPullRequest {
+                    number: 1,
+                    ..Default::default()
+                })
+            });
+
+        let mut update_pull_request_status = MockUpdatePullRequestStatusInterface::new();
+        update_pull_request_status
+            .expect_run()
+            .times(2)
+            .returning(|_, _, _| Ok(()));
+
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        ctx.db_service
+            .pull_requests_create(
+                PullRequest {
+                    number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn UpdatePullRequestStatusInterface>(Box::new(
+                update_pull_request_status,
+            ))
+            .build();
+
+        let event = GhPullRequestEvent {
+            action: GhPullRequestAction::Closed,
+            pull_request: GhPullRequest {
+                number: 1,
+                merged_at: Some(time::OffsetDateTime::now_utc()),
+                ..Default::default()
+            },
+            repository: GhRepository {
+                owner: GhUser { login: "me".into() },
+                name: "test".into(),
+                ..Default::default()
+            },
+            sender: GhUser {
+                login: "merger".into(),
+            },
+            ..Default::default()
+        };
+
+        // The same event can be delivered again
+        for _ in 0..2 {
+            ProcessPullRequestEvent
+                .run(&ctx.as_context(), event.clone())
+                .await
+                .unwrap();
+        }
+
+        let events = ctx
+            .db_service
+            .pull_request_events_list("me", "test", 1)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, PullRequestEventKind::Merged);
+        assert_eq!(events[0].author.as_deref(), Some("merger"));
+    }
 }
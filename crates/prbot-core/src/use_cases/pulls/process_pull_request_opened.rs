@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use prbot_ghapi_interface::types::GhPullRequestEvent;
-use prbot_models::{PullRequest, PullRequestHandle, Repository};
+use prbot_models::{PullRequest, PullRequestEventKind, PullRequestHandle, Repository};
 use shaku::{Component, HasComponent, Interface};
 
 use crate::{
@@ -13,6 +13,8 @@ use crate::{
             ApplyPullRequestRulesInterface, GetOrCreateRepositoryInterface,
             ResolvePullRequestRulesInterface,
         },
+        reviews::{AssignCodeOwnersReviewersInterface, AssignPoolReviewersInterface},
+        stats::record_pull_request_event,
         status::UpdatePullRequestStatusInterface,
     },
     CoreContext, Result,
@@ -91,12 +93,25 @@ impl ProcessPullRequestOpenedInterface for ProcessPullRequestOpened {
                         )
                         .await?;
 
+                    record_pull_request_event(
+                        ctx.db_service,
+                        pr_handle,
+                        PullRequestEventKind::Opened,
+                        Some(&event.pull_request.user.login),
+                        "",
+                    )
+                    .await?;
+
                     // Get upstream pull request
                     let upstream_pr = ctx
                         .api_service
                         .pulls_get(&repo_model.owner, &repo_model.name, pr_model.number)
                         .await?;
 
+                    let assign_code_owners: &dyn AssignCodeOwnersReviewersInterface =
+                        ctx.core_module.resolve_ref();
+                    assign_code_owners.run(ctx, pr_handle, &upstream_pr).await?;
+
                     let update_pull_request_status: &dyn UpdatePullRequestStatusInterface =
                         ctx.core_module.resolve_ref();
                     update_pull_request_status
@@ -143,7 +158,15 @@ impl ProcessPullRequestOpenedInterface for ProcessPullRequestOpened {
 
                     let apply_rules: &dyn ApplyPullRequestRulesInterface =
                         ctx.core_module.resolve_ref();
-                    apply_rules.run(ctx, pr_handle, rules).await?;
+                    apply_rules
+                        .run(ctx, pr_handle, &upstream_pr, rules.clone())
+                        .await?;
+
+                    let assign_pool_reviewers: &dyn AssignPoolReviewersInterface =
+                        ctx.core_module.resolve_ref();
+                    assign_pool_reviewers
+                        .run(ctx, pr_handle, &upstream_pr, &rules)
+                        .await?;
 
                     Ok(PullRequestOpenedStatus::Created)
                 } else {
@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhPullRequestState;
+use shaku::{Component, Interface};
+use time::OffsetDateTime;
+use tracing::warn;
+
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait PurgeClosedPullRequestsInterface: Interface {
+    /// Delete known pull requests closed before a date, and return how many were deleted.
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, closed_before: OffsetDateTime) -> Result<usize>;
+}
+
+#[derive(Component)]
+#[shaku(interface = PurgeClosedPullRequestsInterface)]
+pub(crate) struct PurgeClosedPullRequests;
+
+#[async_trait]
+impl PurgeClosedPullRequestsInterface for PurgeClosedPullRequests {
+    #[tracing::instrument(skip(self, ctx))]
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, closed_before: OffsetDateTime) -> Result<usize> {
+        let mut count = 0;
+
+        for repository in ctx.db_service.repositories_all().await? {
+            let (owner, name) = (repository.owner.as_str(), repository.name.as_str());
+            let pull_requests = ctx.db_service.pull_requests_list(owner, name).await?;
+            if pull_requests.is_empty() {
+                continue;
+            }
+
+            let open_numbers: HashSet<u64> = match ctx.api_service.pulls_list(owner, name).await {
+                Ok(prs) => prs.into_iter().map(|pr| pr.number).collect(),
+                Err(e) => {
+                    warn!(
+                        repository_path = %repository.path(),
+                        error = %e,
+                        message = "Could not list open pull requests"
+                    );
+                    continue;
+                }
+            };
+
+            for pull_request in pull_requests
+                .iter()
+                .filter(|pr| !open_numbers.contains(&pr.number))
+            {
+                let upstream_pr = match ctx
+                    .api_service
+                    .pulls_get(owner, name, pull_request.number)
+                    .await
+                {
+                    Ok(pr) => pr,
+                    Err(e) => {
+                        warn!(
+                            repository_path = %repository.path(),
+                            pr_number = pull_request.number,
+                            error = %e,
+                            message = "Could not get pull request"
+                        );
+                        continue;
+                    }
+                };
+
+                let closed = upstream_pr.state == GhPullRequestState::Closed
+                    && upstream_pr.closed_at.is_some_and(|at| at < closed_before);
+                if closed
+                    && ctx
+                        .db_service
+                        .pull_requests_delete(owner, name, pull_request.number)
+                        .await?
+                {
+                    count += 1;
+                }
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::types::GhPullRequest;
+    use prbot_models::{PullRequest, Repository};
+    use time::Duration;
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    #[tokio::test]
+    async fn run() {
+        let mut ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        for number in 1..=3 {
+            ctx.db_service
+                .pull_requests_create(PullRequest {
+                    repository_id: repo.id,
+                    number,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        let now = OffsetDateTime::now_utc();
+        ctx.api_service
+            .expect_pulls_list()
+            .once()
+            .return_once(|_, _| {
+                Ok(vec![GhPullRequest {
+                    number: 1,
+                    ..Default::default()
+                }])
+            });
+        ctx.api_service
+            .expect_pulls_get()
+            .times(2)
+            .returning(move |_, _, number| {
+                Ok(GhPullRequest {
+                    number,
+                    state: GhPullRequestState::Closed,
+                    // Pull request #3 was closed recently
+                    closed_at: Some(now - Duration::days(if number == 2 { 40 } else { 1 })),
+                    ..Default::default()
+                })
+            });
+
+        let count = PurgeClosedPullRequests
+            .run(&ctx.as_context(), now - Duration::days(30))
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+
+        let numbers: Vec<_> = ctx
+            .db_service
+            .pull_requests_list("me", "test")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|pr| pr.number)
+            .collect();
+        assert_eq!(numbers, vec![1, 3]);
+    }
+}
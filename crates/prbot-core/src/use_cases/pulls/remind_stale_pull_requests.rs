@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhPullRequest;
+use prbot_models::{PullRequest, Repository, StepTransition};
+use shaku::{Component, Interface};
+use time::{Duration, OffsetDateTime};
+use tracing::warn;
+
+use crate::{CoreContext, Result};
+
+/// Label added on stale pull requests.
+pub const STALE_LABEL: &str = "stale";
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait RemindStalePullRequestsInterface: Interface {
+    /// Remind reviewers of pull requests stuck in the same step, and return how many were reminded.
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, now: OffsetDateTime) -> Result<usize>;
+}
+
+#[derive(Component)]
+#[shaku(interface = RemindStalePullRequestsInterface)]
+pub(crate) struct RemindStalePullRequests;
+
+#[async_trait]
+impl RemindStalePullRequestsInterface for RemindStalePullRequests {
+    #[tracing::instrument(skip(self, ctx))]
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, now: OffsetDateTime) -> Result<usize> {
+        let mut count = 0;
+
+        for repository in ctx.db_service.repositories_all().await? {
+            if repository.stale_threshold_days == 0 {
+                continue;
+            }
+
+            let (owner, name) = (repository.owner.as_str(), repository.name.as_str());
+            let mut stale = vec![];
+            for pull_request in ctx.db_service.pull_requests_list(owner, name).await? {
+                let transitions = ctx
+                    .db_service
+                    .step_transitions_list(owner, name, pull_request.number)
+                    .await?;
+                if let Some(transition) = transitions.last() {
+                    if Self::is_stale(&repository, &pull_request, transition, now) {
+                        stale.push((pull_request.number, transition.clone()));
+                    }
+                }
+            }
+            if stale.is_empty() {
+                continue;
+            }
+
+            // Closed pull requests stay known until purged: only open ones are reminded
+            let mut open_prs: HashMap<u64, GhPullRequest> =
+                match ctx.api_service.pulls_list(owner, name).await {
+                    Ok(prs) => prs.into_iter().map(|pr| (pr.number, pr)).collect(),
+                    Err(e) => {
+                        warn!(
+                            repository_path = %repository.path(),
+                            error = %e,
+                            message = "Could not list open pull requests"
+                        );
+                        continue;
+                    }
+                };
+
+            for (number, transition) in stale {
+                let Some(upstream_pr) = open_prs.remove(&number) else {
+                    continue;
+                };
+
+                Self::remind(ctx, &repository, &upstream_pr, &transition, now).await?;
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+impl RemindStalePullRequests {
+    /// Check if a pull request stayed in its current step longer than the repository threshold.
+    ///
+    /// The delay restarts after each reminder, and after a snooze.
+    pub fn is_stale(
+        repository: &Repository,
+        pull_request: &PullRequest,
+        transition: &StepTransition,
+        now: OffsetDateTime,
+    ) -> bool {
+        if repository.stale_threshold_days == 0 || !transition.step.can_be_stale() {
+            return false;
+        }
+
+        let since = [
+            Some(transition.created_at),
+            pull_request.stale_reminded_at,
+            pull_request.snoozed_until,
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(transition.created_at);
+
+        now - since >= Duration::days(repository.stale_threshold_days as i64)
+    }
+
+    async fn remind(
+        ctx: &CoreContext<'_>,
+        repository: &Repository,
+        upstream_pr: &GhPullRequest,
+        transition: &StepTransition,
+        now: OffsetDateTime,
+    ) -> Result<()> {
+        let (owner, name, number) = (
+            repository.owner.as_str(),
+            repository.name.as_str(),
+            upstream_pr.number,
+        );
+
+        ctx.api_service
+            .comments_post(
+                owner,
+                name,
+                number,
+                &Self::reminder_message(upstream_pr, transition, now),
+            )
+            .await?;
+        ctx.api_service
+            .issue_labels_add(owner, name, number, &[STALE_LABEL.into()])
+            .await?;
+        if repository.stale_convert_to_draft && !upstream_pr.draft {
+            ctx.api_service
+                .pulls_convert_to_draft(owner, name, number)
+                .await?;
+        }
+
+        ctx.db_service
+            .pull_requests_set_stale_reminded_at(owner, name, number, Some(now))
+            .await?;
+
+        Ok(())
+    }
+
+    fn reminder_message(
+        upstream_pr: &GhPullRequest,
+        transition: &StepTransition,
+        now: OffsetDateTime,
+    ) -> String {
+        let mentions = if upstream_pr.requested_reviewers.is_empty() {
+            format!("@{}", upstream_pr.user.login)
+        } else {
+            upstream_pr
+                .requested_reviewers
+                .iter()
+                .map(|u| format!("@{}", u.login))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        format!(
+            ":hourglass: {mentions}, this pull request is in step _{}_ for {} day(s).\n\nUse `bot snooze <duration>` to pause these reminders.",
+            transition.step,
+            (now - transition.created_at).whole_days()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::types::GhUser;
+    use prbot_models::StepLabel;
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    async fn arrange(
+        ctx: &CoreContextTest,
+        convert_to_draft: bool,
+        step: StepLabel,
+        entered_at: OffsetDateTime,
+    ) {
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                stale_threshold_days: 3,
+                stale_convert_to_draft: convert_to_draft,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let pr = ctx
+            .db_service
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .step_transitions_create(StepTransition {
+                pull_request_id: pr.id,
+                step,
+                created_at: entered_at,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn is_stale() {
+        let now = OffsetDateTime::now_utc();
+        let repository = Repository {
+            stale_threshold_days: 3,
+            ..Default::default()
+        };
+        let transition = StepTransition {
+            step: StepLabel::AwaitingReview,
+            created_at: now - Duration::days(4),
+            ..Default::default()
+        };
+
+        assert!(RemindStalePullRequests::is_stale(
+            &repository,
+            &PullRequest::default(),
+            &transition,
+            now
+        ));
+        assert!(!RemindStalePullRequests::is_stale(
+            &Repository::default(),
+            &PullRequest::default(),
+            &transition,
+            now
+        ));
+        assert!(!RemindStalePullRequests::is_stale(
+            &repository,
+            &PullRequest::default(),
+            &StepTransition {
+                step: StepLabel::AwaitingMerge,
+                ..transition.clone()
+            },
+            now
+        ));
+        assert!(!RemindStalePullRequests::is_stale(
+            &repository,
+            &PullRequest {
+                stale_reminded_at: Some(now - Duration::days(1)),
+                ..Default::default()
+            },
+            &transition,
+            now
+        ));
+        assert!(!RemindStalePullRequests::is_stale(
+            &repository,
+            &PullRequest {
+                snoozed_until: Some(now + Duration::days(1)),
+                ..Default::default()
+            },
+            &transition,
+            now
+        ));
+    }
+
+    #[tokio::test]
+    async fn run_reminds_stale_pull_request() {
+        let now = OffsetDateTime::now_utc();
+        let mut ctx = CoreContextTest::new();
+        arrange(
+            &ctx,
+            true,
+            StepLabel::AwaitingReview,
+            now - Duration::days(4),
+        )
+        .await;
+
+        ctx.api_service
+            .expect_pulls_list()
+            .once()
+            .return_once(|_, _| {
+                Ok(vec![GhPullRequest {
+                    number: 1,
+                    requested_reviewers: vec![
+                        GhUser {
+                            login: "foo".into(),
+                        },
+                        GhUser {
+                            login: "bar".into(),
+                        },
+                    ],
+                    ..Default::default()
+                }])
+            });
+        ctx.api_service
+            .expect_comments_post()
+            .once()
+            .withf(|_, _, number, body| {
+                *number == 1
+                    && body.starts_with(
+                        ":hourglass: @foo, @bar, this pull request is in step _step/awaiting-review_ for 4 day(s).",
+                    )
+            })
+            .return_once(|_, _, _, _| Ok(1));
+        ctx.api_service
+            .expect_issue_labels_add()
+            .once()
+            .withf(|_, _, _, labels| labels == [STALE_LABEL])
+            .return_once(|_, _, _, _| Ok(()));
+        ctx.api_service
+            .expect_pulls_convert_to_draft()
+            .once()
+            .return_once(|_, _, _| Ok(()));
+
+        let count = RemindStalePullRequests
+            .run(&ctx.as_context(), now)
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+
+        let pr = ctx
+            .db_service
+            .pull_requests_get_expect("me", "test", 1)
+            .await
+            .unwrap();
+        assert_eq!(pr.stale_reminded_at, Some(now));
+
+        // Already reminded
+        let count = RemindStalePullRequests
+            .run(&ctx.as_context(), now + Duration::days(1))
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn run_skips_recent_pull_request() {
+        let now = OffsetDateTime::now_utc();
+        let mut ctx = CoreContextTest::new();
+        arrange(
+            &ctx,
+            false,
+            StepLabel::AwaitingReview,
+            now - Duration::days(1),
+        )
+        .await;
+        ctx.api_service.expect_pulls_list().never();
+
+        let count = RemindStalePullRequests
+            .run(&ctx.as_context(), now)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn run_skips_closed_pull_request() {
+        let now = OffsetDateTime::now_utc();
+        let mut ctx = CoreContextTest::new();
+        arrange(
+            &ctx,
+            false,
+            StepLabel::AwaitingReview,
+            now - Duration::days(4),
+        )
+        .await;
+        ctx.api_service
+            .expect_pulls_list()
+            .once()
+            .return_once(|_, _| Ok(vec![]));
+        ctx.api_service.expect_pulls_get().never();
+        ctx.api_service.expect_comments_post().never();
+
+        let count = RemindStalePullRequests
+            .run(&ctx.as_context(), now)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::HashSet;
+
 use async_trait::async_trait;
+use glob::Pattern;
 use prbot_ghapi_interface::types::GhPullRequest;
-use prbot_models::{PullRequestRule, RepositoryPath, RuleBranch, RuleCondition};
+use prbot_models::{PullRequestRule, RepositoryPath, RuleCondition};
+use regex::Regex;
 use shaku::{Component, Interface};
 
 use crate::{CoreContext, Result};
@@ -28,39 +32,132 @@ impl ResolvePullRequestRulesInterface for ResolvePullRequestRules {
         repository_path: &RepositoryPath,
         upstream_pr: &GhPullRequest,
     ) -> Result<Vec<PullRequestRule>> {
-        fn match_condition(condition: &RuleCondition, upstream_pr: &GhPullRequest) -> bool {
-            match condition {
-                RuleCondition::Author(author) => upstream_pr.user.login == *author,
-                RuleCondition::BaseBranch(branch) => match branch {
-                    RuleBranch::Named(branch) => upstream_pr.base.reference == *branch,
-                    RuleBranch::Wildcard => true,
-                },
-                RuleCondition::HeadBranch(branch) => match branch {
-                    RuleBranch::Named(branch) => upstream_pr.head.reference == *branch,
-                    RuleBranch::Wildcard => true,
-                },
-            }
-        }
-
-        fn match_all_conditions(conditions: &[RuleCondition], upstream_pr: &GhPullRequest) -> bool {
-            conditions.iter().all(|c| match_condition(c, upstream_pr))
-        }
-
-        Ok(ctx
+        let rules = ctx
             .db_service
             .pull_request_rules_list(repository_path.owner(), repository_path.name())
-            .await?
+            .await?;
+
+        Self::match_rules(ctx, repository_path, upstream_pr, rules).await
+    }
+}
+
+impl ResolvePullRequestRules {
+    /// Get rules matching a pull request, sorted by priority.
+    pub(crate) async fn match_rules(
+        ctx: &CoreContext<'_>,
+        repository_path: &RepositoryPath,
+        upstream_pr: &GhPullRequest,
+        rules: Vec<PullRequestRule>,
+    ) -> Result<Vec<PullRequestRule>> {
+        let mut rules: Vec<_> = rules
             .into_iter()
             .filter(|r| !r.conditions.is_empty() && !r.actions.is_empty())
-            .filter(|r| match_all_conditions(&r.conditions, upstream_pr))
+            .collect();
+        PullRequestRule::sort_by_priority(&mut rules);
+
+        let facts = RuleFacts::fetch(ctx, repository_path, upstream_pr, &rules).await?;
+        Ok(rules
+            .into_iter()
+            .filter(|r| r.conditions.iter().all(|c| facts.matches(c)))
             .collect())
     }
 }
 
+/// Pull request data used to match rule conditions.
+pub(crate) struct RuleFacts<'a> {
+    upstream_pr: &'a GhPullRequest,
+    changed_files: Vec<String>,
+    author_teams: HashSet<String>,
+}
+
+impl<'a> RuleFacts<'a> {
+    /// Fetch data from the API, only if rules need it.
+    pub(crate) async fn fetch(
+        ctx: &CoreContext<'_>,
+        repository_path: &RepositoryPath,
+        upstream_pr: &'a GhPullRequest,
+        rules: &[PullRequestRule],
+    ) -> Result<RuleFacts<'a>> {
+        let mut needs_changed_files = false;
+        let mut teams = HashSet::new();
+        for condition in rules.iter().flat_map(|r| r.conditions.iter()) {
+            condition.walk(&mut |c| match c {
+                RuleCondition::ChangedFiles(_) => needs_changed_files = true,
+                RuleCondition::AuthorTeam(team) => {
+                    teams.insert(team.as_str());
+                }
+                _ => (),
+            });
+        }
+
+        let changed_files = if needs_changed_files {
+            ctx.api_service
+                .pull_files_list(
+                    repository_path.owner(),
+                    repository_path.name(),
+                    upstream_pr.number,
+                )
+                .await?
+        } else {
+            vec![]
+        };
+
+        let mut author_teams = HashSet::new();
+        for team in teams {
+            let (org, team_slug) = split_team(repository_path, team);
+            let members = ctx.api_service.team_members_list(org, team_slug).await?;
+            if members.contains(&upstream_pr.user.login) {
+                author_teams.insert(team.to_string());
+            }
+        }
+
+        Ok(RuleFacts {
+            upstream_pr,
+            changed_files,
+            author_teams,
+        })
+    }
+
+    pub(crate) fn matches(&self, condition: &RuleCondition) -> bool {
+        let pr = self.upstream_pr;
+
+        match condition {
+            RuleCondition::Author(author) => pr.user.login == *author,
+            RuleCondition::BaseBranch(branch) => branch.matches(&pr.base.reference),
+            RuleCondition::HeadBranch(branch) => branch.matches(&pr.head.reference),
+            RuleCondition::All(conditions) => conditions.iter().all(|c| self.matches(c)),
+            RuleCondition::Any(conditions) => conditions.iter().any(|c| self.matches(c)),
+            RuleCondition::Not(condition) => !self.matches(condition),
+            RuleCondition::Label(label) => pr.labels.iter().any(|l| l.name == *label),
+            RuleCondition::TitleMatches(regex) => Regex::new(regex)
+                .map(|r| r.is_match(&pr.title))
+                .unwrap_or(false),
+            RuleCondition::Draft(draft) => pr.draft == *draft,
+            RuleCondition::AuthorTeam(team) => self.author_teams.contains(team),
+            RuleCondition::ChangedFiles(pattern) => Pattern::new(pattern)
+                .map(|p| self.changed_files.iter().any(|f| p.matches(f)))
+                .unwrap_or(false),
+            RuleCondition::MaxDiffSize(size) => pr.additions + pr.deletions <= *size,
+            RuleCondition::MinDiffSize(size) => pr.additions + pr.deletions >= *size,
+            RuleCondition::AuthorIsBot(is_bot) => pr.user.login.ends_with("[bot]") == *is_bot,
+        }
+    }
+}
+
+/// Split a team name in organization and team slug, using the repository owner by default.
+fn split_team<'a>(repository_path: &'a RepositoryPath, team: &'a str) -> (&'a str, &'a str) {
+    let team = team.trim_start_matches('@');
+    team.split_once('/')
+        .unwrap_or((repository_path.owner(), team))
+}
+
 #[cfg(test)]
 mod tests {
     use prbot_database_interface::DbService;
-    use prbot_ghapi_interface::types::{GhPullRequest, GhUser};
+    use prbot_ghapi_interface::{
+        types::{GhLabel, GhPullRequest, GhUser},
+        MockApiService,
+    };
     use prbot_models::{PullRequestRule, Repository, RuleAction, RuleCondition};
 
     use super::{ResolvePullRequestRules, ResolvePullRequestRulesInterface};
@@ -85,6 +182,7 @@ mod tests {
                 name: "Rule 1".into(),
                 actions: vec![],
                 conditions: vec![],
+                ..Default::default()
             })
             .await
             .unwrap();
@@ -128,6 +226,7 @@ mod tests {
                 name: "Rule 1".into(),
                 actions: vec![RuleAction::SetAutomerge(true)],
                 conditions: vec![RuleCondition::Author("bot".into())],
+                ..Default::default()
             })
             .await
             .unwrap();
@@ -167,6 +266,7 @@ mod tests {
                 name: "Rule 1".into(),
                 actions: vec![RuleAction::SetAutomerge(true)],
                 conditions: vec![RuleCondition::Author("bot".into())],
+                ..Default::default()
             })
             .await
             .unwrap();
@@ -178,6 +278,7 @@ mod tests {
                 name: "Rule 2".into(),
                 actions: vec![RuleAction::SetChecksEnabled(true)],
                 conditions: vec![RuleCondition::Author("bot".into())],
+                ..Default::default()
             })
             .await
             .unwrap();
@@ -188,6 +289,7 @@ mod tests {
                 name: "Rule 3".into(),
                 actions: vec![RuleAction::SetChecksEnabled(true)],
                 conditions: vec![RuleCondition::Author("bot1".into())],
+                ..Default::default()
             })
             .await
             .unwrap();
@@ -199,4 +301,150 @@ mod tests {
 
         assert_eq!(rules, vec![rule1, rule2]);
     }
+
+    #[tokio::test]
+    async fn resolve_combinators() {
+        let ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let upstream_pr = GhPullRequest {
+            title: "chore(deps): bump serde".into(),
+            user: GhUser {
+                login: "dependabot[bot]".into(),
+            },
+            labels: vec![GhLabel {
+                name: "dependencies".into(),
+                ..Default::default()
+            }],
+            additions: 10,
+            deletions: 5,
+            ..Default::default()
+        };
+
+        let rule1 = ctx
+            .db_service
+            .pull_request_rules_create(PullRequestRule {
+                repository_id: repo.id,
+                name: "Rule 1".into(),
+                actions: vec![RuleAction::SetAutomerge(true)],
+                conditions: vec![RuleCondition::All(vec![
+                    RuleCondition::Label("dependencies".into()),
+                    RuleCondition::AuthorIsBot(true),
+                    RuleCondition::Not(Box::new(RuleCondition::Draft(true))),
+                    RuleCondition::Any(vec![
+                        RuleCondition::TitleMatches("^fix".into()),
+                        RuleCondition::TitleMatches("^chore\\(deps\\)".into()),
+                    ]),
+                    RuleCondition::MaxDiffSize(15),
+                ])],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        ctx.db_service
+            .pull_request_rules_create(PullRequestRule {
+                repository_id: repo.id,
+                name: "Rule 2".into(),
+                actions: vec![RuleAction::SetAutomerge(true)],
+                conditions: vec![RuleCondition::Any(vec![
+                    RuleCondition::Label("security".into()),
+                    RuleCondition::MinDiffSize(16),
+                ])],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let rules = ResolvePullRequestRules
+            .run(&ctx.as_context(), &repo.path(), &upstream_pr)
+            .await
+            .unwrap();
+
+        assert_eq!(rules, vec![rule1]);
+    }
+
+    #[tokio::test]
+    async fn resolve_changed_files_and_team() {
+        let mut ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_pull_files_list()
+                .once()
+                .withf(|owner, name, number| owner == "me" && name == "name" && number == &1)
+                .returning(|_, _, _| Ok(vec!["docs/index.md".into(), "README.md".into()]));
+            svc.expect_team_members_list()
+                .once()
+                .withf(|org, team| org == "me" && team == "docs")
+                .returning(|_, _| Ok(vec!["writer".into()]));
+            svc.expect_team_members_list()
+                .once()
+                .withf(|org, team| org == "other" && team == "core")
+                .returning(|_, _| Ok(vec!["someone".into()]));
+
+            svc
+        };
+
+        let upstream_pr = GhPullRequest {
+            number: 1,
+            user: GhUser {
+                login: "writer".into(),
+            },
+            ..Default::default()
+        };
+
+        let rule1 = ctx
+            .db_service
+            .pull_request_rules_create(PullRequestRule {
+                repository_id: repo.id,
+                name: "Rule 1".into(),
+                actions: vec![RuleAction::SetQaEnabled(false)],
+                conditions: vec![
+                    RuleCondition::ChangedFiles("docs/**".into()),
+                    RuleCondition::AuthorTeam("@docs".into()),
+                ],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        ctx.db_service
+            .pull_request_rules_create(PullRequestRule {
+                repository_id: repo.id,
+                name: "Rule 2".into(),
+                actions: vec![RuleAction::SetQaEnabled(false)],
+                conditions: vec![RuleCondition::Any(vec![
+                    RuleCondition::ChangedFiles("src/**".into()),
+                    RuleCondition::AuthorTeam("other/core".into()),
+                ])],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let rules = ResolvePullRequestRules
+            .run(&ctx.as_context(), &repo.path(), &upstream_pr)
+            .await
+            .unwrap();
+
+        assert_eq!(rules, vec![rule1]);
+    }
 }
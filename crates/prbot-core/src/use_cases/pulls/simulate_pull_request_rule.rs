@@ -0,0 +1,377 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhPullRequest;
+use prbot_models::{PullRequest, PullRequestRule, QaStatus, Repository, RuleAction};
+use shaku::{Component, Interface};
+
+use super::{
+    add_pull_request_rule::validate_rule_conditions,
+    resolve_pull_request_rules::ResolvePullRequestRules,
+};
+use crate::{CoreContext, Result};
+
+/// Setting change on a pull request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSettingChange {
+    pub setting: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// Pull request affected by a simulated rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulatedPullRequest {
+    pub number: u64,
+    pub title: String,
+    pub changes: Vec<RuleSettingChange>,
+}
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait SimulatePullRequestRuleInterface: Interface {
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository: &Repository,
+        rule: PullRequestRule,
+    ) -> Result<Vec<SimulatedPullRequest>>;
+}
+
+/// Simulate a rule on open pull requests, without writing anything.
+#[derive(Component)]
+#[shaku(interface = SimulatePullRequestRuleInterface)]
+pub(crate) struct SimulatePullRequestRule;
+
+#[async_trait]
+impl SimulatePullRequestRuleInterface for SimulatePullRequestRule {
+    #[tracing::instrument(skip(self, ctx), fields(repository_path = %repository.path()))]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository: &Repository,
+        rule: PullRequestRule,
+    ) -> Result<Vec<SimulatedPullRequest>> {
+        let owner = &repository.owner;
+        let name = &repository.name;
+        let repository_path = repository.path();
+        validate_rule_conditions(&rule.name, &rule.conditions)?;
+
+        // The simulated rule replaces an existing rule with the same name
+        let mut rules: Vec<_> = ctx
+            .db_service
+            .pull_request_rules_list(owner, name)
+            .await?
+            .into_iter()
+            .filter(|r| r.name != rule.name)
+            .collect();
+        rules.push(rule.clone());
+
+        let mut simulated = vec![];
+        for listed_pr in ctx.api_service.pulls_list(owner, name).await? {
+            // Rules are only applied on pull requests known by the bot
+            let pr_model = match ctx
+                .db_service
+                .pull_requests_get(owner, name, listed_pr.number)
+                .await?
+            {
+                Some(pr) => pr,
+                None => continue,
+            };
+
+            // Listed pull requests miss some details, like their diff size
+            let upstream_pr = ctx
+                .api_service
+                .pulls_get(owner, name, listed_pr.number)
+                .await?;
+            let matched = ResolvePullRequestRules::match_rules(
+                ctx,
+                &repository_path,
+                &upstream_pr,
+                rules.clone(),
+            )
+            .await?;
+
+            let mut changes = vec![];
+            for resolved in PullRequestRule::resolve_actions(&matched) {
+                if resolved.rule.name != rule.name || resolved.overridden_by.is_some() {
+                    continue;
+                }
+
+                if let Some(change) = self
+                    .simulate_action(ctx, repository, &pr_model, &upstream_pr, resolved.action)
+                    .await?
+                {
+                    changes.push(change);
+                }
+            }
+
+            if !changes.is_empty() {
+                simulated.push(SimulatedPullRequest {
+                    number: upstream_pr.number,
+                    title: upstream_pr.title,
+                    changes,
+                });
+            }
+        }
+
+        Ok(simulated)
+    }
+}
+
+impl SimulatePullRequestRule {
+    async fn simulate_action(
+        &self,
+        ctx: &CoreContext<'_>,
+        repository: &Repository,
+        pr_model: &PullRequest,
+        upstream_pr: &GhPullRequest,
+        action: &RuleAction,
+    ) -> Result<Option<RuleSettingChange>> {
+        let (setting, before, after) = match action {
+            RuleAction::SetAutomerge(value) => (
+                "automerge",
+                pr_model.automerge.to_string(),
+                value.to_string(),
+            ),
+            RuleAction::SetQaEnabled(value) => (
+                "qa",
+                pr_model.qa_status.to_string(),
+                if *value {
+                    QaStatus::Waiting
+                } else {
+                    QaStatus::Skipped
+                }
+                .to_string(),
+            ),
+            RuleAction::SetChecksEnabled(value) => (
+                "checks",
+                pr_model.checks_enabled.to_string(),
+                value.to_string(),
+            ),
+            RuleAction::SetNeededReviewers(value) => (
+                "needed_reviewers",
+                pr_model.needed_reviewers_count.to_string(),
+                value.to_string(),
+            ),
+            RuleAction::SetMergeStrategy(value) => (
+                "merge_strategy",
+                pr_model
+                    .strategy_override
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "default".into()),
+                value.to_string(),
+            ),
+            RuleAction::Lock(_) => ("locked", pr_model.locked.to_string(), "true".into()),
+            RuleAction::AddLabels(labels) | RuleAction::RemoveLabels(labels) => {
+                let before: Vec<_> = upstream_pr.labels.iter().map(|l| l.name.clone()).collect();
+                let after = if matches!(action, RuleAction::AddLabels(_)) {
+                    Self::with_values(&before, labels)
+                } else {
+                    before
+                        .iter()
+                        .filter(|l| !labels.contains(l))
+                        .cloned()
+                        .collect()
+                };
+
+                ("labels", before.join(", "), after.join(", "))
+            }
+            RuleAction::RequestReviewers(reviewers) => {
+                let before: Vec<_> = upstream_pr
+                    .requested_reviewers
+                    .iter()
+                    .map(|u| u.login.clone())
+                    .collect();
+                let reviewers: Vec<_> = reviewers
+                    .iter()
+                    .filter(|r| **r != upstream_pr.user.login)
+                    .cloned()
+                    .collect();
+                let after = Self::with_values(&before, &reviewers);
+
+                ("reviewers", before.join(", "), after.join(", "))
+            }
+            RuleAction::RequestRequiredReviewers(reviewers) => {
+                let before: Vec<_> = ctx
+                    .db_service
+                    .required_reviewers_list(
+                        &repository.owner,
+                        &repository.name,
+                        upstream_pr.number,
+                    )
+                    .await?
+                    .into_iter()
+                    .map(|r| r.username)
+                    .collect();
+                let reviewers: Vec<_> = reviewers
+                    .iter()
+                    .filter(|r| **r != upstream_pr.user.login)
+                    .cloned()
+                    .collect();
+                let after = Self::with_values(&before, &reviewers);
+
+                ("required_reviewers", before.join(", "), after.join(", "))
+            }
+            RuleAction::PostComment(_) => ("comment", String::new(), "posted once".into()),
+            RuleAction::SetReviewerPool(reviewers) => (
+                "reviewer_pool",
+                repository.reviewer_pool.join(", "),
+                reviewers.join(", "),
+            ),
+        };
+
+        if before == after {
+            return Ok(None);
+        }
+
+        Ok(Some(RuleSettingChange {
+            setting: setting.into(),
+            before,
+            after,
+        }))
+    }
+
+    fn with_values(values: &[String], added: &[String]) -> Vec<String> {
+        let mut values = values.to_vec();
+        for value in added {
+            if !values.contains(value) {
+                values.push(value.clone());
+            }
+        }
+
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::{types::GhUser, MockApiService};
+    use prbot_models::RuleCondition;
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    fn upstream_pr(number: u64, author: &str) -> GhPullRequest {
+        GhPullRequest {
+            number,
+            title: format!("PR {number}"),
+            user: GhUser {
+                login: author.into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn run() {
+        let mut ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        for number in [1, 2] {
+            ctx.db_service
+                .pull_requests_create(PullRequest {
+                    repository_id: repo.id,
+                    number,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        ctx.db_service
+            .pull_request_rules_create(PullRequestRule {
+                repository_id: repo.id,
+                name: "Existing".into(),
+                conditions: vec![RuleCondition::Author("bot".into())],
+                actions: vec![RuleAction::SetNeededReviewers(3)],
+                priority: 10,
+            })
+            .await
+            .unwrap();
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_pulls_list().once().return_once(|_, _| {
+                Ok(vec![
+                    upstream_pr(1, "bot"),
+                    upstream_pr(2, "human"),
+                    upstream_pr(3, "bot"),
+                ])
+            });
+            // Unknown pull request #3 is skipped
+            svc.expect_pulls_get()
+                .times(2)
+                .withf(|_, _, number| number == &1 || number == &2)
+                .returning(|_, _, number| {
+                    Ok(upstream_pr(
+                        number,
+                        if number == 1 { "bot" } else { "human" },
+                    ))
+                });
+
+            svc
+        };
+
+        let simulated = SimulatePullRequestRule
+            .run(
+                &ctx.as_context(),
+                &repo,
+                PullRequestRule {
+                    repository_id: repo.id,
+                    name: "Candidate".into(),
+                    conditions: vec![RuleCondition::Author("bot".into())],
+                    actions: vec![
+                        RuleAction::SetAutomerge(true),
+                        RuleAction::SetNeededReviewers(0),
+                        RuleAction::AddLabels(vec!["deps".into()]),
+                    ],
+                    priority: 0,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            simulated,
+            vec![SimulatedPullRequest {
+                number: 1,
+                title: "PR 1".into(),
+                changes: vec![
+                    RuleSettingChange {
+                        setting: "automerge".into(),
+                        before: "false".into(),
+                        after: "true".into(),
+                    },
+                    RuleSettingChange {
+                        setting: "labels".into(),
+                        before: "".into(),
+                        after: "deps".into(),
+                    },
+                ],
+            }]
+        );
+
+        // Nothing was written
+        assert!(
+            !ctx.db_service
+                .pull_requests_get_expect("me", "name", 1)
+                .await
+                .unwrap()
+                .automerge
+        );
+        assert!(ctx
+            .db_service
+            .pull_request_rules_get("me", "name", "Candidate")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
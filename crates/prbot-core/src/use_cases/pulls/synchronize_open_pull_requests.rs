@@ -0,0 +1,185 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use shaku::{Component, HasComponent, Interface};
+use tracing::warn;
+
+use super::SynchronizePullRequestAndUpdateStatusInterface;
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait SynchronizeOpenPullRequestsInterface: Interface {
+    /// Synchronize known open pull requests, and return how many were synchronized.
+    ///
+    /// With `automerge_only`, only pull requests with automerge enabled are synchronized,
+    /// to retry merges which were blocked.
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, automerge_only: bool) -> Result<usize>;
+}
+
+#[derive(Component)]
+#[shaku(interface = SynchronizeOpenPullRequestsInterface)]
+pub(crate) struct SynchronizeOpenPullRequests;
+
+#[async_trait]
+impl SynchronizeOpenPullRequestsInterface for SynchronizeOpenPullRequests {
+    #[tracing::instrument(skip(self, ctx))]
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, automerge_only: bool) -> Result<usize> {
+        let synchronize: &dyn SynchronizePullRequestAndUpdateStatusInterface =
+            ctx.core_module.resolve_ref();
+        let mut count = 0;
+
+        for repository in ctx.db_service.repositories_all().await? {
+            let pull_requests: Vec<_> = ctx
+                .db_service
+                .pull_requests_list(&repository.owner, &repository.name)
+                .await?
+                .into_iter()
+                .filter(|pr| !automerge_only || pr.automerge)
+                .collect();
+            if pull_requests.is_empty() {
+                continue;
+            }
+
+            let open_numbers: HashSet<u64> = match ctx
+                .api_service
+                .pulls_list(&repository.owner, &repository.name)
+                .await
+            {
+                Ok(prs) => prs.into_iter().map(|pr| pr.number).collect(),
+                Err(e) => {
+                    warn!(
+                        repository_path = %repository.path(),
+                        error = %e,
+                        message = "Could not list open pull requests"
+                    );
+                    continue;
+                }
+            };
+
+            for pull_request in pull_requests
+                .iter()
+                .filter(|pr| open_numbers.contains(&pr.number))
+            {
+                let pr_handle = (
+                    repository.owner.as_str(),
+                    repository.name.as_str(),
+                    pull_request.number,
+                )
+                    .into();
+                match synchronize.run(ctx, &pr_handle).await {
+                    Ok(()) => count += 1,
+                    Err(e) => warn!(
+                        pr_handle = %pr_handle,
+                        error = %e,
+                        message = "Could not synchronize pull request"
+                    ),
+                }
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::types::GhPullRequest;
+    use prbot_models::{PullRequest, Repository};
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::pulls::MockSynchronizePullRequestAndUpdateStatusInterface, CoreModule,
+    };
+
+    async fn setup(ctx: &mut CoreContextTest) {
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        for (number, automerge) in [(1, true), (2, false), (3, true)] {
+            ctx.db_service
+                .pull_requests_create(PullRequest {
+                    repository_id: repo.id,
+                    number,
+                    automerge,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        // Pull request #3 is closed
+        ctx.api_service
+            .expect_pulls_list()
+            .once()
+            .withf(|owner, name| owner == "me" && name == "test")
+            .return_once(|_, _| {
+                Ok(vec![
+                    GhPullRequest {
+                        number: 1,
+                        ..Default::default()
+                    },
+                    GhPullRequest {
+                        number: 2,
+                        ..Default::default()
+                    },
+                ])
+            });
+    }
+
+    #[tokio::test]
+    async fn run() {
+        let mut ctx = CoreContextTest::new();
+        setup(&mut ctx).await;
+
+        let mut synchronize = MockSynchronizePullRequestAndUpdateStatusInterface::new();
+        synchronize
+            .expect_run()
+            .times(2)
+            .withf(|_, pr_handle| pr_handle.number() == 1 || pr_handle.number() == 2)
+            .returning(|_, _| Ok(()));
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn SynchronizePullRequestAndUpdateStatusInterface>(
+                Box::new(synchronize),
+            )
+            .build();
+
+        let count = SynchronizeOpenPullRequests
+            .run(&ctx.as_context(), false)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[tokio::test]
+    async fn run_automerge_only() {
+        let mut ctx = CoreContextTest::new();
+        setup(&mut ctx).await;
+
+        let mut synchronize = MockSynchronizePullRequestAndUpdateStatusInterface::new();
+        synchronize
+            .expect_run()
+            .once()
+            .withf(|_, pr_handle| pr_handle == &("me", "test", 1).into())
+            .return_once(|_, _| Ok(()));
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn SynchronizePullRequestAndUpdateStatusInterface>(
+                Box::new(synchronize),
+            )
+            .build();
+
+        let count = SynchronizeOpenPullRequests
+            .run(&ctx.as_context(), true)
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+}
@@ -17,6 +17,7 @@ pub enum TryMergePullRequestState {
     NotReady,
     Error,
     Success(MergeStrategy),
+    Enqueued,
 }
 
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
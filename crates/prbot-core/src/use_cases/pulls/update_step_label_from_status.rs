@@ -1,8 +1,9 @@
 use async_trait::async_trait;
-use prbot_models::{PullRequestHandle, StepLabel};
+use prbot_models::{PullRequestHandle, StepLabel, StepTransition};
 use shaku::{Component, HasComponent, Interface};
+use time::OffsetDateTime;
 
-use super::SetStepLabelInterface;
+use super::{SetStepLabelInterface, STALE_LABEL};
 use crate::{
     use_cases::status::{PullRequestStatus, StepLabelChooser},
     CoreContext, Result,
@@ -35,6 +36,163 @@ impl UpdateStepLabelFromStatusInterface for UpdateStepLabelFromStatus {
         let step_label = StepLabelChooser::default().choose_from_status(pr_status);
         let set_step_label: &dyn SetStepLabelInterface = ctx.core_module.resolve_ref();
         set_step_label.run(ctx, pr_handle, Some(step_label)).await?;
+        Self::record_transition(ctx, pr_handle, step_label).await?;
         Ok(step_label)
     }
 }
+
+impl UpdateStepLabelFromStatus {
+    /// Record a step transition if the step changed, and reset the stale reminder.
+    async fn record_transition(
+        ctx: &CoreContext<'_>,
+        pr_handle: &PullRequestHandle,
+        step_label: StepLabel,
+    ) -> Result<()> {
+        let (owner, name, number) = (
+            pr_handle.repository_path().owner(),
+            pr_handle.repository_path().name(),
+            pr_handle.number(),
+        );
+        let Some(pr_model) = ctx
+            .db_service
+            .pull_requests_get(owner, name, number)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let transitions = ctx
+            .db_service
+            .step_transitions_list(owner, name, number)
+            .await?;
+        if transitions.last().map(|t| t.step) == Some(step_label) {
+            return Ok(());
+        }
+
+        ctx.db_service
+            .step_transitions_create(StepTransition {
+                pull_request_id: pr_model.id,
+                step: step_label,
+                created_at: OffsetDateTime::now_utc(),
+                ..Default::default()
+            })
+            .await?;
+
+        if pr_model.stale_reminded_at.is_some() {
+            ctx.db_service
+                .pull_requests_set_stale_reminded_at(owner, name, number, None)
+                .await?;
+            ctx.api_service
+                .issue_labels_remove(owner, name, number, &[STALE_LABEL.into()])
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_models::{PullRequest, Repository};
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest, use_cases::pulls::MockSetStepLabelInterface, CoreModule,
+    };
+
+    async fn arrange(stale_reminded_at: Option<OffsetDateTime>) -> CoreContextTest {
+        let mut ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                stale_reminded_at,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let mut set_step_label = MockSetStepLabelInterface::new();
+        set_step_label.expect_run().returning(|_, _, _| Ok(()));
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn SetStepLabelInterface>(Box::new(set_step_label))
+            .build();
+        ctx
+    }
+
+    #[tokio::test]
+    async fn record_transitions_on_change() {
+        let ctx = arrange(None).await;
+
+        for status in [
+            PullRequestStatus {
+                wip: true,
+                ..Default::default()
+            },
+            PullRequestStatus {
+                wip: true,
+                ..Default::default()
+            },
+            PullRequestStatus::default(),
+        ] {
+            UpdateStepLabelFromStatus
+                .run(&ctx.as_context(), &("me", "test", 1).into(), &status)
+                .await
+                .unwrap();
+        }
+
+        let steps: Vec<_> = ctx
+            .db_service
+            .step_transitions_list("me", "test", 1)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.step)
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                StepLabel::Wip,
+                StepLabelChooser::default().choose_from_status(&PullRequestStatus::default())
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reset_stale_reminder_on_change() {
+        let mut ctx = arrange(Some(OffsetDateTime::now_utc())).await;
+        ctx.api_service
+            .expect_issue_labels_remove()
+            .once()
+            .withf(|owner, name, number, labels| {
+                owner == "me" && name == "test" && *number == 1 && labels == [STALE_LABEL]
+            })
+            .return_once(|_, _, _, _| Ok(()));
+
+        UpdateStepLabelFromStatus
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &PullRequestStatus::default(),
+            )
+            .await
+            .unwrap();
+
+        let pr = ctx
+            .db_service
+            .pull_requests_get_expect("me", "test", 1)
+            .await
+            .unwrap();
+        assert_eq!(pr.stale_reminded_at, None);
+    }
+}
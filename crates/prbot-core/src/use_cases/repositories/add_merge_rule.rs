@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use prbot_models::{MergeRule, MergeStrategy, Repository, RuleBranch};
+use prbot_models::{MergeRule, Repository, RuleBranch};
 use shaku::{Component, Interface};
 
 use crate::{CoreContext, Result};
@@ -7,13 +7,14 @@ use crate::{CoreContext, Result};
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
 pub trait AddMergeRuleInterface: Interface {
+    /// Add or update a merge rule.
+    ///
+    /// Missing commit templates keep their current value, and empty templates are removed.
     async fn run<'a>(
         &self,
         ctx: &CoreContext<'a>,
         repository: &'a Repository,
-        base: RuleBranch,
-        head: RuleBranch,
-        strategy: MergeStrategy,
+        rule: MergeRule,
     ) -> Result<()>;
 }
 
@@ -27,27 +28,49 @@ impl AddMergeRuleInterface for AddMergeRule {
         &self,
         ctx: &CoreContext<'a>,
         repository: &'a Repository,
-        base: RuleBranch,
-        head: RuleBranch,
-        strategy: MergeStrategy,
+        rule: MergeRule,
     ) -> Result<()> {
         let owner = &repository.owner;
         let name = &repository.name;
 
-        if base == RuleBranch::Wildcard && head == RuleBranch::Wildcard {
+        if rule.base_branch == RuleBranch::Wildcard && rule.head_branch == RuleBranch::Wildcard {
             ctx.db_service
-                .repositories_set_default_strategy(owner, name, strategy)
+                .repositories_set_default_strategy(owner, name, rule.strategy)
                 .await?;
         } else {
+            let existing = ctx
+                .db_service
+                .merge_rules_get(
+                    owner,
+                    name,
+                    rule.base_branch.clone(),
+                    rule.head_branch.clone(),
+                )
+                .await?;
+            let (existing_title, existing_message) = existing
+                .map(|r| (r.commit_title_template, r.commit_message_template))
+                .unwrap_or_default();
+
             ctx.db_service
-                .merge_rules_delete(owner, name, base.clone(), head.clone())
+                .merge_rules_delete(
+                    owner,
+                    name,
+                    rule.base_branch.clone(),
+                    rule.head_branch.clone(),
+                )
                 .await?;
             ctx.db_service
                 .merge_rules_create(MergeRule {
                     repository_id: repository.id,
-                    base_branch: base,
-                    head_branch: head,
-                    strategy,
+                    commit_title_template: Self::resolve_template(
+                        rule.commit_title_template,
+                        existing_title,
+                    ),
+                    commit_message_template: Self::resolve_template(
+                        rule.commit_message_template,
+                        existing_message,
+                    ),
+                    ..rule
                 })
                 .await?;
         }
@@ -56,12 +79,22 @@ impl AddMergeRuleInterface for AddMergeRule {
     }
 }
 
+impl AddMergeRule {
+    fn resolve_template(value: Option<String>, existing: Option<String>) -> Option<String> {
+        match value {
+            Some(value) if value.is_empty() => None,
+            Some(value) => Some(value),
+            None => existing,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
 
     use prbot_database_interface::DbService;
-    use prbot_models::{MergeStrategy, Repository, RuleBranch};
+    use prbot_models::{MergeRule, MergeStrategy, Repository, RuleBranch};
 
     use super::{AddMergeRule, AddMergeRuleInterface};
     use crate::context::tests::CoreContextTest;
@@ -81,9 +114,12 @@ mod tests {
             .run(
                 &ctx.as_context(),
                 &repo,
-                RuleBranch::Wildcard,
-                RuleBranch::Wildcard,
-                MergeStrategy::Squash,
+                MergeRule {
+                    base_branch: RuleBranch::Wildcard,
+                    head_branch: RuleBranch::Wildcard,
+                    strategy: MergeStrategy::Squash,
+                    ..Default::default()
+                },
             )
             .await?;
 
@@ -113,9 +149,13 @@ mod tests {
             .run(
                 &ctx.as_context(),
                 &repo,
-                base.clone(),
-                RuleBranch::Wildcard,
-                MergeStrategy::Squash,
+                MergeRule {
+                    base_branch: base.clone(),
+                    head_branch: RuleBranch::Wildcard,
+                    strategy: MergeStrategy::Squash,
+                    commit_title_template: Some("{title}".into()),
+                    ..Default::default()
+                },
             )
             .await?;
 
@@ -126,6 +166,53 @@ mod tests {
             .unwrap();
         assert_eq!(rule.base_branch, base);
         assert_eq!(rule.head_branch, RuleBranch::Wildcard);
+        assert_eq!(rule.commit_title_template, Some("{title}".into()));
+
+        // Missing templates are kept, empty templates are removed
+        AddMergeRule
+            .run(
+                &ctx.as_context(),
+                &repo,
+                MergeRule {
+                    base_branch: base.clone(),
+                    head_branch: RuleBranch::Wildcard,
+                    strategy: MergeStrategy::Merge,
+                    commit_message_template: Some("{body}".into()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let rule = ctx
+            .db_service
+            .merge_rules_get(&repo.owner, &repo.name, base.clone(), RuleBranch::Wildcard)
+            .await?
+            .unwrap();
+        assert_eq!(rule.strategy, MergeStrategy::Merge);
+        assert_eq!(rule.commit_title_template, Some("{title}".into()));
+        assert_eq!(rule.commit_message_template, Some("{body}".into()));
+
+        AddMergeRule
+            .run(
+                &ctx.as_context(),
+                &repo,
+                MergeRule {
+                    base_branch: base.clone(),
+                    head_branch: RuleBranch::Wildcard,
+                    strategy: MergeStrategy::Merge,
+                    commit_title_template: Some("".into()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let rule = ctx
+            .db_service
+            .merge_rules_get(&repo.owner, &repo.name, base.clone(), RuleBranch::Wildcard)
+            .await?
+            .unwrap();
+        assert_eq!(rule.commit_title_template, None);
+        assert_eq!(rule.commit_message_template, Some("{body}".into()));
 
         Ok(())
     }
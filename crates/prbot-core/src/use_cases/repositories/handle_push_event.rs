@@ -0,0 +1,217 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::{GhCommitStatusState, GhPushEvent};
+use shaku::{Component, HasComponent, Interface};
+
+use super::{RepositoryConfigSync, SyncRepositoryConfigInterface};
+use crate::{use_cases::status::utils::CONFIGURATION_STATUS_MESSAGE, CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait HandlePushEventInterface: Interface {
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhPushEvent) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = HandlePushEventInterface)]
+pub(crate) struct HandlePushEvent;
+
+#[async_trait]
+impl HandlePushEventInterface for HandlePushEvent {
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            repository_path = %event.repository.full_name,
+            reference = %event.reference,
+            after = %event.after
+        )
+    )]
+    async fn run<'a>(&self, ctx: &CoreContext<'a>, event: GhPushEvent) -> Result<()> {
+        // The configuration file is only read from the default branch
+        if !event.is_default_branch_update() {
+            return Ok(());
+        }
+
+        let repo_owner = &event.repository.owner.login;
+        let repo_name = &event.repository.name;
+        let repository = match ctx
+            .db_service
+            .repositories_get(repo_owner, repo_name)
+            .await?
+        {
+            Some(repository) => repository,
+            None => return Ok(()),
+        };
+
+        let sync_repository_config: &dyn SyncRepositoryConfigInterface =
+            ctx.core_module.resolve_ref();
+        let (state, message) = match sync_repository_config.run(ctx, &repository).await? {
+            RepositoryConfigSync::Applied { path } => (
+                GhCommitStatusState::Success,
+                format!("Configuration applied from {path}."),
+            ),
+            RepositoryConfigSync::Invalid { error } => {
+                (GhCommitStatusState::Failure, error.to_string())
+            }
+            RepositoryConfigSync::NotFound | RepositoryConfigSync::Unchanged { .. } => {
+                return Ok(())
+            }
+        };
+
+        // Report the result on the pushed commit
+        ctx.api_service
+            .commit_statuses_update(
+                repo_owner,
+                repo_name,
+                &event.after,
+                state,
+                CONFIGURATION_STATUS_MESSAGE,
+                &message,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::{
+        types::{GhRepository, GhUser},
+        MockApiService,
+    };
+    use prbot_models::{Repository, RepositoryConfigError};
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::repositories::MockSyncRepositoryConfigInterface, CoreModule,
+    };
+
+    fn push_event(reference: &str) -> GhPushEvent {
+        GhPushEvent {
+            reference: reference.into(),
+            after: "abcdef".into(),
+            repository: GhRepository {
+                owner: GhUser { login: "me".into() },
+                name: "test".into(),
+                default_branch: "main".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    async fn arrange(result: RepositoryConfigSync) -> CoreContextTest {
+        let mut ctx = CoreContextTest::new();
+        ctx.db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let sync_repository_config = {
+            let mut mock = MockSyncRepositoryConfigInterface::new();
+            mock.expect_run()
+                .once()
+                .withf(|_, repository| repository.path() == ("me", "test").into())
+                .return_once(move |_, _| Ok(result));
+
+            mock
+        };
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn SyncRepositoryConfigInterface>(Box::new(
+                sync_repository_config,
+            ))
+            .build();
+
+        ctx
+    }
+
+    #[tokio::test]
+    async fn run_other_branch() {
+        // No mock expectations: nothing should be called
+        let ctx = CoreContextTest::new();
+
+        HandlePushEvent
+            .run(&ctx.as_context(), push_event("refs/heads/feature"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_unchanged() {
+        let ctx = arrange(RepositoryConfigSync::Unchanged {
+            path: ".github/prbot.toml".into(),
+        })
+        .await;
+
+        HandlePushEvent
+            .run(&ctx.as_context(), push_event("refs/heads/main"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_applied() {
+        let mut ctx = arrange(RepositoryConfigSync::Applied {
+            path: ".github/prbot.toml".into(),
+        })
+        .await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_commit_statuses_update()
+                .once()
+                .withf(|owner, name, sha, state, title, _| {
+                    owner == "me"
+                        && name == "test"
+                        && sha == "abcdef"
+                        && *state == GhCommitStatusState::Success
+                        && title == CONFIGURATION_STATUS_MESSAGE
+                })
+                .return_once(|_, _, _, _, _, _| Ok(()));
+
+            svc
+        };
+
+        HandlePushEvent
+            .run(&ctx.as_context(), push_event("refs/heads/main"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn run_invalid() {
+        let mut ctx = arrange(RepositoryConfigSync::Invalid {
+            error: RepositoryConfigError::ValidationError {
+                path: ".github/prbot.toml".into(),
+                message: "duplicate pull request rule 'A'".into(),
+            },
+        })
+        .await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_commit_statuses_update()
+                .once()
+                .withf(|_, _, _, state, _, body| {
+                    *state == GhCommitStatusState::Failure
+                        && body
+                            == "Invalid configuration in .github/prbot.toml: duplicate pull request rule 'A'"
+                })
+                .return_once(|_, _, _, _, _, _| Ok(()));
+
+            svc
+        };
+
+        HandlePushEvent
+            .run(&ctx.as_context(), push_event("refs/heads/main"))
+            .await
+            .unwrap();
+    }
+}
@@ -1,10 +1,19 @@
 pub(crate) mod add_merge_rule;
+pub(crate) mod handle_push_event;
 pub(crate) mod rename_repository;
+pub(crate) mod sync_repository_config;
+pub(crate) mod unfreeze_merges;
 
 pub use add_merge_rule::AddMergeRuleInterface;
+pub use handle_push_event::HandlePushEventInterface;
 pub use rename_repository::RenameRepositoryInterface;
+pub use sync_repository_config::{RepositoryConfigSync, SyncRepositoryConfigInterface};
+pub use unfreeze_merges::UnfreezeMergesInterface;
 
 #[cfg(any(test, feature = "testkit"))]
 pub use self::{
-    add_merge_rule::MockAddMergeRuleInterface, rename_repository::MockRenameRepositoryInterface,
+    add_merge_rule::MockAddMergeRuleInterface, handle_push_event::MockHandlePushEventInterface,
+    rename_repository::MockRenameRepositoryInterface,
+    sync_repository_config::MockSyncRepositoryConfigInterface,
+    unfreeze_merges::MockUnfreezeMergesInterface,
 };
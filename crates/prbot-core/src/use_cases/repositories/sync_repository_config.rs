@@ -0,0 +1,340 @@
+use async_trait::async_trait;
+use prbot_models::{Repository, RepositoryConfig, RepositoryConfigError, REPOSITORY_CONFIG_PATHS};
+use shaku::{Component, Interface};
+use tracing::warn;
+
+use crate::{CoreContext, Result};
+
+/// Repository configuration file synchronization result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepositoryConfigSync {
+    /// No configuration file in the repository.
+    NotFound,
+    /// Configuration file already applied.
+    Unchanged { path: String },
+    /// Configuration file applied.
+    Applied { path: String },
+    /// Invalid configuration file, nothing was applied.
+    Invalid { error: RepositoryConfigError },
+}
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait SyncRepositoryConfigInterface: Interface {
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository: &Repository,
+    ) -> Result<RepositoryConfigSync>;
+}
+
+#[derive(Component)]
+#[shaku(interface = SyncRepositoryConfigInterface)]
+pub(crate) struct SyncRepositoryConfig;
+
+#[async_trait]
+impl SyncRepositoryConfigInterface for SyncRepositoryConfig {
+    #[tracing::instrument(skip(self, ctx), fields(repository_path = %repository.path()), ret)]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository: &Repository,
+    ) -> Result<RepositoryConfigSync> {
+        let owner = &repository.owner;
+        let name = &repository.name;
+
+        let mut file = None;
+        for path in REPOSITORY_CONFIG_PATHS {
+            file = ctx
+                .api_service
+                .repository_contents_get(owner, name, path, None)
+                .await?;
+            if file.is_some() {
+                break;
+            }
+        }
+
+        let file = match file {
+            Some(file) => file,
+            None => {
+                // Forget the removed file, so it is applied again if restored
+                if !repository.config_sha.is_empty() {
+                    ctx.db_service
+                        .repositories_update(Repository {
+                            config_sha: String::new(),
+                            ..repository.clone()
+                        })
+                        .await?;
+                }
+
+                return Ok(RepositoryConfigSync::NotFound);
+            }
+        };
+
+        if file.sha == repository.config_sha {
+            return Ok(RepositoryConfigSync::Unchanged { path: file.path });
+        }
+
+        let config = match RepositoryConfig::from_file(&file.path, &file.content) {
+            Ok(config) => config,
+            Err(error) => {
+                warn!(
+                    repository_path = %repository.path(),
+                    error = %error,
+                    message = "Invalid repository configuration file"
+                );
+
+                return Ok(RepositoryConfigSync::Invalid { error });
+            }
+        };
+
+        if let Some(rules) = config.merge_rules(repository) {
+            for rule in ctx.db_service.merge_rules_list(owner, name).await? {
+                ctx.db_service
+                    .merge_rules_delete(owner, name, rule.base_branch, rule.head_branch)
+                    .await?;
+            }
+
+            for rule in rules {
+                ctx.db_service.merge_rules_create(rule).await?;
+            }
+        }
+
+        if let Some(rules) = config.pull_request_rules(repository) {
+            for rule in ctx.db_service.pull_request_rules_list(owner, name).await? {
+                ctx.db_service
+                    .pull_request_rules_delete(owner, name, &rule.name)
+                    .await?;
+            }
+
+            for rule in rules {
+                ctx.db_service.pull_request_rules_create(rule).await?;
+            }
+        }
+
+        ctx.db_service
+            .repositories_update(config.apply_to_repository(repository.clone(), &file.sha))
+            .await?;
+
+        Ok(RepositoryConfigSync::Applied { path: file.path })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::{types::GhFileContent, MockApiService};
+    use prbot_models::{MergeRule, MergeStrategy, PullRequestRule, RuleBranch};
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    const CONFIG: &str = r#"
+        default_strategy = "squash"
+
+        [[merge_rules]]
+        base = "main"
+        head = "*"
+        strategy = "merge"
+
+        [[pull_request_rules]]
+        name = "Automerge"
+        actions = [{ set_automerge = true }]
+    "#;
+
+    fn config_file(sha: &str, content: &str) -> GhFileContent {
+        GhFileContent {
+            path: ".github/prbot.toml".into(),
+            sha: sha.into(),
+            content: content.into(),
+        }
+    }
+
+    async fn create_repository(ctx: &CoreContextTest, config_sha: &str) -> Repository {
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                config_sha: config_sha.into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        ctx.db_service
+            .merge_rules_create(MergeRule {
+                repository_id: repo.id,
+                base_branch: "stable".into(),
+                head_branch: RuleBranch::Wildcard,
+                strategy: MergeStrategy::Rebase,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .pull_request_rules_create(PullRequestRule {
+                repository_id: repo.id,
+                name: "Old".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        repo
+    }
+
+    #[tokio::test]
+    async fn run_not_found() {
+        let mut ctx = CoreContextTest::new();
+        let repo = create_repository(&ctx, "abcdef").await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_repository_contents_get()
+                .times(3)
+                .withf(|owner, name, _, reference| {
+                    owner == "me" && name == "test" && reference.is_none()
+                })
+                .returning(|_, _, _, _| Ok(None));
+
+            svc
+        };
+
+        assert_eq!(
+            SyncRepositoryConfig
+                .run(&ctx.as_context(), &repo)
+                .await
+                .unwrap(),
+            RepositoryConfigSync::NotFound
+        );
+
+        let repo = ctx
+            .db_service
+            .repositories_get_expect("me", "test")
+            .await
+            .unwrap();
+        assert_eq!(repo.config_sha, "");
+    }
+
+    #[tokio::test]
+    async fn run_unchanged() {
+        let mut ctx = CoreContextTest::new();
+        let repo = create_repository(&ctx, "abcdef").await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_repository_contents_get()
+                .once()
+                .withf(|_, _, path, _| path == ".github/prbot.toml")
+                .return_once(|_, _, _, _| Ok(Some(config_file("abcdef", CONFIG))));
+
+            svc
+        };
+
+        assert_eq!(
+            SyncRepositoryConfig
+                .run(&ctx.as_context(), &repo)
+                .await
+                .unwrap(),
+            RepositoryConfigSync::Unchanged {
+                path: ".github/prbot.toml".into()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn run_invalid() {
+        let mut ctx = CoreContextTest::new();
+        let repo = create_repository(&ctx, "").await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_repository_contents_get()
+                .once()
+                .return_once(|_, _, _, _| Ok(Some(config_file("abcdef", "unknown = true"))));
+
+            svc
+        };
+
+        let result = SyncRepositoryConfig
+            .run(&ctx.as_context(), &repo)
+            .await
+            .unwrap();
+        assert!(matches!(
+            result,
+            RepositoryConfigSync::Invalid {
+                error: RepositoryConfigError::ParseError { .. }
+            }
+        ));
+
+        // Nothing changed
+        let repo = ctx
+            .db_service
+            .repositories_get_expect("me", "test")
+            .await
+            .unwrap();
+        assert_eq!(repo.config_sha, "");
+        assert_eq!(
+            ctx.db_service
+                .merge_rules_list("me", "test")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn run_applied() {
+        let mut ctx = CoreContextTest::new();
+        let repo = create_repository(&ctx, "").await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_repository_contents_get()
+                .once()
+                .return_once(|_, _, _, _| Ok(Some(config_file("abcdef", CONFIG))));
+
+            svc
+        };
+
+        assert_eq!(
+            SyncRepositoryConfig
+                .run(&ctx.as_context(), &repo)
+                .await
+                .unwrap(),
+            RepositoryConfigSync::Applied {
+                path: ".github/prbot.toml".into()
+            }
+        );
+
+        let repo = ctx
+            .db_service
+            .repositories_get_expect("me", "test")
+            .await
+            .unwrap();
+        assert_eq!(repo.config_sha, "abcdef");
+        assert_eq!(repo.default_strategy, MergeStrategy::Squash);
+
+        let merge_rules = ctx.db_service.merge_rules_list("me", "test").await.unwrap();
+        assert_eq!(
+            merge_rules,
+            vec![MergeRule {
+                repository_id: repo.id,
+                base_branch: "main".into(),
+                head_branch: RuleBranch::Wildcard,
+                strategy: MergeStrategy::Merge,
+                ..Default::default()
+            }]
+        );
+
+        let rules = ctx
+            .db_service
+            .pull_request_rules_list("me", "test")
+            .await
+            .unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].name, "Automerge");
+    }
+}
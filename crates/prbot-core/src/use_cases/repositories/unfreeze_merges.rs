@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use prbot_models::{Job, JobKind, JobStatus, Repository, RuleBranch};
+use shaku::{Component, Interface};
+use time::OffsetDateTime;
+
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait UnfreezeMergesInterface: Interface {
+    /// Remove the merge freeze of a base branch, or all merge freezes.
+    ///
+    /// Pending merge retries of the repository are run right away.
+    /// Returns the number of removed freezes.
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository: &'a Repository,
+        base_branch: Option<RuleBranch>,
+    ) -> Result<usize>;
+}
+
+#[derive(Component)]
+#[shaku(interface = UnfreezeMergesInterface)]
+pub(crate) struct UnfreezeMerges;
+
+#[async_trait]
+impl UnfreezeMergesInterface for UnfreezeMerges {
+    #[tracing::instrument(skip(self, ctx, repository), fields(repository_path = %repository.path()))]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository: &'a Repository,
+        base_branch: Option<RuleBranch>,
+    ) -> Result<usize> {
+        let owner = &repository.owner;
+        let name = &repository.name;
+
+        let base_branches = match base_branch {
+            Some(base_branch) => vec![base_branch],
+            None => ctx
+                .db_service
+                .merge_freezes_list(owner, name)
+                .await?
+                .into_iter()
+                .map(|f| f.base_branch)
+                .collect(),
+        };
+
+        let mut count = 0;
+        for base_branch in base_branches {
+            if ctx
+                .db_service
+                .merge_freezes_delete(owner, name, base_branch)
+                .await?
+            {
+                count += 1;
+            }
+        }
+
+        if count > 0 {
+            let now = OffsetDateTime::now_utc();
+            let prefix = format!("{}/", repository.path());
+            for job in ctx.db_service.jobs_list(Some(JobStatus::Pending)).await? {
+                if job.kind == JobKind::RetryPullRequestMerge
+                    && job.payload.starts_with(&prefix)
+                    && job.run_at > now
+                {
+                    ctx.db_service
+                        .jobs_update(Job { run_at: now, ..job })
+                        .await?;
+                }
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_models::MergeFreeze;
+    use time::Duration;
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    async fn arrange(ctx: &CoreContextTest) -> Repository {
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        for base_branch in ["main", "release/*"] {
+            ctx.db_service
+                .merge_freezes_set(
+                    MergeFreeze {
+                        base_branch: base_branch.into(),
+                        ..Default::default()
+                    }
+                    .with_repository(&repo),
+                )
+                .await
+                .unwrap();
+        }
+
+        for payload in ["me/test/1", "me/test-other/1"] {
+            ctx.db_service
+                .jobs_create(Job {
+                    kind: JobKind::RetryPullRequestMerge,
+                    payload: payload.into(),
+                    run_at: OffsetDateTime::now_utc() + Duration::days(1),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        repo
+    }
+
+    async fn due_jobs(ctx: &CoreContextTest) -> Vec<String> {
+        let now = OffsetDateTime::now_utc();
+        ctx.db_service
+            .jobs_list(None)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|j| j.run_at <= now)
+            .map(|j| j.payload)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn run_one() {
+        let ctx = CoreContextTest::new();
+        let repo = arrange(&ctx).await;
+
+        assert_eq!(
+            UnfreezeMerges
+                .run(&ctx.as_context(), &repo, Some("dev".into()))
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(due_jobs(&ctx).await, Vec::<String>::new());
+
+        assert_eq!(
+            UnfreezeMerges
+                .run(&ctx.as_context(), &repo, Some("main".into()))
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(due_jobs(&ctx).await, vec!["me/test/1".to_string()]);
+        assert_eq!(
+            ctx.db_service
+                .merge_freezes_list("me", "test")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn run_all() {
+        let ctx = CoreContextTest::new();
+        let repo = arrange(&ctx).await;
+
+        assert_eq!(
+            UnfreezeMerges
+                .run(&ctx.as_context(), &repo, None)
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            ctx.db_service
+                .merge_freezes_list("me", "test")
+                .await
+                .unwrap(),
+            vec![]
+        );
+    }
+}
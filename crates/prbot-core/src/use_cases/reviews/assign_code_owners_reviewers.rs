@@ -0,0 +1,254 @@
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhPullRequest;
+use prbot_models::{CodeOwners, PullRequestHandle, RequiredReviewer, CODEOWNERS_PATHS};
+use shaku::{Component, HasComponent, Interface};
+
+use super::AddReviewersInterface;
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait AssignCodeOwnersReviewersInterface: Interface {
+    /// Register code owners of changed files as required reviewers, if enabled on the repository.
+    ///
+    /// Returns the matching owners, users and `org/team` teams.
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
+    ) -> Result<Vec<String>>;
+}
+
+#[derive(Component)]
+#[shaku(interface = AssignCodeOwnersReviewersInterface)]
+pub(crate) struct AssignCodeOwnersReviewers;
+
+#[async_trait]
+impl AssignCodeOwnersReviewersInterface for AssignCodeOwnersReviewers {
+    #[tracing::instrument(skip(self, ctx, upstream_pr), fields(pr_handle = %pr_handle), ret)]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
+    ) -> Result<Vec<String>> {
+        let (owner, name, number) = (pr_handle.owner(), pr_handle.name(), pr_handle.number());
+        let repository = ctx.db_service.repositories_get_expect(owner, name).await?;
+        if !repository.codeowners_reviewers {
+            return Ok(vec![]);
+        }
+
+        let Some(code_owners) = self
+            .fetch_code_owners(ctx, pr_handle, &upstream_pr.base.reference)
+            .await?
+        else {
+            return Ok(vec![]);
+        };
+
+        let changed_files = ctx.api_service.pull_files_list(owner, name, number).await?;
+        let owners: Vec<String> = code_owners
+            .owners_of_files(&changed_files)
+            .into_iter()
+            .filter(|o| *o != upstream_pr.user.login)
+            .collect();
+
+        let (teams, users): (Vec<String>, Vec<String>) =
+            owners.iter().cloned().partition(|o| o.contains('/'));
+
+        if !users.is_empty() {
+            let add_reviewers: &dyn AddReviewersInterface = ctx.core_module.resolve_ref();
+            add_reviewers.run(ctx, pr_handle, &users, true).await?;
+        }
+
+        // Teams are satisfied by any approving member
+        let pr_model = ctx
+            .db_service
+            .pull_requests_get_expect(owner, name, number)
+            .await?;
+        for team in teams {
+            if ctx
+                .db_service
+                .required_reviewers_get(owner, name, number, &team)
+                .await?
+                .is_none()
+            {
+                ctx.db_service
+                    .required_reviewers_create(RequiredReviewer {
+                        pull_request_id: pr_model.id,
+                        username: team,
+                    })
+                    .await?;
+            }
+        }
+
+        Ok(owners)
+    }
+}
+
+impl AssignCodeOwnersReviewers {
+    async fn fetch_code_owners(
+        &self,
+        ctx: &CoreContext<'_>,
+        pr_handle: &PullRequestHandle,
+        base_branch: &str,
+    ) -> Result<Option<CodeOwners>> {
+        for path in CODEOWNERS_PATHS {
+            if let Some(file) = ctx
+                .api_service
+                .repository_contents_get(
+                    pr_handle.owner(),
+                    pr_handle.name(),
+                    path,
+                    Some(base_branch),
+                )
+                .await?
+            {
+                return Ok(Some(CodeOwners::parse(&file.content)));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::{
+        types::{GhBranch, GhFileContent, GhUser},
+        MockApiService,
+    };
+    use prbot_models::{PullRequest, Repository};
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::reviews::{FilteredReviewers, MockAddReviewersInterface},
+        CoreModule,
+    };
+
+    const CODEOWNERS: &str = "* @lead\n/src/ @author @org/core\n";
+
+    async fn create_pull_request(ctx: &CoreContextTest, codeowners_reviewers: bool) {
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                codeowners_reviewers,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .pull_requests_create(
+                PullRequest {
+                    number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+    }
+
+    fn upstream_pr() -> GhPullRequest {
+        GhPullRequest {
+            number: 1,
+            user: GhUser {
+                login: "author".into(),
+            },
+            base: GhBranch {
+                reference: "main".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn disabled() {
+        let ctx = CoreContextTest::new();
+        create_pull_request(&ctx, false).await;
+
+        let owners = AssignCodeOwnersReviewers
+            .run(&ctx.as_context(), &("me", "test", 1).into(), &upstream_pr())
+            .await
+            .unwrap();
+        assert!(owners.is_empty());
+    }
+
+    #[tokio::test]
+    async fn run() {
+        let mut ctx = CoreContextTest::new();
+        create_pull_request(&ctx, true).await;
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+            svc.expect_repository_contents_get()
+                .once()
+                .withf(|owner, name, path, reference| {
+                    owner == "me"
+                        && name == "test"
+                        && path == ".github/CODEOWNERS"
+                        && reference == &Some("main")
+                })
+                .return_once(|_, _, _, _| Ok(None));
+            svc.expect_repository_contents_get()
+                .once()
+                .withf(|_, _, path, _| path == "CODEOWNERS")
+                .return_once(|_, _, _, _| {
+                    Ok(Some(GhFileContent {
+                        path: "CODEOWNERS".into(),
+                        sha: "abcdef".into(),
+                        content: CODEOWNERS.into(),
+                    }))
+                });
+            svc.expect_pull_files_list()
+                .once()
+                .return_once(|_, _, _| Ok(vec!["README.md".into(), "src/main.rs".into()]));
+            svc
+        };
+
+        let add_reviewers = {
+            let mut mock = MockAddReviewersInterface::new();
+            mock.expect_run()
+                .once()
+                .withf(|_, pr_handle, reviewers, required| {
+                    pr_handle == &("me", "test", 1).into()
+                        && reviewers == ["lead".to_string()]
+                        && *required
+                })
+                .return_once(|_, _, _, _| {
+                    Ok(FilteredReviewers {
+                        allowed: vec!["lead".into()],
+                        rejected: vec![],
+                    })
+                });
+            mock
+        };
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn AddReviewersInterface>(Box::new(add_reviewers))
+            .build();
+
+        let owners = AssignCodeOwnersReviewers
+            .run(&ctx.as_context(), &("me", "test", 1).into(), &upstream_pr())
+            .await
+            .unwrap();
+        assert_eq!(owners, vec!["lead".to_string(), "org/core".to_string()]);
+
+        // The author is skipped, and teams are registered directly
+        assert_eq!(
+            ctx.db_service
+                .required_reviewers_list("me", "test", 1)
+                .await
+                .unwrap(),
+            vec![RequiredReviewer {
+                pull_request_id: 1,
+                username: "org/core".into()
+            }]
+        );
+    }
+}
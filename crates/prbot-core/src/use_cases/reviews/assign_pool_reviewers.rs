@@ -0,0 +1,525 @@
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use prbot_ghapi_interface::types::GhPullRequest;
+use prbot_lock_interface::{using_lock, UsingLockResult};
+use prbot_models::{
+    PullRequestEventKind, PullRequestHandle, PullRequestRule, ReviewerAssignmentStrategy,
+    RuleAction,
+};
+use shaku::{Component, HasComponent, Interface};
+
+use super::AddReviewersInterface;
+use crate::{use_cases::stats::record_pull_request_event, CoreContext, DomainError, Result};
+
+/// Timeout when waiting for the reviewer pool lock of a repository.
+const REVIEWER_POOL_LOCK_TIMEOUT_MS: u64 = 10_000;
+
+/// Get the lock name serializing round-robin assignments on a repository.
+fn reviewer_pool_lock_name(owner: &str, name: &str) -> String {
+    format!("reviewer-pool-{owner}-{name}")
+}
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait AssignPoolReviewersInterface: Interface {
+    /// Request reviewers picked from the reviewer pool, for pull requests ready for review.
+    ///
+    /// The pool is the repository one, unless overridden by a matched rule.
+    /// Returns the requested reviewers.
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
+        rules: &[PullRequestRule],
+    ) -> Result<Vec<String>>;
+}
+
+#[derive(Component)]
+#[shaku(interface = AssignPoolReviewersInterface)]
+pub(crate) struct AssignPoolReviewers;
+
+#[async_trait]
+impl AssignPoolReviewersInterface for AssignPoolReviewers {
+    #[tracing::instrument(skip(self, ctx, upstream_pr, rules), fields(pr_handle = %pr_handle), ret)]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        pr_handle: &PullRequestHandle,
+        upstream_pr: &GhPullRequest,
+        rules: &[PullRequestRule],
+    ) -> Result<Vec<String>> {
+        if upstream_pr.draft {
+            return Ok(vec![]);
+        }
+
+        let (owner, name) = (pr_handle.owner(), pr_handle.name());
+        let repository = ctx.db_service.repositories_get_expect(owner, name).await?;
+        let pool = Self::resolve_pool(rules).unwrap_or(repository.reviewer_pool);
+
+        let requested: HashSet<_> = upstream_pr
+            .requested_reviewers
+            .iter()
+            .map(|u| u.login.as_str())
+            .collect();
+        let already_requested = pool
+            .iter()
+            .filter(|r| requested.contains(r.as_str()))
+            .count() as u64;
+        let needed = repository
+            .reviewer_pool_count
+            .saturating_sub(already_requested) as usize;
+        if pool.is_empty() || needed == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut candidates = vec![];
+        for reviewer in &pool {
+            if *reviewer == upstream_pr.user.login
+                || requested.contains(reviewer.as_str())
+                || candidates.contains(reviewer)
+                || ctx.db_service.away_users_get(reviewer).await?.is_some()
+            {
+                continue;
+            }
+
+            candidates.push(reviewer.clone());
+        }
+
+        let strategy = repository.reviewer_assignment_strategy;
+        let picked = match strategy {
+            ReviewerAssignmentStrategy::RoundRobin => {
+                // Concurrent assignments must not start from the same cursor
+                let lock_name = reviewer_pool_lock_name(owner, name);
+                let output = using_lock(
+                    ctx.lock_service,
+                    &lock_name,
+                    REVIEWER_POOL_LOCK_TIMEOUT_MS,
+                    || self.pick_next_round_robin(ctx, pr_handle, &pool, &candidates, needed),
+                )
+                .await?;
+
+                match output {
+                    UsingLockResult::AlreadyLocked => {
+                        return Err(DomainError::LockTimeout { name: lock_name })
+                    }
+                    UsingLockResult::Locked(result) => result?,
+                }
+            }
+            ReviewerAssignmentStrategy::LeastLoad => {
+                let loads = ctx
+                    .db_service
+                    .pull_request_events_pending_reviews(owner, name, pr_handle.number())
+                    .await?
+                    .into_iter()
+                    .collect();
+                Self::pick_least_load(&candidates, &loads, needed)
+            }
+        };
+
+        if picked.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let add_reviewers: &dyn AddReviewersInterface = ctx.core_module.resolve_ref();
+        let filtered = add_reviewers.run(ctx, pr_handle, &picked, false).await?;
+
+        for reviewer in &filtered.allowed {
+            record_pull_request_event(
+                ctx.db_service,
+                pr_handle,
+                PullRequestEventKind::ReviewerAssigned,
+                Some(reviewer),
+                &strategy.to_string(),
+            )
+            .await?;
+        }
+
+        Ok(filtered.allowed)
+    }
+}
+
+impl AssignPoolReviewers {
+    fn resolve_pool(rules: &[PullRequestRule]) -> Option<Vec<String>> {
+        let mut rules = rules.to_vec();
+        PullRequestRule::sort_by_priority(&mut rules);
+
+        PullRequestRule::resolve_actions(&rules)
+            .into_iter()
+            .filter(|resolved| resolved.overridden_by.is_none())
+            .find_map(|resolved| match resolved.action {
+                RuleAction::SetReviewerPool(reviewers) => Some(reviewers.clone()),
+                _ => None,
+            })
+    }
+
+    /// Pick candidates from the current round-robin cursor, and advance it.
+    ///
+    /// Must be called while holding the reviewer pool lock.
+    async fn pick_next_round_robin(
+        &self,
+        ctx: &CoreContext<'_>,
+        pr_handle: &PullRequestHandle,
+        pool: &[String],
+        candidates: &[String],
+        count: usize,
+    ) -> Result<Vec<String>> {
+        let (owner, name) = (pr_handle.owner(), pr_handle.name());
+        let repository = ctx.db_service.repositories_get_expect(owner, name).await?;
+        let (picked, cursor) =
+            Self::pick_round_robin(pool, candidates, repository.reviewer_pool_cursor, count);
+        ctx.db_service
+            .repositories_set_reviewer_pool_cursor(owner, name, cursor)
+            .await?;
+
+        Ok(picked)
+    }
+
+    /// Pick candidates in pool order, starting at the cursor.
+    ///
+    /// Returns the picked reviewers and the next cursor.
+    fn pick_round_robin(
+        pool: &[String],
+        candidates: &[String],
+        cursor: u64,
+        count: usize,
+    ) -> (Vec<String>, u64) {
+        let start = cursor as usize % pool.len();
+        let mut picked = vec![];
+        let mut next = cursor;
+
+        for offset in 0..pool.len() {
+            if picked.len() == count {
+                break;
+            }
+
+            let index = (start + offset) % pool.len();
+            if candidates.contains(&pool[index]) && !picked.contains(&pool[index]) {
+                picked.push(pool[index].clone());
+                next = (index + 1) as u64;
+            }
+        }
+
+        (picked, next)
+    }
+
+    /// Pick candidates with the lowest load, in pool order on ties.
+    fn pick_least_load(
+        candidates: &[String],
+        loads: &HashMap<String, u64>,
+        count: usize,
+    ) -> Vec<String> {
+        let mut candidates = candidates.to_vec();
+        candidates.sort_by_key(|c| loads.get(c).copied().unwrap_or(0));
+        candidates.into_iter().take(count).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::types::GhUser;
+    use prbot_lock_interface::{LockInstance, LockStatus};
+    use prbot_models::{AwayUser, PullRequest, PullRequestEvent, Repository, RequiredReviewer};
+
+    use super::*;
+    use crate::{
+        context::tests::CoreContextTest,
+        use_cases::reviews::{FilteredReviewers, MockAddReviewersInterface},
+        CoreModule,
+    };
+
+    fn names(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn round_robin() {
+        let pool = names(&["a", "b", "c", "d"]);
+
+        assert_eq!(
+            AssignPoolReviewers::pick_round_robin(&pool, &pool, 0, 2),
+            (names(&["a", "b"]), 2)
+        );
+        assert_eq!(
+            AssignPoolReviewers::pick_round_robin(&pool, &pool, 3, 2),
+            (names(&["d", "a"]), 1)
+        );
+        // Unavailable reviewers are skipped
+        assert_eq!(
+            AssignPoolReviewers::pick_round_robin(&pool, &names(&["a", "d"]), 1, 1),
+            (names(&["d"]), 4)
+        );
+        assert_eq!(
+            AssignPoolReviewers::pick_round_robin(&pool, &[], 1, 1),
+            (vec![], 1)
+        );
+    }
+
+    #[test]
+    fn least_load() {
+        let loads = HashMap::from([("a".to_string(), 2), ("b".to_string(), 1)]);
+
+        assert_eq!(
+            AssignPoolReviewers::pick_least_load(&names(&["a", "b", "c"]), &loads, 2),
+            names(&["c", "b"])
+        );
+    }
+
+    #[test]
+    fn rule_pool() {
+        let rule = |name: &str, priority, reviewers: &[&str]| PullRequestRule {
+            name: name.into(),
+            priority,
+            actions: vec![RuleAction::SetReviewerPool(names(reviewers))],
+            ..Default::default()
+        };
+
+        assert_eq!(AssignPoolReviewers::resolve_pool(&[]), None);
+        assert_eq!(
+            AssignPoolReviewers::resolve_pool(&[rule("A", 0, &["a"]), rule("B", 1, &["b"])]),
+            Some(names(&["b"]))
+        );
+    }
+
+    #[tokio::test]
+    async fn run() {
+        let mut ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                reviewer_pool: names(&["author", "away", "busy", "free"]),
+                reviewer_assignment_strategy: ReviewerAssignmentStrategy::LeastLoad,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        for number in [1, 2] {
+            ctx.db_service
+                .pull_requests_create(
+                    PullRequest {
+                        number,
+                        ..Default::default()
+                    }
+                    .with_repository(&repo),
+                )
+                .await
+                .unwrap();
+        }
+
+        // "busy" has a pending review on another pull request, "free" already reviewed
+        ctx.db_service
+            .required_reviewers_create(RequiredReviewer {
+                pull_request_id: 2,
+                username: "busy".into(),
+            })
+            .await
+            .unwrap();
+        for kind in [
+            PullRequestEventKind::ReviewerAssigned,
+            PullRequestEventKind::Reviewed,
+        ] {
+            ctx.db_service
+                .pull_request_events_create(PullRequestEvent {
+                    pull_request_id: 2,
+                    kind,
+                    author: Some("free".into()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        ctx.db_service
+            .away_users_create(AwayUser {
+                username: "away".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let add_reviewers = {
+            let mut mock = MockAddReviewersInterface::new();
+            mock.expect_run()
+                .once()
+                .withf(|_, pr_handle, reviewers, required| {
+                    pr_handle == &("me", "test", 1).into()
+                        && reviewers == ["free".to_string()]
+                        && !required
+                })
+                .return_once(|_, _, _, _| {
+                    Ok(FilteredReviewers {
+                        allowed: vec!["free".into()],
+                        rejected: vec![],
+                    })
+                });
+            mock
+        };
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn AddReviewersInterface>(Box::new(add_reviewers))
+            .build();
+
+        let reviewers = AssignPoolReviewers
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &GhPullRequest {
+                    number: 1,
+                    user: GhUser {
+                        login: "author".into(),
+                    },
+                    ..Default::default()
+                },
+                &[],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(reviewers, vec!["free".to_string()]);
+
+        let events = ctx
+            .db_service
+            .pull_request_events_list("me", "test", 1)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, PullRequestEventKind::ReviewerAssigned);
+        assert_eq!(events[0].author.as_deref(), Some("free"));
+        assert_eq!(events[0].details, "least_load");
+    }
+
+    async fn arrange_round_robin(ctx: &mut CoreContextTest, available: bool) {
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                reviewer_pool: names(&["a", "b", "c"]),
+                reviewer_pool_count: 1,
+                reviewer_assignment_strategy: ReviewerAssignmentStrategy::RoundRobin,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .repositories_set_reviewer_pool_cursor("me", "test", 1)
+            .await
+            .unwrap();
+        ctx.db_service
+            .pull_requests_create(
+                PullRequest {
+                    number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+
+        ctx.lock_service
+            .expect_wait_lock_resource()
+            .once()
+            .withf(|name, _| name == "reviewer-pool-me-test")
+            .return_once(move |name, _| {
+                Ok(if available {
+                    LockStatus::SuccessfullyLocked(LockInstance::new_dummy(name))
+                } else {
+                    LockStatus::AlreadyLocked
+                })
+            });
+    }
+
+    #[tokio::test]
+    async fn run_round_robin() {
+        let mut ctx = CoreContextTest::new();
+        arrange_round_robin(&mut ctx, true).await;
+
+        let add_reviewers = {
+            let mut mock = MockAddReviewersInterface::new();
+            mock.expect_run()
+                .once()
+                .withf(|_, _, reviewers, _| reviewers == ["b".to_string()])
+                .return_once(|_, _, _, _| {
+                    Ok(FilteredReviewers {
+                        allowed: vec!["b".into()],
+                        rejected: vec![],
+                    })
+                });
+            mock
+        };
+
+        ctx.core_module = CoreModule::builder()
+            .with_component_override::<dyn AddReviewersInterface>(Box::new(add_reviewers))
+            .build();
+
+        let reviewers = AssignPoolReviewers
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &GhPullRequest::default(),
+                &[],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(reviewers, vec!["b".to_string()]);
+        assert_eq!(
+            ctx.db_service
+                .repositories_get_expect("me", "test")
+                .await
+                .unwrap()
+                .reviewer_pool_cursor,
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn run_round_robin_locked() {
+        let mut ctx = CoreContextTest::new();
+        arrange_round_robin(&mut ctx, false).await;
+
+        let result = AssignPoolReviewers
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &GhPullRequest::default(),
+                &[],
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::LockTimeout { .. })));
+        assert_eq!(
+            ctx.db_service
+                .repositories_get_expect("me", "test")
+                .await
+                .unwrap()
+                .reviewer_pool_cursor,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn draft() {
+        let ctx = CoreContextTest::new();
+
+        let reviewers = AssignPoolReviewers
+            .run(
+                &ctx.as_context(),
+                &("me", "test", 1).into(),
+                &GhPullRequest {
+                    draft: true,
+                    ..Default::default()
+                },
+                &[],
+            )
+            .await
+            .unwrap();
+
+        assert!(reviewers.is_empty());
+    }
+}
@@ -37,11 +37,27 @@ impl FilterReviewersInterface for FilterReviewers {
         let (mut allowed, mut rejected) = (vec![], vec![]);
 
         for reviewer in reviewers {
-            let permission = ctx
-                .api_service
-                .user_permissions_get(repository_path.owner(), repository_path.name(), reviewer)
-                .await?
-                .can_write();
+            let permission = match reviewer.split_once('/') {
+                // Teams can only be requested from the repository organization
+                Some((org, team_slug)) => {
+                    org == repository_path.owner()
+                        && ctx
+                            .api_service
+                            .team_permissions_get(
+                                org,
+                                team_slug,
+                                repository_path.owner(),
+                                repository_path.name(),
+                            )
+                            .await?
+                            .can_write()
+                }
+                None => ctx
+                    .api_service
+                    .user_permissions_get(repository_path.owner(), repository_path.name(), reviewer)
+                    .await?
+                    .can_write(),
+            };
 
             if permission {
                 allowed.push(reviewer.clone());
@@ -105,4 +121,44 @@ mod tests {
             }
         )
     }
+
+    #[tokio::test]
+    async fn teams() {
+        let mut ctx = CoreContextTest::new();
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+
+            svc.expect_team_permissions_get()
+                .once()
+                .withf(|org, team_slug, owner, name| {
+                    org == "me" && team_slug == "devs" && owner == "me" && name == "test"
+                })
+                .return_once(|_, _, _, _| Ok(GhUserPermission::Write));
+
+            svc.expect_team_permissions_get()
+                .once()
+                .withf(|org, team_slug, _, _| org == "me" && team_slug == "readers")
+                .return_once(|_, _, _, _| Ok(GhUserPermission::Read));
+
+            svc
+        };
+
+        let result = FilterReviewers
+            .run(
+                &ctx.as_context(),
+                &("me", "test").into(),
+                &["me/devs".into(), "me/readers".into(), "other/devs".into()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            FilteredReviewers {
+                allowed: vec!["me/devs".into()],
+                rejected: vec!["me/readers".into(), "other/devs".into()],
+            }
+        )
+    }
 }
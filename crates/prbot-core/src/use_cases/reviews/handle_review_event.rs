@@ -1,8 +1,12 @@
 use async_trait::async_trait;
-use prbot_ghapi_interface::types::GhReviewEvent;
+use prbot_ghapi_interface::types::{GhReviewAction, GhReviewEvent};
+use prbot_models::PullRequestEventKind;
 use shaku::{Component, HasComponent, Interface};
 
-use crate::{use_cases::status::UpdatePullRequestStatusInterface, CoreContext, Result};
+use crate::{
+    use_cases::{stats::record_pull_request_event, status::UpdatePullRequestStatusInterface},
+    CoreContext, Result,
+};
 
 #[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
 #[async_trait]
@@ -38,6 +42,18 @@ impl HandleReviewEventInterface for HandleReviewEvent {
             .await?
             .is_some()
         {
+            let pr_handle = (repo_owner.as_str(), repo_name.as_str(), pr_number).into();
+            if event.action == GhReviewAction::Submitted {
+                record_pull_request_event(
+                    ctx.db_service,
+                    &pr_handle,
+                    PullRequestEventKind::Reviewed,
+                    Some(&event.review.user.login),
+                    &event.review.state.to_string(),
+                )
+                .await?;
+            }
+
             let upstream_pr = ctx
                 .api_service
                 .pulls_get(repo_owner, repo_name, pr_number)
@@ -46,11 +62,7 @@ impl HandleReviewEventInterface for HandleReviewEvent {
             let update_pull_request_status: &dyn UpdatePullRequestStatusInterface =
                 ctx.core_module.resolve_ref();
             update_pull_request_status
-                .run(
-                    ctx,
-                    &(repo_owner.as_str(), repo_name.as_str(), pr_number).into(),
-                    &upstream_pr,
-                )
+                .run(ctx, &pr_handle, &upstream_pr)
                 .await?;
         }
 
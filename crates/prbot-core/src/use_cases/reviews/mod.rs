@@ -1,16 +1,23 @@
 pub(crate) mod add_reviewers;
+pub(crate) mod assign_code_owners_reviewers;
+pub(crate) mod assign_pool_reviewers;
 pub(crate) mod filter_reviewers;
 pub(crate) mod handle_review_event;
 pub(crate) mod remove_reviewers;
 
 pub use add_reviewers::AddReviewersInterface;
+pub use assign_code_owners_reviewers::AssignCodeOwnersReviewersInterface;
+pub use assign_pool_reviewers::AssignPoolReviewersInterface;
 pub use filter_reviewers::{FilterReviewersInterface, FilteredReviewers};
 pub use handle_review_event::HandleReviewEventInterface;
 pub use remove_reviewers::RemoveReviewersInterface;
 
 #[cfg(any(test, feature = "testkit"))]
 pub use self::{
-    add_reviewers::MockAddReviewersInterface, filter_reviewers::MockFilterReviewersInterface,
+    add_reviewers::MockAddReviewersInterface,
+    assign_code_owners_reviewers::MockAssignCodeOwnersReviewersInterface,
+    assign_pool_reviewers::MockAssignPoolReviewersInterface,
+    filter_reviewers::MockFilterReviewersInterface,
     handle_review_event::MockHandleReviewEventInterface,
     remove_reviewers::MockRemoveReviewersInterface,
 };
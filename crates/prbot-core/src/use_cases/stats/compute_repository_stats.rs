@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use prbot_models::{PullRequestHandle, RepositoryPath, RepositoryStats};
+use shaku::{Component, Interface};
+use time::OffsetDateTime;
+
+use super::get_pull_request_timeline;
+use crate::{CoreContext, Result};
+
+#[cfg_attr(any(test, feature = "testkit"), mockall::automock)]
+#[async_trait]
+pub trait ComputeRepositoryStatsInterface: Interface {
+    /// Compute lifecycle statistics of pull requests opened since a date.
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository_path: &RepositoryPath,
+        since: Option<OffsetDateTime>,
+    ) -> Result<RepositoryStats>;
+}
+
+#[derive(Component)]
+#[shaku(interface = ComputeRepositoryStatsInterface)]
+pub(crate) struct ComputeRepositoryStats;
+
+#[async_trait]
+impl ComputeRepositoryStatsInterface for ComputeRepositoryStats {
+    #[tracing::instrument(skip(self, ctx), fields(repository_path = %repository_path))]
+    async fn run<'a>(
+        &self,
+        ctx: &CoreContext<'a>,
+        repository_path: &RepositoryPath,
+        since: Option<OffsetDateTime>,
+    ) -> Result<RepositoryStats> {
+        let (owner, name) = repository_path.components();
+        ctx.db_service.repositories_get_expect(owner, name).await?;
+
+        let mut timelines = vec![];
+        for pull_request in ctx.db_service.pull_requests_list(owner, name).await? {
+            let pr_handle = PullRequestHandle::new(repository_path.clone(), pull_request.number);
+            timelines.push(get_pull_request_timeline(ctx.db_service, &pr_handle).await?);
+        }
+
+        Ok(RepositoryStats::compute(
+            &repository_path.full_name(),
+            since,
+            &timelines,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_models::{
+        PullRequest, PullRequestEvent, PullRequestEventKind, Repository, StepLabel, StepTransition,
+    };
+
+    use super::*;
+    use crate::context::tests::CoreContextTest;
+
+    #[tokio::test]
+    async fn run() {
+        let ctx = CoreContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let pr = ctx
+            .db_service
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let at = |seconds| OffsetDateTime::from_unix_timestamp(seconds).unwrap();
+        ctx.db_service
+            .step_transitions_create(StepTransition {
+                pull_request_id: pr.id,
+                step: StepLabel::AwaitingReview,
+                created_at: at(1_000),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        for (kind, seconds) in [
+            (PullRequestEventKind::Opened, 1_000),
+            (PullRequestEventKind::Reviewed, 1_600),
+            (PullRequestEventKind::Merged, 4_600),
+        ] {
+            ctx.db_service
+                .pull_request_events_create(PullRequestEvent {
+                    pull_request_id: pr.id,
+                    kind,
+                    created_at: at(seconds),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        let stats = ComputeRepositoryStats
+            .run(&ctx.as_context(), &"me/test".try_into().unwrap(), None)
+            .await
+            .unwrap();
+        assert_eq!(stats.repository_path, "me/test");
+        assert_eq!(stats.pull_requests_count, 1);
+        assert_eq!(stats.merged_count, 1);
+        assert_eq!(stats.time_to_first_review.mean_seconds, 600);
+        assert_eq!(stats.time_to_merge.mean_seconds, 3_600);
+        assert_eq!(
+            stats.step_durations["step/awaiting-review"].mean_seconds,
+            3_600
+        );
+    }
+}
@@ -0,0 +1,8 @@
+pub(crate) mod compute_repository_stats;
+mod utils;
+
+pub use compute_repository_stats::ComputeRepositoryStatsInterface;
+pub use utils::{get_pull_request_timeline, has_pull_request_event, record_pull_request_event};
+
+#[cfg(any(test, feature = "testkit"))]
+pub use self::compute_repository_stats::MockComputeRepositoryStatsInterface;
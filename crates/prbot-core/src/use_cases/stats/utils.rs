@@ -0,0 +1,66 @@
+use prbot_database_interface::DbService;
+use prbot_models::{
+    PullRequestEvent, PullRequestEventKind, PullRequestHandle, PullRequestTimeline,
+};
+use time::OffsetDateTime;
+
+use crate::Result;
+
+/// Record a timeline event on a pull request, if it is known.
+pub async fn record_pull_request_event(
+    db_service: &dyn DbService,
+    pr_handle: &PullRequestHandle,
+    kind: PullRequestEventKind,
+    author: Option<&str>,
+    details: &str,
+) -> Result<()> {
+    let Some(pr_model) = db_service
+        .pull_requests_get(pr_handle.owner(), pr_handle.name(), pr_handle.number())
+        .await?
+    else {
+        return Ok(());
+    };
+
+    db_service
+        .pull_request_events_create(PullRequestEvent {
+            pull_request_id: pr_model.id,
+            kind,
+            author: author.map(Into::into),
+            details: details.into(),
+            created_at: OffsetDateTime::now_utc(),
+            ..Default::default()
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// Check if a timeline event kind was already recorded on a pull request.
+pub async fn has_pull_request_event(
+    db_service: &dyn DbService,
+    pr_handle: &PullRequestHandle,
+    kind: PullRequestEventKind,
+) -> Result<bool> {
+    Ok(db_service
+        .pull_request_events_list(pr_handle.owner(), pr_handle.name(), pr_handle.number())
+        .await?
+        .iter()
+        .any(|e| e.kind == kind))
+}
+
+/// Get the timeline of a pull request.
+pub async fn get_pull_request_timeline(
+    db_service: &dyn DbService,
+    pr_handle: &PullRequestHandle,
+) -> Result<PullRequestTimeline> {
+    let (owner, name, number) = (pr_handle.owner(), pr_handle.name(), pr_handle.number());
+
+    Ok(PullRequestTimeline {
+        transitions: db_service
+            .step_transitions_list(owner, name, number)
+            .await?,
+        events: db_service
+            .pull_request_events_list(owner, name, number)
+            .await?,
+    })
+}
@@ -45,7 +45,9 @@ mod tests {
     use super::*;
     use crate::{
         context::tests::CoreContextTest,
-        use_cases::checks::{DetermineChecksStatusInterface, MockDetermineChecksStatusInterface},
+        use_cases::checks::{
+            ChecksReport, DetermineChecksStatusInterface, MockDetermineChecksStatusInterface,
+        },
         CoreModule,
     };
 
@@ -58,12 +60,12 @@ mod tests {
 
             svc.expect_run()
                 .once()
-                .withf(|_, repository_path, sha, wait_for_checks| {
-                    repository_path == &("me", "test").into()
+                .withf(|_, repository, sha, wait_for_checks| {
+                    repository.path() == ("me", "test").into()
                         && sha == "abcdef"
                         && wait_for_checks == &true
                 })
-                .return_once(|_, _, _, _| Ok(ChecksStatus::Waiting));
+                .return_once(|_, _, _, _| Ok(ChecksReport::from_status(ChecksStatus::Waiting)));
 
             svc
         };
@@ -131,15 +133,19 @@ mod tests {
             PullRequestStatus {
                 changes_required_reviewers: vec![],
                 approved_reviewers: vec![],
+                stale_approval_reviewers: vec![],
                 automerge: false,
                 checks_status: ChecksStatus::Waiting,
+                required_checks: vec![],
                 checks_url: "https://github.com/me/test/pull/1/checks".into(),
                 qa_status: QaStatus::Skipped,
                 needed_reviewers_count: 1,
                 missing_required_reviewers: vec![],
+                required_teams: vec![],
                 pull_request_title_regex: String::new(),
                 valid_pr_title: true,
                 locked: false,
+                frozen: None,
                 mergeable: true,
                 wip: false,
                 merged: false,
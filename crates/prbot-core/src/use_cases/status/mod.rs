@@ -10,7 +10,7 @@ pub use create_or_update_commit_status::CreateOrUpdateCommitStatusInterface;
 pub use disable_pull_request_status::DisablePullRequestStatusInterface;
 pub use set_pull_request_qa_status::SetPullRequestQaStatusInterface;
 pub use update_pull_request_status::UpdatePullRequestStatusInterface;
-pub use utils::{PullRequestStatus, StatusMessageGenerator, StepLabelChooser};
+pub use utils::{PullRequestStatus, RequiredTeamStatus, StatusMessageGenerator, StepLabelChooser};
 
 #[cfg(any(test, feature = "testkit"))]
 pub use self::{
@@ -1,7 +1,9 @@
 //! External module.
 
 use async_trait::async_trait;
-use prbot_models::{ExternalAccount, QaStatus, RepositoryPath};
+use prbot_models::{
+    AuditActor, AuditEntry, AuditSource, ExternalAccount, QaStatus, RepositoryPath,
+};
 use shaku::{Component, HasComponent, Interface};
 
 use crate::{
@@ -9,6 +11,7 @@ use crate::{
         commands::{BotCommand, SetQaStatusCommand},
         CommandContext, CommandExecutorInterface,
     },
+    use_cases::audit::record_audit_entry,
     CoreContext, Result,
 };
 
@@ -58,12 +61,13 @@ impl SetPullRequestQaStatusInterface for SetPullRequestQaStatus {
             .await?
             .is_some()
         {
+            let actor = AuditActor::new(AuditSource::ExternalApi, &external_account.username);
+
             for pr_number in pull_request_numbers {
-                if ctx
+                if let Some(pr_model) = ctx
                     .db_service
                     .pull_requests_get(repo_owner, repo_name, *pr_number)
                     .await?
-                    .is_some()
                 {
                     let upstream_pr = ctx
                         .api_service
@@ -87,6 +91,19 @@ impl SetPullRequestQaStatusInterface for SetPullRequestQaStatus {
                     let result = SetQaStatusCommand::new(status).handle(&ctx).await?;
                     let executor: &dyn CommandExecutorInterface = ctx.core_module.resolve_ref();
                     executor.process_command_result(&ctx, &result).await?;
+
+                    record_audit_entry(
+                        ctx.db_service,
+                        AuditEntry::on_pull_request(
+                            &actor,
+                            "set_qa_status",
+                            &repository_path,
+                            *pr_number,
+                        )
+                        .with_old_value(pr_model.qa_status)
+                        .with_new_value(status),
+                    )
+                    .await?;
                 }
             }
         }
@@ -293,5 +310,13 @@ mod tests {
             )
             .await
             .unwrap();
+
+        let entries = ctx.db_service.audit_entries_all().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].source, AuditSource::ExternalApi);
+        assert_eq!(entries[0].actor, "ext");
+        assert_eq!(entries[0].target, "me/test#2");
+        assert_eq!(entries[0].old_value.as_deref(), Some("skipped"));
+        assert_eq!(entries[0].new_value.as_deref(), Some("pass"));
     }
 }
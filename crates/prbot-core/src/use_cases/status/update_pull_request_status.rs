@@ -85,8 +85,11 @@ impl UpdatePullRequestStatusInterface for UpdatePullRequestStatus {
             let result = automerge_uc
                 .run(ctx, pr_handle, upstream_pr, &pr_status)
                 .await?;
-            if result == TryMergePullRequestState::Error {
-                // Disable automerge.
+            if matches!(
+                result,
+                TryMergePullRequestState::Error | TryMergePullRequestState::Enqueued
+            ) {
+                // Disable automerge, on error or once handed over to the merge queue.
                 ctx.db_service
                     .pull_requests_set_automerge(
                         pr_handle.repository_path().owner(),
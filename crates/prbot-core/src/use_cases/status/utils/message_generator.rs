@@ -5,6 +5,7 @@ use super::PullRequestStatus;
 use crate::Result;
 
 pub const VALIDATION_STATUS_MESSAGE: &str = "Validation";
+pub const CONFIGURATION_STATUS_MESSAGE: &str = "Configuration";
 
 #[derive(Debug)]
 pub struct StatusMessage {
@@ -72,6 +73,9 @@ impl StatusMessageGenerator {
                                 if pr_status.locked {
                                     status_message = "PR is locked".to_string();
                                     status_state = GhCommitStatusState::Failure;
+                                } else if let Some(frozen) = &pr_status.frozen {
+                                    status_message = frozen.to_string();
+                                    status_state = GhCommitStatusState::Pending;
                                 }
                             }
                         }
@@ -2,6 +2,8 @@ mod message_generator;
 mod pull_status;
 mod step_label_chooser;
 
-pub use message_generator::{StatusMessageGenerator, VALIDATION_STATUS_MESSAGE};
-pub use pull_status::PullRequestStatus;
+pub use message_generator::{
+    StatusMessageGenerator, CONFIGURATION_STATUS_MESSAGE, VALIDATION_STATUS_MESSAGE,
+};
+pub use pull_status::{PullRequestStatus, RequiredTeamStatus};
 pub use step_label_chooser::StepLabelChooser;
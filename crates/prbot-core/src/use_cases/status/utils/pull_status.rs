@@ -5,20 +5,32 @@ use prbot_ghapi_interface::{
     types::{GhPullRequest, GhReviewState},
 };
 use prbot_models::{
-    ChecksStatus, MergeStrategy, PullRequestHandle, PullRequestRule, QaStatus, RequiredReviewer,
+    ChecksStatus, FrozenReason, MergeStrategy, PullRequestHandle, PullRequestRule, QaStatus,
+    RequiredReviewer, StaleApprovalPolicy,
 };
 use regex::Regex;
 use shaku::HasComponent;
+use time::OffsetDateTime;
+use tracing::warn;
 
 use crate::{
     errors::Result,
     use_cases::{
-        checks::DetermineChecksStatusInterface,
+        checks::{ChecksReport, DetermineChecksStatusInterface, RequiredCheckStatus},
         pulls::{DeterminePullRequestMergeStrategyInterface, ResolvePullRequestRulesInterface},
     },
     CoreContext,
 };
 
+/// Required team reviewer status.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RequiredTeamStatus {
+    /// Team name, as `org/team`.
+    pub team: String,
+    /// Approving team member, if any.
+    pub approved_by: Option<String>,
+}
+
 /// Pull request status.
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct PullRequestStatus {
@@ -26,10 +38,14 @@ pub struct PullRequestStatus {
     pub changes_required_reviewers: Vec<String>,
     /// Approved reviewer usernames.
     pub approved_reviewers: Vec<String>,
+    /// Reviewers whose approval was invalidated by new commits.
+    pub stale_approval_reviewers: Vec<String>,
     /// Automerge enabled?
     pub automerge: bool,
     /// Checks status.
     pub checks_status: ChecksStatus,
+    /// Required checks statuses.
+    pub required_checks: Vec<RequiredCheckStatus>,
     /// Checks URL.
     pub checks_url: String,
     /// Needed reviewers count.
@@ -38,12 +54,16 @@ pub struct PullRequestStatus {
     pub qa_status: QaStatus,
     /// Missing required reviewers.
     pub missing_required_reviewers: Vec<String>,
+    /// Required team reviewers statuses.
+    pub required_teams: Vec<RequiredTeamStatus>,
     /// Pull request title regex.
     pub pull_request_title_regex: String,
     /// PR title is valid?
     pub valid_pr_title: bool,
     /// PR is locked?
     pub locked: bool,
+    /// Merges frozen on the base branch?
+    pub frozen: Option<FrozenReason>,
     /// PR is in WIP?
     pub wip: bool,
     /// PR is mergeable?
@@ -88,31 +108,56 @@ impl PullRequestStatus {
             pr_handle.number(),
         );
 
-        let (repo_model, pr_model, upstream_reviews, required_reviewers) = tokio::join!(
+        let fetch_merge_freezes = ctx.db_service.merge_freezes_list(
+            pr_handle.repository_path().owner(),
+            pr_handle.repository_path().name(),
+        );
+
+        let fetch_merge_windows = ctx.db_service.merge_windows_list(
+            pr_handle.repository_path().owner(),
+            pr_handle.repository_path().name(),
+        );
+
+        let (
+            repo_model,
+            pr_model,
+            upstream_reviews,
+            required_reviewers,
+            merge_freezes,
+            merge_windows,
+        ) = tokio::join!(
             fetch_repo_model,
             fetch_pr_model,
             fetch_upstream_reviews,
-            fetch_required_reviewers
+            fetch_required_reviewers,
+            fetch_merge_freezes,
+            fetch_merge_windows
         );
 
         let repo_model = repo_model?.unwrap();
         let pr_model = pr_model?.unwrap();
         let upstream_reviews = upstream_reviews?;
         let required_reviewers = required_reviewers?;
+        let frozen = FrozenReason::resolve(
+            &merge_freezes?,
+            &merge_windows?,
+            &upstream_pr.base.reference,
+            OffsetDateTime::now_utc(),
+        );
 
-        let checks_status = if pr_model.checks_enabled {
+        let checks_report = if pr_model.checks_enabled {
             let determine_check_status: &dyn DetermineChecksStatusInterface =
                 ctx.core_module.resolve_ref();
             determine_check_status
                 .run(
                     ctx,
-                    pr_handle.repository_path(),
+                    &repo_model,
                     &upstream_pr.head.sha,
                     pr_model.checks_enabled,
                 )
                 .await?
         } else {
-            ChecksStatus::Skipped
+            ChecksReport::from_status(ChecksStatus::Skipped)
         };
 
         let strategy = if let Some(s) = pr_model.strategy_override {
@@ -143,6 +188,9 @@ impl PullRequestStatus {
         let mut approved_reviews = vec![];
         let mut required_reviews = vec![];
         let mut changes_required_reviews = vec![];
+        let mut required_teams = vec![];
+        let mut stale_approval_reviews = vec![];
+        let mut pr_files = None;
 
         // Required reviewers may not be in upstream reviews,
         // we need to make sure they are parsed as well.
@@ -151,9 +199,24 @@ impl PullRequestStatus {
             let username = review.user.login;
             let required = Self::is_required_reviewer(&required_reviewers, &username);
             let state = review.state;
-            let approved = state == GhReviewState::Approved;
+            let mut approved = state == GhReviewState::Approved;
             seen_reviewers.insert(username.clone());
 
+            if approved
+                && Self::is_stale_approval(
+                    ctx,
+                    pr_handle,
+                    repo_model.stale_approval_policy,
+                    review.commit_id.as_deref(),
+                    &upstream_pr.head.sha,
+                    &mut pr_files,
+                )
+                .await?
+            {
+                stale_approval_reviews.push(username.clone());
+                approved = false;
+            }
+
             if state == GhReviewState::ChangesRequested {
                 changes_required_reviews.push(username);
             } else if required && !approved {
@@ -164,7 +227,22 @@ impl PullRequestStatus {
         }
 
         for required_reviewer in required_reviewers {
-            if !seen_reviewers.contains(&required_reviewer.username) {
+            if let Some((org, team_slug)) = required_reviewer.team() {
+                // Teams are satisfied by any approving member
+                let members = ctx.api_service.team_members_list(org, team_slug).await?;
+                let approved_by = approved_reviews
+                    .iter()
+                    .find(|r| members.contains(r))
+                    .cloned();
+                if approved_by.is_none() {
+                    required_reviews.push(required_reviewer.username.to_string());
+                }
+
+                required_teams.push(RequiredTeamStatus {
+                    team: required_reviewer.username.to_string(),
+                    approved_by,
+                });
+            } else if !seen_reviewers.contains(&required_reviewer.username) {
                 required_reviews.push(required_reviewer.username.to_string());
             }
         }
@@ -172,18 +250,22 @@ impl PullRequestStatus {
         Ok(Self {
             changes_required_reviewers: changes_required_reviews,
             approved_reviewers: approved_reviews,
+            stale_approval_reviewers: stale_approval_reviews,
             automerge: pr_model.automerge,
-            checks_status,
+            checks_status: checks_report.status,
+            required_checks: checks_report.required_checks,
             checks_url: Self::get_checks_url(&repo_model.owner, &repo_model.name, pr_model.number),
             pull_request_title_regex: repo_model.pr_title_validation_regex.clone(),
             needed_reviewers_count: needed_reviews,
             qa_status: pr_model.qa_status,
             missing_required_reviewers: required_reviews,
+            required_teams,
             valid_pr_title: Self::check_pr_title(
                 &upstream_pr.title,
                 &repo_model.pr_title_validation_regex,
             )?,
             locked: pr_model.locked,
+            frozen,
             wip: upstream_pr.draft,
             mergeable: upstream_pr.mergeable.unwrap_or(true),
             merged: upstream_pr.merged.unwrap_or(false),
@@ -192,6 +274,62 @@ impl PullRequestStatus {
         })
     }
 
+    /// Check if an approval given on an older commit is invalidated by the repository policy.
+    ///
+    /// Pull request files are only fetched once, when needed.
+    async fn is_stale_approval(
+        ctx: &CoreContext<'_>,
+        pr_handle: &PullRequestHandle,
+        policy: StaleApprovalPolicy,
+        commit_id: Option<&str>,
+        head_sha: &str,
+        pr_files: &mut Option<Vec<String>>,
+    ) -> Result<bool> {
+        let Some(commit_id) = commit_id else {
+            return Ok(false);
+        };
+
+        if commit_id == head_sha {
+            return Ok(false);
+        }
+
+        match policy {
+            StaleApprovalPolicy::Keep => Ok(false),
+            StaleApprovalPolicy::InvalidateAll => Ok(true),
+            StaleApprovalPolicy::InvalidateChangedFiles => {
+                let (owner, name) = (pr_handle.owner(), pr_handle.name());
+                let changed_files = match ctx
+                    .api_service
+                    .commits_compare_files(owner, name, commit_id, head_sha)
+                    .await
+                {
+                    Ok(files) => files,
+                    Err(e) => {
+                        // The reviewed commit may be gone after a force-push
+                        warn!(
+                            pr_handle = %pr_handle,
+                            commit_id = commit_id,
+                            error = %e,
+                            message = "Could not compare reviewed commit, invalidating approval"
+                        );
+                        return Ok(true);
+                    }
+                };
+
+                if pr_files.is_none() {
+                    *pr_files = Some(
+                        ctx.api_service
+                            .pull_files_list(owner, name, pr_handle.number())
+                            .await?,
+                    );
+                }
+
+                let pr_files = pr_files.as_deref().unwrap_or_default();
+                Ok(changed_files.iter().any(|f| pr_files.contains(f)))
+            }
+        }
+    }
+
     /// Get checks url.
     pub fn get_checks_url(owner: &str, name: &str, number: u64) -> String {
         format!("https://github.com/{owner}/{name}/pull/{number}/checks")
@@ -320,14 +458,18 @@ mod tests {
                 checks_url: "https://github.com/me/test/pull/1/checks".into(),
                 needed_reviewers_count: 0,
                 checks_status: ChecksStatus::Skipped,
+                required_checks: vec![],
                 qa_status: QaStatus::Skipped,
                 valid_pr_title: true,
                 mergeable: true,
                 changes_required_reviewers: vec![],
                 approved_reviewers: vec![],
+                stale_approval_reviewers: vec![],
                 missing_required_reviewers: vec![],
+                required_teams: vec![],
                 automerge: false,
                 locked: false,
+                frozen: None,
                 merged: false,
                 wip: false,
                 merge_strategy: MergeStrategy::Merge,
@@ -344,12 +486,12 @@ mod tests {
             let mut mock = MockDetermineChecksStatusInterface::new();
             mock.expect_run()
                 .once()
-                .withf(|_, repository_path, sha, wait_for_checks| {
-                    repository_path == &("me", "test").into()
+                .withf(|_, repository, sha, wait_for_checks| {
+                    repository.path() == ("me", "test").into()
                         && sha == "abcdef"
                         && wait_for_checks == &true
                 })
-                .return_once(|_, _, _, _| Ok(ChecksStatus::Waiting));
+                .return_once(|_, _, _, _| Ok(ChecksReport::from_status(ChecksStatus::Waiting)));
 
             mock
         };
@@ -420,14 +562,18 @@ mod tests {
                 checks_url: "https://github.com/me/test/pull/1/checks".into(),
                 needed_reviewers_count: 0,
                 checks_status: ChecksStatus::Waiting,
+                required_checks: vec![],
                 qa_status: QaStatus::Skipped,
                 valid_pr_title: true,
                 mergeable: true,
                 changes_required_reviewers: vec![],
                 approved_reviewers: vec![],
+                stale_approval_reviewers: vec![],
                 missing_required_reviewers: vec![],
+                required_teams: vec![],
                 automerge: false,
                 locked: false,
+                frozen: None,
                 merged: false,
                 wip: false,
                 merge_strategy: MergeStrategy::Merge,
@@ -444,12 +590,12 @@ mod tests {
             let mut mock = MockDetermineChecksStatusInterface::new();
             mock.expect_run()
                 .once()
-                .withf(|_, repository_path, sha, wait_for_checks| {
-                    repository_path == &("me", "test").into()
+                .withf(|_, repository, sha, wait_for_checks| {
+                    repository.path() == ("me", "test").into()
                         && sha == "abcdef"
                         && wait_for_checks == &true
                 })
-                .return_once(|_, _, _, _| Ok(ChecksStatus::Waiting));
+                .return_once(|_, _, _, _| Ok(ChecksReport::from_status(ChecksStatus::Waiting)));
 
             mock
         };
@@ -520,14 +666,18 @@ mod tests {
                 checks_url: "https://github.com/me/test/pull/1/checks".into(),
                 needed_reviewers_count: 0,
                 checks_status: ChecksStatus::Waiting,
+                required_checks: vec![],
                 qa_status: QaStatus::Waiting,
                 valid_pr_title: true,
                 mergeable: true,
                 changes_required_reviewers: vec![],
                 approved_reviewers: vec![],
+                stale_approval_reviewers: vec![],
                 missing_required_reviewers: vec![],
+                required_teams: vec![],
                 automerge: false,
                 locked: false,
+                frozen: None,
                 merged: false,
                 wip: false,
                 merge_strategy: MergeStrategy::Merge,
@@ -544,12 +694,12 @@ mod tests {
             let mut mock = MockDetermineChecksStatusInterface::new();
             mock.expect_run()
                 .once()
-                .withf(|_, repository_path, sha, wait_for_checks| {
-                    repository_path == &("me", "test").into()
+                .withf(|_, repository, sha, wait_for_checks| {
+                    repository.path() == ("me", "test").into()
                         && sha == "abcdef"
                         && wait_for_checks == &true
                 })
-                .return_once(|_, _, _, _| Ok(ChecksStatus::Waiting));
+                .return_once(|_, _, _, _| Ok(ChecksReport::from_status(ChecksStatus::Waiting)));
 
             mock
         };
@@ -628,14 +778,18 @@ mod tests {
                 checks_url: "https://github.com/me/test/pull/1/checks".into(),
                 needed_reviewers_count: 2,
                 checks_status: ChecksStatus::Waiting,
+                required_checks: vec![],
                 qa_status: QaStatus::Waiting,
                 valid_pr_title: true,
                 mergeable: true,
                 changes_required_reviewers: vec![],
                 approved_reviewers: vec!["dummy".into()],
+                stale_approval_reviewers: vec![],
                 missing_required_reviewers: vec![],
+                required_teams: vec![],
                 automerge: false,
                 locked: false,
+                frozen: None,
                 merged: false,
                 wip: false,
                 merge_strategy: MergeStrategy::Merge,
@@ -644,4 +798,181 @@ mod tests {
             }
         )
     }
+
+    #[tokio::test]
+    async fn required_team_reviewers() {
+        let mut ctx = CoreContextTest::new();
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+
+            svc.expect_pull_reviews_list()
+                .once()
+                .return_once(|_, _, _| {
+                    Ok(vec![GhReviewApi {
+                        state: GhReviewStateApi::Approved,
+                        user: GhUser {
+                            login: "member".into(),
+                        },
+                        ..Default::default()
+                    }])
+                });
+            svc.expect_team_members_list()
+                .once()
+                .withf(|org, team| org == "org" && team == "core")
+                .return_once(|_, _| Ok(vec!["member".into()]));
+            svc.expect_team_members_list()
+                .once()
+                .withf(|org, team| org == "org" && team == "docs")
+                .return_once(|_, _| Ok(vec!["other".into()]));
+
+            svc
+        };
+
+        ctx.db_service = {
+            let svc = MemoryDb::new();
+
+            let repo = svc
+                .repositories_create(Repository {
+                    owner: "me".into(),
+                    name: "test".into(),
+                    default_enable_checks: false,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            let pr = svc
+                .pull_requests_create(
+                    PullRequest {
+                        number: 1,
+                        ..Default::default()
+                    }
+                    .with_repository(&repo),
+                )
+                .await
+                .unwrap();
+
+            for team in ["org/core", "org/docs"] {
+                svc.required_reviewers_create(RequiredReviewer {
+                    pull_request_id: pr.id,
+                    username: team.into(),
+                })
+                .await
+                .unwrap();
+            }
+
+            svc
+        };
+
+        let status = PullRequestStatus::from_database(
+            &ctx.as_context(),
+            &("me", "test", 1).into(),
+            &GhPullRequest::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status.approved_reviewers, vec!["member".to_string()]);
+        assert_eq!(
+            status.missing_required_reviewers,
+            vec!["org/docs".to_string()]
+        );
+        assert_eq!(
+            status.required_teams,
+            vec![
+                RequiredTeamStatus {
+                    team: "org/core".into(),
+                    approved_by: Some("member".into()),
+                },
+                RequiredTeamStatus {
+                    team: "org/docs".into(),
+                    approved_by: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stale_approvals() {
+        let mut ctx = CoreContextTest::new();
+
+        ctx.api_service = {
+            let mut svc = MockApiService::new();
+
+            svc.expect_pull_reviews_list()
+                .once()
+                .return_once(|_, _, _| {
+                    let review = |login: &str, commit_id: &str| GhReviewApi {
+                        state: GhReviewStateApi::Approved,
+                        user: GhUser {
+                            login: login.into(),
+                        },
+                        commit_id: Some(commit_id.into()),
+                        ..Default::default()
+                    };
+
+                    Ok(vec![
+                        review("current", "abcdef"),
+                        review("docs", "docs"),
+                        review("code", "code"),
+                    ])
+                });
+            svc.expect_commits_compare_files()
+                .withf(|_, _, base, head| base == "docs" && head == "abcdef")
+                .return_once(|_, _, _, _| Ok(vec!["README.md".into()]));
+            svc.expect_commits_compare_files()
+                .withf(|_, _, base, head| base == "code" && head == "abcdef")
+                .return_once(|_, _, _, _| Ok(vec!["src/main.rs".into()]));
+            // Files are only fetched once
+            svc.expect_pull_files_list()
+                .once()
+                .return_once(|_, _, _| Ok(vec!["src/main.rs".into()]));
+
+            svc
+        };
+
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "test".into(),
+                default_enable_checks: false,
+                default_needed_reviewers_count: 3,
+                stale_approval_policy: StaleApprovalPolicy::InvalidateChangedFiles,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        ctx.db_service
+            .pull_requests_create(
+                PullRequest {
+                    number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await
+            .unwrap();
+
+        let status = PullRequestStatus::from_database(
+            &ctx.as_context(),
+            &("me", "test", 1).into(),
+            &GhPullRequest {
+                head: GhBranch {
+                    sha: "abcdef".into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let mut approved_reviewers = status.approved_reviewers.clone();
+        approved_reviewers.sort();
+        assert_eq!(approved_reviewers, vec!["current", "docs"]);
+        assert_eq!(status.stale_approval_reviewers, vec!["code"]);
+        assert!(status.missing_reviews());
+    }
 }
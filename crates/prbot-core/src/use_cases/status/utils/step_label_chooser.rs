@@ -16,6 +16,10 @@ impl StepLabelChooser {
                 ChecksStatus::Pass | ChecksStatus::Skipped => {
                     if pr_status.changes_required() || !pr_status.mergeable && !pr_status.merged {
                         StepLabel::AwaitingChanges
+                    } else if pr_status.missing_reviews()
+                        && !pr_status.stale_approval_reviewers.is_empty()
+                    {
+                        StepLabel::StaleApproval
                     } else if pr_status.missing_required_reviews() {
                         StepLabel::AwaitingRequiredReview
                     } else if pr_status.missing_reviews() {
@@ -27,6 +31,8 @@ impl StepLabelChooser {
                             QaStatus::Pass | QaStatus::Skipped => {
                                 if pr_status.locked {
                                     StepLabel::Locked
+                                } else if pr_status.frozen.is_some() {
+                                    StepLabel::Frozen
                                 } else {
                                     StepLabel::AwaitingMerge
                                 }
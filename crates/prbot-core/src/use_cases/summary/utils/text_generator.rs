@@ -72,12 +72,31 @@ impl SummaryTextGenerator {
     }
 
     fn generate_status_comment_checks_section(pull_request_status: &PullRequestStatus) -> String {
-        let checks_message = match pull_request_status.checks_status {
-            ChecksStatus::Pass => "_passed!_ :heavy_check_mark:",
-            ChecksStatus::Waiting => "_running..._ :clock2:",
-            ChecksStatus::Fail => "_failed._ :x:",
-            ChecksStatus::Skipped => "_skipped._ :heavy_check_mark:",
-        };
+        let checks_message = Self::checks_status_message(pull_request_status.checks_status);
+        let mut required_checks_message = String::new();
+        for check in &pull_request_status.required_checks {
+            required_checks_message.push_str(&format!(
+                "\n>   - `{}`: {}",
+                check.name,
+                Self::checks_status_message(check.status)
+            ));
+        }
+
+        let mut required_teams_message = String::new();
+        for team in &pull_request_status.required_teams {
+            let team_message = match &team.approved_by {
+                Some(member) => format!("_approved by {member}!_ :heavy_check_mark:"),
+                None => "_waiting..._ :clock2:".to_string(),
+            };
+            required_teams_message.push_str(&format!("\n>   - `@{}`: {team_message}", team.team));
+        }
+
+        let mut stale_approvals_message = String::new();
+        for reviewer in &pull_request_status.stale_approval_reviewers {
+            stale_approvals_message.push_str(&format!(
+                "\n>   - `{reviewer}`: _stale approval, new commits were pushed_ :warning:"
+            ));
+        }
 
         let qa_message = match pull_request_status.qa_status {
             QaStatus::Pass => "_passed!_ :heavy_check_mark:",
@@ -92,6 +111,11 @@ impl SummaryTextGenerator {
             "No :heavy_check_mark:"
         };
 
+        let frozen_message = match &pull_request_status.frozen {
+            Some(frozen) => format!("Yes :x: (_{frozen}_)"),
+            None => "No :heavy_check_mark:".to_string(),
+        };
+
         let wip_message = if pull_request_status.wip {
             "Yes :x:"
         } else {
@@ -132,20 +156,34 @@ impl SummaryTextGenerator {
             ":speech_balloon: &mdash; **Status comment**\n\
             \n\
             > - :construction: **WIP?** {wip_message}\n\
-            > - :checkered_flag: **Checks**: {checks_message}\n\
-            > - :mag: **Code reviews**: {reviews_message}\n\
+            > - :checkered_flag: **Checks**: {checks_message}{required_checks_message}\n\
+            > - :mag: **Code reviews**: {reviews_message}{required_teams_message}{stale_approvals_message}\n\
             > - :test_tube: **QA**: {qa_message}\n\
             > - :lock: **Locked?**: {lock_message}\n\
+            > - :ice_cube: **Frozen?**: {frozen_message}\n\
             > - :twisted_rightwards_arrows: **Mergeable?**: {mergeable_message}",
             wip_message = wip_message,
             checks_message = checks_message,
+            required_checks_message = required_checks_message,
             reviews_message = code_review_section,
+            required_teams_message = required_teams_message,
+            stale_approvals_message = stale_approvals_message,
             qa_message = qa_message,
             lock_message = lock_message,
+            frozen_message = frozen_message,
             mergeable_message = mergeable_message,
         )
     }
 
+    fn checks_status_message(status: ChecksStatus) -> &'static str {
+        match status {
+            ChecksStatus::Pass => "_passed!_ :heavy_check_mark:",
+            ChecksStatus::Waiting => "_running..._ :clock2:",
+            ChecksStatus::Fail => "_failed._ :x:",
+            ChecksStatus::Skipped => "_skipped._ :heavy_check_mark:",
+        }
+    }
+
     fn generate_status_comment_config_section(automerge: bool) -> String {
         let automerge_message = if automerge {
             ":heavy_check_mark:"
@@ -176,3 +214,93 @@ impl SummaryTextGenerator {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use prbot_models::FrozenReason;
+    use time::OffsetDateTime;
+
+    use super::*;
+    use crate::use_cases::{checks::RequiredCheckStatus, status::RequiredTeamStatus};
+
+    #[test]
+    fn checks_section_with_required_checks() {
+        let section =
+            SummaryTextGenerator::generate_status_comment_checks_section(&PullRequestStatus {
+                checks_status: ChecksStatus::Waiting,
+                required_checks: vec![
+                    RequiredCheckStatus {
+                        name: "test (*)".into(),
+                        status: ChecksStatus::Pass,
+                    },
+                    RequiredCheckStatus {
+                        name: "build".into(),
+                        status: ChecksStatus::Waiting,
+                    },
+                ],
+                ..Default::default()
+            });
+
+        assert!(section.contains(
+            "> - :checkered_flag: **Checks**: _running..._ :clock2:\n\
+            >   - `test (*)`: _passed!_ :heavy_check_mark:\n\
+            >   - `build`: _running..._ :clock2:\n"
+        ));
+    }
+
+    #[test]
+    fn checks_section_with_required_teams() {
+        let section =
+            SummaryTextGenerator::generate_status_comment_checks_section(&PullRequestStatus {
+                missing_required_reviewers: vec!["org/docs".into()],
+                required_teams: vec![
+                    RequiredTeamStatus {
+                        team: "org/core".into(),
+                        approved_by: Some("member".into()),
+                    },
+                    RequiredTeamStatus {
+                        team: "org/docs".into(),
+                        approved_by: None,
+                    },
+                ],
+                ..Default::default()
+            });
+
+        assert!(section.contains(
+            "> - :mag: **Code reviews**: _waiting on mandatory reviews..._ (org/docs) :clock2:\n\
+            >   - `@org/core`: _approved by member!_ :heavy_check_mark:\n\
+            >   - `@org/docs`: _waiting..._ :clock2:\n"
+        ));
+    }
+
+    #[test]
+    fn checks_section_with_stale_approvals() {
+        let section =
+            SummaryTextGenerator::generate_status_comment_checks_section(&PullRequestStatus {
+                needed_reviewers_count: 1,
+                stale_approval_reviewers: vec!["reviewer".into()],
+                ..Default::default()
+            });
+
+        assert!(section.contains(
+            "> - :mag: **Code reviews**: _waiting..._ (0 given / 1 required) :clock2:\n\
+            >   - `reviewer`: _stale approval, new commits were pushed_ :warning:\n"
+        ));
+    }
+
+    #[test]
+    fn checks_section_frozen() {
+        let section =
+            SummaryTextGenerator::generate_status_comment_checks_section(&PullRequestStatus {
+                frozen: Some(FrozenReason::Freeze {
+                    until: OffsetDateTime::from_unix_timestamp(0).unwrap(),
+                    reason: Some("Release".into()),
+                }),
+                ..Default::default()
+            });
+
+        assert!(section.contains(
+            "> - :ice_cube: **Frozen?**: Yes :x: (_Merges are frozen until 1970-01-01 00:00 UTC (Release)_)\n"
+        ));
+    }
+}
@@ -19,6 +19,9 @@ pub enum DatabaseError {
     #[error("Unknown account '{0}'")]
     UnknownAccount(String),
 
+    #[error("Unknown merge queue entry '#{1}' for repository path '{0}'")]
+    UnknownMergeQueueEntry(String, u64),
+
     #[error("Unknown merge rule '{0}' -> '{1}'")]
     UnknownMergeRule(RuleBranch, RuleBranch),
 
@@ -34,6 +37,12 @@ pub enum DatabaseError {
     #[error("Unknown pull request rule '{0}'")]
     UnknownPullRequestRule(String),
 
+    #[error("Unknown job '{0}'")]
+    UnknownJob(u64),
+
+    #[error("Unknown webhook delivery '{0}'")]
+    UnknownWebhookDelivery(String),
+
     #[error(transparent)]
     ImplementationError {
         source: Box<dyn std::error::Error + Send + Sync + 'static>,
@@ -4,8 +4,8 @@ use std::{
 };
 
 use prbot_models::{
-    Account, ExternalAccount, ExternalAccountRight, MergeRule, PullRequest, PullRequestRule,
-    Repository, RequiredReviewer,
+    Account, AuditEntry, AwayUser, ExternalAccount, ExternalAccountRight, MergeRule, PullRequest,
+    PullRequestRule, Repository, RequiredReviewer,
 };
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +21,10 @@ struct ExchangeData {
     external_accounts: Vec<ExternalAccount>,
     external_account_rights: Vec<ExternalAccountRight>,
     pull_request_rules: Vec<PullRequestRule>,
+    #[serde(default)]
+    audit_entries: Vec<AuditEntry>,
+    #[serde(default)]
+    away_users: Vec<AwayUser>,
 }
 
 pub struct Exchanger;
@@ -39,6 +43,8 @@ impl Exchanger {
             repositories: db_service.repositories_all().await?,
             required_reviewers: db_service.required_reviewers_all().await?,
             pull_request_rules: db_service.pull_request_rules_all().await?,
+            audit_entries: db_service.audit_entries_all().await?,
+            away_users: db_service.away_users_all().await?,
         };
 
         serde_json::to_writer_pretty(writer, &data).map_err(|e| {
@@ -160,6 +166,23 @@ impl Exchanger {
             Self::create_or_update_pull_request_rule(db_service, repo, rule).await?;
         }
 
+        for entry in data.audit_entries {
+            println!(
+                "> Importing audit entry '{}' on '{}' by '{}'",
+                entry.action, entry.target, entry.actor
+            );
+
+            Self::create_audit_entry_if_missing(db_service, entry).await?;
+        }
+
+        for user in data.away_users {
+            println!("> Importing away user '{}'", user.username);
+
+            if db_service.away_users_get(&user.username).await?.is_none() {
+                db_service.away_users_create(user).await?;
+            }
+        }
+
         Ok(())
     }
 
@@ -276,4 +299,29 @@ impl Exchanger {
             None => db_service.pull_request_rules_create(rule).await,
         }
     }
+
+    async fn create_audit_entry_if_missing(
+        db_service: &dyn DbService,
+        entry: AuditEntry,
+    ) -> Result<Option<AuditEntry>> {
+        let existing = db_service
+            .audit_entries_list(
+                entry.repository_path.as_deref(),
+                Some(&entry.actor),
+                Some(entry.created_at),
+            )
+            .await?;
+
+        // Audit entries are append-only, only import unknown ones
+        if existing.iter().any(|e| {
+            AuditEntry {
+                id: e.id,
+                ..entry.clone()
+            } == *e
+        }) {
+            Ok(None)
+        } else {
+            db_service.audit_entries_create(entry).await.map(Some)
+        }
+    }
 }
@@ -1,8 +1,12 @@
 use async_trait::async_trait;
 use prbot_models::{
-    Account, ExternalAccount, ExternalAccountRight, MergeRule, MergeStrategy, PullRequest,
-    PullRequestRule, QaStatus, Repository, RequiredReviewer, RuleBranch,
+    Account, AuditEntry, AwayUser, ExternalAccount, ExternalAccountRight, Job, JobStatus,
+    MergeFreeze, MergeQueueEntry, MergeRule, MergeStrategy, MergeWindow, PullRequest,
+    PullRequestEvent, PullRequestRule, QaStatus, Repository, RequiredReviewer,
+    ReviewerAssignmentStrategy, RuleBranch, StaleApprovalPolicy, StepTransition, WebhookDelivery,
+    WebhookDeliveryOutcome,
 };
+use time::OffsetDateTime;
 
 use crate::{DatabaseError, Result};
 
@@ -20,6 +24,21 @@ pub trait DbService: Send + Sync {
     async fn accounts_delete(&self, username: &str) -> Result<bool>;
     async fn accounts_list_admins(&self) -> Result<Vec<Account>>;
     async fn accounts_set_is_admin(&self, username: &str, value: bool) -> Result<Account>;
+    async fn audit_entries_create(&self, instance: AuditEntry) -> Result<AuditEntry>;
+    /// List audit entries, oldest first, optionally filtered by repository, actor and date.
+    async fn audit_entries_list(
+        &self,
+        repository_path: Option<&str>,
+        actor: Option<&str>,
+        since: Option<OffsetDateTime>,
+    ) -> Result<Vec<AuditEntry>>;
+    async fn audit_entries_all(&self) -> Result<Vec<AuditEntry>> {
+        self.audit_entries_list(None, None, None).await
+    }
+    async fn away_users_create(&self, instance: AwayUser) -> Result<AwayUser>;
+    async fn away_users_get(&self, username: &str) -> Result<Option<AwayUser>>;
+    async fn away_users_delete(&self, username: &str) -> Result<bool>;
+    async fn away_users_all(&self) -> Result<Vec<AwayUser>>;
     async fn external_account_rights_create(
         &self,
         instance: ExternalAccountRight,
@@ -59,6 +78,78 @@ pub trait DbService: Send + Sync {
         private_key: &str,
     ) -> Result<ExternalAccount>;
     async fn health_check(&self) -> Result<()>;
+    /// Create a job, or get the existing job of the same webhook delivery.
+    async fn jobs_create(&self, instance: Job) -> Result<Job>;
+    async fn jobs_update(&self, instance: Job) -> Result<Job>;
+    async fn jobs_get(&self, id: u64) -> Result<Option<Job>>;
+    async fn jobs_get_expect(&self, id: u64) -> Result<Job> {
+        self.jobs_get(id)
+            .await?
+            .ok_or(DatabaseError::UnknownJob(id))
+    }
+    async fn jobs_list(&self, status: Option<JobStatus>) -> Result<Vec<Job>>;
+    async fn jobs_claim_next(
+        &self,
+        now: OffsetDateTime,
+        worker_id: &str,
+        locked_until: OffsetDateTime,
+    ) -> Result<Option<Job>>;
+    async fn jobs_renew_lease(
+        &self,
+        id: u64,
+        worker_id: &str,
+        locked_until: OffsetDateTime,
+    ) -> Result<bool>;
+    async fn jobs_requeue_expired(&self, now: OffsetDateTime) -> Result<u64>;
+    /// Store the outcome of a running job, if it is still leased by the worker.
+    ///
+    /// Returns `None` when the lease was lost, e.g. expired and claimed again.
+    async fn jobs_finish(&self, instance: Job, worker_id: &str) -> Result<Option<Job>>;
+    /// Cancel a job if it is still pending, and return whether it was cancelled.
+    async fn jobs_cancel(&self, id: u64) -> Result<bool>;
+    async fn merge_freezes_set(&self, instance: MergeFreeze) -> Result<MergeFreeze>;
+    async fn merge_freezes_list(&self, owner: &str, name: &str) -> Result<Vec<MergeFreeze>>;
+    async fn merge_freezes_delete(
+        &self,
+        owner: &str,
+        name: &str,
+        base_branch: RuleBranch,
+    ) -> Result<bool>;
+    async fn merge_queue_entries_create(
+        &self,
+        instance: MergeQueueEntry,
+    ) -> Result<MergeQueueEntry>;
+    async fn merge_queue_entries_update(
+        &self,
+        instance: MergeQueueEntry,
+    ) -> Result<MergeQueueEntry>;
+    async fn merge_queue_entries_get(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Option<MergeQueueEntry>>;
+    async fn merge_queue_entries_get_expect(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<MergeQueueEntry> {
+        self.merge_queue_entries_get(owner, name, number)
+            .await?
+            .ok_or_else(|| DatabaseError::UnknownMergeQueueEntry(format!("{owner}/{name}"), number))
+    }
+    async fn merge_queue_entries_list(
+        &self,
+        owner: &str,
+        name: &str,
+    ) -> Result<Vec<MergeQueueEntry>>;
+    async fn merge_queue_entries_delete(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<bool>;
     async fn merge_rules_create(&self, instance: MergeRule) -> Result<MergeRule>;
     async fn merge_rules_update(&self, instance: MergeRule) -> Result<MergeRule>;
     async fn merge_rules_get(
@@ -88,6 +179,9 @@ pub trait DbService: Send + Sync {
     ) -> Result<bool>;
     async fn merge_rules_all(&self) -> Result<Vec<MergeRule>>;
     async fn merge_rules_list(&self, owner: &str, name: &str) -> Result<Vec<MergeRule>>;
+    async fn merge_windows_create(&self, instance: MergeWindow) -> Result<MergeWindow>;
+    async fn merge_windows_list(&self, owner: &str, name: &str) -> Result<Vec<MergeWindow>>;
+    async fn merge_windows_delete(&self, owner: &str, name: &str, id: u64) -> Result<bool>;
     async fn pull_requests_create(&self, instance: PullRequest) -> Result<PullRequest>;
     async fn pull_requests_update(&self, instance: PullRequest) -> Result<PullRequest>;
     async fn pull_requests_get(
@@ -164,6 +258,40 @@ pub trait DbService: Send + Sync {
         number: u64,
         strategy: Option<MergeStrategy>,
     ) -> Result<PullRequest>;
+    async fn pull_requests_set_snoozed_until(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        value: Option<OffsetDateTime>,
+    ) -> Result<PullRequest>;
+    async fn pull_requests_set_stale_reminded_at(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        value: Option<OffsetDateTime>,
+    ) -> Result<PullRequest>;
+    async fn pull_request_events_create(
+        &self,
+        instance: PullRequestEvent,
+    ) -> Result<PullRequestEvent>;
+    /// List timeline events of a pull request, oldest first.
+    async fn pull_request_events_list(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<PullRequestEvent>>;
+    /// Count open pull requests waiting for a review from each user, ignoring one pull request.
+    ///
+    /// Assigned and required reviewers who did not review yet are counted, by username.
+    async fn pull_request_events_pending_reviews(
+        &self,
+        owner: &str,
+        name: &str,
+        excluded_number: u64,
+    ) -> Result<Vec<(String, u64)>>;
     async fn pull_request_rules_all(&self) -> Result<Vec<PullRequestRule>>;
     async fn pull_request_rules_list(
         &self,
@@ -252,6 +380,71 @@ pub trait DbService: Send + Sync {
         name: &str,
         value: bool,
     ) -> Result<Repository>;
+    async fn repositories_set_ci_app_slugs(
+        &self,
+        owner: &str,
+        name: &str,
+        values: &[String],
+    ) -> Result<Repository>;
+    async fn repositories_set_required_checks(
+        &self,
+        owner: &str,
+        name: &str,
+        values: &[String],
+    ) -> Result<Repository>;
+    async fn repositories_set_optional_checks(
+        &self,
+        owner: &str,
+        name: &str,
+        values: &[String],
+    ) -> Result<Repository>;
+    async fn repositories_set_native_merge_queue(
+        &self,
+        owner: &str,
+        name: &str,
+        value: bool,
+    ) -> Result<Repository>;
+    async fn repositories_set_merge_commit_settings(
+        &self,
+        owner: &str,
+        name: &str,
+        title_template: &str,
+        message_template: &str,
+        conventional_commits: bool,
+    ) -> Result<Repository>;
+    async fn repositories_set_stale_settings(
+        &self,
+        owner: &str,
+        name: &str,
+        threshold_days: u64,
+        convert_to_draft: bool,
+    ) -> Result<Repository>;
+    async fn repositories_set_codeowners_reviewers(
+        &self,
+        owner: &str,
+        name: &str,
+        value: bool,
+    ) -> Result<Repository>;
+    async fn repositories_set_reviewer_pool(
+        &self,
+        owner: &str,
+        name: &str,
+        reviewers: &[String],
+        count: u64,
+        strategy: ReviewerAssignmentStrategy,
+    ) -> Result<Repository>;
+    async fn repositories_set_reviewer_pool_cursor(
+        &self,
+        owner: &str,
+        name: &str,
+        value: u64,
+    ) -> Result<Repository>;
+    async fn repositories_set_stale_approval_policy(
+        &self,
+        owner: &str,
+        name: &str,
+        value: StaleApprovalPolicy,
+    ) -> Result<Repository>;
     async fn required_reviewers_create(
         &self,
         instance: RequiredReviewer,
@@ -277,4 +470,27 @@ pub trait DbService: Send + Sync {
         username: &str,
     ) -> Result<bool>;
     async fn required_reviewers_all(&self) -> Result<Vec<RequiredReviewer>>;
+    async fn step_transitions_create(&self, instance: StepTransition) -> Result<StepTransition>;
+    /// List step transitions of a pull request, oldest first.
+    async fn step_transitions_list(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<StepTransition>>;
+    async fn webhook_deliveries_create(&self, instance: WebhookDelivery)
+        -> Result<WebhookDelivery>;
+    async fn webhook_deliveries_get(&self, delivery_id: &str) -> Result<Option<WebhookDelivery>>;
+    async fn webhook_deliveries_get_expect(&self, delivery_id: &str) -> Result<WebhookDelivery> {
+        self.webhook_deliveries_get(delivery_id)
+            .await?
+            .ok_or_else(|| DatabaseError::UnknownWebhookDelivery(delivery_id.into()))
+    }
+    async fn webhook_deliveries_set_outcome(
+        &self,
+        delivery_id: &str,
+        outcome: WebhookDeliveryOutcome,
+        error: Option<String>,
+    ) -> Result<WebhookDelivery>;
+    async fn webhook_deliveries_purge(&self, received_before: OffsetDateTime) -> Result<u64>;
 }
@@ -1,22 +1,38 @@
-use std::{collections::HashMap, sync::RwLock};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::RwLock,
+};
 
 use async_trait::async_trait;
 use prbot_database_interface::{DbService, Result};
 use prbot_models::{
-    Account, ExternalAccount, ExternalAccountRight, MergeRule, MergeStrategy, PullRequest,
-    PullRequestRule, QaStatus, Repository, RequiredReviewer, RuleBranch,
+    Account, AuditEntry, AwayUser, ExternalAccount, ExternalAccountRight, Job, JobStatus,
+    MergeFreeze, MergeQueueEntry, MergeRule, MergeStrategy, MergeWindow, PullRequest,
+    PullRequestEvent, PullRequestRule, PullRequestTimeline, QaStatus, Repository, RequiredReviewer,
+    ReviewerAssignmentStrategy, RuleBranch, StaleApprovalPolicy, StepTransition, WebhookDelivery,
+    WebhookDeliveryOutcome,
 };
+use time::OffsetDateTime;
 
 #[derive(Debug, Default)]
 pub struct MemoryDb {
     repositories: RwLock<HashMap<u64, Repository>>,
     pull_requests: RwLock<HashMap<u64, PullRequest>>,
     accounts: RwLock<HashMap<String, Account>>,
+    audit_entries: RwLock<HashMap<u64, AuditEntry>>,
+    away_users: RwLock<HashMap<String, AwayUser>>,
     external_accounts: RwLock<HashMap<String, ExternalAccount>>,
     external_account_rights: RwLock<HashMap<(String, u64), ExternalAccountRight>>,
     merge_rules: RwLock<HashMap<(u64, RuleBranch, RuleBranch), MergeRule>>,
     required_reviewers: RwLock<HashMap<(String, u64), RequiredReviewer>>,
     pull_request_rules: RwLock<HashMap<(u64, String), PullRequestRule>>,
+    webhook_deliveries: RwLock<HashMap<String, WebhookDelivery>>,
+    jobs: RwLock<HashMap<u64, Job>>,
+    merge_queue_entries: RwLock<HashMap<u64, MergeQueueEntry>>,
+    merge_freezes: RwLock<HashMap<(u64, RuleBranch), MergeFreeze>>,
+    merge_windows: RwLock<HashMap<u64, MergeWindow>>,
+    step_transitions: RwLock<HashMap<u64, StepTransition>>,
+    pull_request_events: RwLock<HashMap<u64, PullRequestEvent>>,
 }
 
 impl MemoryDb {
@@ -45,6 +61,76 @@ impl MemoryDb {
             .unwrap_or(0)
             + 1
     }
+
+    fn get_last_audit_entry_id(&self) -> u64 {
+        self.audit_entries
+            .read()
+            .unwrap()
+            .keys()
+            .max()
+            .copied()
+            .unwrap_or(0)
+            + 1
+    }
+
+    fn get_last_job_id(&self) -> u64 {
+        self.jobs.read().unwrap().keys().max().copied().unwrap_or(0) + 1
+    }
+
+    fn get_last_merge_queue_entry_id(&self) -> u64 {
+        self.merge_queue_entries
+            .read()
+            .unwrap()
+            .keys()
+            .max()
+            .copied()
+            .unwrap_or(0)
+            + 1
+    }
+
+    fn get_last_pull_request_event_id(&self) -> u64 {
+        self.pull_request_events
+            .read()
+            .unwrap()
+            .keys()
+            .max()
+            .copied()
+            .unwrap_or(0)
+            + 1
+    }
+
+    fn get_last_step_transition_id(&self) -> u64 {
+        self.step_transitions
+            .read()
+            .unwrap()
+            .keys()
+            .max()
+            .copied()
+            .unwrap_or(0)
+            + 1
+    }
+
+    fn get_last_merge_window_id(&self) -> u64 {
+        self.merge_windows
+            .read()
+            .unwrap()
+            .keys()
+            .max()
+            .copied()
+            .unwrap_or(0)
+            + 1
+    }
+
+    fn get_last_webhook_delivery_id(&self) -> u64 {
+        self.webhook_deliveries
+            .read()
+            .unwrap()
+            .values()
+            .map(|d| d.id)
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
 }
 
 #[async_trait]
@@ -107,6 +193,63 @@ impl DbService for MemoryDb {
         Ok(account)
     }
 
+    ////////////////
+    // Audit entries
+
+    async fn audit_entries_create(&self, mut instance: AuditEntry) -> Result<AuditEntry> {
+        instance.id = self.get_last_audit_entry_id();
+        self.audit_entries
+            .write()
+            .unwrap()
+            .insert(instance.id, instance.clone());
+        Ok(instance)
+    }
+
+    async fn audit_entries_list(
+        &self,
+        repository_path: Option<&str>,
+        actor: Option<&str>,
+        since: Option<OffsetDateTime>,
+    ) -> Result<Vec<AuditEntry>> {
+        let mut entries: Vec<_> = self
+            .audit_entries
+            .read()
+            .unwrap()
+            .values()
+            .filter(|e| repository_path.map_or(true, |p| e.repository_path.as_deref() == Some(p)))
+            .filter(|e| actor.map_or(true, |a| e.actor == a))
+            .filter(|e| since.map_or(true, |s| e.created_at >= s))
+            .cloned()
+            .collect();
+        entries.sort_by_key(|e| (e.created_at, e.id));
+        Ok(entries)
+    }
+
+    /////////////
+    // Away users
+
+    async fn away_users_create(&self, instance: AwayUser) -> Result<AwayUser> {
+        self.away_users
+            .write()
+            .unwrap()
+            .insert(instance.username.clone(), instance.clone());
+        Ok(instance)
+    }
+
+    async fn away_users_get(&self, username: &str) -> Result<Option<AwayUser>> {
+        Ok(self.away_users.read().unwrap().get(username).cloned())
+    }
+
+    async fn away_users_delete(&self, username: &str) -> Result<bool> {
+        Ok(self.away_users.write().unwrap().remove(username).is_some())
+    }
+
+    async fn away_users_all(&self) -> Result<Vec<AwayUser>> {
+        let mut users: Vec<_> = self.away_users.read().unwrap().values().cloned().collect();
+        users.sort_by(|a, b| a.username.cmp(&b.username));
+        Ok(users)
+    }
+
     //////////////////////////
     // External account rights
 
@@ -302,6 +445,274 @@ impl DbService for MemoryDb {
         Ok(())
     }
 
+    ///////
+    // Jobs
+
+    async fn jobs_create(&self, mut instance: Job) -> Result<Job> {
+        if let Some(delivery_id) = &instance.delivery_id {
+            let existing = self
+                .jobs
+                .read()
+                .unwrap()
+                .values()
+                .find(|j| j.delivery_id.as_ref() == Some(delivery_id))
+                .cloned();
+            if let Some(job) = existing {
+                return Ok(job);
+            }
+        }
+
+        instance.id = self.get_last_job_id();
+        self.jobs
+            .write()
+            .unwrap()
+            .insert(instance.id, instance.clone());
+        Ok(instance)
+    }
+
+    async fn jobs_update(&self, instance: Job) -> Result<Job> {
+        self.jobs_get_expect(instance.id).await?;
+        self.jobs
+            .write()
+            .unwrap()
+            .insert(instance.id, instance.clone());
+        Ok(instance)
+    }
+
+    async fn jobs_get(&self, id: u64) -> Result<Option<Job>> {
+        Ok(self.jobs.read().unwrap().get(&id).cloned())
+    }
+
+    async fn jobs_list(&self, status: Option<JobStatus>) -> Result<Vec<Job>> {
+        let mut jobs: Vec<_> = self
+            .jobs
+            .read()
+            .unwrap()
+            .values()
+            .filter(|j| status.map(|s| j.status == s).unwrap_or(true))
+            .cloned()
+            .collect();
+        jobs.sort_by_key(|j| j.id);
+        Ok(jobs)
+    }
+
+    async fn jobs_claim_next(
+        &self,
+        now: OffsetDateTime,
+        worker_id: &str,
+        locked_until: OffsetDateTime,
+    ) -> Result<Option<Job>> {
+        let mut jobs = self.jobs.write().unwrap();
+        let job = jobs
+            .values_mut()
+            .filter(|j| j.status == JobStatus::Pending && j.run_at <= now)
+            .min_by_key(|j| (j.run_at, j.id));
+
+        Ok(job.map(|j| {
+            j.status = JobStatus::Running;
+            j.attempts += 1;
+            j.locked_by = Some(worker_id.into());
+            j.locked_until = Some(locked_until);
+            j.clone()
+        }))
+    }
+
+    async fn jobs_renew_lease(
+        &self,
+        id: u64,
+        worker_id: &str,
+        locked_until: OffsetDateTime,
+    ) -> Result<bool> {
+        let mut jobs = self.jobs.write().unwrap();
+        match jobs.get_mut(&id) {
+            Some(job)
+                if job.status == JobStatus::Running
+                    && job.locked_by.as_deref() == Some(worker_id) =>
+            {
+                job.locked_until = Some(locked_until);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn jobs_requeue_expired(&self, now: OffsetDateTime) -> Result<u64> {
+        let mut count = 0;
+        for job in self.jobs.write().unwrap().values_mut() {
+            if job.status == JobStatus::Running
+                && job.locked_until.map(|until| until <= now).unwrap_or(true)
+            {
+                job.status = JobStatus::Pending;
+                job.locked_by = None;
+                job.locked_until = None;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    async fn jobs_finish(&self, instance: Job, worker_id: &str) -> Result<Option<Job>> {
+        let mut jobs = self.jobs.write().unwrap();
+        match jobs.get_mut(&instance.id) {
+            Some(job)
+                if job.status == JobStatus::Running
+                    && job.locked_by.as_deref() == Some(worker_id) =>
+            {
+                *job = instance.clone();
+                Ok(Some(instance))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    async fn jobs_cancel(&self, id: u64) -> Result<bool> {
+        let mut jobs = self.jobs.write().unwrap();
+        match jobs.get_mut(&id) {
+            Some(job) if job.status == JobStatus::Pending => {
+                job.status = JobStatus::Cancelled;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    ////////////////
+    // Merge freezes
+
+    async fn merge_freezes_set(&self, instance: MergeFreeze) -> Result<MergeFreeze> {
+        self.repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+
+        self.merge_freezes.write().unwrap().insert(
+            (instance.repository_id, instance.base_branch.clone()),
+            instance.clone(),
+        );
+        Ok(instance)
+    }
+
+    async fn merge_freezes_list(&self, owner: &str, name: &str) -> Result<Vec<MergeFreeze>> {
+        if let Some(repo) = self.repositories_get(owner, name).await? {
+            let mut values: Vec<_> = self
+                .merge_freezes
+                .read()
+                .unwrap()
+                .values()
+                .filter(|f| f.repository_id == repo.id)
+                .cloned()
+                .collect();
+            values.sort_by(|a, b| a.base_branch.cmp(&b.base_branch));
+
+            Ok(values)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn merge_freezes_delete(
+        &self,
+        owner: &str,
+        name: &str,
+        base_branch: RuleBranch,
+    ) -> Result<bool> {
+        if let Some(repo) = self.repositories_get(owner, name).await? {
+            Ok(self
+                .merge_freezes
+                .write()
+                .unwrap()
+                .remove(&(repo.id, base_branch))
+                .is_some())
+        } else {
+            Ok(false)
+        }
+    }
+
+    //////////////////////
+    // Merge queue entries
+
+    async fn merge_queue_entries_create(
+        &self,
+        mut instance: MergeQueueEntry,
+    ) -> Result<MergeQueueEntry> {
+        self.repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+        instance.id = self.get_last_merge_queue_entry_id();
+        self.merge_queue_entries
+            .write()
+            .unwrap()
+            .insert(instance.id, instance.clone());
+        Ok(instance)
+    }
+
+    async fn merge_queue_entries_update(
+        &self,
+        instance: MergeQueueEntry,
+    ) -> Result<MergeQueueEntry> {
+        let repository = self
+            .repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+        self.merge_queue_entries_get_expect(
+            &repository.owner,
+            &repository.name,
+            instance.pull_request_number,
+        )
+        .await?;
+        self.merge_queue_entries
+            .write()
+            .unwrap()
+            .insert(instance.id, instance.clone());
+        Ok(instance)
+    }
+
+    async fn merge_queue_entries_get(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Option<MergeQueueEntry>> {
+        Ok(self
+            .merge_queue_entries_list(owner, name)
+            .await?
+            .into_iter()
+            .find(|e| e.pull_request_number == number))
+    }
+
+    async fn merge_queue_entries_list(
+        &self,
+        owner: &str,
+        name: &str,
+    ) -> Result<Vec<MergeQueueEntry>> {
+        let repository = match self.repositories_get(owner, name).await? {
+            Some(r) => r,
+            None => return Ok(vec![]),
+        };
+
+        let mut entries: Vec<_> = self
+            .merge_queue_entries
+            .read()
+            .unwrap()
+            .values()
+            .filter(|e| e.repository_id == repository.id)
+            .cloned()
+            .collect();
+        entries.sort_by_key(|e| (e.queued_at, e.id));
+        Ok(entries)
+    }
+
+    async fn merge_queue_entries_delete(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<bool> {
+        match self.merge_queue_entries_get(owner, name, number).await? {
+            Some(entry) => {
+                self.merge_queue_entries.write().unwrap().remove(&entry.id);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     //////////////
     // Merge rules
 
@@ -420,6 +831,51 @@ impl DbService for MemoryDb {
         }
     }
 
+    ////////////////
+    // Merge windows
+
+    async fn merge_windows_create(&self, mut instance: MergeWindow) -> Result<MergeWindow> {
+        self.repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+
+        instance.id = self.get_last_merge_window_id();
+        self.merge_windows
+            .write()
+            .unwrap()
+            .insert(instance.id, instance.clone());
+        Ok(instance)
+    }
+
+    async fn merge_windows_list(&self, owner: &str, name: &str) -> Result<Vec<MergeWindow>> {
+        if let Some(repo) = self.repositories_get(owner, name).await? {
+            let mut values: Vec<_> = self
+                .merge_windows
+                .read()
+                .unwrap()
+                .values()
+                .filter(|w| w.repository_id == repo.id)
+                .cloned()
+                .collect();
+            values.sort_by_key(|w| w.id);
+
+            Ok(values)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn merge_windows_delete(&self, owner: &str, name: &str, id: u64) -> Result<bool> {
+        if let Some(repo) = self.repositories_get(owner, name).await? {
+            let mut windows = self.merge_windows.write().unwrap();
+            if windows.get(&id).map(|w| w.repository_id) == Some(repo.id) {
+                windows.remove(&id);
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     ////////////////
     // Pull requests
 
@@ -489,6 +945,14 @@ impl DbService for MemoryDb {
                 self.required_reviewers_delete(owner, name, number, &r.username)
                     .await?;
             }
+            self.step_transitions
+                .write()
+                .unwrap()
+                .retain(|_, t| t.pull_request_id != pr.id);
+            self.pull_request_events
+                .write()
+                .unwrap()
+                .retain(|_, e| e.pull_request_id != pr.id);
 
             self.pull_requests.write().unwrap().remove(&pr.id);
             Ok(true)
@@ -628,6 +1092,40 @@ impl DbService for MemoryDb {
         Ok(pr)
     }
 
+    async fn pull_requests_set_snoozed_until(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        value: Option<OffsetDateTime>,
+    ) -> Result<PullRequest> {
+        self.repositories_get_expect(owner, name).await?;
+        let mut pr = self.pull_requests_get_expect(owner, name, number).await?;
+        pr.snoozed_until = value;
+        self.pull_requests
+            .write()
+            .unwrap()
+            .insert(pr.id, pr.clone());
+        Ok(pr)
+    }
+
+    async fn pull_requests_set_stale_reminded_at(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        value: Option<OffsetDateTime>,
+    ) -> Result<PullRequest> {
+        self.repositories_get_expect(owner, name).await?;
+        let mut pr = self.pull_requests_get_expect(owner, name, number).await?;
+        pr.stale_reminded_at = value;
+        self.pull_requests
+            .write()
+            .unwrap()
+            .insert(pr.id, pr.clone());
+        Ok(pr)
+    }
+
     async fn pull_requests_set_strategy_override(
         &self,
         owner: &str,
@@ -645,6 +1143,93 @@ impl DbService for MemoryDb {
         Ok(pr)
     }
 
+    //////////////////////
+    // Pull request events
+
+    async fn pull_request_events_create(
+        &self,
+        mut instance: PullRequestEvent,
+    ) -> Result<PullRequestEvent> {
+        self.pull_requests_get_from_id_expect(instance.pull_request_id)
+            .await?;
+
+        instance.id = self.get_last_pull_request_event_id();
+        self.pull_request_events
+            .write()
+            .unwrap()
+            .insert(instance.id, instance.clone());
+        Ok(instance)
+    }
+
+    async fn pull_request_events_list(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<PullRequestEvent>> {
+        if let Some(pr) = self.pull_requests_get(owner, name, number).await? {
+            let mut values: Vec<_> = self
+                .pull_request_events
+                .read()
+                .unwrap()
+                .values()
+                .filter(|e| e.pull_request_id == pr.id)
+                .cloned()
+                .collect();
+            values.sort_by_key(|e| (e.created_at, e.id));
+            Ok(values)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn pull_request_events_pending_reviews(
+        &self,
+        owner: &str,
+        name: &str,
+        excluded_number: u64,
+    ) -> Result<Vec<(String, u64)>> {
+        let mut loads: HashMap<String, u64> = HashMap::new();
+
+        for pull_request in self.pull_requests_list(owner, name).await? {
+            if pull_request.number == excluded_number {
+                continue;
+            }
+
+            let timeline = PullRequestTimeline {
+                transitions: vec![],
+                events: self
+                    .pull_request_events_list(owner, name, pull_request.number)
+                    .await?,
+            };
+            if !timeline.is_open() {
+                continue;
+            }
+
+            let mut reviewers: HashSet<String> = timeline
+                .assigned_reviewers()
+                .into_iter()
+                .map(Into::into)
+                .collect();
+            reviewers.extend(
+                self.required_reviewers_list(owner, name, pull_request.number)
+                    .await?
+                    .into_iter()
+                    .map(|r| r.username),
+            );
+
+            for reviewer in reviewers {
+                if !timeline.reviewed_by(&reviewer) {
+                    *loads.entry(reviewer).or_default() += 1;
+                }
+            }
+        }
+
+        let mut values: Vec<_> = loads.into_iter().collect();
+        values.sort();
+        Ok(values)
+    }
+
     /////////////////////
     // Pull request rules
 
@@ -845,6 +1430,15 @@ impl DbService for MemoryDb {
                     .await?;
             }
 
+            self.merge_freezes
+                .write()
+                .unwrap()
+                .retain(|(repository_id, _), _| *repository_id != v.id);
+            self.merge_windows
+                .write()
+                .unwrap()
+                .retain(|_, w| w.repository_id != v.id);
+
             self.repositories.write().unwrap().remove(&v.id);
             Ok(true)
         } else {
@@ -957,6 +1551,166 @@ impl DbService for MemoryDb {
         Ok(repository)
     }
 
+    async fn repositories_set_ci_app_slugs(
+        &self,
+        owner: &str,
+        name: &str,
+        values: &[String],
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.ci_app_slugs = values.to_vec();
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
+    async fn repositories_set_required_checks(
+        &self,
+        owner: &str,
+        name: &str,
+        values: &[String],
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.required_checks = values.to_vec();
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
+    async fn repositories_set_optional_checks(
+        &self,
+        owner: &str,
+        name: &str,
+        values: &[String],
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.optional_checks = values.to_vec();
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
+    async fn repositories_set_native_merge_queue(
+        &self,
+        owner: &str,
+        name: &str,
+        value: bool,
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.native_merge_queue = value;
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
+    async fn repositories_set_merge_commit_settings(
+        &self,
+        owner: &str,
+        name: &str,
+        title_template: &str,
+        message_template: &str,
+        conventional_commits: bool,
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.merge_commit_title_template = title_template.into();
+        repository.merge_commit_message_template = message_template.into();
+        repository.conventional_commits = conventional_commits;
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
+    async fn repositories_set_stale_settings(
+        &self,
+        owner: &str,
+        name: &str,
+        threshold_days: u64,
+        convert_to_draft: bool,
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.stale_threshold_days = threshold_days;
+        repository.stale_convert_to_draft = convert_to_draft;
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
+    async fn repositories_set_codeowners_reviewers(
+        &self,
+        owner: &str,
+        name: &str,
+        value: bool,
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.codeowners_reviewers = value;
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
+    async fn repositories_set_reviewer_pool(
+        &self,
+        owner: &str,
+        name: &str,
+        reviewers: &[String],
+        count: u64,
+        strategy: ReviewerAssignmentStrategy,
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.reviewer_pool = reviewers.to_vec();
+        repository.reviewer_pool_count = count;
+        repository.reviewer_assignment_strategy = strategy;
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
+    async fn repositories_set_reviewer_pool_cursor(
+        &self,
+        owner: &str,
+        name: &str,
+        value: u64,
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.reviewer_pool_cursor = value;
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
+    async fn repositories_set_stale_approval_policy(
+        &self,
+        owner: &str,
+        name: &str,
+        value: StaleApprovalPolicy,
+    ) -> Result<Repository> {
+        let mut repository = self.repositories_get_expect(owner, name).await?;
+        repository.stale_approval_policy = value;
+        self.repositories
+            .write()
+            .unwrap()
+            .insert(repository.id, repository.clone());
+        Ok(repository)
+    }
+
     /////////////////////
     // Required reviewers
 
@@ -1052,4 +1806,91 @@ impl DbService for MemoryDb {
         });
         Ok(values)
     }
+
+    /////////////////////
+    // Step transitions
+
+    async fn step_transitions_create(
+        &self,
+        mut instance: StepTransition,
+    ) -> Result<StepTransition> {
+        self.pull_requests_get_from_id_expect(instance.pull_request_id)
+            .await?;
+
+        instance.id = self.get_last_step_transition_id();
+        self.step_transitions
+            .write()
+            .unwrap()
+            .insert(instance.id, instance.clone());
+        Ok(instance)
+    }
+
+    async fn step_transitions_list(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<StepTransition>> {
+        if let Some(pr) = self.pull_requests_get(owner, name, number).await? {
+            let mut values: Vec<_> = self
+                .step_transitions
+                .read()
+                .unwrap()
+                .values()
+                .filter(|t| t.pull_request_id == pr.id)
+                .cloned()
+                .collect();
+            values.sort_by_key(|t| (t.created_at, t.id));
+            Ok(values)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /////////////////////
+    // Webhook deliveries
+
+    async fn webhook_deliveries_create(
+        &self,
+        mut instance: WebhookDelivery,
+    ) -> Result<WebhookDelivery> {
+        instance.id = self.get_last_webhook_delivery_id();
+        self.webhook_deliveries
+            .write()
+            .unwrap()
+            .insert(instance.delivery_id.clone(), instance.clone());
+        Ok(instance)
+    }
+
+    async fn webhook_deliveries_get(&self, delivery_id: &str) -> Result<Option<WebhookDelivery>> {
+        Ok(self
+            .webhook_deliveries
+            .read()
+            .unwrap()
+            .get(delivery_id)
+            .cloned())
+    }
+
+    async fn webhook_deliveries_set_outcome(
+        &self,
+        delivery_id: &str,
+        outcome: WebhookDeliveryOutcome,
+        error: Option<String>,
+    ) -> Result<WebhookDelivery> {
+        let mut delivery = self.webhook_deliveries_get_expect(delivery_id).await?;
+        delivery.outcome = outcome;
+        delivery.error = error;
+        self.webhook_deliveries
+            .write()
+            .unwrap()
+            .insert(delivery.delivery_id.clone(), delivery.clone());
+        Ok(delivery)
+    }
+
+    async fn webhook_deliveries_purge(&self, received_before: OffsetDateTime) -> Result<u64> {
+        let mut deliveries = self.webhook_deliveries.write().unwrap();
+        let count = deliveries.len();
+        deliveries.retain(|_, d| d.received_at >= received_before);
+        Ok((count - deliveries.len()) as u64)
+    }
 }
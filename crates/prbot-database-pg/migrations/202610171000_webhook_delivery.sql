@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS webhook_delivery (
+    id serial NOT NULL,
+    delivery_id varchar(255) NOT NULL,
+    event_type varchar(255) NOT NULL,
+    headers text NOT NULL,
+    body text NOT NULL,
+    outcome varchar(255) NOT NULL,
+    error text NULL,
+    received_at timestamptz NOT NULL,
+
+    CONSTRAINT webhook_delivery_pkey PRIMARY KEY (id),
+    CONSTRAINT webhook_delivery_delivery_id_key UNIQUE (delivery_id)
+);
+
+CREATE INDEX IF NOT EXISTS webhook_delivery_received_at_idx ON webhook_delivery (received_at);
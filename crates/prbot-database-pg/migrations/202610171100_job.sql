@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS job (
+    id serial NOT NULL,
+    kind varchar(255) NOT NULL,
+    payload text NOT NULL,
+    status varchar(255) NOT NULL,
+    attempts int NOT NULL,
+    max_attempts int NOT NULL,
+    last_error text NULL,
+    delivery_id varchar(255) NULL,
+    run_at timestamptz NOT NULL,
+    created_at timestamptz NOT NULL,
+
+    CONSTRAINT job_pkey PRIMARY KEY (id)
+);
+
+CREATE INDEX IF NOT EXISTS job_status_run_at_idx ON job (status, run_at);
@@ -0,0 +1,3 @@
+ALTER TABLE repository ADD COLUMN ci_app_slugs text[] NOT NULL DEFAULT '{github-actions}';
+ALTER TABLE repository ADD COLUMN required_checks text[] NOT NULL DEFAULT '{}';
+ALTER TABLE repository ADD COLUMN optional_checks text[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS merge_queue_entry (
+    id serial NOT NULL,
+    repository_id int4 NOT NULL,
+    pull_request_number int4 NOT NULL,
+    base_branch varchar(255) NOT NULL,
+    status varchar(255) NOT NULL,
+    staging_sha varchar(255) NULL,
+    queued_at timestamptz NOT NULL,
+
+    CONSTRAINT merge_queue_entry_pkey PRIMARY KEY (id),
+    CONSTRAINT merge_queue_entry_repository_id_pull_request_number_key UNIQUE (repository_id, pull_request_number),
+    CONSTRAINT merge_queue_entry_repository_id_fkey FOREIGN KEY (repository_id) REFERENCES repository(id) ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+ALTER TABLE repository ADD COLUMN native_merge_queue boolean NOT NULL DEFAULT false;
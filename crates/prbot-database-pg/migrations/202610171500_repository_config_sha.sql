@@ -0,0 +1 @@
+ALTER TABLE repository ADD COLUMN config_sha varchar(255) NOT NULL DEFAULT '';
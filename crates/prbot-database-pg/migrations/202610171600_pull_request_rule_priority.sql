@@ -0,0 +1 @@
+ALTER TABLE pull_request_rule ADD COLUMN priority integer NOT NULL DEFAULT 0;
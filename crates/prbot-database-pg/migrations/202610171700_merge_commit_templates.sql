@@ -0,0 +1,5 @@
+ALTER TABLE repository ADD COLUMN merge_commit_title_template text NOT NULL DEFAULT '{title} (#{number})';
+ALTER TABLE repository ADD COLUMN merge_commit_message_template text NOT NULL DEFAULT '';
+ALTER TABLE repository ADD COLUMN conventional_commits boolean NOT NULL DEFAULT false;
+ALTER TABLE merge_rule ADD COLUMN commit_title_template text;
+ALTER TABLE merge_rule ADD COLUMN commit_message_template text;
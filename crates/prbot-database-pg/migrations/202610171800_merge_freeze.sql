@@ -0,0 +1,25 @@
+CREATE TABLE IF NOT EXISTS merge_freeze (
+    id serial NOT NULL,
+    repository_id int4 NOT NULL,
+    base_branch varchar(255) NOT NULL,
+    frozen_until timestamptz NOT NULL,
+    reason text NULL,
+    created_by varchar(255) NOT NULL,
+    created_at timestamptz NOT NULL,
+
+    CONSTRAINT merge_freeze_pkey PRIMARY KEY (id),
+    CONSTRAINT merge_freeze_repository_id_base_branch_key UNIQUE (repository_id, base_branch),
+    CONSTRAINT merge_freeze_repository_id_fkey FOREIGN KEY (repository_id) REFERENCES repository(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS merge_window (
+    id serial NOT NULL,
+    repository_id int4 NOT NULL,
+    base_branch varchar(255) NOT NULL,
+    days varchar(255) NOT NULL,
+    start_time time NOT NULL,
+    end_time time NOT NULL,
+
+    CONSTRAINT merge_window_pkey PRIMARY KEY (id),
+    CONSTRAINT merge_window_repository_id_fkey FOREIGN KEY (repository_id) REFERENCES repository(id) ON DELETE CASCADE
+);
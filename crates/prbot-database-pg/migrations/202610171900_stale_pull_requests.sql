@@ -0,0 +1,15 @@
+ALTER TABLE pull_request ADD COLUMN snoozed_until timestamptz NULL;
+ALTER TABLE pull_request ADD COLUMN stale_reminded_at timestamptz NULL;
+
+ALTER TABLE repository ADD COLUMN stale_threshold_days int4 NOT NULL DEFAULT 0;
+ALTER TABLE repository ADD COLUMN stale_convert_to_draft bool NOT NULL DEFAULT false;
+
+CREATE TABLE IF NOT EXISTS step_transition (
+    id serial NOT NULL,
+    pull_request_id int4 NOT NULL,
+    step varchar(255) NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+
+    CONSTRAINT step_transition_pkey PRIMARY KEY (id),
+    CONSTRAINT step_transition_pull_request_id_fkey FOREIGN KEY (pull_request_id) REFERENCES pull_request(id) ON DELETE CASCADE
+);
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS pull_request_event (
+    id serial NOT NULL,
+    pull_request_id int4 NOT NULL,
+    kind varchar(255) NOT NULL,
+    author varchar(255) NULL,
+    details text NOT NULL DEFAULT '',
+    created_at timestamptz NOT NULL DEFAULT now(),
+
+    CONSTRAINT pull_request_event_pkey PRIMARY KEY (id),
+    CONSTRAINT pull_request_event_pull_request_id_fkey FOREIGN KEY (pull_request_id) REFERENCES pull_request(id) ON DELETE CASCADE
+);
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS audit_entry (
+    id serial NOT NULL,
+    source varchar(255) NOT NULL,
+    actor varchar(255) NOT NULL,
+    action varchar(255) NOT NULL,
+    target varchar(255) NOT NULL,
+    repository_path varchar(255) NULL,
+    old_value text NULL,
+    new_value text NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+
+    CONSTRAINT audit_entry_pkey PRIMARY KEY (id)
+);
+
+CREATE INDEX IF NOT EXISTS audit_entry_repository_path_idx ON audit_entry (repository_path);
+CREATE INDEX IF NOT EXISTS audit_entry_actor_idx ON audit_entry (actor);
@@ -0,0 +1 @@
+ALTER TABLE repository ADD COLUMN codeowners_reviewers boolean NOT NULL DEFAULT false;
@@ -0,0 +1,11 @@
+ALTER TABLE repository ADD COLUMN reviewer_pool text[] NOT NULL DEFAULT '{}';
+ALTER TABLE repository ADD COLUMN reviewer_pool_count int4 NOT NULL DEFAULT 1;
+ALTER TABLE repository ADD COLUMN reviewer_assignment_strategy varchar(255) NOT NULL DEFAULT 'round_robin';
+ALTER TABLE repository ADD COLUMN reviewer_pool_cursor int4 NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS away_user (
+    username varchar(255) NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+
+    CONSTRAINT away_user_pkey PRIMARY KEY (username)
+);
@@ -0,0 +1 @@
+ALTER TABLE repository ADD COLUMN stale_approval_policy varchar(255) NOT NULL DEFAULT 'keep';
@@ -0,0 +1,2 @@
+ALTER TABLE job ADD COLUMN IF NOT EXISTS locked_by varchar(255) NULL;
+ALTER TABLE job ADD COLUMN IF NOT EXISTS locked_until timestamptz NULL;
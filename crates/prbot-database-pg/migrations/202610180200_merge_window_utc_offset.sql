@@ -0,0 +1 @@
+ALTER TABLE merge_window ADD COLUMN IF NOT EXISTS utc_offset int4 NOT NULL DEFAULT 0;
@@ -0,0 +1 @@
+CREATE UNIQUE INDEX IF NOT EXISTS job_delivery_id_key ON job (delivery_id) WHERE delivery_id IS NOT NULL;
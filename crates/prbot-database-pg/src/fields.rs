@@ -1,6 +1,10 @@
-use std::ops::Deref;
+use std::{collections::BTreeMap, ops::Deref};
 
-use prbot_models::{MergeStrategy, QaStatus, RuleAction, RuleBranch, RuleCondition};
+use prbot_models::{
+    AuditSource, JobKind, JobStatus, MergeQueueStatus, MergeStrategy, PullRequestEventKind,
+    QaStatus, ReviewerAssignmentStrategy, RuleAction, RuleBranch, RuleCondition,
+    StaleApprovalPolicy, StepLabel, WebhookDeliveryOutcome,
+};
 use sqlx::{
     postgres::{PgTypeInfo, PgValueRef},
     Decode, Postgres, Type,
@@ -58,6 +62,54 @@ impl From<MergeStrategyDecode> for MergeStrategy {
     }
 }
 
+pub struct ReviewerAssignmentStrategyDecode(ReviewerAssignmentStrategy);
+impl<'r> Decode<'r, Postgres> for ReviewerAssignmentStrategyDecode {
+    fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        ReviewerAssignmentStrategy::try_from(str_value)
+            .map(Self)
+            .map_err(Into::into)
+    }
+}
+
+impl Type<Postgres> for ReviewerAssignmentStrategyDecode {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("varchar")
+    }
+}
+
+impl Deref for ReviewerAssignmentStrategyDecode {
+    type Target = ReviewerAssignmentStrategy;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct StaleApprovalPolicyDecode(StaleApprovalPolicy);
+impl<'r> Decode<'r, Postgres> for StaleApprovalPolicyDecode {
+    fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        StaleApprovalPolicy::try_from(str_value)
+            .map(Self)
+            .map_err(Into::into)
+    }
+}
+
+impl Type<Postgres> for StaleApprovalPolicyDecode {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("varchar")
+    }
+}
+
+impl Deref for StaleApprovalPolicyDecode {
+    type Target = StaleApprovalPolicy;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 pub struct QaStatusDecode(QaStatus);
 impl<'r> Decode<'r, Postgres> for QaStatusDecode {
     fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
@@ -125,3 +177,185 @@ impl Deref for RuleConditionsDecode {
         &self.0
     }
 }
+
+pub struct AuditSourceDecode(AuditSource);
+impl<'r> Decode<'r, Postgres> for AuditSourceDecode {
+    fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        AuditSource::try_from(str_value)
+            .map(Self)
+            .map_err(Into::into)
+    }
+}
+
+impl Type<Postgres> for AuditSourceDecode {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("varchar")
+    }
+}
+
+impl Deref for AuditSourceDecode {
+    type Target = AuditSource;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct PullRequestEventKindDecode(PullRequestEventKind);
+impl<'r> Decode<'r, Postgres> for PullRequestEventKindDecode {
+    fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        PullRequestEventKind::try_from(str_value)
+            .map(Self)
+            .map_err(Into::into)
+    }
+}
+
+impl Type<Postgres> for PullRequestEventKindDecode {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("varchar")
+    }
+}
+
+impl Deref for PullRequestEventKindDecode {
+    type Target = PullRequestEventKind;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct StepLabelDecode(StepLabel);
+impl<'r> Decode<'r, Postgres> for StepLabelDecode {
+    fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        StepLabel::try_from(str_value).map(Self).map_err(Into::into)
+    }
+}
+
+impl Type<Postgres> for StepLabelDecode {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("varchar")
+    }
+}
+
+impl Deref for StepLabelDecode {
+    type Target = StepLabel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct WebhookDeliveryOutcomeDecode(WebhookDeliveryOutcome);
+impl<'r> Decode<'r, Postgres> for WebhookDeliveryOutcomeDecode {
+    fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        WebhookDeliveryOutcome::try_from(str_value)
+            .map(Self)
+            .map_err(Into::into)
+    }
+}
+
+impl Type<Postgres> for WebhookDeliveryOutcomeDecode {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("varchar")
+    }
+}
+
+impl Deref for WebhookDeliveryOutcomeDecode {
+    type Target = WebhookDeliveryOutcome;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct HeadersDecode(BTreeMap<String, String>);
+impl<'r> Decode<'r, Postgres> for HeadersDecode {
+    fn decode(value: PgValueRef) -> Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        Ok(Self(serde_json::from_str(str_value)?))
+    }
+}
+
+impl Type<Postgres> for HeadersDecode {
+    fn type_info() -> <Postgres as sqlx::Database>::TypeInfo {
+        PgTypeInfo::with_name("text")
+    }
+}
+
+impl From<HeadersDecode> for BTreeMap<String, String> {
+    fn from(v: HeadersDecode) -> Self {
+        v.0
+    }
+}
+
+pub struct JobKindDecode(JobKind);
+impl<'r> Decode<'r, Postgres> for JobKindDecode {
+    fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        JobKind::try_from(str_value).map(Self).map_err(Into::into)
+    }
+}
+
+impl Type<Postgres> for JobKindDecode {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("varchar")
+    }
+}
+
+impl Deref for JobKindDecode {
+    type Target = JobKind;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct MergeQueueStatusDecode(MergeQueueStatus);
+impl<'r> Decode<'r, Postgres> for MergeQueueStatusDecode {
+    fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        MergeQueueStatus::try_from(str_value)
+            .map(Self)
+            .map_err(Into::into)
+    }
+}
+
+impl Type<Postgres> for MergeQueueStatusDecode {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("varchar")
+    }
+}
+
+impl Deref for MergeQueueStatusDecode {
+    type Target = MergeQueueStatus;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct JobStatusDecode(JobStatus);
+impl<'r> Decode<'r, Postgres> for JobStatusDecode {
+    fn decode(value: PgValueRef) -> core::result::Result<Self, sqlx::error::BoxDynError> {
+        let str_value = <&str as Decode<Postgres>>::decode(value)?;
+        JobStatus::try_from(str_value).map(Self).map_err(Into::into)
+    }
+}
+
+impl Type<Postgres> for JobStatusDecode {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("varchar")
+    }
+}
+
+impl Deref for JobStatusDecode {
+    type Target = JobStatus;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -1,14 +1,20 @@
 use async_trait::async_trait;
 use prbot_database_interface::{DatabaseError, DbService, Result};
 use prbot_models::{
-    Account, ExternalAccount, ExternalAccountRight, MergeRule, MergeStrategy, PullRequest,
-    PullRequestRule, QaStatus, Repository, RequiredReviewer, RuleBranch,
+    Account, AuditEntry, AwayUser, ExternalAccount, ExternalAccountRight, Job, JobStatus,
+    MergeFreeze, MergeQueueEntry, MergeRule, MergeStrategy, MergeWindow, PullRequest,
+    PullRequestEvent, PullRequestRule, QaStatus, Repository, RequiredReviewer,
+    ReviewerAssignmentStrategy, RuleBranch, StaleApprovalPolicy, StepTransition, WebhookDelivery,
+    WebhookDeliveryOutcome,
 };
 use sqlx::{PgPool, Row};
+use time::OffsetDateTime;
 
 use crate::row::{
-    AccountRow, ExternalAccountRightRow, ExternalAccountRow, MergeRuleRow, PullRequestRow,
-    PullRequestRuleRow, RepositoryRow, RequiredReviewerRow,
+    AccountRow, AuditEntryRow, AwayUserRow, ExternalAccountRightRow, ExternalAccountRow, JobRow,
+    MergeFreezeRow, MergeQueueEntryRow, MergeRuleRow, MergeWindowRow, PullRequestEventRow,
+    PullRequestRow, PullRequestRuleRow, RepositoryRow, RequiredReviewerRow, StepTransitionRow,
+    WebhookDeliveryRow,
 };
 
 pub struct PostgresDb {
@@ -43,6 +49,18 @@ impl PostgresDb {
         Self::wrap_row_not_found(e, DatabaseError::UnknownExternalAccount(username.into()))
     }
 
+    fn wrap_unknown_merge_queue_entry(
+        e: sqlx::Error,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> DatabaseError {
+        Self::wrap_row_not_found(
+            e,
+            DatabaseError::UnknownMergeQueueEntry(format!("{owner}/{name}"), number),
+        )
+    }
+
     fn wrap_unknown_merge_rule(
         e: sqlx::Error,
         base_branch: RuleBranch,
@@ -67,6 +85,14 @@ impl PostgresDb {
         Self::wrap_row_not_found(e, DatabaseError::UnknownPullRequestRule(name.into()))
     }
 
+    fn wrap_unknown_job(e: sqlx::Error, id: u64) -> DatabaseError {
+        Self::wrap_row_not_found(e, DatabaseError::UnknownJob(id))
+    }
+
+    fn wrap_unknown_webhook_delivery(e: sqlx::Error, delivery_id: &str) -> DatabaseError {
+        Self::wrap_row_not_found(e, DatabaseError::UnknownWebhookDelivery(delivery_id.into()))
+    }
+
     async fn external_accounts_get_from_id(
         &self,
         username: &str,
@@ -89,6 +115,86 @@ impl PostgresDb {
         Ok(row.map(Into::into))
     }
 
+    async fn merge_queue_entries_get_from_id(&self, id: i32) -> Result<Option<MergeQueueEntry>> {
+        let row = sqlx::query_as::<_, MergeQueueEntryRow>(
+            r#"
+            SELECT *
+            FROM merge_queue_entry
+            WHERE id = $1
+        "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn merge_windows_get_from_id(&self, id: i32) -> Result<Option<MergeWindow>> {
+        let row = sqlx::query_as::<_, MergeWindowRow>(
+            r#"
+            SELECT *
+            FROM merge_window
+            WHERE id = $1
+        "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn audit_entries_get_from_id(&self, id: i32) -> Result<Option<AuditEntry>> {
+        let row = sqlx::query_as::<_, AuditEntryRow>(
+            r#"
+            SELECT *
+            FROM audit_entry
+            WHERE id = $1
+        "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn step_transitions_get_from_id(&self, id: i32) -> Result<Option<StepTransition>> {
+        let row = sqlx::query_as::<_, StepTransitionRow>(
+            r#"
+            SELECT *
+            FROM step_transition
+            WHERE id = $1
+        "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn pull_request_events_get_from_id(&self, id: i32) -> Result<Option<PullRequestEvent>> {
+        let row = sqlx::query_as::<_, PullRequestEventRow>(
+            r#"
+            SELECT *
+            FROM pull_request_event
+            WHERE id = $1
+        "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
     async fn merge_rules_get_from_id(&self, id: i32) -> Result<Option<MergeRule>> {
         let row = sqlx::query_as::<_, MergeRuleRow>(
             r#"
@@ -280,6 +386,163 @@ impl DbService for PostgresDb {
         self.accounts_get_expect(&username).await
     }
 
+    ////////////////
+    // Audit entries
+
+    #[tracing::instrument(skip(self))]
+    async fn audit_entries_create(&self, instance: AuditEntry) -> Result<AuditEntry> {
+        let new_id: i32 = sqlx::query(
+            r#"
+            INSERT INTO audit_entry
+            (
+                source,
+                actor,
+                action,
+                target,
+                repository_path,
+                old_value,
+                new_value,
+                created_at
+            )
+            VALUES
+            (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7,
+                $8
+            )
+            RETURNING id
+            ;
+        "#,
+        )
+        .bind(instance.source.to_str())
+        .bind(&instance.actor)
+        .bind(&instance.action)
+        .bind(&instance.target)
+        .bind(&instance.repository_path)
+        .bind(&instance.old_value)
+        .bind(&instance.new_value)
+        .bind(instance.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
+        .get(0);
+
+        self.audit_entries_get_from_id(new_id)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn audit_entries_list(
+        &self,
+        repository_path: Option<&str>,
+        actor: Option<&str>,
+        since: Option<OffsetDateTime>,
+    ) -> Result<Vec<AuditEntry>> {
+        let rows = sqlx::query_as::<_, AuditEntryRow>(
+            r#"
+            SELECT *
+            FROM audit_entry
+            WHERE ($1::varchar IS NULL OR repository_path = $1)
+            AND ($2::varchar IS NULL OR actor = $2)
+            AND ($3::timestamptz IS NULL OR created_at >= $3)
+            ORDER BY created_at, id
+        "#,
+        )
+        .bind(repository_path)
+        .bind(actor)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /////////////
+    // Away users
+
+    #[tracing::instrument(skip(self))]
+    async fn away_users_create(&self, instance: AwayUser) -> Result<AwayUser> {
+        sqlx::query(
+            r#"
+            INSERT INTO away_user
+            (
+                username,
+                created_at
+            )
+            VALUES
+            (
+                $1,
+                $2
+            )
+            ;
+        "#,
+        )
+        .bind(&instance.username)
+        .bind(instance.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        self.away_users_get(&instance.username)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn away_users_get(&self, username: &str) -> Result<Option<AwayUser>> {
+        let row = sqlx::query_as::<_, AwayUserRow>(
+            r#"
+            SELECT *
+            FROM away_user
+            WHERE username = $1
+        "#,
+        )
+        .bind(username)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn away_users_delete(&self, username: &str) -> Result<bool> {
+        sqlx::query(
+            r#"
+            DELETE FROM away_user
+            WHERE username = $1
+        "#,
+        )
+        .bind(username)
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected() > 0)
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn away_users_all(&self) -> Result<Vec<AwayUser>> {
+        let rows = sqlx::query_as::<_, AwayUserRow>(
+            r#"
+            SELECT *
+            FROM away_user
+            ORDER BY username
+        "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
     //////////////////////////
     // External account rights
 
@@ -564,83 +827,644 @@ impl DbService for PostgresDb {
         Ok(())
     }
 
-    //////////////
-    // Merge rules
+    #[tracing::instrument(skip(self, instance), fields(kind = %instance.kind))]
+    async fn jobs_create(&self, instance: Job) -> Result<Job> {
+        let delivery_id = instance.delivery_id.clone();
+        let row = sqlx::query(
+            r#"
+                INSERT INTO job
+                (
+                    kind,
+                    payload,
+                    status,
+                    attempts,
+                    max_attempts,
+                    last_error,
+                    delivery_id,
+                    locked_by,
+                    locked_until,
+                    run_at,
+                    created_at
+                )
+                VALUES
+                (
+                    $1,
+                    $2,
+                    $3,
+                    $4,
+                    $5,
+                    $6,
+                    $7,
+                    $8,
+                    $9,
+                    $10,
+                    $11
+                )
+                ON CONFLICT (delivery_id) WHERE delivery_id IS NOT NULL DO NOTHING
+                RETURNING id
+            "#,
+        )
+        .bind(instance.kind.to_str())
+        .bind(instance.payload)
+        .bind(instance.status.to_str())
+        .bind(instance.attempts as i32)
+        .bind(instance.max_attempts as i32)
+        .bind(instance.last_error)
+        .bind(instance.delivery_id)
+        .bind(instance.locked_by)
+        .bind(instance.locked_until)
+        .bind(instance.run_at)
+        .bind(instance.created_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
 
-    #[tracing::instrument(skip(self))]
-    async fn merge_rules_create(&self, instance: MergeRule) -> Result<MergeRule> {
-        self.repositories_get_from_id_expect(instance.repository_id)
-            .await?;
+        if let Some(row) = row {
+            return self.jobs_get_expect(row.get::<i32, _>(0) as u64).await;
+        }
 
-        let new_id: i32 = sqlx::query(
+        // The webhook delivery already has a job
+        let row = sqlx::query_as::<_, JobRow>(
             r#"
-            INSERT INTO merge_rule
-            (
-                repository_id,
-                base_branch,
-                head_branch,
-                strategy
-            )
-            VALUES
-            (
-                $1,
-                $2,
-                $3,
-                $4
-            )
-            RETURNING id
-            ;
+            SELECT *
+            FROM job
+            WHERE delivery_id = $1
         "#,
         )
-        .bind(instance.repository_id as i32)
-        .bind(instance.base_branch.to_string())
-        .bind(instance.head_branch.to_string())
-        .bind(instance.strategy.to_string())
+        .bind(delivery_id)
         .fetch_one(&self.pool)
         .await
-        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
-        .get(0);
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
 
-        self.merge_rules_get_from_id(new_id)
-            .await
-            .map(|x| x.unwrap())
+        Ok(row.into())
     }
 
-    #[tracing::instrument(skip(self))]
-    async fn merge_rules_update(&self, instance: MergeRule) -> Result<MergeRule> {
-        self.repositories_get_from_id_expect(instance.repository_id)
-            .await?;
-
-        let new_id: i32 = sqlx::query(
+    #[tracing::instrument(skip(self, instance), fields(id = instance.id))]
+    async fn jobs_update(&self, instance: Job) -> Result<Job> {
+        sqlx::query(
             r#"
-            UPDATE merge_rule
-            SET strategy = $1
-            WHERE repository_id = $2
-            AND base_branch = $3
-            AND head_branch = $4
+            UPDATE job
+            SET status = $1,
+            attempts = $2,
+            max_attempts = $3,
+            last_error = $4,
+            locked_by = $5,
+            locked_until = $6,
+            run_at = $7
+            WHERE id = $8
             RETURNING id
         "#,
         )
-        .bind(instance.strategy.to_string())
-        .bind(instance.repository_id as i32)
-        .bind(instance.base_branch.to_string())
-        .bind(instance.head_branch.to_string())
+        .bind(instance.status.to_str())
+        .bind(instance.attempts as i32)
+        .bind(instance.max_attempts as i32)
+        .bind(instance.last_error)
+        .bind(instance.locked_by)
+        .bind(instance.locked_until)
+        .bind(instance.run_at)
+        .bind(instance.id as i32)
         .fetch_one(&self.pool)
         .await
-        .map_err(|e| Self::wrap_unknown_merge_rule(e, instance.base_branch, instance.head_branch))?
-        .get(0);
+        .map_err(|e| Self::wrap_unknown_job(e, instance.id))?;
 
-        self.merge_rules_get_from_id(new_id)
-            .await
-            .map(|x| x.unwrap())
+        self.jobs_get_expect(instance.id).await
     }
 
     #[tracing::instrument(skip(self))]
-    async fn merge_rules_get(
-        &self,
-        owner: &str,
-        name: &str,
-        base_branch: RuleBranch,
+    async fn jobs_get(&self, id: u64) -> Result<Option<Job>> {
+        let row = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT *
+            FROM job
+            WHERE id = $1
+        "#,
+        )
+        .bind(id as i32)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn jobs_list(&self, status: Option<JobStatus>) -> Result<Vec<Job>> {
+        let rows = sqlx::query_as::<_, JobRow>(
+            r#"
+            SELECT *
+            FROM job
+            WHERE $1::varchar IS NULL OR status = $1
+            ORDER BY id
+        "#,
+        )
+        .bind(status.map(JobStatus::to_str))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn jobs_claim_next(
+        &self,
+        now: OffsetDateTime,
+        worker_id: &str,
+        locked_until: OffsetDateTime,
+    ) -> Result<Option<Job>> {
+        let row = sqlx::query_as::<_, JobRow>(
+            r#"
+            UPDATE job
+            SET status = $1,
+            attempts = attempts + 1,
+            locked_by = $4,
+            locked_until = $5
+            WHERE id = (
+                SELECT id
+                FROM job
+                WHERE status = $2
+                AND run_at <= $3
+                ORDER BY run_at, id
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+        "#,
+        )
+        .bind(JobStatus::Running.to_str())
+        .bind(JobStatus::Pending.to_str())
+        .bind(now)
+        .bind(worker_id)
+        .bind(locked_until)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn jobs_renew_lease(
+        &self,
+        id: u64,
+        worker_id: &str,
+        locked_until: OffsetDateTime,
+    ) -> Result<bool> {
+        sqlx::query(
+            r#"
+            UPDATE job
+            SET locked_until = $1
+            WHERE id = $2
+            AND status = $3
+            AND locked_by = $4
+        "#,
+        )
+        .bind(locked_until)
+        .bind(id as i32)
+        .bind(JobStatus::Running.to_str())
+        .bind(worker_id)
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected() > 0)
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn jobs_requeue_expired(&self, now: OffsetDateTime) -> Result<u64> {
+        sqlx::query(
+            r#"
+            UPDATE job
+            SET status = $1,
+            locked_by = NULL,
+            locked_until = NULL
+            WHERE status = $2
+            AND (locked_until IS NULL OR locked_until <= $3)
+        "#,
+        )
+        .bind(JobStatus::Pending.to_str())
+        .bind(JobStatus::Running.to_str())
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+    }
+
+    #[tracing::instrument(skip(self, instance), fields(id = instance.id))]
+    async fn jobs_finish(&self, instance: Job, worker_id: &str) -> Result<Option<Job>> {
+        let updated = sqlx::query(
+            r#"
+            UPDATE job
+            SET status = $1,
+            attempts = $2,
+            max_attempts = $3,
+            last_error = $4,
+            locked_by = $5,
+            locked_until = $6,
+            run_at = $7
+            WHERE id = $8
+            AND status = $9
+            AND locked_by = $10
+        "#,
+        )
+        .bind(instance.status.to_str())
+        .bind(instance.attempts as i32)
+        .bind(instance.max_attempts as i32)
+        .bind(instance.last_error)
+        .bind(instance.locked_by)
+        .bind(instance.locked_until)
+        .bind(instance.run_at)
+        .bind(instance.id as i32)
+        .bind(JobStatus::Running.to_str())
+        .bind(worker_id)
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected() > 0)
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        if updated {
+            self.jobs_get(instance.id).await
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn jobs_cancel(&self, id: u64) -> Result<bool> {
+        sqlx::query(
+            r#"
+            UPDATE job
+            SET status = $1
+            WHERE id = $2
+            AND status = $3
+        "#,
+        )
+        .bind(JobStatus::Cancelled.to_str())
+        .bind(id as i32)
+        .bind(JobStatus::Pending.to_str())
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected() > 0)
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+    }
+
+    ////////////////
+    // Merge freezes
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_freezes_set(&self, instance: MergeFreeze) -> Result<MergeFreeze> {
+        self.repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+
+        let row = sqlx::query_as::<_, MergeFreezeRow>(
+            r#"
+            INSERT INTO merge_freeze
+            (
+                repository_id,
+                base_branch,
+                frozen_until,
+                reason,
+                created_by,
+                created_at
+            )
+            VALUES
+            (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6
+            )
+            ON CONFLICT (repository_id, base_branch) DO UPDATE
+            SET frozen_until = EXCLUDED.frozen_until,
+            reason = EXCLUDED.reason,
+            created_by = EXCLUDED.created_by,
+            created_at = EXCLUDED.created_at
+            RETURNING *
+            ;
+        "#,
+        )
+        .bind(instance.repository_id as i32)
+        .bind(instance.base_branch.to_string())
+        .bind(instance.until)
+        .bind(&instance.reason)
+        .bind(&instance.created_by)
+        .bind(instance.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.into())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_freezes_list(&self, owner: &str, name: &str) -> Result<Vec<MergeFreeze>> {
+        let rows = sqlx::query_as::<_, MergeFreezeRow>(
+            r#"
+            SELECT merge_freeze.*
+            FROM merge_freeze
+            INNER JOIN repository ON (repository.owner = $1 AND repository.name = $2 AND repository.id = repository_id)
+            ORDER BY base_branch
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_freezes_delete(
+        &self,
+        owner: &str,
+        name: &str,
+        base_branch: RuleBranch,
+    ) -> Result<bool> {
+        sqlx::query(
+            r#"
+            DELETE
+            FROM merge_freeze
+            USING repository
+            WHERE repository.owner = $1
+            AND repository.name = $2
+            AND repository.id = repository_id
+            AND base_branch = $3;
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .bind(base_branch.to_string())
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected() > 0)
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+    }
+
+    //////////////////////
+    // Merge queue entries
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_queue_entries_create(
+        &self,
+        instance: MergeQueueEntry,
+    ) -> Result<MergeQueueEntry> {
+        self.repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+
+        let new_id: i32 = sqlx::query(
+            r#"
+            INSERT INTO merge_queue_entry
+            (
+                repository_id,
+                pull_request_number,
+                base_branch,
+                status,
+                staging_sha,
+                queued_at
+            )
+            VALUES
+            (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6
+            )
+            RETURNING id
+            ;
+        "#,
+        )
+        .bind(instance.repository_id as i32)
+        .bind(instance.pull_request_number as i32)
+        .bind(&instance.base_branch)
+        .bind(instance.status.to_str())
+        .bind(&instance.staging_sha)
+        .bind(instance.queued_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
+        .get(0);
+
+        self.merge_queue_entries_get_from_id(new_id)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_queue_entries_update(
+        &self,
+        instance: MergeQueueEntry,
+    ) -> Result<MergeQueueEntry> {
+        let repository = self
+            .repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+
+        let new_id: i32 = sqlx::query(
+            r#"
+            UPDATE merge_queue_entry
+            SET base_branch = $1,
+            status = $2,
+            staging_sha = $3,
+            queued_at = $4
+            WHERE repository_id = $5
+            AND pull_request_number = $6
+            RETURNING id
+        "#,
+        )
+        .bind(&instance.base_branch)
+        .bind(instance.status.to_str())
+        .bind(&instance.staging_sha)
+        .bind(instance.queued_at)
+        .bind(instance.repository_id as i32)
+        .bind(instance.pull_request_number as i32)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            Self::wrap_unknown_merge_queue_entry(
+                e,
+                &repository.owner,
+                &repository.name,
+                instance.pull_request_number,
+            )
+        })?
+        .get(0);
+
+        self.merge_queue_entries_get_from_id(new_id)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_queue_entries_get(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Option<MergeQueueEntry>> {
+        let row = sqlx::query_as::<_, MergeQueueEntryRow>(
+            r#"
+            SELECT merge_queue_entry.*
+            FROM merge_queue_entry
+            INNER JOIN repository ON (repository.id = repository_id)
+            WHERE repository.owner = $1
+            AND repository.name = $2
+            AND pull_request_number = $3;
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .bind(number as i32)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_queue_entries_list(
+        &self,
+        owner: &str,
+        name: &str,
+    ) -> Result<Vec<MergeQueueEntry>> {
+        let rows = sqlx::query_as::<_, MergeQueueEntryRow>(
+            r#"
+            SELECT merge_queue_entry.*
+            FROM merge_queue_entry
+            INNER JOIN repository ON (repository.id = repository_id)
+            WHERE repository.owner = $1
+            AND repository.name = $2
+            ORDER BY queued_at, merge_queue_entry.id
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_queue_entries_delete(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<bool> {
+        sqlx::query(
+            r#"
+            DELETE
+            FROM merge_queue_entry
+            USING repository
+            WHERE repository.owner = $1
+            AND repository.name = $2
+            AND repository.id = repository_id
+            AND pull_request_number = $3;
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .bind(number as i32)
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected() > 0)
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+    }
+
+    //////////////
+    // Merge rules
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_rules_create(&self, instance: MergeRule) -> Result<MergeRule> {
+        self.repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+
+        let new_id: i32 = sqlx::query(
+            r#"
+            INSERT INTO merge_rule
+            (
+                repository_id,
+                base_branch,
+                head_branch,
+                strategy,
+                commit_title_template,
+                commit_message_template
+            )
+            VALUES
+            (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6
+            )
+            RETURNING id
+            ;
+        "#,
+        )
+        .bind(instance.repository_id as i32)
+        .bind(instance.base_branch.to_string())
+        .bind(instance.head_branch.to_string())
+        .bind(instance.strategy.to_string())
+        .bind(instance.commit_title_template)
+        .bind(instance.commit_message_template)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
+        .get(0);
+
+        self.merge_rules_get_from_id(new_id)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_rules_update(&self, instance: MergeRule) -> Result<MergeRule> {
+        self.repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+
+        let new_id: i32 = sqlx::query(
+            r#"
+            UPDATE merge_rule
+            SET strategy = $1,
+            commit_title_template = $2,
+            commit_message_template = $3
+            WHERE repository_id = $4
+            AND base_branch = $5
+            AND head_branch = $6
+            RETURNING id
+        "#,
+        )
+        .bind(instance.strategy.to_string())
+        .bind(instance.commit_title_template)
+        .bind(instance.commit_message_template)
+        .bind(instance.repository_id as i32)
+        .bind(instance.base_branch.to_string())
+        .bind(instance.head_branch.to_string())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_merge_rule(e, instance.base_branch, instance.head_branch))?
+        .get(0);
+
+        self.merge_rules_get_from_id(new_id)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_rules_get(
+        &self,
+        owner: &str,
+        name: &str,
+        base_branch: RuleBranch,
         head_branch: RuleBranch,
     ) -> Result<Option<MergeRule>> {
         let row = sqlx::query_as::<_, MergeRuleRow>(
@@ -728,6 +1552,95 @@ impl DbService for PostgresDb {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    ////////////////
+    // Merge windows
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_windows_create(&self, instance: MergeWindow) -> Result<MergeWindow> {
+        self.repositories_get_from_id_expect(instance.repository_id)
+            .await?;
+
+        let new_id: i32 = sqlx::query(
+            r#"
+            INSERT INTO merge_window
+            (
+                repository_id,
+                base_branch,
+                days,
+                start_time,
+                end_time,
+                utc_offset
+            )
+            VALUES
+            (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6
+            )
+            RETURNING id
+            ;
+        "#,
+        )
+        .bind(instance.repository_id as i32)
+        .bind(instance.base_branch.to_string())
+        .bind(instance.days_str())
+        .bind(instance.start_time)
+        .bind(instance.end_time)
+        .bind(instance.utc_offset.whole_seconds())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
+        .get(0);
+
+        self.merge_windows_get_from_id(new_id)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_windows_list(&self, owner: &str, name: &str) -> Result<Vec<MergeWindow>> {
+        let rows = sqlx::query_as::<_, MergeWindowRow>(
+            r#"
+            SELECT merge_window.*
+            FROM merge_window
+            INNER JOIN repository ON (repository.owner = $1 AND repository.name = $2 AND repository.id = repository_id)
+            ORDER BY merge_window.id
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn merge_windows_delete(&self, owner: &str, name: &str, id: u64) -> Result<bool> {
+        sqlx::query(
+            r#"
+            DELETE
+            FROM merge_window
+            USING repository
+            WHERE repository.owner = $1
+            AND repository.name = $2
+            AND repository.id = repository_id
+            AND merge_window.id = $3;
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .bind(id as i32)
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected() > 0)
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+    }
+
     ////////////////
     // Pull requests
 
@@ -748,7 +1661,9 @@ impl DbService for PostgresDb {
                 checks_enabled,
                 automerge,
                 locked,
-                strategy_override
+                strategy_override,
+                snoozed_until,
+                stale_reminded_at
             )
             VALUES
             (
@@ -760,7 +1675,9 @@ impl DbService for PostgresDb {
                 $6,
                 $7,
                 $8,
-                $9
+                $9,
+                $10,
+                $11
             )
             RETURNING id;
             "#,
@@ -774,6 +1691,8 @@ impl DbService for PostgresDb {
         .bind(instance.automerge)
         .bind(instance.locked)
         .bind(instance.strategy_override.map(|x| x.to_string()))
+        .bind(instance.snoozed_until)
+        .bind(instance.stale_reminded_at)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
@@ -797,9 +1716,11 @@ impl DbService for PostgresDb {
             checks_enabled = $4,
             automerge = $5,
             locked = $6,
-            strategy_override = $7
-            WHERE repository_id = $8
-            AND number = $9
+            strategy_override = $7,
+            snoozed_until = $8,
+            stale_reminded_at = $9
+            WHERE repository_id = $10
+            AND number = $11
             RETURNING id;
             "#,
         )
@@ -810,6 +1731,8 @@ impl DbService for PostgresDb {
         .bind(instance.automerge)
         .bind(instance.locked)
         .bind(instance.strategy_override.map(|x| x.to_string()))
+        .bind(instance.snoozed_until)
+        .bind(instance.stale_reminded_at)
         .bind(instance.repository_id as i32)
         .bind(instance.number as i32)
         .fetch_one(&self.pool)
@@ -1126,6 +2049,74 @@ impl DbService for PostgresDb {
         self.pull_requests_get_from_id_expect(new_id as u64).await
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn pull_requests_set_snoozed_until(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        value: Option<OffsetDateTime>,
+    ) -> Result<PullRequest> {
+        self.repositories_get_expect(owner, name).await?;
+
+        let new_id: i32 = sqlx::query(
+            r#"
+            UPDATE pull_request
+            SET snoozed_until = $1
+            FROM repository
+            WHERE repository_id = repository.id
+            AND repository.owner = $2
+            AND repository.name = $3
+            AND number = $4
+            RETURNING pull_request.id;
+            "#,
+        )
+        .bind(value)
+        .bind(owner)
+        .bind(name)
+        .bind(number as i32)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_pull_request(e, owner, name, number))?
+        .get(0);
+
+        self.pull_requests_get_from_id_expect(new_id as u64).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn pull_requests_set_stale_reminded_at(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        value: Option<OffsetDateTime>,
+    ) -> Result<PullRequest> {
+        self.repositories_get_expect(owner, name).await?;
+
+        let new_id: i32 = sqlx::query(
+            r#"
+            UPDATE pull_request
+            SET stale_reminded_at = $1
+            FROM repository
+            WHERE repository_id = repository.id
+            AND repository.owner = $2
+            AND repository.name = $3
+            AND number = $4
+            RETURNING pull_request.id;
+            "#,
+        )
+        .bind(value)
+        .bind(owner)
+        .bind(name)
+        .bind(number as i32)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_pull_request(e, owner, name, number))?
+        .get(0);
+
+        self.pull_requests_get_from_id_expect(new_id as u64).await
+    }
+
     #[tracing::instrument(skip(self))]
     async fn pull_requests_set_strategy_override(
         &self,
@@ -1148,16 +2139,154 @@ impl DbService for PostgresDb {
             RETURNING pull_request.id;
             "#,
         )
-        .bind(strategy.map(|x| x.to_string()))
+        .bind(strategy.map(|x| x.to_string()))
+        .bind(owner)
+        .bind(name)
+        .bind(number as i32)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_pull_request(e, owner, name, number))?
+        .get(0);
+
+        self.pull_requests_get_from_id_expect(new_id as u64).await
+    }
+
+    //////////////////////
+    // Pull request events
+
+    #[tracing::instrument(skip(self))]
+    async fn pull_request_events_create(
+        &self,
+        instance: PullRequestEvent,
+    ) -> Result<PullRequestEvent> {
+        self.pull_requests_get_from_id_expect(instance.pull_request_id)
+            .await?;
+
+        let new_id: i32 = sqlx::query(
+            r#"
+            INSERT INTO pull_request_event
+            (
+                pull_request_id,
+                kind,
+                author,
+                details,
+                created_at
+            )
+            VALUES
+            (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5
+            )
+            RETURNING id
+            ;
+        "#,
+        )
+        .bind(instance.pull_request_id as i32)
+        .bind(instance.kind.to_str())
+        .bind(&instance.author)
+        .bind(&instance.details)
+        .bind(instance.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
+        .get(0);
+
+        self.pull_request_events_get_from_id(new_id)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn pull_request_events_list(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<PullRequestEvent>> {
+        let rows = sqlx::query_as::<_, PullRequestEventRow>(
+            r#"
+            SELECT pull_request_event.*
+            FROM pull_request_event
+            INNER JOIN pull_request ON (pull_request.id = pull_request_event.pull_request_id)
+            INNER JOIN repository ON (repository.id = pull_request.repository_id)
+            WHERE repository.owner = $1
+            AND repository.name = $2
+            AND pull_request.number = $3
+            ORDER BY pull_request_event.created_at, pull_request_event.id
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .bind(number as i32)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn pull_request_events_pending_reviews(
+        &self,
+        owner: &str,
+        name: &str,
+        excluded_number: u64,
+    ) -> Result<Vec<(String, u64)>> {
+        let rows = sqlx::query(
+            r#"
+            WITH open_pull_request AS (
+                SELECT pull_request.id
+                FROM pull_request
+                INNER JOIN repository ON (repository.id = pull_request.repository_id)
+                WHERE repository.owner = $1
+                AND repository.name = $2
+                AND pull_request.number <> $3
+                AND COALESCE((
+                    SELECT pull_request_event.kind
+                    FROM pull_request_event
+                    WHERE pull_request_event.pull_request_id = pull_request.id
+                    AND pull_request_event.kind IN ('merged', 'closed', 'reopened')
+                    ORDER BY pull_request_event.created_at DESC, pull_request_event.id DESC
+                    LIMIT 1
+                ), 'reopened') = 'reopened'
+            ), pending_reviewer AS (
+                SELECT pull_request_event.pull_request_id, pull_request_event.author AS username
+                FROM pull_request_event
+                INNER JOIN open_pull_request ON (open_pull_request.id = pull_request_event.pull_request_id)
+                WHERE pull_request_event.kind = 'reviewer_assigned'
+                AND pull_request_event.author IS NOT NULL
+                UNION
+                SELECT required_reviewer.pull_request_id, required_reviewer.username
+                FROM required_reviewer
+                INNER JOIN open_pull_request ON (open_pull_request.id = required_reviewer.pull_request_id)
+            )
+            SELECT pending_reviewer.username, COUNT(*)
+            FROM pending_reviewer
+            WHERE NOT EXISTS (
+                SELECT 1
+                FROM pull_request_event
+                WHERE pull_request_event.pull_request_id = pending_reviewer.pull_request_id
+                AND pull_request_event.kind = 'reviewed'
+                AND pull_request_event.author = pending_reviewer.username
+            )
+            GROUP BY pending_reviewer.username
+            ORDER BY pending_reviewer.username
+        "#,
+        )
         .bind(owner)
         .bind(name)
-        .bind(number as i32)
-        .fetch_one(&self.pool)
+        .bind(excluded_number as i32)
+        .fetch_all(&self.pool)
         .await
-        .map_err(|e| Self::wrap_unknown_pull_request(e, owner, name, number))?
-        .get(0);
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
 
-        self.pull_requests_get_from_id_expect(new_id as u64).await
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.get(0), row.get::<i64, _>(1) as u64))
+            .collect())
     }
 
     /////////////////////
@@ -1243,14 +2372,16 @@ impl DbService for PostgresDb {
                     repository_id,
                     name,
                     conditions,
-                    actions
+                    actions,
+                    priority
                 )
                 VALUES
                 (
                     $1,
                     $2,
                     $3,
-                    $4
+                    $4,
+                    $5
                 )
                 RETURNING id;
                 "#,
@@ -1259,6 +2390,7 @@ impl DbService for PostgresDb {
         .bind(rule.name)
         .bind(serde_json::to_string(&rule.conditions).unwrap())
         .bind(serde_json::to_string(&rule.actions).unwrap())
+        .bind(rule.priority)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
@@ -1278,14 +2410,16 @@ impl DbService for PostgresDb {
             r#"
             UPDATE pull_request_rule
             SET conditions = $1,
-            actions = $2
-            WHERE repository_id = $3
-            AND name = $4
+            actions = $2,
+            priority = $3
+            WHERE repository_id = $4
+            AND name = $5
             RETURNING id
         "#,
         )
         .bind(serde_json::to_string(&rule.conditions).unwrap())
         .bind(serde_json::to_string(&rule.actions).unwrap())
+        .bind(rule.priority)
         .bind(rule.repository_id as i32)
         .bind(&rule.name)
         .fetch_one(&self.pool)
@@ -1342,7 +2476,23 @@ impl DbService for PostgresDb {
                 default_needed_reviewers_count,
                 default_automerge,
                 default_enable_qa,
-                default_enable_checks
+                default_enable_checks,
+                ci_app_slugs,
+                required_checks,
+                optional_checks,
+                native_merge_queue,
+                config_sha,
+                merge_commit_title_template,
+                merge_commit_message_template,
+                conventional_commits,
+                stale_threshold_days,
+                stale_convert_to_draft,
+                codeowners_reviewers,
+                reviewer_pool,
+                reviewer_pool_count,
+                reviewer_assignment_strategy,
+                reviewer_pool_cursor,
+                stale_approval_policy
             )
             VALUES
             (
@@ -1354,7 +2504,23 @@ impl DbService for PostgresDb {
                 $6,
                 $7,
                 $8,
-                $9
+                $9,
+                $10,
+                $11,
+                $12,
+                $13,
+                $14,
+                $15,
+                $16,
+                $17,
+                $18,
+                $19,
+                $20,
+                $21,
+                $22,
+                $23,
+                $24,
+                $25
             )
             RETURNING id
             ;
@@ -1369,6 +2535,22 @@ impl DbService for PostgresDb {
         .bind(instance.default_automerge)
         .bind(instance.default_enable_qa)
         .bind(instance.default_enable_checks)
+        .bind(instance.ci_app_slugs)
+        .bind(instance.required_checks)
+        .bind(instance.optional_checks)
+        .bind(instance.native_merge_queue)
+        .bind(instance.config_sha)
+        .bind(instance.merge_commit_title_template)
+        .bind(instance.merge_commit_message_template)
+        .bind(instance.conventional_commits)
+        .bind(instance.stale_threshold_days as i32)
+        .bind(instance.stale_convert_to_draft)
+        .bind(instance.codeowners_reviewers)
+        .bind(instance.reviewer_pool)
+        .bind(instance.reviewer_pool_count as i32)
+        .bind(instance.reviewer_assignment_strategy.to_string())
+        .bind(instance.reviewer_pool_cursor as i32)
+        .bind(instance.stale_approval_policy.to_string())
         .fetch_one(&self.pool)
         .await
         .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
@@ -1392,8 +2574,24 @@ impl DbService for PostgresDb {
             default_needed_reviewers_count = $6,
             default_automerge = $7,
             default_enable_qa = $8,
-            default_enable_checks = $9
-            WHERE id = $10
+            default_enable_checks = $9,
+            ci_app_slugs = $10,
+            required_checks = $11,
+            optional_checks = $12,
+            native_merge_queue = $13,
+            config_sha = $14,
+            merge_commit_title_template = $15,
+            merge_commit_message_template = $16,
+            conventional_commits = $17,
+            stale_threshold_days = $18,
+            stale_convert_to_draft = $19,
+            codeowners_reviewers = $20,
+            reviewer_pool = $21,
+            reviewer_pool_count = $22,
+            reviewer_assignment_strategy = $23,
+            reviewer_pool_cursor = $24,
+            stale_approval_policy = $25
+            WHERE id = $26
             RETURNING id
             ;
         "#,
@@ -1407,87 +2605,393 @@ impl DbService for PostgresDb {
         .bind(instance.default_automerge)
         .bind(instance.default_enable_qa)
         .bind(instance.default_enable_checks)
+        .bind(instance.ci_app_slugs)
+        .bind(instance.required_checks)
+        .bind(instance.optional_checks)
+        .bind(instance.native_merge_queue)
+        .bind(instance.config_sha)
+        .bind(instance.merge_commit_title_template)
+        .bind(instance.merge_commit_message_template)
+        .bind(instance.conventional_commits)
+        .bind(instance.stale_threshold_days as i32)
+        .bind(instance.stale_convert_to_draft)
+        .bind(instance.codeowners_reviewers)
+        .bind(instance.reviewer_pool)
+        .bind(instance.reviewer_pool_count as i32)
+        .bind(instance.reviewer_assignment_strategy.to_string())
+        .bind(instance.reviewer_pool_cursor as i32)
+        .bind(instance.stale_approval_policy.to_string())
         .bind(instance.id as i32)
         .fetch_one(&self.pool)
         .await
-        .map_err(|e| Self::wrap_unknown_repository(e, &instance.owner, &instance.name))?
+        .map_err(|e| Self::wrap_unknown_repository(e, &instance.owner, &instance.name))?
+        .get(0);
+
+        self.repositories_get_from_id(new_id as u64)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_all(&self) -> Result<Vec<Repository>> {
+        let rows = sqlx::query_as::<_, RepositoryRow>(
+            r#"
+                SELECT *
+                FROM repository
+                ORDER BY id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_get(&self, owner: &str, name: &str) -> Result<Option<Repository>> {
+        let row = sqlx::query_as::<_, RepositoryRow>(
+            r#"
+            SELECT *
+            FROM repository
+            WHERE owner = $1
+            AND name = $2
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_get_from_id(&self, id: u64) -> Result<Option<Repository>> {
+        let row = sqlx::query_as::<_, RepositoryRow>(
+            r#"
+            SELECT *
+            FROM repository
+            WHERE id = $1
+        "#,
+        )
+        .bind(id as i32)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_delete(&self, owner: &str, name: &str) -> Result<bool> {
+        sqlx::query(
+            r#"
+            DELETE FROM repository
+            WHERE owner = $1 AND name = $2
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected() > 0)
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_set_manual_interaction(
+        &self,
+        owner: &str,
+        name: &str,
+        value: bool,
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
+            r#"
+            UPDATE repository
+            SET manual_interaction = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
+        "#,
+        )
+        .bind(value)
+        .bind(owner)
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
+        .get(0);
+
+        self.repositories_get_from_id(id as u64)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_set_pr_title_validation_regex(
+        &self,
+        owner: &str,
+        name: &str,
+        value: &str,
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
+            r#"
+            UPDATE repository
+            SET pr_title_validation_regex = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
+        "#,
+        )
+        .bind(value)
+        .bind(owner)
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
+        .get(0);
+
+        self.repositories_get_from_id(id as u64)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_set_default_strategy(
+        &self,
+        owner: &str,
+        name: &str,
+        strategy: MergeStrategy,
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
+            r#"
+            UPDATE repository
+            SET default_strategy = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
+        "#,
+        )
+        .bind(strategy.to_string())
+        .bind(owner)
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
+        .get(0);
+
+        self.repositories_get_from_id(id as u64)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_set_default_needed_reviewers_count(
+        &self,
+        owner: &str,
+        name: &str,
+        count: u64,
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
+            r#"
+            UPDATE repository
+            SET default_needed_reviewers_count = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
+        "#,
+        )
+        .bind(count as i32)
+        .bind(owner)
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
+        .get(0);
+
+        self.repositories_get_from_id(id as u64)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_set_default_automerge(
+        &self,
+        owner: &str,
+        name: &str,
+        value: bool,
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
+            r#"
+            UPDATE repository
+            SET default_automerge = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
+        "#,
+        )
+        .bind(value)
+        .bind(owner)
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
+        .get(0);
+
+        self.repositories_get_from_id(id as u64)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repositories_set_default_enable_qa(
+        &self,
+        owner: &str,
+        name: &str,
+        value: bool,
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
+            r#"
+            UPDATE repository
+            SET default_enable_qa = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
+        "#,
+        )
+        .bind(value)
+        .bind(owner)
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
         .get(0);
 
-        self.repositories_get_from_id(new_id as u64)
+        self.repositories_get_from_id(id as u64)
             .await
             .map(|x| x.unwrap())
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_all(&self) -> Result<Vec<Repository>> {
-        let rows = sqlx::query_as::<_, RepositoryRow>(
+    async fn repositories_set_default_enable_checks(
+        &self,
+        owner: &str,
+        name: &str,
+        value: bool,
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
             r#"
-                SELECT *
-                FROM repository
-                ORDER BY id
-            "#,
+            UPDATE repository
+            SET default_enable_checks = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
+        "#,
         )
-        .fetch_all(&self.pool)
+        .bind(value)
+        .bind(owner)
+        .bind(name)
+        .fetch_one(&self.pool)
         .await
-        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
+        .get(0);
 
-        Ok(rows.into_iter().map(Into::into).collect())
+        self.repositories_get_from_id(id as u64)
+            .await
+            .map(|x| x.unwrap())
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_get(&self, owner: &str, name: &str) -> Result<Option<Repository>> {
-        let row = sqlx::query_as::<_, RepositoryRow>(
+    async fn repositories_set_ci_app_slugs(
+        &self,
+        owner: &str,
+        name: &str,
+        values: &[String],
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
             r#"
-            SELECT *
-            FROM repository
-            WHERE owner = $1
-            AND name = $2
+            UPDATE repository
+            SET ci_app_slugs = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
         "#,
         )
+        .bind(values)
         .bind(owner)
         .bind(name)
-        .fetch_optional(&self.pool)
+        .fetch_one(&self.pool)
         .await
-        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
+        .get(0);
 
-        Ok(row.map(Into::into))
+        self.repositories_get_from_id(id as u64)
+            .await
+            .map(|x| x.unwrap())
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_get_from_id(&self, id: u64) -> Result<Option<Repository>> {
-        let row = sqlx::query_as::<_, RepositoryRow>(
+    async fn repositories_set_required_checks(
+        &self,
+        owner: &str,
+        name: &str,
+        values: &[String],
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
             r#"
-            SELECT *
-            FROM repository
-            WHERE id = $1
+            UPDATE repository
+            SET required_checks = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
         "#,
         )
-        .bind(id as i32)
-        .fetch_optional(&self.pool)
+        .bind(values)
+        .bind(owner)
+        .bind(name)
+        .fetch_one(&self.pool)
         .await
-        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
+        .get(0);
 
-        Ok(row.map(Into::into))
+        self.repositories_get_from_id(id as u64)
+            .await
+            .map(|x| x.unwrap())
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_delete(&self, owner: &str, name: &str) -> Result<bool> {
-        sqlx::query(
+    async fn repositories_set_optional_checks(
+        &self,
+        owner: &str,
+        name: &str,
+        values: &[String],
+    ) -> Result<Repository> {
+        let id: i32 = sqlx::query(
             r#"
-            DELETE FROM repository
-            WHERE owner = $1 AND name = $2
+            UPDATE repository
+            SET optional_checks = $1
+            WHERE owner = $2
+            AND name = $3
+            RETURNING id
         "#,
         )
+        .bind(values)
         .bind(owner)
         .bind(name)
-        .execute(&self.pool)
+        .fetch_one(&self.pool)
         .await
-        .map(|x| x.rows_affected() > 0)
-        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+        .map_err(|e| Self::wrap_unknown_repository(e, owner, name))?
+        .get(0);
+
+        self.repositories_get_from_id(id as u64)
+            .await
+            .map(|x| x.unwrap())
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_set_manual_interaction(
+    async fn repositories_set_native_merge_queue(
         &self,
         owner: &str,
         name: &str,
@@ -1496,7 +3000,7 @@ impl DbService for PostgresDb {
         let id: i32 = sqlx::query(
             r#"
             UPDATE repository
-            SET manual_interaction = $1
+            SET native_merge_queue = $1
             WHERE owner = $2
             AND name = $3
             RETURNING id
@@ -1516,22 +3020,28 @@ impl DbService for PostgresDb {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_set_pr_title_validation_regex(
+    async fn repositories_set_merge_commit_settings(
         &self,
         owner: &str,
         name: &str,
-        value: &str,
+        title_template: &str,
+        message_template: &str,
+        conventional_commits: bool,
     ) -> Result<Repository> {
         let id: i32 = sqlx::query(
             r#"
             UPDATE repository
-            SET pr_title_validation_regex = $1
-            WHERE owner = $2
-            AND name = $3
+            SET merge_commit_title_template = $1,
+            merge_commit_message_template = $2,
+            conventional_commits = $3
+            WHERE owner = $4
+            AND name = $5
             RETURNING id
         "#,
         )
-        .bind(value)
+        .bind(title_template)
+        .bind(message_template)
+        .bind(conventional_commits)
         .bind(owner)
         .bind(name)
         .fetch_one(&self.pool)
@@ -1545,22 +3055,25 @@ impl DbService for PostgresDb {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_set_default_strategy(
+    async fn repositories_set_stale_settings(
         &self,
         owner: &str,
         name: &str,
-        strategy: MergeStrategy,
+        threshold_days: u64,
+        convert_to_draft: bool,
     ) -> Result<Repository> {
         let id: i32 = sqlx::query(
             r#"
             UPDATE repository
-            SET default_strategy = $1
-            WHERE owner = $2
-            AND name = $3
+            SET stale_threshold_days = $1,
+            stale_convert_to_draft = $2
+            WHERE owner = $3
+            AND name = $4
             RETURNING id
         "#,
         )
-        .bind(strategy.to_string())
+        .bind(threshold_days as i32)
+        .bind(convert_to_draft)
         .bind(owner)
         .bind(name)
         .fetch_one(&self.pool)
@@ -1574,22 +3087,22 @@ impl DbService for PostgresDb {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_set_default_needed_reviewers_count(
+    async fn repositories_set_codeowners_reviewers(
         &self,
         owner: &str,
         name: &str,
-        count: u64,
+        value: bool,
     ) -> Result<Repository> {
         let id: i32 = sqlx::query(
             r#"
             UPDATE repository
-            SET default_needed_reviewers_count = $1
+            SET codeowners_reviewers = $1
             WHERE owner = $2
             AND name = $3
             RETURNING id
         "#,
         )
-        .bind(count as i32)
+        .bind(value)
         .bind(owner)
         .bind(name)
         .fetch_one(&self.pool)
@@ -1603,22 +3116,28 @@ impl DbService for PostgresDb {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_set_default_automerge(
+    async fn repositories_set_reviewer_pool(
         &self,
         owner: &str,
         name: &str,
-        value: bool,
+        reviewers: &[String],
+        count: u64,
+        strategy: ReviewerAssignmentStrategy,
     ) -> Result<Repository> {
         let id: i32 = sqlx::query(
             r#"
             UPDATE repository
-            SET default_automerge = $1
-            WHERE owner = $2
-            AND name = $3
+            SET reviewer_pool = $1,
+            reviewer_pool_count = $2,
+            reviewer_assignment_strategy = $3
+            WHERE owner = $4
+            AND name = $5
             RETURNING id
         "#,
         )
-        .bind(value)
+        .bind(reviewers)
+        .bind(count as i32)
+        .bind(strategy.to_string())
         .bind(owner)
         .bind(name)
         .fetch_one(&self.pool)
@@ -1632,22 +3151,22 @@ impl DbService for PostgresDb {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_set_default_enable_qa(
+    async fn repositories_set_stale_approval_policy(
         &self,
         owner: &str,
         name: &str,
-        value: bool,
+        value: StaleApprovalPolicy,
     ) -> Result<Repository> {
         let id: i32 = sqlx::query(
             r#"
             UPDATE repository
-            SET default_enable_qa = $1
+            SET stale_approval_policy = $1
             WHERE owner = $2
             AND name = $3
             RETURNING id
         "#,
         )
-        .bind(value)
+        .bind(value.to_string())
         .bind(owner)
         .bind(name)
         .fetch_one(&self.pool)
@@ -1661,22 +3180,22 @@ impl DbService for PostgresDb {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn repositories_set_default_enable_checks(
+    async fn repositories_set_reviewer_pool_cursor(
         &self,
         owner: &str,
         name: &str,
-        value: bool,
+        value: u64,
     ) -> Result<Repository> {
         let id: i32 = sqlx::query(
             r#"
             UPDATE repository
-            SET default_enable_checks = $1
+            SET reviewer_pool_cursor = $1
             WHERE owner = $2
             AND name = $3
             RETURNING id
         "#,
         )
-        .bind(value)
+        .bind(value as i32)
         .bind(owner)
         .bind(name)
         .fetch_one(&self.pool)
@@ -1827,4 +3346,175 @@ impl DbService for PostgresDb {
 
         Ok(rows.into_iter().map(Into::into).collect())
     }
+
+    ///////////////////
+    // Step transitions
+
+    #[tracing::instrument(skip(self))]
+    async fn step_transitions_create(&self, instance: StepTransition) -> Result<StepTransition> {
+        self.pull_requests_get_from_id_expect(instance.pull_request_id)
+            .await?;
+
+        let new_id: i32 = sqlx::query(
+            r#"
+            INSERT INTO step_transition
+            (
+                pull_request_id,
+                step,
+                created_at
+            )
+            VALUES
+            (
+                $1,
+                $2,
+                $3
+            )
+            RETURNING id
+            ;
+        "#,
+        )
+        .bind(instance.pull_request_id as i32)
+        .bind(instance.step.to_str())
+        .bind(instance.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?
+        .get(0);
+
+        self.step_transitions_get_from_id(new_id)
+            .await
+            .map(|x| x.unwrap())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn step_transitions_list(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<StepTransition>> {
+        let rows = sqlx::query_as::<_, StepTransitionRow>(
+            r#"
+            SELECT step_transition.*
+            FROM step_transition
+            INNER JOIN pull_request ON (pull_request.id = step_transition.pull_request_id)
+            INNER JOIN repository ON (repository.id = pull_request.repository_id)
+            WHERE repository.owner = $1
+            AND repository.name = $2
+            AND pull_request.number = $3
+            ORDER BY step_transition.created_at, step_transition.id
+        "#,
+        )
+        .bind(owner)
+        .bind(name)
+        .bind(number as i32)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(self, instance), fields(delivery_id = instance.delivery_id))]
+    async fn webhook_deliveries_create(
+        &self,
+        instance: WebhookDelivery,
+    ) -> Result<WebhookDelivery> {
+        let delivery_id = instance.delivery_id.clone();
+
+        sqlx::query(
+            r#"
+                INSERT INTO webhook_delivery
+                (
+                    delivery_id,
+                    event_type,
+                    headers,
+                    body,
+                    outcome,
+                    error,
+                    received_at
+                )
+                VALUES
+                (
+                    $1,
+                    $2,
+                    $3,
+                    $4,
+                    $5,
+                    $6,
+                    $7
+                )
+            "#,
+        )
+        .bind(instance.delivery_id)
+        .bind(instance.event_type)
+        .bind(serde_json::to_string(&instance.headers).unwrap())
+        .bind(instance.body)
+        .bind(instance.outcome.to_str())
+        .bind(instance.error)
+        .bind(instance.received_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        self.webhook_deliveries_get_expect(&delivery_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn webhook_deliveries_get(&self, delivery_id: &str) -> Result<Option<WebhookDelivery>> {
+        let row = sqlx::query_as::<_, WebhookDeliveryRow>(
+            r#"
+            SELECT *
+            FROM webhook_delivery
+            WHERE delivery_id = $1
+        "#,
+        )
+        .bind(delivery_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn webhook_deliveries_set_outcome(
+        &self,
+        delivery_id: &str,
+        outcome: WebhookDeliveryOutcome,
+        error: Option<String>,
+    ) -> Result<WebhookDelivery> {
+        sqlx::query(
+            r#"
+            UPDATE webhook_delivery
+            SET outcome = $1,
+            error = $2
+            WHERE delivery_id = $3
+            RETURNING id
+        "#,
+        )
+        .bind(outcome.to_str())
+        .bind(error)
+        .bind(delivery_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Self::wrap_unknown_webhook_delivery(e, delivery_id))?;
+
+        self.webhook_deliveries_get_expect(delivery_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn webhook_deliveries_purge(&self, received_before: OffsetDateTime) -> Result<u64> {
+        sqlx::query(
+            r#"
+            DELETE FROM webhook_delivery
+            WHERE received_at < $1
+        "#,
+        )
+        .bind(received_before)
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+        .map_err(|e| DatabaseError::ImplementationError { source: e.into() })
+    }
 }
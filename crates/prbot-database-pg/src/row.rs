@@ -1,11 +1,16 @@
 use prbot_models::{
-    Account, ExternalAccount, ExternalAccountRight, MergeRule, PullRequest, PullRequestRule,
-    Repository, RequiredReviewer,
+    Account, AuditEntry, AwayUser, ExternalAccount, ExternalAccountRight, Job, MergeFreeze,
+    MergeQueueEntry, MergeRule, MergeWindow, PullRequest, PullRequestEvent, PullRequestRule,
+    Repository, RequiredReviewer, StepTransition, WebhookDelivery,
 };
 use sqlx::{postgres::PgRow, FromRow, Row};
+use time::UtcOffset;
 
 use crate::fields::{
-    MergeStrategyDecode, QaStatusDecode, RuleActionsDecode, RuleBranchDecode, RuleConditionsDecode,
+    AuditSourceDecode, HeadersDecode, JobKindDecode, JobStatusDecode, MergeQueueStatusDecode,
+    MergeStrategyDecode, PullRequestEventKindDecode, QaStatusDecode,
+    ReviewerAssignmentStrategyDecode, RuleActionsDecode, RuleBranchDecode, RuleConditionsDecode,
+    StaleApprovalPolicyDecode, StepLabelDecode, WebhookDeliveryOutcomeDecode,
 };
 
 pub(crate) struct AccountRow(Account);
@@ -16,6 +21,15 @@ pub(crate) struct PullRequestRow(PullRequest);
 pub(crate) struct RepositoryRow(Repository);
 pub(crate) struct RequiredReviewerRow(RequiredReviewer);
 pub(crate) struct PullRequestRuleRow(PullRequestRule);
+pub(crate) struct WebhookDeliveryRow(WebhookDelivery);
+pub(crate) struct JobRow(Job);
+pub(crate) struct MergeQueueEntryRow(MergeQueueEntry);
+pub(crate) struct MergeFreezeRow(MergeFreeze);
+pub(crate) struct MergeWindowRow(MergeWindow);
+pub(crate) struct StepTransitionRow(StepTransition);
+pub(crate) struct PullRequestEventRow(PullRequestEvent);
+pub(crate) struct AuditEntryRow(AuditEntry);
+pub(crate) struct AwayUserRow(AwayUser);
 
 impl From<AccountRow> for Account {
     fn from(r: AccountRow) -> Self {
@@ -65,6 +79,60 @@ impl From<PullRequestRuleRow> for PullRequestRule {
     }
 }
 
+impl From<WebhookDeliveryRow> for WebhookDelivery {
+    fn from(r: WebhookDeliveryRow) -> Self {
+        r.0
+    }
+}
+
+impl From<JobRow> for Job {
+    fn from(r: JobRow) -> Self {
+        r.0
+    }
+}
+
+impl From<MergeQueueEntryRow> for MergeQueueEntry {
+    fn from(r: MergeQueueEntryRow) -> Self {
+        r.0
+    }
+}
+
+impl From<MergeFreezeRow> for MergeFreeze {
+    fn from(r: MergeFreezeRow) -> Self {
+        r.0
+    }
+}
+
+impl From<MergeWindowRow> for MergeWindow {
+    fn from(r: MergeWindowRow) -> Self {
+        r.0
+    }
+}
+
+impl From<AwayUserRow> for AwayUser {
+    fn from(r: AwayUserRow) -> Self {
+        r.0
+    }
+}
+
+impl From<AuditEntryRow> for AuditEntry {
+    fn from(r: AuditEntryRow) -> Self {
+        r.0
+    }
+}
+
+impl From<PullRequestEventRow> for PullRequestEvent {
+    fn from(r: PullRequestEventRow) -> Self {
+        r.0
+    }
+}
+
+impl From<StepTransitionRow> for StepTransition {
+    fn from(r: StepTransitionRow) -> Self {
+        r.0
+    }
+}
+
 impl<'r> FromRow<'r, PgRow> for AccountRow {
     fn from_row(row: &'r PgRow) -> core::result::Result<Self, sqlx::Error> {
         Ok(Self(Account {
@@ -100,6 +168,8 @@ impl<'r> FromRow<'r, PgRow> for MergeRuleRow {
             base_branch: row.try_get::<RuleBranchDecode, _>("base_branch")?.clone(),
             head_branch: row.try_get::<RuleBranchDecode, _>("head_branch")?.clone(),
             strategy: *row.try_get::<MergeStrategyDecode, _>("strategy")?,
+            commit_title_template: row.try_get("commit_title_template")?,
+            commit_message_template: row.try_get("commit_message_template")?,
         }))
     }
 }
@@ -119,6 +189,8 @@ impl<'r> FromRow<'r, PgRow> for PullRequestRow {
             strategy_override: row
                 .try_get::<Option<MergeStrategyDecode>, _>("strategy_override")?
                 .map(Into::into),
+            snoozed_until: row.try_get("snoozed_until")?,
+            stale_reminded_at: row.try_get("stale_reminded_at")?,
         }))
     }
 }
@@ -138,6 +210,24 @@ impl<'r> FromRow<'r, PgRow> for RepositoryRow {
             default_automerge: row.try_get("default_automerge")?,
             default_enable_qa: row.try_get("default_enable_qa")?,
             default_enable_checks: row.try_get("default_enable_checks")?,
+            ci_app_slugs: row.try_get("ci_app_slugs")?,
+            required_checks: row.try_get("required_checks")?,
+            optional_checks: row.try_get("optional_checks")?,
+            native_merge_queue: row.try_get("native_merge_queue")?,
+            config_sha: row.try_get("config_sha")?,
+            merge_commit_title_template: row.try_get("merge_commit_title_template")?,
+            merge_commit_message_template: row.try_get("merge_commit_message_template")?,
+            conventional_commits: row.try_get("conventional_commits")?,
+            stale_threshold_days: row.try_get::<i32, _>("stale_threshold_days")? as u64,
+            stale_convert_to_draft: row.try_get("stale_convert_to_draft")?,
+            codeowners_reviewers: row.try_get("codeowners_reviewers")?,
+            reviewer_pool: row.try_get("reviewer_pool")?,
+            reviewer_pool_count: row.try_get::<i32, _>("reviewer_pool_count")? as u64,
+            reviewer_assignment_strategy: *row
+                .try_get::<ReviewerAssignmentStrategyDecode, _>("reviewer_assignment_strategy")?,
+            reviewer_pool_cursor: row.try_get::<i32, _>("reviewer_pool_cursor")? as u64,
+            stale_approval_policy: *row
+                .try_get::<StaleApprovalPolicyDecode, _>("stale_approval_policy")?,
         }))
     }
 }
@@ -158,6 +248,141 @@ impl<'r> FromRow<'r, PgRow> for PullRequestRuleRow {
             name: row.try_get("name")?,
             conditions: (*row.try_get::<RuleConditionsDecode, _>("conditions")?).clone(),
             actions: (*row.try_get::<RuleActionsDecode, _>("actions")?).clone(),
+            priority: row.try_get("priority")?,
+        }))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for WebhookDeliveryRow {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self(WebhookDelivery {
+            id: row.try_get::<i32, _>("id")? as u64,
+            delivery_id: row.try_get("delivery_id")?,
+            event_type: row.try_get("event_type")?,
+            headers: row.try_get::<HeadersDecode, _>("headers")?.into(),
+            body: row.try_get("body")?,
+            outcome: *row.try_get::<WebhookDeliveryOutcomeDecode, _>("outcome")?,
+            error: row.try_get("error")?,
+            received_at: row.try_get("received_at")?,
+        }))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for JobRow {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self(Job {
+            id: row.try_get::<i32, _>("id")? as u64,
+            kind: *row.try_get::<JobKindDecode, _>("kind")?,
+            payload: row.try_get("payload")?,
+            status: *row.try_get::<JobStatusDecode, _>("status")?,
+            attempts: row.try_get::<i32, _>("attempts")? as u64,
+            max_attempts: row.try_get::<i32, _>("max_attempts")? as u64,
+            last_error: row.try_get("last_error")?,
+            delivery_id: row.try_get("delivery_id")?,
+            locked_by: row.try_get("locked_by")?,
+            locked_until: row.try_get("locked_until")?,
+            run_at: row.try_get("run_at")?,
+            created_at: row.try_get("created_at")?,
+        }))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for MergeQueueEntryRow {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self(MergeQueueEntry {
+            id: row.try_get::<i32, _>("id")? as u64,
+            repository_id: row.try_get::<i32, _>("repository_id")? as u64,
+            pull_request_number: row.try_get::<i32, _>("pull_request_number")? as u64,
+            base_branch: row.try_get("base_branch")?,
+            status: *row.try_get::<MergeQueueStatusDecode, _>("status")?,
+            staging_sha: row.try_get("staging_sha")?,
+            queued_at: row.try_get("queued_at")?,
+        }))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for MergeFreezeRow {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self(MergeFreeze {
+            repository_id: row.try_get::<i32, _>("repository_id")? as u64,
+            base_branch: row.try_get::<RuleBranchDecode, _>("base_branch")?.clone(),
+            until: row.try_get("frozen_until")?,
+            reason: row.try_get("reason")?,
+            created_by: row.try_get("created_by")?,
+            created_at: row.try_get("created_at")?,
+        }))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for MergeWindowRow {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let days: String = row.try_get("days")?;
+
+        Ok(Self(MergeWindow {
+            id: row.try_get::<i32, _>("id")? as u64,
+            repository_id: row.try_get::<i32, _>("repository_id")? as u64,
+            base_branch: row.try_get::<RuleBranchDecode, _>("base_branch")?.clone(),
+            days: MergeWindow::parse_days(&days).ok_or_else(|| sqlx::Error::ColumnDecode {
+                index: "days".into(),
+                source: format!("Invalid merge window days: '{days}'").into(),
+            })?,
+            start_time: row.try_get("start_time")?,
+            end_time: row.try_get("end_time")?,
+            utc_offset: UtcOffset::from_whole_seconds(row.try_get("utc_offset")?).map_err(|e| {
+                sqlx::Error::ColumnDecode {
+                    index: "utc_offset".into(),
+                    source: e.into(),
+                }
+            })?,
+        }))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for StepTransitionRow {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self(StepTransition {
+            id: row.try_get::<i32, _>("id")? as u64,
+            pull_request_id: row.try_get::<i32, _>("pull_request_id")? as u64,
+            step: *row.try_get::<StepLabelDecode, _>("step")?,
+            created_at: row.try_get("created_at")?,
+        }))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for PullRequestEventRow {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self(PullRequestEvent {
+            id: row.try_get::<i32, _>("id")? as u64,
+            pull_request_id: row.try_get::<i32, _>("pull_request_id")? as u64,
+            kind: *row.try_get::<PullRequestEventKindDecode, _>("kind")?,
+            author: row.try_get("author")?,
+            details: row.try_get("details")?,
+            created_at: row.try_get("created_at")?,
+        }))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for AuditEntryRow {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self(AuditEntry {
+            id: row.try_get::<i32, _>("id")? as u64,
+            source: *row.try_get::<AuditSourceDecode, _>("source")?,
+            actor: row.try_get("actor")?,
+            action: row.try_get("action")?,
+            target: row.try_get("target")?,
+            repository_path: row.try_get("repository_path")?,
+            old_value: row.try_get("old_value")?,
+            new_value: row.try_get("new_value")?,
+            created_at: row.try_get("created_at")?,
+        }))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for AwayUserRow {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        Ok(Self(AwayUser {
+            username: row.try_get("username")?,
+            created_at: row.try_get("created_at")?,
         }))
     }
 }
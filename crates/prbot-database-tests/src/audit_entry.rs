@@ -0,0 +1,78 @@
+use prbot_models::{AuditEntry, AuditSource, Repository};
+use time::OffsetDateTime;
+
+use crate::testcase::db_test_case;
+
+#[tokio::test]
+async fn create_and_list() {
+    db_test_case("audit_entry_create_and_list", |db| async move {
+        assert_eq!(db.audit_entries_all().await?, vec![]);
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        let automerge = db
+            .audit_entries_create(AuditEntry {
+                source: AuditSource::Comment,
+                actor: "me".into(),
+                action: "set_default_automerge".into(),
+                target: "me/repo".into(),
+                repository_path: Some("me/repo".into()),
+                old_value: Some("false".into()),
+                new_value: Some("true".into()),
+                created_at: OffsetDateTime::from_unix_timestamp(2_000)?,
+                ..Default::default()
+            })
+            .await?;
+        let admin = db
+            .audit_entries_create(AuditEntry {
+                source: AuditSource::Cli,
+                actor: "root".into(),
+                action: "set_admin_right".into(),
+                target: "me".into(),
+                new_value: Some("true".into()),
+                created_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            db.audit_entries_all().await?,
+            vec![admin.clone(), automerge.clone()]
+        );
+        assert_eq!(
+            db.audit_entries_list(Some("me/repo"), None, None).await?,
+            vec![automerge.clone()]
+        );
+        assert_eq!(
+            db.audit_entries_list(None, Some("root"), None).await?,
+            vec![admin.clone()]
+        );
+        assert_eq!(
+            db.audit_entries_list(
+                None,
+                None,
+                Some(OffsetDateTime::from_unix_timestamp(1_500)?)
+            )
+            .await?,
+            vec![automerge.clone()]
+        );
+        assert_eq!(
+            db.audit_entries_list(Some("me/repo"), Some("root"), None)
+                .await?,
+            vec![]
+        );
+
+        // Entries are kept when their repository is removed
+        db.repositories_delete(&repo.owner, &repo.name).await?;
+        assert_eq!(db.audit_entries_all().await?, vec![admin, automerge]);
+
+        Ok(())
+    })
+    .await;
+}
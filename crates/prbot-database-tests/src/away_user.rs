@@ -0,0 +1,40 @@
+use prbot_models::AwayUser;
+
+use crate::testcase::db_test_case;
+
+#[tokio::test]
+async fn create_and_delete() {
+    db_test_case("away_user_create_and_delete", |db| async move {
+        assert_eq!(db.away_users_get("me").await?, None);
+
+        let user = db
+            .away_users_create(AwayUser {
+                username: "me".into(),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(user.username, "me");
+        assert_eq!(db.away_users_get("me").await?, Some(user.clone()));
+
+        db.away_users_create(AwayUser {
+            username: "another".into(),
+            ..Default::default()
+        })
+        .await?;
+        assert_eq!(
+            db.away_users_all()
+                .await?
+                .into_iter()
+                .map(|u| u.username)
+                .collect::<Vec<_>>(),
+            vec!["another", "me"]
+        );
+
+        assert!(db.away_users_delete("me").await?);
+        assert!(!db.away_users_delete("me").await?);
+        assert_eq!(db.away_users_get("me").await?, None);
+
+        Ok(())
+    })
+    .await;
+}
@@ -0,0 +1,273 @@
+use prbot_database_interface::DatabaseError;
+use prbot_models::{Job, JobKind, JobStatus};
+use time::{Duration, OffsetDateTime};
+
+use crate::testcase::db_test_case;
+
+#[tokio::test]
+async fn create() {
+    db_test_case("job_create", |db| async move {
+        let job = db
+            .jobs_create(Job {
+                kind: JobKind::HandleIssueCommentEvent,
+                payload: "{}".into(),
+                delivery_id: Some("abcd".into()),
+                run_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+                created_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(job.kind, JobKind::HandleIssueCommentEvent);
+        assert_eq!(job.payload, "{}");
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(job.attempts, 0);
+        assert_eq!(job.max_attempts, 5);
+        assert_eq!(job.last_error, None);
+        assert_eq!(job.delivery_id, Some("abcd".into()));
+
+        assert_eq!(db.jobs_get(job.id).await?, Some(job.clone()));
+        assert_eq!(db.jobs_get(job.id + 1).await?, None);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn create_for_delivery() {
+    db_test_case("job_create_for_delivery", |db| async move {
+        let job = db
+            .jobs_create(Job {
+                delivery_id: Some("abcd".into()),
+                ..Default::default()
+            })
+            .await?;
+
+        // A delivery sent again gets its existing job
+        assert_eq!(
+            db.jobs_create(Job {
+                payload: "{}".into(),
+                delivery_id: Some("abcd".into()),
+                ..Default::default()
+            })
+            .await?,
+            job
+        );
+
+        // Jobs without delivery are always created
+        db.jobs_create(Job::default()).await?;
+        db.jobs_create(Job::default()).await?;
+        assert_eq!(db.jobs_list(None).await?.len(), 3);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn update() {
+    db_test_case("job_update", |db| async move {
+        assert!(matches!(
+            db.jobs_update(Job {
+                id: 1234,
+                ..Default::default()
+            })
+            .await,
+            Err(DatabaseError::UnknownJob(1234))
+        ));
+
+        let job = db.jobs_create(Job::default()).await?;
+        let job = db
+            .jobs_update(Job {
+                status: JobStatus::DeadLetter,
+                attempts: 5,
+                last_error: Some("Oops".into()),
+                ..job
+            })
+            .await?;
+
+        assert_eq!(job.status, JobStatus::DeadLetter);
+        assert_eq!(job.attempts, 5);
+        assert_eq!(job.last_error, Some("Oops".into()));
+        assert_eq!(db.jobs_get_expect(job.id).await?, job);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn list() {
+    db_test_case("job_list", |db| async move {
+        let first = db.jobs_create(Job::default()).await?;
+        let second = db
+            .jobs_create(Job {
+                status: JobStatus::DeadLetter,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            db.jobs_list(None).await?,
+            vec![first.clone(), second.clone()]
+        );
+        assert_eq!(db.jobs_list(Some(JobStatus::Pending)).await?, vec![first]);
+        assert_eq!(
+            db.jobs_list(Some(JobStatus::DeadLetter)).await?,
+            vec![second]
+        );
+        assert_eq!(db.jobs_list(Some(JobStatus::Running)).await?, vec![]);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn claim_next() {
+    db_test_case("job_claim_next", |db| async move {
+        let later = db
+            .jobs_create(Job {
+                run_at: OffsetDateTime::from_unix_timestamp(3_000)?,
+                ..Default::default()
+            })
+            .await?;
+        let sooner = db
+            .jobs_create(Job {
+                run_at: OffsetDateTime::from_unix_timestamp(2_000)?,
+                ..Default::default()
+            })
+            .await?;
+        db.jobs_create(Job {
+            status: JobStatus::DeadLetter,
+            run_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+            ..Default::default()
+        })
+        .await?;
+
+        let now = OffsetDateTime::from_unix_timestamp(2_500)?;
+        let lease = OffsetDateTime::from_unix_timestamp(2_600)?;
+        let job = db.jobs_claim_next(now, "worker-1", lease).await?.unwrap();
+        assert_eq!(job.id, sooner.id);
+        assert_eq!(job.status, JobStatus::Running);
+        assert_eq!(job.attempts, 1);
+        assert_eq!(job.locked_by, Some("worker-1".into()));
+        assert_eq!(job.locked_until, Some(lease));
+
+        assert_eq!(db.jobs_claim_next(now, "worker-1", lease).await?, None);
+
+        let job = db
+            .jobs_claim_next(
+                OffsetDateTime::from_unix_timestamp(3_000)?,
+                "worker-2",
+                OffsetDateTime::from_unix_timestamp(3_100)?,
+            )
+            .await?
+            .unwrap();
+        assert_eq!(job.id, later.id);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn leases() {
+    db_test_case("job_leases", |db| async move {
+        let first = db.jobs_create(Job::default()).await?;
+        let second = db.jobs_create(Job::default()).await?;
+
+        let now = OffsetDateTime::now_utc();
+        db.jobs_claim_next(now, "worker-1", now + Duration::seconds(10))
+            .await?;
+        db.jobs_claim_next(now, "worker-2", now + Duration::seconds(10))
+            .await?;
+
+        // Only the lease owner can renew it
+        assert!(
+            db.jobs_renew_lease(first.id, "worker-1", now + Duration::seconds(60))
+                .await?
+        );
+        assert!(
+            !db.jobs_renew_lease(first.id, "worker-2", now + Duration::seconds(60))
+                .await?
+        );
+
+        // Jobs with a live lease are kept running
+        assert_eq!(db.jobs_requeue_expired(now).await?, 0);
+        assert_eq!(
+            db.jobs_requeue_expired(now + Duration::seconds(30)).await?,
+            1
+        );
+
+        let second = db.jobs_get_expect(second.id).await?;
+        assert_eq!(second.status, JobStatus::Pending);
+        assert_eq!(second.locked_by, None);
+        assert_eq!(second.locked_until, None);
+        assert_eq!(
+            db.jobs_get_expect(first.id).await?.status,
+            JobStatus::Running
+        );
+
+        // An expired job can no longer be renewed nor finished by its previous worker
+        assert!(
+            !db.jobs_renew_lease(second.id, "worker-2", now + Duration::seconds(60))
+                .await?
+        );
+        let succeeded = Job {
+            status: JobStatus::Succeeded,
+            locked_by: None,
+            locked_until: None,
+            ..second.clone()
+        };
+        assert_eq!(db.jobs_finish(succeeded.clone(), "worker-2").await?, None);
+        assert_eq!(db.jobs_get_expect(second.id).await?, second);
+
+        let first = db.jobs_get_expect(first.id).await?;
+        let succeeded = Job {
+            status: JobStatus::Succeeded,
+            locked_by: None,
+            locked_until: None,
+            ..first
+        };
+        assert_eq!(
+            db.jobs_finish(succeeded.clone(), "worker-1").await?,
+            Some(succeeded)
+        );
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn cancel() {
+    db_test_case("job_cancel", |db| async move {
+        assert!(!db.jobs_cancel(1).await?);
+
+        let pending = db.jobs_create(Job::default()).await?;
+        let running = db
+            .jobs_create(Job {
+                status: JobStatus::Running,
+                ..Default::default()
+            })
+            .await?;
+
+        // Only pending jobs are cancelled
+        assert!(db.jobs_cancel(pending.id).await?);
+        assert!(!db.jobs_cancel(pending.id).await?);
+        assert!(!db.jobs_cancel(running.id).await?);
+        assert_eq!(
+            db.jobs_get_expect(pending.id).await?.status,
+            JobStatus::Cancelled
+        );
+        assert_eq!(
+            db.jobs_get_expect(running.id).await?.status,
+            JobStatus::Running
+        );
+
+        Ok(())
+    })
+    .await;
+}
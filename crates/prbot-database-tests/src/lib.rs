@@ -3,18 +3,34 @@ mod testcase;
 #[cfg(test)]
 mod account;
 #[cfg(test)]
+mod audit_entry;
+#[cfg(test)]
+mod away_user;
+#[cfg(test)]
 mod external_account;
 #[cfg(test)]
 mod external_account_right;
 #[cfg(test)]
+mod job;
+#[cfg(test)]
+mod merge_freeze;
+#[cfg(test)]
+mod merge_queue_entry;
+#[cfg(test)]
 mod merge_rule;
 #[cfg(test)]
 mod pull_request;
 #[cfg(test)]
+mod pull_request_event;
+#[cfg(test)]
 mod pull_request_rule;
 #[cfg(test)]
 mod repository;
 #[cfg(test)]
 mod required_reviewer;
+#[cfg(test)]
+mod step_transition;
+#[cfg(test)]
+mod webhook_delivery;
 
 pub use testcase::{db_test_case, db_test_case_pg};
@@ -0,0 +1,179 @@
+use prbot_database_interface::DatabaseError;
+use prbot_models::{MergeFreeze, MergeWindow, Repository, RuleBranch};
+use time::{OffsetDateTime, Time, UtcOffset, Weekday};
+
+use crate::testcase::db_test_case;
+
+#[tokio::test]
+async fn freezes() {
+    db_test_case("merge_freeze_freezes", |db| async move {
+        assert!(matches!(
+            db.merge_freezes_set(MergeFreeze {
+                repository_id: 1,
+                ..Default::default()
+            })
+            .await,
+            Err(DatabaseError::UnknownRepositoryId(1))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        let freeze = db
+            .merge_freezes_set(
+                MergeFreeze {
+                    base_branch: "main".into(),
+                    until: OffsetDateTime::from_unix_timestamp(2_000)?,
+                    reason: Some("Release".into()),
+                    created_by: "me".into(),
+                    created_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+        let wildcard_freeze = db
+            .merge_freezes_set(
+                MergeFreeze {
+                    base_branch: RuleBranch::Wildcard,
+                    until: OffsetDateTime::from_unix_timestamp(2_000)?,
+                    created_by: "me".into(),
+                    created_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+        assert_eq!(
+            db.merge_freezes_list("me", "repo").await?,
+            vec![wildcard_freeze.clone(), freeze]
+        );
+
+        // Freezing again replaces the freeze
+        let freeze = db
+            .merge_freezes_set(
+                MergeFreeze {
+                    base_branch: "main".into(),
+                    until: OffsetDateTime::from_unix_timestamp(3_000)?,
+                    created_by: "other".into(),
+                    created_at: OffsetDateTime::from_unix_timestamp(1_500)?,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+        assert_eq!(freeze.reason, None);
+        assert_eq!(
+            db.merge_freezes_list("me", "repo").await?,
+            vec![wildcard_freeze.clone(), freeze]
+        );
+
+        assert!(db.merge_freezes_delete("me", "repo", "main".into()).await?);
+        assert!(!db.merge_freezes_delete("me", "repo", "main".into()).await?);
+        assert_eq!(
+            db.merge_freezes_list("me", "repo").await?,
+            vec![wildcard_freeze]
+        );
+        assert_eq!(db.merge_freezes_list("me", "other").await?, vec![]);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn windows() {
+    db_test_case("merge_freeze_windows", |db| async move {
+        assert!(matches!(
+            db.merge_windows_create(MergeWindow {
+                repository_id: 1,
+                ..Default::default()
+            })
+            .await,
+            Err(DatabaseError::UnknownRepositoryId(1))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        let window = db
+            .merge_windows_create(
+                MergeWindow {
+                    base_branch: "release/*".into(),
+                    days: vec![Weekday::Monday, Weekday::Friday],
+                    start_time: Time::from_hms(22, 0, 0)?,
+                    end_time: Time::from_hms(6, 30, 0)?,
+                    utc_offset: UtcOffset::from_hms(-5, -30, 0)?,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+        assert_eq!(window.base_branch, RuleBranch::Glob("release/*".into()));
+        assert_eq!(window.schedule(), "mon,fri 22:00-06:30 UTC-05:30");
+
+        let other_window = db
+            .merge_windows_create(
+                MergeWindow {
+                    base_branch: "main".into(),
+                    days: vec![Weekday::Saturday],
+                    start_time: Time::from_hms(9, 0, 0)?,
+                    end_time: Time::from_hms(12, 0, 0)?,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+        assert_eq!(
+            db.merge_windows_list("me", "repo").await?,
+            vec![window.clone(), other_window.clone()]
+        );
+
+        assert!(!db.merge_windows_delete("me", "other", window.id).await?);
+        assert!(db.merge_windows_delete("me", "repo", window.id).await?);
+        assert!(!db.merge_windows_delete("me", "repo", window.id).await?);
+        assert_eq!(
+            db.merge_windows_list("me", "repo").await?,
+            vec![other_window]
+        );
+
+        // Repository removal removes freezes and windows
+        db.merge_freezes_set(
+            MergeFreeze {
+                base_branch: "main".into(),
+                ..Default::default()
+            }
+            .with_repository(&repo),
+        )
+        .await?;
+        db.repositories_delete("me", "repo").await?;
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(
+            db.merge_freezes_list(&repo.owner, &repo.name).await?,
+            vec![]
+        );
+        assert_eq!(
+            db.merge_windows_list(&repo.owner, &repo.name).await?,
+            vec![]
+        );
+
+        Ok(())
+    })
+    .await;
+}
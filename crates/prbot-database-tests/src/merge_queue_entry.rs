@@ -0,0 +1,178 @@
+use prbot_database_interface::DatabaseError;
+use prbot_models::{MergeQueueEntry, MergeQueueStatus, Repository};
+use time::OffsetDateTime;
+
+use crate::testcase::db_test_case;
+
+#[tokio::test]
+async fn create() {
+    db_test_case("merge_queue_entry_create", |db| async move {
+        assert!(matches!(
+            db.merge_queue_entries_create(MergeQueueEntry {
+                repository_id: 1,
+                pull_request_number: 1,
+                ..Default::default()
+            })
+            .await,
+            Err(DatabaseError::UnknownRepositoryId(1))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        let entry = db
+            .merge_queue_entries_create(
+                MergeQueueEntry {
+                    pull_request_number: 1,
+                    base_branch: "main".into(),
+                    queued_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+
+        assert_eq!(entry.repository_id, repo.id);
+        assert_eq!(entry.pull_request_number, 1);
+        assert_eq!(entry.base_branch, "main");
+        assert_eq!(entry.status, MergeQueueStatus::Queued);
+        assert_eq!(entry.staging_sha, None);
+
+        assert_eq!(
+            db.merge_queue_entries_get("me", "repo", 1).await?,
+            Some(entry)
+        );
+        assert_eq!(db.merge_queue_entries_get("me", "repo", 2).await?, None);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn update() {
+    db_test_case("merge_queue_entry_update", |db| async move {
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        assert!(matches!(
+            db.merge_queue_entries_update(
+                MergeQueueEntry {
+                    pull_request_number: 1,
+                    ..Default::default()
+                }
+                .with_repository(&repo)
+            )
+            .await,
+            Err(DatabaseError::UnknownMergeQueueEntry(_, 1))
+        ));
+
+        let entry = db
+            .merge_queue_entries_create(
+                MergeQueueEntry {
+                    pull_request_number: 1,
+                    base_branch: "main".into(),
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+
+        let entry = db
+            .merge_queue_entries_update(MergeQueueEntry {
+                status: MergeQueueStatus::Testing,
+                staging_sha: Some("abcdef".into()),
+                ..entry
+            })
+            .await?;
+
+        assert_eq!(entry.status, MergeQueueStatus::Testing);
+        assert_eq!(entry.staging_sha, Some("abcdef".into()));
+        assert_eq!(
+            db.merge_queue_entries_get_expect("me", "repo", 1)
+                .await?
+                .status,
+            MergeQueueStatus::Testing
+        );
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn list() {
+    db_test_case("merge_queue_entry_list", |db| async move {
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        for (number, timestamp) in [(3, 2_000), (1, 3_000), (2, 1_000)] {
+            db.merge_queue_entries_create(
+                MergeQueueEntry {
+                    pull_request_number: number,
+                    base_branch: "main".into(),
+                    queued_at: OffsetDateTime::from_unix_timestamp(timestamp)?,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+        }
+
+        let numbers: Vec<_> = db
+            .merge_queue_entries_list("me", "repo")
+            .await?
+            .into_iter()
+            .map(|e| e.pull_request_number)
+            .collect();
+        assert_eq!(numbers, vec![2, 3, 1]);
+        assert!(db.merge_queue_entries_list("me", "other").await?.is_empty());
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn delete() {
+    db_test_case("merge_queue_entry_delete", |db| async move {
+        assert!(!db.merge_queue_entries_delete("me", "repo", 1).await?);
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        db.merge_queue_entries_create(
+            MergeQueueEntry {
+                pull_request_number: 1,
+                ..Default::default()
+            }
+            .with_repository(&repo),
+        )
+        .await?;
+
+        assert!(db.merge_queue_entries_delete("me", "repo", 1).await?);
+        assert_eq!(db.merge_queue_entries_get("me", "repo", 1).await?, None);
+
+        Ok(())
+    })
+    .await;
+}
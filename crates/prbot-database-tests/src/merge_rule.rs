@@ -11,7 +11,8 @@ async fn create() {
                 repository_id: 1,
                 base_branch: RuleBranch::Wildcard,
                 head_branch: RuleBranch::Named("hello".to_owned()),
-                strategy: MergeStrategy::Merge
+                strategy: MergeStrategy::Merge,
+                ..Default::default()
             })
             .await,
             Err(DatabaseError::UnknownRepositoryId(1))
@@ -31,6 +32,7 @@ async fn create() {
                 base_branch: RuleBranch::Wildcard,
                 head_branch: RuleBranch::Named("hello".to_owned()),
                 strategy: MergeStrategy::Merge,
+                ..Default::default()
             })
             .await?;
 
@@ -52,7 +54,8 @@ async fn update() {
                 repository_id: 1,
                 base_branch: RuleBranch::Wildcard,
                 head_branch: RuleBranch::Named("hello".to_owned()),
-                strategy: MergeStrategy::Merge
+                strategy: MergeStrategy::Merge,
+                ..Default::default()
             },)
                 .await,
             Err(DatabaseError::UnknownRepositoryId(1))
@@ -71,7 +74,8 @@ async fn update() {
                 repository_id: repo.id,
                 base_branch: RuleBranch::Wildcard,
                 head_branch: RuleBranch::Named("hello".to_owned()),
-                strategy: MergeStrategy::Merge
+                strategy: MergeStrategy::Merge,
+                ..Default::default()
             },)
                 .await,
             Err(DatabaseError::UnknownMergeRule(_, _))
@@ -82,6 +86,7 @@ async fn update() {
             base_branch: RuleBranch::Wildcard,
             head_branch: RuleBranch::Named("hello".to_owned()),
             strategy: MergeStrategy::Merge,
+            ..Default::default()
         })
         .await?;
 
@@ -91,6 +96,7 @@ async fn update() {
                 base_branch: RuleBranch::Wildcard,
                 head_branch: RuleBranch::Named("hello".to_owned()),
                 strategy: MergeStrategy::Squash,
+                ..Default::default()
             })
             .await?;
 
@@ -142,6 +148,7 @@ async fn get() {
                 base_branch: RuleBranch::Wildcard,
                 head_branch: RuleBranch::Named("hello".to_owned()),
                 strategy: MergeStrategy::Merge,
+                ..Default::default()
             })
             .await?;
 
@@ -186,6 +193,7 @@ async fn delete() {
             base_branch: RuleBranch::Wildcard,
             head_branch: RuleBranch::Named("hello".to_owned()),
             strategy: MergeStrategy::Merge,
+            ..Default::default()
         })
         .await?;
 
@@ -241,6 +249,7 @@ async fn all() {
                 base_branch: RuleBranch::Wildcard,
                 head_branch: RuleBranch::Named("hello".to_owned()),
                 strategy: MergeStrategy::Merge,
+                ..Default::default()
             })
             .await?;
         let rule2 = db
@@ -249,6 +258,7 @@ async fn all() {
                 base_branch: RuleBranch::Named("hi".to_owned()),
                 head_branch: RuleBranch::Named("hello".to_owned()),
                 strategy: MergeStrategy::Merge,
+                ..Default::default()
             })
             .await?;
         let rule3 = db
@@ -257,6 +267,7 @@ async fn all() {
                 base_branch: RuleBranch::Wildcard,
                 head_branch: RuleBranch::Named("hello".to_owned()),
                 strategy: MergeStrategy::Merge,
+                ..Default::default()
             })
             .await?;
         let rule4 = db
@@ -265,6 +276,7 @@ async fn all() {
                 base_branch: RuleBranch::Named("hi".to_owned()),
                 head_branch: RuleBranch::Named("hello2".to_owned()),
                 strategy: MergeStrategy::Merge,
+                ..Default::default()
             })
             .await?;
 
@@ -297,6 +309,7 @@ async fn list() {
                 base_branch: RuleBranch::Wildcard,
                 head_branch: RuleBranch::Named("hello".to_owned()),
                 strategy: MergeStrategy::Merge,
+                ..Default::default()
             })
             .await?;
         let rule2 = db
@@ -305,6 +318,7 @@ async fn list() {
                 base_branch: RuleBranch::Named("hi".to_owned()),
                 head_branch: RuleBranch::Named("hello2".to_owned()),
                 strategy: MergeStrategy::Merge,
+                ..Default::default()
             })
             .await?;
 
@@ -331,6 +345,7 @@ async fn cascade_repository() {
             base_branch: RuleBranch::Wildcard,
             head_branch: RuleBranch::Named("hello".to_owned()),
             strategy: MergeStrategy::Merge,
+            ..Default::default()
         })
         .await?;
 
@@ -341,3 +356,81 @@ async fn cascade_repository() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn patterns() {
+    db_test_case("merge_rule_patterns", |db| async move {
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        let rule = db
+            .merge_rules_create(MergeRule {
+                repository_id: repo.id,
+                base_branch: RuleBranch::Glob("release/*".to_owned()),
+                head_branch: RuleBranch::Regex("^renovate/".to_owned()),
+                strategy: MergeStrategy::Squash,
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(rule.base_branch, RuleBranch::Glob("release/*".to_owned()));
+        assert_eq!(rule.head_branch, RuleBranch::Regex("^renovate/".to_owned()));
+
+        assert_eq!(
+            db.merge_rules_get(
+                "me",
+                "repo",
+                RuleBranch::Glob("release/*".to_owned()),
+                RuleBranch::Regex("^renovate/".to_owned())
+            )
+            .await?,
+            Some(rule)
+        );
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn templates() {
+    db_test_case("merge_rule_templates", |db| async move {
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        let rule = db
+            .merge_rules_create(MergeRule {
+                repository_id: repo.id,
+                base_branch: RuleBranch::Named("main".to_owned()),
+                head_branch: RuleBranch::Wildcard,
+                strategy: MergeStrategy::Squash,
+                commit_title_template: Some("{title}".to_owned()),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(rule.commit_title_template, Some("{title}".to_owned()));
+        assert_eq!(rule.commit_message_template, None);
+
+        let rule = db
+            .merge_rules_update(MergeRule {
+                commit_title_template: None,
+                commit_message_template: Some("{body}".to_owned()),
+                ..rule
+            })
+            .await?;
+        assert_eq!(rule.commit_title_template, None);
+        assert_eq!(rule.commit_message_template, Some("{body}".to_owned()));
+
+        Ok(())
+    })
+    .await;
+}
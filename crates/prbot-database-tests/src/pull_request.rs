@@ -1,5 +1,6 @@
 use prbot_database_interface::DatabaseError;
 use prbot_models::{MergeStrategy, PullRequest, QaStatus, Repository};
+use time::OffsetDateTime;
 
 use crate::testcase::db_test_case;
 
@@ -531,3 +532,85 @@ async fn set_strategy_override() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn set_snoozed_until() {
+    db_test_case("pull_request_set_snoozed_until", |db| async move {
+        let until = OffsetDateTime::from_unix_timestamp(2_000)?;
+        assert!(matches!(
+            db.pull_requests_set_snoozed_until("me", "repo", 1, Some(until))
+                .await,
+            Err(DatabaseError::UnknownRepository(_))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        assert!(matches!(
+            db.pull_requests_set_snoozed_until("me", "repo", 1, Some(until))
+                .await,
+            Err(DatabaseError::UnknownPullRequest(_, _))
+        ));
+
+        db.pull_requests_create(PullRequest {
+            repository_id: repo.id,
+            number: 1,
+            ..Default::default()
+        })
+        .await?;
+
+        let pr = db
+            .pull_requests_set_snoozed_until("me", "repo", 1, Some(until))
+            .await?;
+        assert_eq!(pr.snoozed_until, Some(until));
+
+        let pr = db
+            .pull_requests_set_snoozed_until("me", "repo", 1, None)
+            .await?;
+        assert_eq!(pr.snoozed_until, None);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn set_stale_reminded_at() {
+    db_test_case("pull_request_set_stale_reminded_at", |db| async move {
+        let reminded_at = OffsetDateTime::from_unix_timestamp(1_000)?;
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        assert!(matches!(
+            db.pull_requests_set_stale_reminded_at("me", "repo", 1, Some(reminded_at))
+                .await,
+            Err(DatabaseError::UnknownPullRequest(_, _))
+        ));
+
+        db.pull_requests_create(PullRequest {
+            repository_id: repo.id,
+            number: 1,
+            ..Default::default()
+        })
+        .await?;
+
+        let pr = db
+            .pull_requests_set_stale_reminded_at("me", "repo", 1, Some(reminded_at))
+            .await?;
+        assert_eq!(pr.stale_reminded_at, Some(reminded_at));
+        assert_eq!(db.pull_requests_get_expect("me", "repo", 1).await?, pr);
+
+        Ok(())
+    })
+    .await;
+}
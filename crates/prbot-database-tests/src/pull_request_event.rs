@@ -0,0 +1,154 @@
+use prbot_database_interface::DatabaseError;
+use prbot_models::{
+    PullRequest, PullRequestEvent, PullRequestEventKind, Repository, RequiredReviewer,
+};
+use time::OffsetDateTime;
+
+use crate::testcase::db_test_case;
+
+#[tokio::test]
+async fn create_and_list() {
+    db_test_case("pull_request_event_create_and_list", |db| async move {
+        assert!(matches!(
+            db.pull_request_events_create(PullRequestEvent {
+                pull_request_id: 1,
+                ..Default::default()
+            })
+            .await,
+            Err(DatabaseError::UnknownPullRequestId(1))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        let pr = db
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(db.pull_request_events_list("me", "repo", 1).await?, vec![]);
+
+        let command = db
+            .pull_request_events_create(PullRequestEvent {
+                pull_request_id: pr.id,
+                kind: PullRequestEventKind::Command,
+                author: Some("me".into()),
+                details: "bot qa+".into(),
+                created_at: OffsetDateTime::from_unix_timestamp(2_000)?,
+                ..Default::default()
+            })
+            .await?;
+        let opened = db
+            .pull_request_events_create(PullRequestEvent {
+                pull_request_id: pr.id,
+                kind: PullRequestEventKind::Opened,
+                created_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(
+            db.pull_request_events_list("me", "repo", 1).await?,
+            vec![opened, command]
+        );
+
+        // Events are deleted with their pull request
+        db.pull_requests_delete("me", "repo", 1).await?;
+        assert_eq!(db.pull_request_events_list("me", "repo", 1).await?, vec![]);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn pending_reviews() {
+    db_test_case("pull_request_event_pending_reviews", |db| async move {
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        // Open, closed, reopened and excluded pull requests
+        let kinds = [
+            vec![],
+            vec![PullRequestEventKind::Closed],
+            vec![PullRequestEventKind::Merged, PullRequestEventKind::Reopened],
+            vec![],
+        ];
+        let mut ids = vec![];
+        for (number, kinds) in kinds.into_iter().enumerate() {
+            let pr = db
+                .pull_requests_create(PullRequest {
+                    repository_id: repo.id,
+                    number: number as u64 + 1,
+                    ..Default::default()
+                })
+                .await?;
+            db.pull_request_events_create(PullRequestEvent {
+                pull_request_id: pr.id,
+                kind: PullRequestEventKind::ReviewerAssigned,
+                author: Some("a".into()),
+                created_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+                ..Default::default()
+            })
+            .await?;
+            for (offset, kind) in kinds.into_iter().enumerate() {
+                db.pull_request_events_create(PullRequestEvent {
+                    pull_request_id: pr.id,
+                    kind,
+                    created_at: OffsetDateTime::from_unix_timestamp(2_000 + offset as i64)?,
+                    ..Default::default()
+                })
+                .await?;
+            }
+            ids.push(pr.id);
+        }
+
+        // Reviewed assignments are done, required reviewers are counted once
+        db.pull_request_events_create(PullRequestEvent {
+            pull_request_id: ids[0],
+            kind: PullRequestEventKind::ReviewerAssigned,
+            author: Some("b".into()),
+            ..Default::default()
+        })
+        .await?;
+        db.pull_request_events_create(PullRequestEvent {
+            pull_request_id: ids[0],
+            kind: PullRequestEventKind::Reviewed,
+            author: Some("b".into()),
+            ..Default::default()
+        })
+        .await?;
+        for (id, username) in [(ids[0], "c"), (ids[2], "a")] {
+            db.required_reviewers_create(RequiredReviewer {
+                pull_request_id: id,
+                username: username.into(),
+            })
+            .await?;
+        }
+
+        assert_eq!(
+            db.pull_request_events_pending_reviews("me", "repo", 4)
+                .await?,
+            vec![("a".into(), 2), ("c".into(), 1)]
+        );
+        assert_eq!(
+            db.pull_request_events_pending_reviews("me", "other", 4)
+                .await?,
+            vec![]
+        );
+
+        Ok(())
+    })
+    .await;
+}
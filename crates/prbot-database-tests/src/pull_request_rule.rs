@@ -11,7 +11,8 @@ async fn create() {
                 repository_id: 1,
                 name: "My rule".into(),
                 conditions: vec![],
-                actions: vec![]
+                actions: vec![],
+                ..Default::default()
             })
             .await,
             Err(DatabaseError::UnknownRepositoryId(1))
@@ -31,6 +32,7 @@ async fn create() {
                 name: "My rule".into(),
                 conditions: vec![],
                 actions: vec![],
+                priority: 5,
             })
             .await?;
 
@@ -38,6 +40,7 @@ async fn create() {
         assert_eq!(rule.name, "My rule");
         assert_eq!(rule.conditions, vec![]);
         assert_eq!(rule.actions, vec![]);
+        assert_eq!(rule.priority, 5);
 
         let rule = db
             .pull_request_rules_create(PullRequestRule {
@@ -48,6 +51,7 @@ async fn create() {
                     RuleCondition::HeadBranch(RuleBranch::Named("staging".into())),
                 ],
                 actions: vec![RuleAction::SetAutomerge(true)],
+                ..Default::default()
             })
             .await?;
 
@@ -75,7 +79,8 @@ async fn update() {
                 repository_id: 1,
                 name: "My rule".into(),
                 conditions: vec![],
-                actions: vec![]
+                actions: vec![],
+                ..Default::default()
             })
             .await,
             Err(DatabaseError::UnknownRepositoryId(1))
@@ -94,7 +99,8 @@ async fn update() {
                 repository_id: repo.id,
                 name: "My rule".into(),
                 conditions: vec![],
-                actions: vec![]
+                actions: vec![],
+                ..Default::default()
             },)
                 .await,
             Err(DatabaseError::UnknownPullRequestRule(_))
@@ -108,6 +114,7 @@ async fn update() {
                 RuleCondition::HeadBranch(RuleBranch::Named("staging".into())),
             ],
             actions: vec![RuleAction::SetAutomerge(true)],
+            ..Default::default()
         })
         .await?;
 
@@ -120,6 +127,7 @@ async fn update() {
                     RuleCondition::HeadBranch(RuleBranch::Named("staging".into())),
                 ],
                 actions: vec![],
+                ..Default::default()
             })
             .await?;
 
@@ -158,6 +166,7 @@ async fn get() {
                 name: "rule".into(),
                 conditions: vec![],
                 actions: vec![],
+                ..Default::default()
             })
             .await?;
 
@@ -187,6 +196,7 @@ async fn delete() {
             name: "rule".into(),
             conditions: vec![],
             actions: vec![],
+            ..Default::default()
         })
         .await?;
 
@@ -225,6 +235,7 @@ async fn all() {
                 name: "Rule 1".into(),
                 actions: vec![],
                 conditions: vec![],
+                ..Default::default()
             })
             .await?;
         let rule2 = db
@@ -1,5 +1,5 @@
 use prbot_database_interface::DatabaseError;
-use prbot_models::{MergeStrategy, Repository};
+use prbot_models::{MergeStrategy, Repository, ReviewerAssignmentStrategy, StaleApprovalPolicy};
 
 use crate::testcase::db_test_case;
 
@@ -38,12 +38,14 @@ async fn update() {
                 id: repo.id,
                 owner: "me".into(),
                 name: "repo2".into(),
+                config_sha: "abcdef".into(),
                 ..Default::default()
             })
             .await?;
 
         assert_eq!(repo.owner, "me");
         assert_eq!(repo.name, "repo2");
+        assert_eq!(repo.config_sha, "abcdef");
 
         Ok(())
     })
@@ -334,3 +336,275 @@ async fn all() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn set_checks_settings() {
+    db_test_case("repository_set_checks_settings", |db| async move {
+        assert!(matches!(
+            db.repositories_set_required_checks("me", "repo", &["test".into()])
+                .await,
+            Err(DatabaseError::UnknownRepository(_))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(repo.ci_app_slugs, vec!["github-actions".to_string()]);
+        assert!(repo.required_checks.is_empty());
+        assert!(repo.optional_checks.is_empty());
+
+        db.repositories_set_ci_app_slugs(
+            "me",
+            "repo",
+            &["github-actions".into(), "circleci-checks".into()],
+        )
+        .await?;
+        db.repositories_set_required_checks("me", "repo", &["test (*)".into()])
+            .await?;
+        let repo = db
+            .repositories_set_optional_checks("me", "repo", &["lint".into()])
+            .await?;
+        assert_eq!(
+            repo.ci_app_slugs,
+            vec!["github-actions".to_string(), "circleci-checks".to_string()]
+        );
+        assert_eq!(repo.required_checks, vec!["test (*)".to_string()]);
+        assert_eq!(repo.optional_checks, vec!["lint".to_string()]);
+
+        let repo = db
+            .repositories_update(Repository {
+                required_checks: vec![],
+                ..repo
+            })
+            .await?;
+        assert!(repo.required_checks.is_empty());
+        assert_eq!(db.repositories_get_expect("me", "repo").await?, repo);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn set_native_merge_queue() {
+    db_test_case("repository_set_native_merge_queue", |db| async move {
+        assert!(matches!(
+            db.repositories_set_native_merge_queue("me", "repo", true)
+                .await,
+            Err(DatabaseError::UnknownRepository(_))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        assert!(!repo.native_merge_queue);
+
+        let repo = db
+            .repositories_set_native_merge_queue("me", "repo", true)
+            .await?;
+        assert!(repo.native_merge_queue);
+        assert_eq!(db.repositories_get_expect("me", "repo").await?, repo);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn set_merge_commit_settings() {
+    db_test_case("repository_set_merge_commit_settings", |db| async move {
+        assert!(matches!(
+            db.repositories_set_merge_commit_settings("me", "repo", "{title}", "", true)
+                .await,
+            Err(DatabaseError::UnknownRepository(_))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(repo.merge_commit_title_template, "{title} (#{number})");
+        assert_eq!(repo.merge_commit_message_template, "");
+        assert!(!repo.conventional_commits);
+
+        let repo = db
+            .repositories_set_merge_commit_settings(
+                "me",
+                "repo",
+                "{title}",
+                "{body}\n\n{co_authors}",
+                true,
+            )
+            .await?;
+        assert_eq!(repo.merge_commit_title_template, "{title}");
+        assert_eq!(repo.merge_commit_message_template, "{body}\n\n{co_authors}");
+        assert!(repo.conventional_commits);
+        assert_eq!(db.repositories_get_expect("me", "repo").await?, repo);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn set_stale_settings() {
+    db_test_case("repository_set_stale_settings", |db| async move {
+        assert!(matches!(
+            db.repositories_set_stale_settings("me", "repo", 7, true)
+                .await,
+            Err(DatabaseError::UnknownRepository(_))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(repo.stale_threshold_days, 0);
+        assert!(!repo.stale_convert_to_draft);
+
+        let repo = db
+            .repositories_set_stale_settings("me", "repo", 7, true)
+            .await?;
+        assert_eq!(repo.stale_threshold_days, 7);
+        assert!(repo.stale_convert_to_draft);
+        assert_eq!(db.repositories_get_expect("me", "repo").await?, repo);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn set_codeowners_reviewers() {
+    db_test_case("repository_set_codeowners_reviewers", |db| async move {
+        assert!(matches!(
+            db.repositories_set_codeowners_reviewers("me", "repo", true)
+                .await,
+            Err(DatabaseError::UnknownRepository(_))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        assert!(!repo.codeowners_reviewers);
+
+        let repo = db
+            .repositories_set_codeowners_reviewers("me", "repo", true)
+            .await?;
+        assert!(repo.codeowners_reviewers);
+        assert_eq!(db.repositories_get_expect("me", "repo").await?, repo);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn set_reviewer_pool() {
+    db_test_case("repository_set_reviewer_pool", |db| async move {
+        assert!(matches!(
+            db.repositories_set_reviewer_pool(
+                "me",
+                "repo",
+                &[],
+                1,
+                ReviewerAssignmentStrategy::RoundRobin
+            )
+            .await,
+            Err(DatabaseError::UnknownRepository(_))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(repo.reviewer_pool, Vec::<String>::new());
+        assert_eq!(repo.reviewer_pool_count, 1);
+        assert_eq!(
+            repo.reviewer_assignment_strategy,
+            ReviewerAssignmentStrategy::RoundRobin
+        );
+
+        let repo = db
+            .repositories_set_reviewer_pool(
+                "me",
+                "repo",
+                &["alice".into(), "bob".into()],
+                2,
+                ReviewerAssignmentStrategy::LeastLoad,
+            )
+            .await?;
+        assert_eq!(repo.reviewer_pool, vec!["alice", "bob"]);
+        assert_eq!(repo.reviewer_pool_count, 2);
+        assert_eq!(
+            repo.reviewer_assignment_strategy,
+            ReviewerAssignmentStrategy::LeastLoad
+        );
+
+        let repo = db
+            .repositories_set_reviewer_pool_cursor("me", "repo", 3)
+            .await?;
+        assert_eq!(repo.reviewer_pool_cursor, 3);
+        assert_eq!(db.repositories_get_expect("me", "repo").await?, repo);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn set_stale_approval_policy() {
+    db_test_case("repository_set_stale_approval_policy", |db| async move {
+        assert!(matches!(
+            db.repositories_set_stale_approval_policy("me", "repo", StaleApprovalPolicy::Keep)
+                .await,
+            Err(DatabaseError::UnknownRepository(_))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(repo.stale_approval_policy, StaleApprovalPolicy::Keep);
+
+        let repo = db
+            .repositories_set_stale_approval_policy(
+                "me",
+                "repo",
+                StaleApprovalPolicy::InvalidateChangedFiles,
+            )
+            .await?;
+        assert_eq!(
+            repo.stale_approval_policy,
+            StaleApprovalPolicy::InvalidateChangedFiles
+        );
+        assert_eq!(db.repositories_get_expect("me", "repo").await?, repo);
+
+        Ok(())
+    })
+    .await;
+}
@@ -0,0 +1,64 @@
+use prbot_database_interface::DatabaseError;
+use prbot_models::{PullRequest, Repository, StepLabel, StepTransition};
+use time::OffsetDateTime;
+
+use crate::testcase::db_test_case;
+
+#[tokio::test]
+async fn create_and_list() {
+    db_test_case("step_transition_create_and_list", |db| async move {
+        assert!(matches!(
+            db.step_transitions_create(StepTransition {
+                pull_request_id: 1,
+                ..Default::default()
+            })
+            .await,
+            Err(DatabaseError::UnknownPullRequestId(1))
+        ));
+
+        let repo = db
+            .repositories_create(Repository {
+                owner: "me".into(),
+                name: "repo".into(),
+                ..Default::default()
+            })
+            .await?;
+        let pr = db
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(db.step_transitions_list("me", "repo", 1).await?, vec![]);
+
+        let second = db
+            .step_transitions_create(StepTransition {
+                pull_request_id: pr.id,
+                step: StepLabel::AwaitingReview,
+                created_at: OffsetDateTime::from_unix_timestamp(2_000)?,
+                ..Default::default()
+            })
+            .await?;
+        let first = db
+            .step_transitions_create(StepTransition {
+                pull_request_id: pr.id,
+                step: StepLabel::AwaitingChecks,
+                created_at: OffsetDateTime::from_unix_timestamp(1_000)?,
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(
+            db.step_transitions_list("me", "repo", 1).await?,
+            vec![first, second]
+        );
+
+        // Transitions are deleted with their pull request
+        db.pull_requests_delete("me", "repo", 1).await?;
+        assert_eq!(db.step_transitions_list("me", "repo", 1).await?, vec![]);
+
+        Ok(())
+    })
+    .await;
+}
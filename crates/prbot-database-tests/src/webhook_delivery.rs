@@ -0,0 +1,108 @@
+use prbot_database_interface::DatabaseError;
+use prbot_models::{WebhookDelivery, WebhookDeliveryOutcome};
+use time::OffsetDateTime;
+
+use crate::testcase::db_test_case;
+
+#[tokio::test]
+async fn create() {
+    db_test_case("webhook_delivery_create", |db| async move {
+        let delivery = db
+            .webhook_deliveries_create(WebhookDelivery {
+                delivery_id: "abcd-efgh".into(),
+                event_type: "ping".into(),
+                headers: [("X-GitHub-Event".to_string(), "ping".to_string())].into(),
+                body: "{}".into(),
+                received_at: OffsetDateTime::from_unix_timestamp(1_000_000)?,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(delivery.delivery_id, "abcd-efgh");
+        assert_eq!(delivery.event_type, "ping");
+        assert_eq!(
+            delivery.headers.get("X-GitHub-Event").map(String::as_str),
+            Some("ping")
+        );
+        assert_eq!(delivery.body, "{}");
+        assert_eq!(delivery.outcome, WebhookDeliveryOutcome::Pending);
+        assert_eq!(delivery.error, None);
+        assert_eq!(
+            delivery.received_at,
+            OffsetDateTime::from_unix_timestamp(1_000_000)?
+        );
+
+        assert_eq!(
+            db.webhook_deliveries_get("abcd-efgh").await?,
+            Some(delivery)
+        );
+        assert_eq!(db.webhook_deliveries_get("unknown").await?, None);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn set_outcome() {
+    db_test_case("webhook_delivery_set_outcome", |db| async move {
+        assert!(matches!(
+            db.webhook_deliveries_set_outcome("abcd", WebhookDeliveryOutcome::Success, None)
+                .await,
+            Err(DatabaseError::UnknownWebhookDelivery(_))
+        ));
+
+        db.webhook_deliveries_create(WebhookDelivery {
+            delivery_id: "abcd".into(),
+            event_type: "pull_request".into(),
+            ..Default::default()
+        })
+        .await?;
+
+        let delivery = db
+            .webhook_deliveries_set_outcome(
+                "abcd",
+                WebhookDeliveryOutcome::Failure,
+                Some("Oops".into()),
+            )
+            .await?;
+        assert_eq!(delivery.outcome, WebhookDeliveryOutcome::Failure);
+        assert_eq!(delivery.error, Some("Oops".into()));
+
+        let delivery = db
+            .webhook_deliveries_set_outcome("abcd", WebhookDeliveryOutcome::Success, None)
+            .await?;
+        assert_eq!(delivery.outcome, WebhookDeliveryOutcome::Success);
+        assert_eq!(delivery.error, None);
+
+        Ok(())
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn purge() {
+    db_test_case("webhook_delivery_purge", |db| async move {
+        for (delivery_id, timestamp) in [("one", 1_000), ("two", 2_000), ("three", 3_000)] {
+            db.webhook_deliveries_create(WebhookDelivery {
+                delivery_id: delivery_id.into(),
+                event_type: "ping".into(),
+                received_at: OffsetDateTime::from_unix_timestamp(timestamp)?,
+                ..Default::default()
+            })
+            .await?;
+        }
+
+        assert_eq!(
+            db.webhook_deliveries_purge(OffsetDateTime::from_unix_timestamp(2_500)?)
+                .await?,
+            2
+        );
+        assert_eq!(db.webhook_deliveries_get("one").await?, None);
+        assert_eq!(db.webhook_deliveries_get("two").await?, None);
+        assert!(db.webhook_deliveries_get("three").await?.is_some());
+
+        Ok(())
+    })
+    .await;
+}
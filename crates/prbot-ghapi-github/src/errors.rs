@@ -16,6 +16,18 @@ pub enum GitHubError {
         repository_path: String,
     },
 
+    #[error(
+        "Could not merge '{}' into '{}' on repository {}",
+        head,
+        base,
+        repository_path
+    )]
+    MergeConflict {
+        base: String,
+        head: String,
+        repository_path: String,
+    },
+
     #[error(transparent)]
     ImplementationError {
         source: Box<dyn std::error::Error + Send + Sync + 'static>,
@@ -38,6 +50,15 @@ impl From<GitHubError> for ApiError {
                 pr_number,
                 repository_path,
             },
+            GitHubError::MergeConflict {
+                base,
+                head,
+                repository_path,
+            } => ApiError::MergeConflict {
+                base,
+                head,
+                repository_path,
+            },
             e => ApiError::ImplementationError { source: e.into() },
         }
     }
@@ -1,20 +1,27 @@
 //! GitHub adapter
 
-use std::{future::Future, time::Duration};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
 use backoff::ExponentialBackoffBuilder;
+use base64::{engine::general_purpose::STANDARD, Engine};
 use prbot_config::Config;
 use prbot_ghapi_interface::{
     gif::GifResponse,
     review::GhReviewApi,
     types::{
-        GhCheckRun, GhCommitStatus, GhCommitStatusState, GhMergeStrategy, GhPullRequest,
+        GhCheckRun, GhCommitStatus, GhCommitStatusState, GhFileContent, GhGitCommit, GhGitRef,
+        GhIssueComment, GhMergeStrategy, GhPullCommit, GhPullRequest, GhPullRequestShort,
         GhReactionType, GhUserPermission,
     },
     ApiService, Result,
 };
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
@@ -26,16 +33,58 @@ use crate::{
 const MAX_STATUS_DESCRIPTION_LEN: usize = 139;
 const GIF_API_URL: &str = "https://g.tenor.com/v1";
 
+/// Team members, by organization and team slug, with their fetch time.
+type TeamMembersCache = HashMap<(String, String), (Instant, Vec<String>)>;
+
 /// GitHub API adapter implementation.
 #[derive(Clone)]
 pub struct GithubApiService {
     config: Config,
+    team_members_cache: Arc<Mutex<TeamMembersCache>>,
 }
 
 impl GithubApiService {
     /// Creates new GitHub API adapter.
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            team_members_cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn cached_team_members(&self, org: &str, team_slug: &str) -> Option<Vec<String>> {
+        let ttl = Duration::from_secs(self.config.api.github.team_members_cache_ttl);
+        let cache = self.team_members_cache.lock().unwrap();
+        cache
+            .get(&(org.to_string(), team_slug.to_string()))
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < ttl)
+            .map(|(_, members)| members.clone())
+    }
+
+    fn cache_team_members(&self, org: &str, team_slug: &str, members: &[String]) {
+        if self.config.api.github.team_members_cache_ttl == 0 {
+            return;
+        }
+
+        let mut cache = self.team_members_cache.lock().unwrap();
+        cache.insert(
+            (org.to_string(), team_slug.to_string()),
+            (Instant::now(), members.to_vec()),
+        );
+    }
+
+    /// Split reviewers between users and team slugs (`org/team` reviewers).
+    fn split_reviewers(reviewers: &[String]) -> (Vec<String>, Vec<String>) {
+        let mut users = vec![];
+        let mut teams = vec![];
+        for reviewer in reviewers {
+            match reviewer.split_once('/') {
+                Some((_, team_slug)) => teams.push(team_slug.to_string()),
+                None => users.push(reviewer.clone()),
+            }
+        }
+
+        (users, teams)
     }
 
     async fn get_client(&self) -> Result<Client, GitHubError> {
@@ -285,6 +334,49 @@ impl ApiService for GithubApiService {
         .await
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn comments_list(
+        &self,
+        owner: &str,
+        name: &str,
+        issue_number: u64,
+    ) -> Result<Vec<GhIssueComment>> {
+        let mut responses = vec![];
+        let mut curr_page = 1;
+        let max_per_page = 100;
+
+        loop {
+            debug!(current = curr_page, message = "Fetching comment page");
+
+            let results: Vec<GhIssueComment> = self
+                .call_with_retry(|| async move {
+                    self.get_client()
+                        .await?
+                        .get(&self.build_url(format!(
+                            "/repos/{owner}/{name}/issues/{issue_number}/comments"
+                        )))
+                        .query(&[("per_page", max_per_page), ("page", curr_page)])
+                        .send()
+                        .await?
+                        .error_for_status()?
+                        .json()
+                        .await
+                        .map_err(Into::into)
+                })
+                .await?;
+
+            let count = results.len();
+            responses.extend(results);
+            if count < max_per_page {
+                break;
+            }
+
+            curr_page += 1;
+        }
+
+        Ok(responses)
+    }
+
     #[tracing::instrument(skip(self), ret)]
     async fn comments_update(
         &self,
@@ -385,6 +477,43 @@ impl ApiService for GithubApiService {
         .await
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn pulls_list(&self, owner: &str, name: &str) -> Result<Vec<GhPullRequest>> {
+        let mut responses = vec![];
+        let mut curr_page = 1;
+        let max_per_page = 100;
+
+        loop {
+            debug!(current = curr_page, message = "Fetching pull request page");
+
+            let results: Vec<GhPullRequest> = self
+                .call_with_retry(|| async move {
+                    self.get_client()
+                        .await?
+                        .get(&self.build_url(format!("/repos/{owner}/{name}/pulls")))
+                        .query(&[("state", "open")])
+                        .query(&[("per_page", max_per_page), ("page", curr_page)])
+                        .send()
+                        .await?
+                        .error_for_status()?
+                        .json()
+                        .await
+                        .map_err(Into::into)
+                })
+                .await?;
+
+            let count = results.len();
+            responses.extend(results);
+            if count < max_per_page {
+                break;
+            }
+
+            curr_page += 1;
+        }
+
+        Ok(responses)
+    }
+
     #[tracing::instrument(skip(self))]
     async fn pulls_merge(
         &self,
@@ -424,6 +553,164 @@ impl ApiService for GithubApiService {
         .await
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn pulls_create(
+        &self,
+        owner: &str,
+        name: &str,
+        title: &str,
+        body: &str,
+        head: &str,
+        base: &str,
+    ) -> Result<GhPullRequest> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            title: &'a str,
+            body: &'a str,
+            head: &'a str,
+            base: &'a str,
+        }
+
+        self.call_with_retry(|| async move {
+            Ok(self
+                .get_client()
+                .await?
+                .post(&self.build_url(format!("/repos/{owner}/{name}/pulls")))
+                .json(&Request {
+                    title,
+                    body,
+                    head,
+                    base,
+                })
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?)
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn pulls_enqueue(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct ResponseError {
+            message: String,
+        }
+
+        #[derive(Deserialize)]
+        struct Response {
+            #[serde(default)]
+            errors: Vec<ResponseError>,
+        }
+
+        const QUERY: &str = "mutation($id: ID!) { enqueuePullRequest(input: { pullRequestId: $id }) { clientMutationId } }";
+
+        // Enqueuing is only available through GraphQL
+        let upstream_pr = self.pulls_get(owner, name, number).await?;
+        let node_id = upstream_pr.node_id.as_str();
+
+        self.call_with_retry(|| async move {
+            let response: Response = self
+                .get_client()
+                .await?
+                .post(&self.build_url("/graphql".into()))
+                .json(&Request {
+                    query: QUERY,
+                    variables: Variables { id: node_id },
+                })
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+
+            if response.errors.is_empty() {
+                Ok(())
+            } else {
+                for error in response.errors {
+                    debug!(message = "Could not enqueue pull request", error = %error.message);
+                }
+
+                Err(GitHubError::MergeError {
+                    pr_number: number,
+                    repository_path: format!("{owner}/{name}"),
+                })
+            }
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn pulls_convert_to_draft(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        #[derive(Serialize)]
+        struct Variables<'a> {
+            id: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            query: &'a str,
+            variables: Variables<'a>,
+        }
+
+        #[derive(Deserialize)]
+        struct ResponseError {
+            message: String,
+        }
+
+        #[derive(Deserialize)]
+        struct Response {
+            #[serde(default)]
+            errors: Vec<ResponseError>,
+        }
+
+        const QUERY: &str = "mutation($id: ID!) { convertPullRequestToDraft(input: { pullRequestId: $id }) { clientMutationId } }";
+
+        // Converting to draft is only available through GraphQL
+        let upstream_pr = self.pulls_get(owner, name, number).await?;
+        let node_id = upstream_pr.node_id.as_str();
+
+        self.call_with_retry(|| async move {
+            let response: Response = self
+                .get_client()
+                .await?
+                .post(&self.build_url("/graphql".into()))
+                .json(&Request {
+                    query: QUERY,
+                    variables: Variables { id: node_id },
+                })
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+
+            match response.errors.into_iter().next() {
+                None => Ok(()),
+                Some(error) => Err(GitHubError::ImplementationError {
+                    source: format!(
+                        "Could not convert pull request #{number} on {owner}/{name} to draft: {}",
+                        error.message
+                    )
+                    .into(),
+                }),
+            }
+        })
+        .await
+    }
+
     #[tracing::instrument(skip(self))]
     async fn pull_reviewer_requests_add(
         &self,
@@ -435,15 +722,21 @@ impl ApiService for GithubApiService {
         #[derive(Serialize)]
         struct Request<'a> {
             reviewers: &'a [String],
+            team_reviewers: &'a [String],
         }
 
+        let (reviewers, team_reviewers) = Self::split_reviewers(reviewers);
+        let (reviewers, team_reviewers) = (&reviewers, &team_reviewers);
         self.call_with_retry(|| async move {
             self.get_client()
                 .await?
                 .post(&self.build_url(format!(
                     "/repos/{owner}/{name}/pulls/{number}/requested_reviewers"
                 )))
-                .json(&Request { reviewers })
+                .json(&Request {
+                    reviewers,
+                    team_reviewers,
+                })
                 .send()
                 .await?
                 .error_for_status()?;
@@ -464,15 +757,21 @@ impl ApiService for GithubApiService {
         #[derive(Serialize)]
         struct Request<'a> {
             reviewers: &'a [String],
+            team_reviewers: &'a [String],
         }
 
+        let (reviewers, team_reviewers) = Self::split_reviewers(reviewers);
+        let (reviewers, team_reviewers) = (&reviewers, &team_reviewers);
         self.call_with_retry(|| async move {
             self.get_client()
                 .await?
                 .delete(&self.build_url(format!(
                     "/repos/{owner}/{name}/pulls/{number}/requested_reviewers"
                 )))
-                .json(&Request { reviewers })
+                .json(&Request {
+                    reviewers,
+                    team_reviewers,
+                })
                 .header(
                     http::header::ACCEPT,
                     http::header::HeaderValue::from_static("application/vnd.github.v3+json"),
@@ -537,63 +836,598 @@ impl ApiService for GithubApiService {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn commit_statuses_combined(
-        &self,
-        owner: &str,
-        name: &str,
-        git_ref: &str,
-    ) -> Result<GhCommitStatus> {
-        let data = self
-            .call_with_retry(|| async move {
-                self.get_client()
-                    .await?
-                    .get(&self.build_url(format!("/repos/{owner}/{name}/commits/{git_ref}/status")))
-                    .send()
-                    .await?
-                    .error_for_status()?
-                    .json()
-                    .await
-                    .map_err(Into::into)
-            })
-            .await?;
+    async fn pull_files_list(&self, owner: &str, name: &str, number: u64) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct File {
+            filename: String,
+        }
 
-        Ok(data)
+        let mut responses = vec![];
+        let mut curr_page = 1;
+        let max_per_page = 100;
+
+        loop {
+            debug!(current = curr_page, message = "Fetching file page");
+
+            let results: Vec<File> = self
+                .call_with_retry(|| async move {
+                    self.get_client()
+                        .await?
+                        .get(&self.build_url(format!("/repos/{owner}/{name}/pulls/{number}/files")))
+                        .query(&[("per_page", max_per_page), ("page", curr_page)])
+                        .send()
+                        .await?
+                        .error_for_status()?
+                        .json()
+                        .await
+                        .map_err(Into::into)
+                })
+                .await?;
+
+            let count = results.len();
+            responses.extend(results.into_iter().map(|f| f.filename));
+            if count < max_per_page {
+                break;
+            }
+
+            curr_page += 1;
+        }
+
+        Ok(responses)
     }
 
     #[tracing::instrument(skip(self))]
-    async fn commit_statuses_update(
+    async fn pull_commits_list(
         &self,
         owner: &str,
         name: &str,
-        git_ref: &str,
-        status: GhCommitStatusState,
-        title: &str,
-        body: &str,
-    ) -> Result<()> {
-        #[derive(Serialize)]
-        struct Request<'a> {
-            state: &'a str,
-            description: String,
-            context: &'a str,
+        number: u64,
+    ) -> Result<Vec<GhPullCommit>> {
+        #[derive(Deserialize)]
+        struct CommitAuthor {
+            name: String,
+            email: String,
         }
 
-        self.call_with_retry(|| async move {
-            self.get_client()
-                .await?
-                .post(&self.build_url(format!("/repos/{owner}/{name}/statuses/{git_ref}")))
-                .json(&Request {
-                    state: status.to_str(),
-                    context: title,
-                    description: body
-                        .chars()
-                        .take(MAX_STATUS_DESCRIPTION_LEN)
-                        .collect::<String>(),
-                })
-                .send()
-                .await?
-                .error_for_status()?;
+        #[derive(Deserialize)]
+        struct CommitDetails {
+            message: String,
+            author: CommitAuthor,
+        }
 
-            Ok(())
+        #[derive(Deserialize)]
+        struct Commit {
+            sha: String,
+            commit: CommitDetails,
+        }
+
+        let mut responses = vec![];
+        let mut curr_page = 1;
+        let max_per_page = 100;
+
+        loop {
+            debug!(current = curr_page, message = "Fetching commit page");
+
+            let results: Vec<Commit> = self
+                .call_with_retry(|| async move {
+                    self.get_client()
+                        .await?
+                        .get(
+                            &self
+                                .build_url(format!("/repos/{owner}/{name}/pulls/{number}/commits")),
+                        )
+                        .query(&[("per_page", max_per_page), ("page", curr_page)])
+                        .send()
+                        .await?
+                        .error_for_status()?
+                        .json()
+                        .await
+                        .map_err(Into::into)
+                })
+                .await?;
+
+            let count = results.len();
+            responses.extend(results.into_iter().map(|c| GhPullCommit {
+                sha: c.sha,
+                message: c.commit.message,
+                author_name: c.commit.author.name,
+                author_email: c.commit.author.email,
+            }));
+            if count < max_per_page {
+                break;
+            }
+
+            curr_page += 1;
+        }
+
+        Ok(responses)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn commit_pulls_list(
+        &self,
+        owner: &str,
+        name: &str,
+        sha: &str,
+    ) -> Result<Vec<GhPullRequestShort>> {
+        self.call_with_retry(|| async move {
+            Ok(self
+                .get_client()
+                .await?
+                .get(&self.build_url(format!("/repos/{owner}/{name}/commits/{sha}/pulls")))
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?)
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn commits_compare_files(
+        &self,
+        owner: &str,
+        name: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct File {
+            filename: String,
+        }
+
+        #[derive(Deserialize)]
+        struct Response {
+            #[serde(default)]
+            files: Vec<File>,
+        }
+
+        let response: Response = self
+            .call_with_retry(|| async move {
+                self.get_client()
+                    .await?
+                    .get(&self.build_url(format!("/repos/{owner}/{name}/compare/{base}...{head}")))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await
+                    .map_err(Into::into)
+            })
+            .await?;
+
+        Ok(response.files.into_iter().map(|f| f.filename).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn commit_statuses_combined(
+        &self,
+        owner: &str,
+        name: &str,
+        git_ref: &str,
+    ) -> Result<GhCommitStatus> {
+        let data = self
+            .call_with_retry(|| async move {
+                self.get_client()
+                    .await?
+                    .get(&self.build_url(format!("/repos/{owner}/{name}/commits/{git_ref}/status")))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await
+                    .map_err(Into::into)
+            })
+            .await?;
+
+        Ok(data)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn commit_statuses_update(
+        &self,
+        owner: &str,
+        name: &str,
+        git_ref: &str,
+        status: GhCommitStatusState,
+        title: &str,
+        body: &str,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            state: &'a str,
+            description: String,
+            context: &'a str,
+        }
+
+        self.call_with_retry(|| async move {
+            self.get_client()
+                .await?
+                .post(&self.build_url(format!("/repos/{owner}/{name}/statuses/{git_ref}")))
+                .json(&Request {
+                    state: status.to_str(),
+                    context: title,
+                    description: body
+                        .chars()
+                        .take(MAX_STATUS_DESCRIPTION_LEN)
+                        .collect::<String>(),
+                })
+                .send()
+                .await?
+                .error_for_status()?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_refs_get(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+    ) -> Result<Option<GhGitRef>> {
+        self.call_with_retry(|| async move {
+            let response = self
+                .get_client()
+                .await?
+                .get(&self.build_url(format!("/repos/{owner}/{name}/git/ref/{reference}")))
+                .send()
+                .await?;
+
+            if response.status() == StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+
+            Ok(Some(response.error_for_status()?.json().await?))
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_refs_create(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+        sha: &str,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            #[serde(rename = "ref")]
+            reference: String,
+            sha: &'a str,
+        }
+
+        self.call_with_retry(|| async move {
+            self.get_client()
+                .await?
+                .post(&self.build_url(format!("/repos/{owner}/{name}/git/refs")))
+                .json(&Request {
+                    reference: format!("refs/{reference}"),
+                    sha,
+                })
+                .send()
+                .await?
+                .error_for_status()?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_refs_update(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+        sha: &str,
+        force: bool,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            sha: &'a str,
+            force: bool,
+        }
+
+        self.call_with_retry(|| async move {
+            self.get_client()
+                .await?
+                .patch(&self.build_url(format!("/repos/{owner}/{name}/git/refs/{reference}")))
+                .json(&Request { sha, force })
+                .send()
+                .await?
+                .error_for_status()?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_refs_delete(&self, owner: &str, name: &str, reference: &str) -> Result<()> {
+        self.call_with_retry(|| async move {
+            self.get_client()
+                .await?
+                .delete(&self.build_url(format!("/repos/{owner}/{name}/git/refs/{reference}")))
+                .send()
+                .await?
+                .error_for_status()?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_commits_get(&self, owner: &str, name: &str, sha: &str) -> Result<GhGitCommit> {
+        self.call_with_retry(|| async move {
+            Ok(self
+                .get_client()
+                .await?
+                .get(&self.build_url(format!("/repos/{owner}/{name}/git/commits/{sha}")))
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?)
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_commits_create(
+        &self,
+        owner: &str,
+        name: &str,
+        message: &str,
+        tree: &str,
+        parents: &[String],
+    ) -> Result<GhGitCommit> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            message: &'a str,
+            tree: &'a str,
+            parents: &'a [String],
+        }
+
+        self.call_with_retry(|| async move {
+            Ok(self
+                .get_client()
+                .await?
+                .post(&self.build_url(format!("/repos/{owner}/{name}/git/commits")))
+                .json(&Request {
+                    message,
+                    tree,
+                    parents,
+                })
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?)
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repository_merges_create(
+        &self,
+        owner: &str,
+        name: &str,
+        base: &str,
+        head: &str,
+        commit_message: &str,
+    ) -> Result<Option<String>> {
+        #[derive(Serialize)]
+        struct Request<'a> {
+            base: &'a str,
+            head: &'a str,
+            commit_message: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct Response {
+            sha: String,
+        }
+
+        self.call_with_retry(|| async move {
+            let response = self
+                .get_client()
+                .await?
+                .post(&self.build_url(format!("/repos/{owner}/{name}/merges")))
+                .json(&Request {
+                    base,
+                    head,
+                    commit_message,
+                })
+                .send()
+                .await?;
+
+            match response.status() {
+                StatusCode::NO_CONTENT => Ok(None),
+                StatusCode::CONFLICT => Err(GitHubError::MergeConflict {
+                    base: base.into(),
+                    head: head.into(),
+                    repository_path: format!("{owner}/{name}"),
+                }),
+                _ => {
+                    let response: Response = response.error_for_status()?.json().await?;
+                    Ok(Some(response.sha))
+                }
+            }
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repository_contents_get<'a>(
+        &self,
+        owner: &str,
+        name: &str,
+        path: &str,
+        reference: Option<&'a str>,
+    ) -> Result<Option<GhFileContent>> {
+        #[derive(Deserialize)]
+        struct Response {
+            path: String,
+            sha: String,
+            content: String,
+        }
+
+        self.call_with_retry(|| async move {
+            let mut request = self
+                .get_client()
+                .await?
+                .get(self.build_url(format!("/repos/{owner}/{name}/contents/{path}")));
+            if let Some(reference) = reference {
+                request = request.query(&[("ref", reference)]);
+            }
+
+            let response = request.send().await?;
+
+            if response.status() == StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+
+            let response: Response = response.error_for_status()?.json().await?;
+
+            // Content is base64-encoded, with line breaks
+            let encoded: String = response
+                .content
+                .chars()
+                .filter(|c| !c.is_ascii_whitespace())
+                .collect();
+            let content = STANDARD
+                .decode(encoded)
+                .map_err(|e| GitHubError::ImplementationError { source: e.into() })?;
+            let content = String::from_utf8(content)
+                .map_err(|e| GitHubError::ImplementationError { source: e.into() })?;
+
+            Ok(Some(GhFileContent {
+                path: response.path,
+                sha: response.sha,
+                content,
+            }))
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn team_members_list(&self, org: &str, team_slug: &str) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct Member {
+            login: String,
+        }
+
+        if let Some(members) = self.cached_team_members(org, team_slug) {
+            return Ok(members);
+        }
+
+        let mut responses = vec![];
+        let mut curr_page = 1;
+        let max_per_page = 100;
+
+        loop {
+            debug!(current = curr_page, message = "Fetching team member page");
+
+            let results: Vec<Member> = self
+                .call_with_retry(|| async move {
+                    let response = self
+                        .get_client()
+                        .await?
+                        .get(&self.build_url(format!("/orgs/{org}/teams/{team_slug}/members")))
+                        .query(&[("per_page", max_per_page), ("page", curr_page)])
+                        .send()
+                        .await?;
+
+                    // Unknown team
+                    if response.status() == StatusCode::NOT_FOUND {
+                        return Ok(vec![]);
+                    }
+
+                    response
+                        .error_for_status()?
+                        .json()
+                        .await
+                        .map_err(Into::into)
+                })
+                .await?;
+
+            let count = results.len();
+            responses.extend(results.into_iter().map(|m| m.login));
+            if count < max_per_page {
+                break;
+            }
+
+            curr_page += 1;
+        }
+
+        self.cache_team_members(org, team_slug, &responses);
+        Ok(responses)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn team_permissions_get(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        name: &str,
+    ) -> Result<GhUserPermission> {
+        #[derive(Deserialize)]
+        struct Permissions {
+            admin: bool,
+            #[serde(default)]
+            maintain: bool,
+            push: bool,
+            pull: bool,
+        }
+
+        #[derive(Deserialize)]
+        struct Response {
+            permissions: Permissions,
+        }
+
+        self.call_with_retry(|| async move {
+            let response = self
+                .get_client()
+                .await?
+                .get(&self.build_url(format!(
+                    "/orgs/{org}/teams/{team_slug}/repos/{owner}/{name}"
+                )))
+                .header(
+                    http::header::ACCEPT,
+                    http::header::HeaderValue::from_static(
+                        "application/vnd.github.v3.repository+json",
+                    ),
+                )
+                .send()
+                .await?;
+
+            // Unknown team, or no access to the repository
+            if response.status() == StatusCode::NOT_FOUND {
+                return Ok(GhUserPermission::None);
+            }
+
+            let permissions = response
+                .error_for_status()?
+                .json::<Response>()
+                .await?
+                .permissions;
+
+            Ok(if permissions.admin {
+                GhUserPermission::Admin
+            } else if permissions.maintain || permissions.push {
+                GhUserPermission::Write
+            } else if permissions.pull {
+                GhUserPermission::Read
+            } else {
+                GhUserPermission::None
+            })
         })
         .await
     }
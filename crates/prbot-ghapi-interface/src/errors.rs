@@ -17,6 +17,19 @@ pub enum ApiError {
         repository_path: String,
     },
 
+    /// Merge conflict.
+    #[error(
+        "Could not merge '{}' into '{}' on repository {}",
+        head,
+        base,
+        repository_path
+    )]
+    MergeConflict {
+        base: String,
+        head: String,
+        repository_path: String,
+    },
+
     #[error(transparent)]
     ImplementationError {
         source: Box<dyn std::error::Error + Send + Sync + 'static>,
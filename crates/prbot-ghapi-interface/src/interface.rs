@@ -4,7 +4,8 @@ use crate::{
     gif::GifResponse,
     review::GhReviewApi,
     types::{
-        GhCheckRun, GhCommitStatus, GhCommitStatusState, GhMergeStrategy, GhPullRequest,
+        GhCheckRun, GhCommitStatus, GhCommitStatusState, GhFileContent, GhGitCommit, GhGitRef,
+        GhIssueComment, GhMergeStrategy, GhPullCommit, GhPullRequest, GhPullRequestShort,
         GhReactionType, GhUserPermission,
     },
     Result,
@@ -75,6 +76,13 @@ pub trait ApiService: Send + Sync {
         issue_number: u64,
         body: &str,
     ) -> Result<u64>;
+    /// List comments from a pull request.
+    async fn comments_list(
+        &self,
+        owner: &str,
+        name: &str,
+        issue_number: u64,
+    ) -> Result<Vec<GhIssueComment>>;
     /// Update a comment on a pull request.
     async fn comments_update(
         &self,
@@ -95,6 +103,8 @@ pub trait ApiService: Send + Sync {
     ) -> Result<()>;
     /// Get a pull request from its number.
     async fn pulls_get(&self, owner: &str, name: &str, number: u64) -> Result<GhPullRequest>;
+    /// List open pull requests from a repository.
+    async fn pulls_list(&self, owner: &str, name: &str) -> Result<Vec<GhPullRequest>>;
     /// Merge a pull request.
     async fn pulls_merge(
         &self,
@@ -105,7 +115,21 @@ pub trait ApiService: Send + Sync {
         commit_message: &str,
         merge_strategy: GhMergeStrategy,
     ) -> Result<()>;
-    /// Add reviewers to a pull request.
+    /// Create a pull request.
+    async fn pulls_create(
+        &self,
+        owner: &str,
+        name: &str,
+        title: &str,
+        body: &str,
+        head: &str,
+        base: &str,
+    ) -> Result<GhPullRequest>;
+    /// Add a pull request to the repository native merge queue.
+    async fn pulls_enqueue(&self, owner: &str, name: &str, number: u64) -> Result<()>;
+    /// Convert a pull request to a draft.
+    async fn pulls_convert_to_draft(&self, owner: &str, name: &str, number: u64) -> Result<()>;
+    /// Add reviewers to a pull request (users, or `org/team` teams).
     async fn pull_reviewer_requests_add(
         &self,
         owner: &str,
@@ -113,7 +137,7 @@ pub trait ApiService: Send + Sync {
         number: u64,
         reviewers: &[String],
     ) -> Result<()>;
-    /// Remove reviewers from a pull request.
+    /// Remove reviewers from a pull request (users, or `org/team` teams).
     async fn pull_reviewer_requests_remove(
         &self,
         owner: &str,
@@ -128,6 +152,30 @@ pub trait ApiService: Send + Sync {
         name: &str,
         number: u64,
     ) -> Result<Vec<GhReviewApi>>;
+    /// List file paths changed by a pull request.
+    async fn pull_files_list(&self, owner: &str, name: &str, number: u64) -> Result<Vec<String>>;
+    /// List commits from a pull request.
+    async fn pull_commits_list(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<GhPullCommit>>;
+    /// List pull requests associated with a commit.
+    async fn commit_pulls_list(
+        &self,
+        owner: &str,
+        name: &str,
+        sha: &str,
+    ) -> Result<Vec<GhPullRequestShort>>;
+    /// List file paths changed between two commits.
+    async fn commits_compare_files(
+        &self,
+        owner: &str,
+        name: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<String>>;
     /// Get combined commit status.
     async fn commit_statuses_combined(
         &self,
@@ -145,6 +193,71 @@ pub trait ApiService: Send + Sync {
         title: &str,
         body: &str,
     ) -> Result<()>;
+    /// Get a Git reference (e.g. `heads/main`), if it exists.
+    async fn git_refs_get(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+    ) -> Result<Option<GhGitRef>>;
+    /// Create a Git reference (e.g. `heads/main`).
+    async fn git_refs_create(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+        sha: &str,
+    ) -> Result<()>;
+    /// Update a Git reference (e.g. `heads/main`).
+    async fn git_refs_update(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+        sha: &str,
+        force: bool,
+    ) -> Result<()>;
+    /// Delete a Git reference (e.g. `heads/main`).
+    async fn git_refs_delete(&self, owner: &str, name: &str, reference: &str) -> Result<()>;
+    /// Get a Git commit.
+    async fn git_commits_get(&self, owner: &str, name: &str, sha: &str) -> Result<GhGitCommit>;
+    /// Create a Git commit, without updating any reference.
+    async fn git_commits_create(
+        &self,
+        owner: &str,
+        name: &str,
+        message: &str,
+        tree: &str,
+        parents: &[String],
+    ) -> Result<GhGitCommit>;
+    /// Merge a head (branch or SHA) into a base branch.
+    /// Returns the merge commit SHA, or `None` if there was nothing to merge.
+    async fn repository_merges_create(
+        &self,
+        owner: &str,
+        name: &str,
+        base: &str,
+        head: &str,
+        commit_message: &str,
+    ) -> Result<Option<String>>;
+    /// Get a file content from a branch (or the repository default branch), if it exists.
+    async fn repository_contents_get<'a>(
+        &self,
+        owner: &str,
+        name: &str,
+        path: &str,
+        reference: Option<&'a str>,
+    ) -> Result<Option<GhFileContent>>;
+    /// List member logins of an organization team (empty if the team is unknown).
+    async fn team_members_list(&self, org: &str, team_slug: &str) -> Result<Vec<String>>;
+    /// Get organization team permissions on a repository.
+    async fn team_permissions_get(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        name: &str,
+    ) -> Result<GhUserPermission>;
     /// Search a GIF.
     async fn gif_search(&self, api_key: &str, search: &str) -> Result<GifResponse>;
     /// Create installation token.
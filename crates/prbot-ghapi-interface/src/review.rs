@@ -38,6 +38,9 @@ pub struct GhReviewApi {
     pub submitted_at: OffsetDateTime,
     /// State.
     pub state: GhReviewStateApi,
+    /// Reviewed commit SHA.
+    #[serde(default)]
+    pub commit_id: Option<String>,
 }
 
 impl Default for GhReviewApi {
@@ -46,6 +49,7 @@ impl Default for GhReviewApi {
             user: GhUser::default(),
             submitted_at: OffsetDateTime::now_utc(),
             state: GhReviewStateApi::Pending,
+            commit_id: None,
         }
     }
 }
@@ -48,6 +48,7 @@ impl ReviewApi {
                         submitted_at: Some(review.submitted_at),
                         user: review.user,
                         state: review.state.into(),
+                        commit_id: review.commit_id,
                     },
                 );
             }
@@ -76,6 +77,7 @@ mod tests {
             user: GhUser {
                 login: username.into(),
             },
+            commit_id: None,
         }
     }
 
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// GitHub Check run action.
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GhCheckRunAction {
+    /// Created.
+    Created,
+    /// Completed.
+    #[default]
+    Completed,
+    /// Re-requested.
+    Rerequested,
+    /// Requested action.
+    RequestedAction,
+}
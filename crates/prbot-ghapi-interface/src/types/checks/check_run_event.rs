@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use super::{GhCheckRun, GhCheckRunAction};
+use crate::types::common::{GhRepository, GhUser};
+
+/// GitHub Check run event.
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct GhCheckRunEvent {
+    /// Action.
+    pub action: GhCheckRunAction,
+    /// Check run.
+    pub check_run: GhCheckRun,
+    /// Repository.
+    pub repository: GhRepository,
+    /// Organization.
+    pub organization: Option<GhUser>,
+    /// Sender.
+    pub sender: GhUser,
+}
@@ -15,4 +15,6 @@ pub enum GhCheckStatus {
     Requested,
     /// Pending.
     Pending,
+    /// Waiting.
+    Waiting,
 }
@@ -1,5 +1,7 @@
 mod check_conclusion;
 mod check_run;
+mod check_run_action;
+mod check_run_event;
 mod check_status;
 mod check_suite;
 mod check_suite_action;
@@ -7,6 +9,8 @@ mod check_suite_event;
 
 pub use check_conclusion::GhCheckConclusion;
 pub use check_run::GhCheckRun;
+pub use check_run_action::GhCheckRunAction;
+pub use check_run_event::GhCheckRunEvent;
 pub use check_status::GhCheckStatus;
 pub use check_suite::GhCheckSuite;
 pub use check_suite_action::GhCheckSuiteAction;
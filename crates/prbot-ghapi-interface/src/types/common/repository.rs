@@ -11,4 +11,7 @@ pub struct GhRepository {
     pub full_name: String,
     /// Owner.
     pub owner: GhUser,
+    /// Default branch.
+    #[serde(default)]
+    pub default_branch: String,
 }
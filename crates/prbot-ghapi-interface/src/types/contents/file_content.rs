@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// GitHub repository file content.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct GhFileContent {
+    /// Path in the repository.
+    pub path: String,
+    /// Blob SHA.
+    pub sha: String,
+    /// Decoded content.
+    pub content: String,
+}
@@ -0,0 +1,3 @@
+mod file_content;
+
+pub use file_content::GhFileContent;
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// GitHub Git object.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct GhGitObject {
+    /// SHA.
+    pub sha: String,
+}
+
+/// GitHub Git commit.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct GhGitCommit {
+    /// SHA.
+    pub sha: String,
+    /// Message.
+    pub message: String,
+    /// Tree.
+    pub tree: GhGitObject,
+    /// Parents.
+    pub parents: Vec<GhGitObject>,
+}
+
+/// GitHub Git reference.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct GhGitRef {
+    /// Reference, e.g. `refs/heads/main`.
+    #[serde(rename = "ref")]
+    pub reference: String,
+    /// Target object.
+    pub object: GhGitObject,
+}
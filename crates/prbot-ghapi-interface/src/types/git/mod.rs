@@ -0,0 +1,3 @@
+mod git_ref;
+
+pub use git_ref::{GhGitCommit, GhGitObject, GhGitRef};
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// GitHub Merge group.
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq, Eq, Clone)]
+pub struct GhMergeGroup {
+    /// Head SHA.
+    pub head_sha: String,
+    /// Head reference, e.g. `refs/heads/gh-readonly-queue/main/pr-1-abcdef`.
+    pub head_ref: String,
+    /// Base SHA.
+    pub base_sha: String,
+    /// Base reference, e.g. `refs/heads/main`.
+    pub base_ref: String,
+}
+
+impl GhMergeGroup {
+    /// Get the pull request number from the head reference.
+    ///
+    /// The head reference targets the last pull request of the group.
+    pub fn pull_request_number(&self) -> Option<u64> {
+        let last_part = self.head_ref.rsplit('/').next()?;
+        let number = last_part.strip_prefix("pr-")?.split('-').next()?;
+        number.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pull_request_number() {
+        let group = |head_ref: &str| GhMergeGroup {
+            head_ref: head_ref.into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            group("refs/heads/gh-readonly-queue/main/pr-12-abcdef").pull_request_number(),
+            Some(12)
+        );
+        assert_eq!(
+            group("refs/heads/gh-readonly-queue/feature/a/pr-3-abcdef").pull_request_number(),
+            Some(3)
+        );
+        assert_eq!(group("refs/heads/main").pull_request_number(), None);
+    }
+}
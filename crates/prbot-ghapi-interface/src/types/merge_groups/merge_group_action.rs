@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// GitHub Merge group action.
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GhMergeGroupAction {
+    /// Checks requested.
+    #[default]
+    ChecksRequested,
+    /// Destroyed.
+    Destroyed,
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use super::{GhMergeGroup, GhMergeGroupAction};
+use crate::types::common::{GhRepository, GhUser};
+
+/// GitHub Merge group event.
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct GhMergeGroupEvent {
+    /// Action.
+    pub action: GhMergeGroupAction,
+    /// Merge group.
+    pub merge_group: GhMergeGroup,
+    /// Destruction reason (`merged`, `invalidated` or `dequeued`).
+    pub reason: Option<String>,
+    /// Repository.
+    pub repository: GhRepository,
+    /// Organization.
+    pub organization: Option<GhUser>,
+    /// Sender.
+    pub sender: GhUser,
+}
@@ -0,0 +1,7 @@
+mod merge_group;
+mod merge_group_action;
+mod merge_group_event;
+
+pub use merge_group::GhMergeGroup;
+pub use merge_group_action::GhMergeGroupAction;
+pub use merge_group_event::GhMergeGroupEvent;
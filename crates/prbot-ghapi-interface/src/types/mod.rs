@@ -1,15 +1,25 @@
 mod checks;
 mod common;
+mod contents;
+mod git;
 mod issues;
+mod merge_groups;
 mod ping;
 mod pulls;
+mod pushes;
 mod reviews;
 mod statuses;
+mod workflows;
 
 pub use checks::*;
 pub use common::*;
+pub use contents::*;
+pub use git::*;
 pub use issues::*;
+pub use merge_groups::*;
 pub use ping::*;
 pub use pulls::*;
+pub use pushes::*;
 pub use reviews::*;
 pub use statuses::*;
+pub use workflows::*;
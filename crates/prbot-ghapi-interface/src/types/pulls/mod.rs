@@ -1,10 +1,12 @@
 mod merge_strategy;
+mod pull_commit;
 mod pull_request;
 mod pull_request_action;
 mod pull_request_event;
 mod pull_request_state;
 
 pub use merge_strategy::GhMergeStrategy;
+pub use pull_commit::GhPullCommit;
 pub use pull_request::{GhPullRequest, GhPullRequestShort};
 pub use pull_request_action::GhPullRequestAction;
 pub use pull_request_event::GhPullRequestEvent;
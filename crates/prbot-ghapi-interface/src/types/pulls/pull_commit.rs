@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// GitHub Pull request commit.
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq, Eq, Clone)]
+pub struct GhPullCommit {
+    /// SHA.
+    pub sha: String,
+    /// Message.
+    pub message: String,
+    /// Author name.
+    pub author_name: String,
+    /// Author email.
+    pub author_email: String,
+}
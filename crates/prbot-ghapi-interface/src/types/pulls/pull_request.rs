@@ -10,6 +10,9 @@ use crate::types::common::{GhBranch, GhBranchShort, GhLabel, GhUser};
 pub struct GhPullRequest {
     /// Number.
     pub number: u64,
+    /// GraphQL node ID.
+    #[serde(default)]
+    pub node_id: String,
     /// State.
     pub state: GhPullRequestState,
     /// Locked.
@@ -50,6 +53,15 @@ pub struct GhPullRequest {
     pub mergeable: Option<bool>,
     /// Rebaseable?
     pub rebaseable: Option<bool>,
+    /// Merge commit SHA, once merged.
+    #[serde(default)]
+    pub merge_commit_sha: Option<String>,
+    /// Added lines.
+    #[serde(default)]
+    pub additions: u64,
+    /// Deleted lines.
+    #[serde(default)]
+    pub deletions: u64,
 }
 
 /// GitHub Pull request short format.
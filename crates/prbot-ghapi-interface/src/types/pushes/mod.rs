@@ -0,0 +1,3 @@
+mod push_event;
+
+pub use push_event::GhPushEvent;
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::common::{GhRepository, GhUser};
+
+/// GitHub Push event.
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct GhPushEvent {
+    /// Pushed reference, e.g. `refs/heads/main`.
+    #[serde(rename = "ref")]
+    pub reference: String,
+    /// SHA before the push.
+    pub before: String,
+    /// SHA after the push.
+    pub after: String,
+    /// Whether the reference was deleted.
+    #[serde(default)]
+    pub deleted: bool,
+    /// Repository.
+    pub repository: GhRepository,
+    /// Organization.
+    pub organization: Option<GhUser>,
+    /// Sender.
+    pub sender: GhUser,
+}
+
+impl GhPushEvent {
+    /// Check if the push updates the repository default branch.
+    pub fn is_default_branch_update(&self) -> bool {
+        !self.deleted
+            && !self.repository.default_branch.is_empty()
+            && self.reference == format!("refs/heads/{}", self.repository.default_branch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_default_branch_update() {
+        let event = |reference: &str, deleted: bool| GhPushEvent {
+            reference: reference.into(),
+            deleted,
+            repository: GhRepository {
+                default_branch: "main".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(event("refs/heads/main", false).is_default_branch_update());
+        assert!(!event("refs/heads/main", true).is_default_branch_update());
+        assert!(!event("refs/heads/feature", false).is_default_branch_update());
+        assert!(!event("refs/tags/main", false).is_default_branch_update());
+    }
+}
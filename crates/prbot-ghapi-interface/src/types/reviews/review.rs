@@ -14,4 +14,7 @@ pub struct GhReview {
     pub submitted_at: Option<OffsetDateTime>,
     /// State.
     pub state: GhReviewState,
+    /// Reviewed commit SHA.
+    #[serde(default)]
+    pub commit_id: Option<String>,
 }
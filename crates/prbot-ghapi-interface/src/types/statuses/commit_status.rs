@@ -6,6 +6,7 @@ use time::OffsetDateTime;
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, SmartDefault)]
 pub struct GhCommitStatus {
     pub state: GhCommitStatusState,
+    #[serde(rename = "statuses")]
     pub items: Vec<GhCommitStatusItem>,
 }
 
@@ -24,6 +25,7 @@ pub struct GhCommitStatusItem {
 
 /// GitHub commit status state
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, SmartDefault)]
+#[serde(rename_all = "snake_case")]
 pub enum GhCommitStatusState {
     /// Error.
     Error,
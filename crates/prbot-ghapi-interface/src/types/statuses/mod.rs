@@ -1,3 +1,5 @@
 mod commit_status;
+mod status_event;
 
 pub use commit_status::{GhCommitStatus, GhCommitStatusItem, GhCommitStatusState};
+pub use status_event::GhStatusEvent;
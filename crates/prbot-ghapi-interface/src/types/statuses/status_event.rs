@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use time::OffsetDateTime;
+
+use super::GhCommitStatusState;
+use crate::types::common::{GhRepository, GhUser};
+
+/// GitHub Status event.
+#[derive(Debug, Serialize, Deserialize, SmartDefault, PartialEq, Eq)]
+pub struct GhStatusEvent {
+    /// ID.
+    pub id: u64,
+    /// Commit SHA.
+    pub sha: String,
+    /// Context.
+    pub context: String,
+    /// Description.
+    pub description: Option<String>,
+    /// State.
+    pub state: GhCommitStatusState,
+    /// Target URL.
+    pub target_url: Option<String>,
+    /// Created at.
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    /// Updated at.
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+    /// Repository.
+    pub repository: GhRepository,
+    /// Organization.
+    pub organization: Option<GhUser>,
+    /// Sender.
+    pub sender: GhUser,
+}
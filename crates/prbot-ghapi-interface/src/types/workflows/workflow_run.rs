@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use time::OffsetDateTime;
+
+use crate::types::{
+    checks::{GhCheckConclusion, GhCheckStatus},
+    pulls::GhPullRequestShort,
+};
+
+/// GitHub Workflow run.
+#[derive(Debug, Deserialize, Serialize, SmartDefault, PartialEq, Eq, Clone)]
+pub struct GhWorkflowRun {
+    /// ID.
+    pub id: u64,
+    /// Name.
+    pub name: String,
+    /// Head branch.
+    pub head_branch: String,
+    /// Head commit SHA.
+    pub head_sha: String,
+    /// Status.
+    pub status: GhCheckStatus,
+    /// Conclusion.
+    pub conclusion: Option<GhCheckConclusion>,
+    /// Pull requests.
+    pub pull_requests: Vec<GhPullRequestShort>,
+    /// Created at.
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    /// Updated at.
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
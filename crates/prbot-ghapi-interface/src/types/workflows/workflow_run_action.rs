@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// GitHub Workflow run action.
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GhWorkflowRunAction {
+    /// Requested.
+    Requested,
+    /// In progress.
+    InProgress,
+    /// Completed.
+    #[default]
+    Completed,
+}
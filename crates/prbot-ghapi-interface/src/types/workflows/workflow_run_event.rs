@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use super::{GhWorkflowRun, GhWorkflowRunAction};
+use crate::types::common::{GhRepository, GhUser};
+
+/// GitHub Workflow run event.
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct GhWorkflowRunEvent {
+    /// Action.
+    pub action: GhWorkflowRunAction,
+    /// Workflow run.
+    pub workflow_run: GhWorkflowRun,
+    /// Repository.
+    pub repository: GhRepository,
+    /// Organization.
+    pub organization: Option<GhUser>,
+    /// Sender.
+    pub sender: GhUser,
+}
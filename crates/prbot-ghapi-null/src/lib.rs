@@ -8,7 +8,8 @@ use prbot_ghapi_interface::{
     gif::GifResponse,
     review::GhReviewApi,
     types::{
-        GhCheckRun, GhCommitStatus, GhCommitStatusState, GhMergeStrategy, GhPullRequest,
+        GhCheckRun, GhCommitStatus, GhCommitStatusState, GhFileContent, GhGitCommit, GhGitRef,
+        GhIssueComment, GhMergeStrategy, GhPullCommit, GhPullRequest, GhPullRequestShort,
         GhReactionType, GhUser, GhUserPermission,
     },
     ApiService, Result,
@@ -92,6 +93,16 @@ impl ApiService for NullApiService {
         Ok(1)
     }
 
+    #[tracing::instrument(skip(self), ret)]
+    async fn comments_list(
+        &self,
+        owner: &str,
+        name: &str,
+        _issue_number: u64,
+    ) -> Result<Vec<GhIssueComment>> {
+        Ok(vec![])
+    }
+
     #[tracing::instrument(skip(self), ret)]
     async fn comments_update(
         &self,
@@ -130,6 +141,11 @@ impl ApiService for NullApiService {
         })
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn pulls_list(&self, owner: &str, name: &str) -> Result<Vec<GhPullRequest>> {
+        Ok(vec![])
+    }
+
     #[tracing::instrument(skip(self))]
     async fn pulls_merge(
         &self,
@@ -143,6 +159,33 @@ impl ApiService for NullApiService {
         Ok(())
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn pulls_create(
+        &self,
+        owner: &str,
+        name: &str,
+        title: &str,
+        body: &str,
+        head: &str,
+        base: &str,
+    ) -> Result<GhPullRequest> {
+        Ok(GhPullRequest {
+            title: title.into(),
+            body: Some(body.into()),
+            ..Default::default()
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn pulls_enqueue(&self, owner: &str, name: &str, _number: u64) -> Result<()> {
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn pulls_convert_to_draft(&self, owner: &str, name: &str, _number: u64) -> Result<()> {
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     async fn pull_reviewer_requests_add(
         &self,
@@ -175,6 +218,42 @@ impl ApiService for NullApiService {
         Ok(vec![])
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn pull_files_list(&self, owner: &str, name: &str, _number: u64) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn pull_commits_list(
+        &self,
+        owner: &str,
+        name: &str,
+        _number: u64,
+    ) -> Result<Vec<GhPullCommit>> {
+        Ok(vec![])
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn commit_pulls_list(
+        &self,
+        owner: &str,
+        name: &str,
+        sha: &str,
+    ) -> Result<Vec<GhPullRequestShort>> {
+        Ok(vec![])
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn commits_compare_files(
+        &self,
+        owner: &str,
+        name: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
     #[tracing::instrument(skip(self))]
     async fn commit_statuses_combined(
         &self,
@@ -201,6 +280,106 @@ impl ApiService for NullApiService {
         Ok(())
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn git_refs_get(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+    ) -> Result<Option<GhGitRef>> {
+        Ok(None)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_refs_create(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+        sha: &str,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_refs_update(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+        sha: &str,
+        _force: bool,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_refs_delete(&self, owner: &str, name: &str, reference: &str) -> Result<()> {
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_commits_get(&self, owner: &str, name: &str, sha: &str) -> Result<GhGitCommit> {
+        Ok(GhGitCommit {
+            sha: sha.into(),
+            ..Default::default()
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn git_commits_create(
+        &self,
+        owner: &str,
+        name: &str,
+        message: &str,
+        tree: &str,
+        parents: &[String],
+    ) -> Result<GhGitCommit> {
+        Ok(GhGitCommit {
+            message: message.into(),
+            ..Default::default()
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repository_merges_create(
+        &self,
+        owner: &str,
+        name: &str,
+        base: &str,
+        head: &str,
+        commit_message: &str,
+    ) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn repository_contents_get<'a>(
+        &self,
+        owner: &str,
+        name: &str,
+        path: &str,
+        _reference: Option<&'a str>,
+    ) -> Result<Option<GhFileContent>> {
+        Ok(None)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn team_members_list(&self, org: &str, team_slug: &str) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn team_permissions_get(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        name: &str,
+    ) -> Result<GhUserPermission> {
+        Ok(GhUserPermission::Write)
+    }
+
     #[tracing::instrument(skip(self))]
     async fn gif_search(&self, api_key: &str, search: &str) -> Result<GifResponse> {
         Ok(GifResponse { results: vec![] })
@@ -0,0 +1,147 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+use crate::RepositoryPath;
+
+#[derive(Debug, Error)]
+pub enum AuditSourceError {
+    /// Unknown audit source.
+    #[error("Unknown audit source: {}", value)]
+    UnknownAuditSource { value: String },
+}
+
+/// Where an audited change comes from.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Copy, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditSource {
+    /// Bot command in a comment or a pull request body.
+    #[default]
+    Comment,
+    /// Command line.
+    Cli,
+    /// External API, using an external account token.
+    ExternalApi,
+}
+
+impl AuditSource {
+    /// Convert source to static str.
+    pub fn to_str(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl Display for AuditSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+impl TryFrom<&str> for AuditSource {
+    type Error = AuditSourceError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "comment" => Ok(Self::Comment),
+            "cli" => Ok(Self::Cli),
+            "external_api" => Ok(Self::ExternalApi),
+            e => Err(AuditSourceError::UnknownAuditSource {
+                value: e.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<AuditSource> for &'static str {
+    fn from(source: AuditSource) -> Self {
+        match source {
+            AuditSource::Comment => "comment",
+            AuditSource::Cli => "cli",
+            AuditSource::ExternalApi => "external_api",
+        }
+    }
+}
+
+/// Who made an audited change, and from where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditActor {
+    pub source: AuditSource,
+    pub username: String,
+}
+
+impl AuditActor {
+    /// Creates a new actor.
+    pub fn new(source: AuditSource, username: &str) -> Self {
+        Self {
+            source,
+            username: username.into(),
+        }
+    }
+}
+
+/// Audit log entry, for a configuration or admin change.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SmartDefault)]
+pub struct AuditEntry {
+    pub id: u64,
+    pub source: AuditSource,
+    pub actor: String,
+    pub action: String,
+    /// Changed object: a repository path, a pull request, an account, ...
+    pub target: String,
+    /// Repository path of the changed object, if any.
+    pub repository_path: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl AuditEntry {
+    /// Creates a new entry on a target.
+    pub fn new(actor: &AuditActor, action: &str, target: &str) -> Self {
+        Self {
+            source: actor.source,
+            actor: actor.username.clone(),
+            action: action.into(),
+            target: target.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new entry on a repository.
+    pub fn on_repository(actor: &AuditActor, action: &str, path: &RepositoryPath) -> Self {
+        Self::new(actor, action, &path.full_name()).with_repository(path)
+    }
+
+    /// Creates a new entry on a pull request.
+    pub fn on_pull_request(
+        actor: &AuditActor,
+        action: &str,
+        path: &RepositoryPath,
+        number: u64,
+    ) -> Self {
+        Self::new(actor, action, &format!("{path}#{number}")).with_repository(path)
+    }
+
+    /// Set the repository path.
+    pub fn with_repository(mut self, path: &RepositoryPath) -> Self {
+        self.repository_path = Some(path.full_name());
+        self
+    }
+
+    /// Set the value before the change.
+    pub fn with_old_value<T: ToString>(mut self, value: T) -> Self {
+        self.old_value = Some(value.to_string());
+        self
+    }
+
+    /// Set the value after the change.
+    pub fn with_new_value<T: ToString>(mut self, value: T) -> Self {
+        self.new_value = Some(value.to_string());
+        self
+    }
+}
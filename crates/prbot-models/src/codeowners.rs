@@ -0,0 +1,174 @@
+use glob::{MatchOptions, Pattern};
+
+/// Code owners file paths, in lookup order.
+pub const CODEOWNERS_PATHS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Code owners rule, from a CODEOWNERS line.
+#[derive(Debug, Clone)]
+pub struct CodeOwnersRule {
+    pub pattern: String,
+    /// Owner usernames and `org/team` team names, without the leading `@`.
+    pub owners: Vec<String>,
+    matchers: Vec<Pattern>,
+}
+
+impl CodeOwnersRule {
+    /// Check if the rule matches a file path.
+    pub fn matches(&self, path: &str) -> bool {
+        self.matchers
+            .iter()
+            .any(|m| m.matches_with(path, MATCH_OPTIONS))
+    }
+}
+
+/// Parsed CODEOWNERS file.
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    pub rules: Vec<CodeOwnersRule>,
+}
+
+impl CodeOwners {
+    /// Parse a CODEOWNERS file.
+    ///
+    /// Invalid patterns and e-mail owners are ignored.
+    pub fn parse(content: &str) -> Self {
+        let mut rules = vec![];
+        for line in content.lines() {
+            let line = line.split(" #").next().unwrap_or_default().trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            let pattern = parts.next().unwrap_or_default();
+            let Some(matchers) = Self::build_matchers(pattern) else {
+                continue;
+            };
+            let owners = parts
+                .filter_map(|owner| owner.strip_prefix('@'))
+                .map(Into::into)
+                .collect();
+
+            rules.push(CodeOwnersRule {
+                pattern: pattern.into(),
+                owners,
+                matchers,
+            });
+        }
+
+        Self { rules }
+    }
+
+    /// Get the owners of a file path: the last matching rule wins.
+    pub fn owners_of(&self, path: &str) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|r| r.matches(path))
+            .map(|r| &r.owners[..])
+            .unwrap_or_default()
+    }
+
+    /// Get the owners of multiple file paths, deduplicated.
+    pub fn owners_of_files<S: AsRef<str>>(&self, paths: &[S]) -> Vec<String> {
+        let mut owners: Vec<String> = vec![];
+        for path in paths {
+            for owner in self.owners_of(path.as_ref()) {
+                if !owners.contains(owner) {
+                    owners.push(owner.clone());
+                }
+            }
+        }
+
+        owners
+    }
+
+    /// Convert a gitignore-like pattern to glob patterns.
+    fn build_matchers(pattern: &str) -> Option<Vec<Pattern>> {
+        let directory_only = pattern.ends_with('/');
+        let trimmed = pattern.trim_end_matches('/');
+        // Patterns with a slash are relative to the repository root
+        let anchored = trimmed.contains('/');
+        let trimmed = trimmed.trim_start_matches('/');
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        let base = if anchored {
+            trimmed.to_string()
+        } else {
+            format!("**/{trimmed}")
+        };
+
+        let mut globs = vec![];
+        if !directory_only {
+            globs.push(base.clone());
+        }
+        // Named directories own all their files, but `docs/*` only owns direct children
+        let last_component = trimmed.rsplit('/').next().unwrap_or_default();
+        if directory_only || !last_component.contains('*') {
+            globs.push(format!("{base}/**"));
+        }
+
+        globs.iter().map(|g| Pattern::new(g).ok()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeOwners;
+
+    const CODEOWNERS: &str = r#"
+# Default owners
+*       @global-owner
+
+*.js    @js-owner # JavaScript files
+/build/logs/ @doctocat
+docs/*  docs@example.com @docs-owner
+apps/   @org/apps
+/scripts/ @org/ops @ops-owner
+/scripts/generated/
+"#;
+
+    #[test]
+    fn owners_of() {
+        let owners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(owners.owners_of("README.md"), ["global-owner"]);
+        assert_eq!(owners.owners_of("src/main.js"), ["js-owner"]);
+        assert_eq!(owners.owners_of("build/logs/out.txt"), ["doctocat"]);
+        assert_eq!(owners.owners_of("build/logs/a/b.txt"), ["doctocat"]);
+        assert_eq!(
+            owners.owners_of("other/build/logs/out.txt"),
+            ["global-owner"]
+        );
+        assert_eq!(owners.owners_of("docs/index.md"), ["docs-owner"]);
+        assert_eq!(owners.owners_of("docs/guide/index.md"), ["global-owner"]);
+        assert_eq!(owners.owners_of("apps/web/index.html"), ["org/apps"]);
+        assert_eq!(owners.owners_of("src/apps/web/index.html"), ["org/apps"]);
+        assert_eq!(
+            owners.owners_of("scripts/deploy.sh"),
+            ["org/ops", "ops-owner"]
+        );
+        assert!(owners.owners_of("scripts/generated/out.sh").is_empty());
+    }
+
+    #[test]
+    fn owners_of_files() {
+        let owners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(
+            owners.owners_of_files(&["README.md", "src/main.js", "scripts/a.sh", "index.js"]),
+            vec!["global-owner", "js-owner", "org/ops", "ops-owner"]
+        );
+        assert!(CodeOwners::parse("")
+            .owners_of_files(&["README.md"])
+            .is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+//! Deadline helpers.
+
+use time::{format_description::well_known::Rfc3339, Date, Duration, Month, OffsetDateTime, Time};
+
+/// Parse a deadline, relative to `now`.
+///
+/// Supported formats:
+/// - a duration, like `30m`, `2h`, `3d` or `1w`
+/// - a date, like `2026-10-20` (midnight, UTC)
+/// - a RFC 3339 datetime, like `2026-10-20T18:00:00Z`
+pub fn parse_deadline(value: &str, now: OffsetDateTime) -> Result<OffsetDateTime, String> {
+    parse_date(value, now, false).map_err(|()| format!("Invalid deadline: '{}'", value.trim()))
+}
+
+/// Parse a start date, relative to `now`.
+///
+/// Supports the same formats as [`parse_deadline`], but durations are
+/// counted backwards: `30d` means 30 days ago.
+pub fn parse_since(value: &str, now: OffsetDateTime) -> Result<OffsetDateTime, String> {
+    parse_date(value, now, true).map_err(|()| format!("Invalid date: '{}'", value.trim()))
+}
+
+fn parse_date(value: &str, now: OffsetDateTime, backwards: bool) -> Result<OffsetDateTime, ()> {
+    let value = value.trim();
+
+    if let Some(unit) = value.chars().last().filter(|c| c.is_ascii_alphabetic()) {
+        if let Ok(count) = value[..value.len() - 1].parse::<i64>() {
+            let duration = match unit {
+                'm' => Duration::minutes(count),
+                'h' => Duration::hours(count),
+                'd' => Duration::days(count),
+                'w' => Duration::weeks(count),
+                _ => return Err(()),
+            };
+
+            return Ok(if backwards {
+                now - duration
+            } else {
+                now + duration
+            });
+        }
+    }
+
+    if let Ok(datetime) = OffsetDateTime::parse(value, &Rfc3339) {
+        return Ok(datetime);
+    }
+
+    let parts: Vec<_> = value.split('-').collect();
+    if let [year, month, day] = parts[..] {
+        let year = year.parse().map_err(|_| ())?;
+        let month = month
+            .parse::<u8>()
+            .ok()
+            .and_then(|m| Month::try_from(m).ok())
+            .ok_or(())?;
+        let day = day.parse().map_err(|_| ())?;
+        let date = Date::from_calendar_date(year, month, day).map_err(|_| ())?;
+
+        return Ok(date.with_time(Time::MIDNIGHT).assume_utc());
+    }
+
+    Err(())
+}
+
+/// Format a datetime for messages, like `2026-10-20 18:00 UTC`.
+pub fn format_datetime(value: OffsetDateTime) -> String {
+    let value = value.to_offset(time::UtcOffset::UTC);
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02} UTC",
+        value.year(),
+        value.month() as u8,
+        value.day(),
+        value.hour(),
+        value.minute()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, day)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn parse() {
+        let now = utc(17, 10, 30);
+
+        assert_eq!(parse_deadline("30m", now), Ok(utc(17, 11, 0)));
+        assert_eq!(parse_deadline("2h", now), Ok(utc(17, 12, 30)));
+        assert_eq!(parse_deadline("3d", now), Ok(utc(20, 10, 30)));
+        assert_eq!(parse_deadline("1w", now), Ok(utc(24, 10, 30)));
+        assert_eq!(parse_deadline("2026-10-20", now), Ok(utc(20, 0, 0)));
+        assert_eq!(
+            parse_deadline("2026-10-20T18:00:00+02:00", now),
+            Ok(utc(20, 16, 0))
+        );
+
+        for value in ["", "2y", "h", "tomorrow", "2026-13-01", "2026-10"] {
+            assert!(parse_deadline(value, now).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn parse_since_date() {
+        let now = utc(17, 10, 30);
+
+        assert_eq!(parse_since("2h", now), Ok(utc(17, 8, 30)));
+        assert_eq!(parse_since("1w", now), Ok(utc(10, 10, 30)));
+        assert_eq!(parse_since("2026-10-01", now), Ok(utc(1, 0, 0)));
+        assert_eq!(
+            parse_since("tomorrow", now),
+            Err("Invalid date: 'tomorrow'".into())
+        );
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(
+            format_datetime(utc(20, 18, 5).to_offset(time::UtcOffset::from_hms(2, 0, 0).unwrap())),
+            "2026-10-20 18:05 UTC"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+#[derive(Debug, Error)]
+pub enum JobError {
+    /// Unknown job kind.
+    #[error("Unknown job kind: {}", kind)]
+    UnknownJobKind { kind: String },
+    /// Unknown job status.
+    #[error("Unknown job status: {}", status)]
+    UnknownJobStatus { status: String },
+}
+
+/// Job kind, i.e. the work to execute.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Copy, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    /// Process a pull request event.
+    #[default]
+    ProcessPullRequestEvent,
+    /// Handle an issue comment event.
+    HandleIssueCommentEvent,
+    /// Handle a check run event.
+    HandleCheckRunEvent,
+    /// Handle a check suite event.
+    HandleCheckSuiteEvent,
+    /// Handle a workflow run event.
+    HandleWorkflowRunEvent,
+    /// Handle a commit status event.
+    HandleStatusEvent,
+    /// Handle a merge group event.
+    HandleMergeGroupEvent,
+    /// Handle a pull request review event.
+    HandleReviewEvent,
+    /// Handle a push event.
+    HandlePushEvent,
+    /// Set QA status on pull requests.
+    SetPullRequestQaStatus,
+    /// Retry merging a pull request, once merges are not frozen anymore.
+    RetryPullRequestMerge,
+}
+
+impl JobKind {
+    /// Convert job kind to static str.
+    pub fn to_str(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl Display for JobKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+impl TryFrom<&str> for JobKind {
+    type Error = JobError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "process_pull_request_event" => Ok(Self::ProcessPullRequestEvent),
+            "handle_issue_comment_event" => Ok(Self::HandleIssueCommentEvent),
+            "handle_check_run_event" => Ok(Self::HandleCheckRunEvent),
+            "handle_check_suite_event" => Ok(Self::HandleCheckSuiteEvent),
+            "handle_workflow_run_event" => Ok(Self::HandleWorkflowRunEvent),
+            "handle_status_event" => Ok(Self::HandleStatusEvent),
+            "handle_merge_group_event" => Ok(Self::HandleMergeGroupEvent),
+            "handle_review_event" => Ok(Self::HandleReviewEvent),
+            "handle_push_event" => Ok(Self::HandlePushEvent),
+            "set_pull_request_qa_status" => Ok(Self::SetPullRequestQaStatus),
+            "retry_pull_request_merge" => Ok(Self::RetryPullRequestMerge),
+            e => Err(JobError::UnknownJobKind {
+                kind: e.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<JobKind> for &'static str {
+    fn from(kind: JobKind) -> Self {
+        match kind {
+            JobKind::ProcessPullRequestEvent => "process_pull_request_event",
+            JobKind::HandleIssueCommentEvent => "handle_issue_comment_event",
+            JobKind::HandleCheckRunEvent => "handle_check_run_event",
+            JobKind::HandleCheckSuiteEvent => "handle_check_suite_event",
+            JobKind::HandleWorkflowRunEvent => "handle_workflow_run_event",
+            JobKind::HandleStatusEvent => "handle_status_event",
+            JobKind::HandleMergeGroupEvent => "handle_merge_group_event",
+            JobKind::HandleReviewEvent => "handle_review_event",
+            JobKind::HandlePushEvent => "handle_push_event",
+            JobKind::SetPullRequestQaStatus => "set_pull_request_qa_status",
+            JobKind::RetryPullRequestMerge => "retry_pull_request_merge",
+        }
+    }
+}
+
+/// Job status.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Copy, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Waiting to be run.
+    #[default]
+    Pending,
+    /// Currently running.
+    Running,
+    /// Ran without error.
+    Succeeded,
+    /// Failed too many times, will not be retried automatically.
+    DeadLetter,
+    /// Cancelled by hand.
+    Cancelled,
+}
+
+impl JobStatus {
+    /// Convert job status to static str.
+    pub fn to_str(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl Display for JobStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+impl FromStr for JobStatus {
+    type Err = JobError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<&str> for JobStatus {
+    type Error = JobError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "running" => Ok(Self::Running),
+            "succeeded" => Ok(Self::Succeeded),
+            "dead_letter" => Ok(Self::DeadLetter),
+            "cancelled" => Ok(Self::Cancelled),
+            e => Err(JobError::UnknownJobStatus {
+                status: e.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<JobStatus> for &'static str {
+    fn from(status: JobStatus) -> Self {
+        match status {
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Succeeded => "succeeded",
+            JobStatus::DeadLetter => "dead_letter",
+            JobStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SmartDefault)]
+pub struct Job {
+    pub id: u64,
+    pub kind: JobKind,
+    pub payload: String,
+    pub status: JobStatus,
+    pub attempts: u64,
+    #[default(5)]
+    pub max_attempts: u64,
+    pub last_error: Option<String>,
+    pub delivery_id: Option<String>,
+    /// Worker running the job.
+    pub locked_by: Option<String>,
+    /// Lease expiration of the running job, renewed by its worker.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub locked_until: Option<OffsetDateTime>,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub run_at: OffsetDateTime,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl Job {
+    /// Check if the job can be manually retried.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self.status, JobStatus::DeadLetter | JobStatus::Cancelled)
+    }
+}
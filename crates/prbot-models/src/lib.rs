@@ -1,31 +1,66 @@
 mod account;
+mod audit_entry;
 mod checks_status;
+mod codeowners;
+mod deadline;
 mod external_account;
 mod external_account_right;
+mod job;
+mod merge_commit;
+mod merge_freeze;
+mod merge_queue_entry;
 mod merge_rule;
 mod merge_strategy;
 mod pull_request;
+mod pull_request_event;
 mod pull_request_handle;
 mod pull_request_rule;
 mod qa_status;
 mod repository;
+mod repository_config;
 mod repository_path;
+mod repository_stats;
 mod required_reviewer;
+mod reviewer_assignment;
 mod rule_branch;
+mod stale_approval_policy;
 mod step_label;
+mod step_transition;
+mod webhook_delivery;
 
 pub use account::Account;
+pub use audit_entry::{AuditActor, AuditEntry, AuditSource, AuditSourceError};
 pub use checks_status::ChecksStatus;
+pub use codeowners::{CodeOwners, CodeOwnersRule, CODEOWNERS_PATHS};
+pub use deadline::{format_datetime, parse_deadline, parse_since};
 pub use external_account::{ExternalAccount, ExternalJwtClaims};
 pub use external_account_right::ExternalAccountRight;
+pub use job::{Job, JobError, JobKind, JobStatus};
+pub use merge_commit::{
+    normalize_conventional_commit, MergeCommitVariables, DEFAULT_MERGE_COMMIT_TITLE_TEMPLATE,
+};
+pub use merge_freeze::{FrozenReason, MergeFreeze, MergeWindow};
+pub use merge_queue_entry::{MergeQueueEntry, MergeQueueStatus, MergeQueueStatusError};
 pub use merge_rule::MergeRule;
 pub use merge_strategy::MergeStrategy;
 pub use pull_request::PullRequest;
+pub use pull_request_event::{PullRequestEvent, PullRequestEventKind, PullRequestEventKindError};
 pub use pull_request_handle::PullRequestHandle;
-pub use pull_request_rule::{PullRequestRule, RuleAction, RuleCondition};
+pub use pull_request_rule::{PullRequestRule, ResolvedRuleAction, RuleAction, RuleCondition};
 pub use qa_status::QaStatus;
 pub use repository::Repository;
+pub use repository_config::{
+    MergeRuleConfig, PullRequestRuleConfig, RepositoryConfig, RepositoryConfigError,
+    REPOSITORY_CONFIG_PATHS,
+};
 pub use repository_path::RepositoryPath;
+pub use repository_stats::{DurationStats, PullRequestTimeline, RepositoryStats};
 pub use required_reviewer::RequiredReviewer;
+pub use reviewer_assignment::{
+    AwayUser, ReviewerAssignmentStrategy, ReviewerAssignmentStrategyError,
+};
 pub use rule_branch::RuleBranch;
+pub use stale_approval_policy::{StaleApprovalPolicy, StaleApprovalPolicyError};
 pub use step_label::StepLabel;
+pub use step_transition::StepTransition;
+pub use webhook_delivery::{WebhookDelivery, WebhookDeliveryOutcome, WebhookDeliveryOutcomeError};
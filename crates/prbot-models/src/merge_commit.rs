@@ -0,0 +1,228 @@
+//! Merge commit templates.
+
+use std::sync::OnceLock;
+
+use regex::{Captures, Regex};
+
+use crate::QaStatus;
+
+/// Default merge commit title template.
+pub const DEFAULT_MERGE_COMMIT_TITLE_TEMPLATE: &str = "{title} (#{number})";
+
+/// Variables available in merge commit templates.
+///
+/// - `{title}`, `{number}`, `{author}`, `{body}`, `{base}`, `{head}`: pull request details
+/// - `{approvers}`: approvers logins, like `@one, @two`
+/// - `{co_authors}`: one `Co-authored-by: Name <email>` trailer per line
+/// - `{issues}`: issues closed by the pull request body, like `#1, #2`
+/// - `{qa_status}`: QA status
+///
+/// Unknown placeholders are kept as-is.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergeCommitVariables {
+    pub title: String,
+    pub number: u64,
+    pub author: String,
+    pub body: String,
+    pub base: String,
+    pub head: String,
+    pub approvers: Vec<String>,
+    /// Co-authors, as `Name <email>`.
+    pub co_authors: Vec<String>,
+    pub issues: Vec<u64>,
+    pub qa_status: QaStatus,
+}
+
+impl MergeCommitVariables {
+    /// Check if a template uses a variable.
+    pub fn is_used(template: &str, variable: &str) -> bool {
+        template.contains(&format!("{{{variable}}}"))
+    }
+
+    /// Render a template, trimming surrounding whitespace.
+    pub fn render(&self, template: &str) -> String {
+        static PLACEHOLDER: OnceLock<Regex> = OnceLock::new();
+        let placeholder = PLACEHOLDER.get_or_init(|| Regex::new(r"\{([a-z_]+)\}").unwrap());
+
+        placeholder
+            .replace_all(template, |captures: &Captures| match &captures[1] {
+                "title" => self.title.clone(),
+                "number" => self.number.to_string(),
+                "author" => self.author.clone(),
+                "body" => self.body.clone(),
+                "base" => self.base.clone(),
+                "head" => self.head.clone(),
+                "approvers" => self
+                    .approvers
+                    .iter()
+                    .map(|a| format!("@{a}"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                "co_authors" => self
+                    .co_authors
+                    .iter()
+                    .map(|a| format!("Co-authored-by: {a}"))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                "issues" => self
+                    .issues
+                    .iter()
+                    .map(|i| format!("#{i}"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                "qa_status" => self.qa_status.to_string(),
+                _ => captures[0].to_string(),
+            })
+            .trim()
+            .to_string()
+    }
+
+    /// Extract issues closed by a pull request body, like `Fixes #12`.
+    pub fn parse_linked_issues(body: &str) -> Vec<u64> {
+        static KEYWORD: OnceLock<Regex> = OnceLock::new();
+        let keyword = KEYWORD.get_or_init(|| {
+            Regex::new(r"(?i)\b(?:close[sd]?|fix(?:e[sd])?|resolve[sd]?)\s*:?\s+#(\d+)\b").unwrap()
+        });
+
+        let mut issues = vec![];
+        for captures in keyword.captures_iter(body) {
+            if let Ok(issue) = captures[1].parse() {
+                if !issues.contains(&issue) {
+                    issues.push(issue);
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Extract `Co-authored-by` trailers from a commit message.
+    pub fn parse_co_authors(message: &str) -> Vec<String> {
+        message
+            .lines()
+            .filter_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.trim()
+                    .eq_ignore_ascii_case("co-authored-by")
+                    .then(|| value.trim().to_string())
+            })
+            .filter(|value| !value.is_empty())
+            .collect()
+    }
+}
+
+/// Normalize a title following the Conventional Commits format.
+///
+/// The type and scope are lowercased, common type aliases are replaced
+/// (like `feature` to `feat`), spacing is fixed, and the description
+/// starts with a lowercase letter, without a trailing period.
+/// Titles without a type are kept as-is.
+pub fn normalize_conventional_commit(title: &str) -> String {
+    static CONVENTIONAL: OnceLock<Regex> = OnceLock::new();
+    let conventional = CONVENTIONAL.get_or_init(|| {
+        Regex::new(r"^\s*([A-Za-z]+)\s*(?:\(\s*([^)]*?)\s*\))?\s*(!)?\s*:\s*(.+?)\s*$").unwrap()
+    });
+
+    let Some(captures) = conventional.captures(title) else {
+        return title.to_string();
+    };
+
+    let kind = captures[1].to_lowercase();
+    let kind = match kind.as_str() {
+        "feature" | "features" => "feat",
+        "bug" | "bugfix" | "hotfix" => "fix",
+        "doc" => "docs",
+        "tests" => "test",
+        kind => kind,
+    };
+    let scope = captures
+        .get(2)
+        .map(|s| s.as_str().to_lowercase())
+        .filter(|s| !s.is_empty())
+        .map(|s| format!("({s})"))
+        .unwrap_or_default();
+    let breaking = captures.get(3).map(|_| "!").unwrap_or_default();
+
+    let description = captures[4].trim_end_matches('.');
+    let mut chars = description.chars();
+    let description = match (chars.next(), chars.next()) {
+        // Keep acronyms, like "API"
+        (Some(first), Some(second)) if !second.is_uppercase() => {
+            format!(
+                "{}{}",
+                first.to_lowercase(),
+                &description[first.len_utf8()..]
+            )
+        }
+        _ => description.to_string(),
+    };
+
+    format!("{kind}{scope}{breaking}: {description}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let variables = MergeCommitVariables {
+            title: "Add {body} support".into(),
+            number: 12,
+            author: "me".into(),
+            approvers: vec!["one".into(), "two".into()],
+            co_authors: vec!["One <one@example.com>".into()],
+            issues: vec![1, 2],
+            qa_status: QaStatus::Pass,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            variables.render(DEFAULT_MERGE_COMMIT_TITLE_TEMPLATE),
+            "Add {body} support (#12)"
+        );
+        assert_eq!(
+            variables.render(
+                "Closes {issues}\nApproved by {approvers}, QA {qa_status}, {unknown}\n\n{co_authors}\n"
+            ),
+            "Closes #1, #2\n\
+            Approved by @one, @two, QA pass, {unknown}\n\
+            \n\
+            Co-authored-by: One <one@example.com>"
+        );
+        assert_eq!(MergeCommitVariables::default().render("{co_authors}\n"), "");
+    }
+
+    #[test]
+    fn parse_linked_issues() {
+        assert_eq!(
+            MergeCommitVariables::parse_linked_issues(
+                "Fixes #1, closes: #2\nResolved #3 and #4, fix #1, see #5"
+            ),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn parse_co_authors() {
+        assert_eq!(
+            MergeCommitVariables::parse_co_authors(
+                "Fix\n\nco-authored-by: One <one@example.com>\nCo-Authored-By:Two <two@example.com>"
+            ),
+            vec!["One <one@example.com>", "Two <two@example.com>"]
+        );
+    }
+
+    #[test]
+    fn conventional_commit() {
+        for (title, expected) in [
+            ("feat: add thing", "feat: add thing"),
+            ("Feature(API): Add thing.", "feat(api): add thing"),
+            ("BUGFIX (core) ! :  Fix crash", "fix(core)!: fix crash"),
+            ("docs(): API changes", "docs: API changes"),
+            ("Add thing", "Add thing"),
+        ] {
+            assert_eq!(normalize_conventional_commit(title), expected);
+        }
+    }
+}
@@ -0,0 +1,462 @@
+//! Merge freezes and merge windows.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use time::{Duration, OffsetDateTime, Time, UtcOffset, Weekday};
+
+use crate::{deadline::format_datetime, Repository, RuleBranch};
+
+const WEEKDAYS: [(Weekday, &str); 7] = [
+    (Weekday::Monday, "mon"),
+    (Weekday::Tuesday, "tue"),
+    (Weekday::Wednesday, "wed"),
+    (Weekday::Thursday, "thu"),
+    (Weekday::Friday, "fri"),
+    (Weekday::Saturday, "sat"),
+    (Weekday::Sunday, "sun"),
+];
+
+/// Merge freeze: no merges on matching base branches until a date.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SmartDefault)]
+pub struct MergeFreeze {
+    pub repository_id: u64,
+    pub base_branch: RuleBranch,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub until: OffsetDateTime,
+    pub reason: Option<String>,
+    pub created_by: String,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl MergeFreeze {
+    pub fn with_repository(mut self, repository: &Repository) -> Self {
+        self.repository_id = repository.id;
+        self
+    }
+
+    /// Check if the freeze is still running.
+    pub fn is_active(&self, now: OffsetDateTime) -> bool {
+        now < self.until
+    }
+}
+
+/// Merge window: merges on matching base branches only happen during it.
+///
+/// Days and times are evaluated at the window UTC offset.
+/// A window ending before its start ends on the next day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SmartDefault)]
+pub struct MergeWindow {
+    pub id: u64,
+    pub repository_id: u64,
+    pub base_branch: RuleBranch,
+    pub days: Vec<Weekday>,
+    #[default(Time::MIDNIGHT)]
+    pub start_time: Time,
+    #[default(Time::MIDNIGHT)]
+    pub end_time: Time,
+    #[default(UtcOffset::UTC)]
+    pub utc_offset: UtcOffset,
+}
+
+impl MergeWindow {
+    pub fn with_repository(mut self, repository: &Repository) -> Self {
+        self.repository_id = repository.id;
+        self
+    }
+
+    /// Parse a schedule, like `mon-fri 09:00-18:00` or `sat,sun 22:00-06:00 UTC+02:00`.
+    ///
+    /// Times are in UTC without an offset.
+    pub fn parse_schedule(value: &str) -> Result<(Vec<Weekday>, Time, Time, UtcOffset), String> {
+        let invalid = || format!("Invalid merge window schedule: '{value}'");
+        let mut parts = value.split_whitespace();
+        let days = parts.next().ok_or_else(invalid)?;
+        let days = Self::parse_days(days).ok_or_else(invalid)?;
+        let times = parts.next().ok_or_else(invalid)?;
+        let (start_time, end_time) = times.split_once('-').ok_or_else(invalid)?;
+        let start_time = Self::parse_time(start_time).ok_or_else(invalid)?;
+        let end_time = Self::parse_time(end_time).ok_or_else(invalid)?;
+        if start_time == end_time {
+            return Err(invalid());
+        }
+        let utc_offset = match parts.next() {
+            Some(offset) => Self::parse_utc_offset(offset).ok_or_else(invalid)?,
+            None => UtcOffset::UTC,
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok((days, start_time, end_time, utc_offset))
+    }
+
+    /// Parse comma-separated days or day ranges, like `mon-wed,fri`.
+    pub fn parse_days(value: &str) -> Option<Vec<Weekday>> {
+        let parse_day = |day: &str| {
+            WEEKDAYS
+                .iter()
+                .find(|(_, name)| day.trim().eq_ignore_ascii_case(name))
+                .map(|(day, _)| *day)
+        };
+
+        let mut days = vec![];
+        for item in value.split(',') {
+            if let Some((first, last)) = item.split_once('-') {
+                let (mut day, last) = (parse_day(first)?, parse_day(last)?);
+                days.push(day);
+                while day != last {
+                    day = day.next();
+                    days.push(day);
+                }
+            } else {
+                days.push(parse_day(item)?);
+            }
+        }
+
+        days.sort_by_key(|d| d.number_days_from_monday());
+        days.dedup();
+        Some(days)
+    }
+
+    fn parse_time(value: &str) -> Option<Time> {
+        let (hour, minute) = value.trim().split_once(':')?;
+        Time::from_hms(hour.parse().ok()?, minute.parse().ok()?, 0).ok()
+    }
+
+    /// Parse a UTC offset, like `UTC`, `UTC+2`, `+02:00` or `UTC-05:30`.
+    pub fn parse_utc_offset(value: &str) -> Option<UtcOffset> {
+        let value = value.trim();
+        let value = match value.get(..3) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("utc") => &value[3..],
+            _ => value,
+        };
+        if value.is_empty() {
+            return Some(UtcOffset::UTC);
+        }
+
+        let (sign, value) = if let Some(value) = value.strip_prefix('+') {
+            (1, value)
+        } else {
+            (-1, value.strip_prefix('-')?)
+        };
+        let (hours, minutes) = value.split_once(':').unwrap_or((value, "0"));
+        let (hours, minutes): (i8, i8) = (hours.parse().ok()?, minutes.parse().ok()?);
+        if !(0..=14).contains(&hours) || !(0..60).contains(&minutes) {
+            return None;
+        }
+
+        UtcOffset::from_hms(sign * hours, sign * minutes, 0).ok()
+    }
+
+    /// UTC offset, like `UTC` or `UTC+02:00`.
+    pub fn utc_offset_str(&self) -> String {
+        if self.utc_offset.is_utc() {
+            return "UTC".into();
+        }
+
+        let sign = if self.utc_offset.is_negative() {
+            '-'
+        } else {
+            '+'
+        };
+        format!(
+            "UTC{sign}{:02}:{:02}",
+            self.utc_offset.whole_hours().abs(),
+            self.utc_offset.minutes_past_hour().abs()
+        )
+    }
+
+    /// Comma-separated days, like `mon,tue,wed`.
+    pub fn days_str(&self) -> String {
+        self.days
+            .iter()
+            .filter_map(|day| WEEKDAYS.iter().find(|(d, _)| d == day))
+            .map(|(_, name)| *name)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Schedule, like `mon,tue,wed 09:00-18:00 UTC+02:00`.
+    pub fn schedule(&self) -> String {
+        format!(
+            "{} {:02}:{:02}-{:02}:{:02} {}",
+            self.days_str(),
+            self.start_time.hour(),
+            self.start_time.minute(),
+            self.end_time.hour(),
+            self.end_time.minute(),
+            self.utc_offset_str()
+        )
+    }
+
+    /// Check if the window is open.
+    pub fn is_open(&self, now: OffsetDateTime) -> bool {
+        let now = now.to_offset(self.utc_offset);
+        let (day, time) = (now.weekday(), now.time());
+
+        if self.start_time < self.end_time {
+            self.days.contains(&day) && self.start_time <= time && time < self.end_time
+        } else {
+            (self.days.contains(&day) && time >= self.start_time)
+                || (self.days.contains(&day.previous()) && time < self.end_time)
+        }
+    }
+
+    /// Get the next opening of the window, after `now`.
+    pub fn next_opening(&self, now: OffsetDateTime) -> Option<OffsetDateTime> {
+        let now = now.to_offset(self.utc_offset);
+
+        (0..=7)
+            .map(|offset| now.date() + Duration::days(offset))
+            .filter(|date| self.days.contains(&date.weekday()))
+            .map(|date| {
+                date.with_time(self.start_time)
+                    .assume_offset(self.utc_offset)
+            })
+            .find(|opening| *opening > now)
+            .map(|opening| opening.to_offset(UtcOffset::UTC))
+    }
+}
+
+/// Why merges are blocked on a base branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrozenReason {
+    /// A merge freeze is running.
+    Freeze {
+        until: OffsetDateTime,
+        reason: Option<String>,
+    },
+    /// No merge window is open.
+    OutsideMergeWindow { opens_at: Option<OffsetDateTime> },
+}
+
+impl FrozenReason {
+    /// Resolve freezes and windows for a base branch.
+    ///
+    /// The latest running freeze wins. Without freezes, branches matching
+    /// at least one window are frozen until one of them opens.
+    pub fn resolve(
+        freezes: &[MergeFreeze],
+        windows: &[MergeWindow],
+        base_branch: &str,
+        now: OffsetDateTime,
+    ) -> Option<Self> {
+        let freeze = freezes
+            .iter()
+            .filter(|f| f.is_active(now) && f.base_branch.matches(base_branch))
+            .max_by_key(|f| f.until);
+        if let Some(freeze) = freeze {
+            return Some(Self::Freeze {
+                until: freeze.until,
+                reason: freeze.reason.clone(),
+            });
+        }
+
+        let windows: Vec<_> = windows
+            .iter()
+            .filter(|w| w.base_branch.matches(base_branch))
+            .collect();
+        if windows.is_empty() || windows.iter().any(|w| w.is_open(now)) {
+            None
+        } else {
+            Some(Self::OutsideMergeWindow {
+                opens_at: windows.iter().filter_map(|w| w.next_opening(now)).min(),
+            })
+        }
+    }
+
+    /// When merges should be retried.
+    pub fn retry_at(&self) -> Option<OffsetDateTime> {
+        match self {
+            Self::Freeze { until, .. } => Some(*until),
+            Self::OutsideMergeWindow { opens_at } => *opens_at,
+        }
+    }
+}
+
+impl Display for FrozenReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Freeze { until, reason } => {
+                write!(f, "Merges are frozen until {}", format_datetime(*until))?;
+                if let Some(reason) = reason {
+                    write!(f, " ({reason})")?;
+                }
+
+                Ok(())
+            }
+            Self::OutsideMergeWindow { opens_at } => {
+                f.write_str("Outside of merge windows")?;
+                if let Some(opens_at) = opens_at {
+                    write!(f, ", next opening on {}", format_datetime(*opens_at))?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    // 2026-10-17 is a Saturday.
+    fn utc(day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, day)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    fn window(base_branch: &str, schedule: &str) -> MergeWindow {
+        let (days, start_time, end_time, utc_offset) =
+            MergeWindow::parse_schedule(schedule).unwrap();
+        MergeWindow {
+            base_branch: base_branch.into(),
+            days,
+            start_time,
+            end_time,
+            utc_offset,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse_schedule() {
+        let office_hours = window("main", "Mon-Fri 09:00-18:30");
+        assert_eq!(
+            office_hours.days,
+            vec![
+                Weekday::Monday,
+                Weekday::Tuesday,
+                Weekday::Wednesday,
+                Weekday::Thursday,
+                Weekday::Friday
+            ]
+        );
+        assert_eq!(
+            office_hours.schedule(),
+            "mon,tue,wed,thu,fri 09:00-18:30 UTC"
+        );
+        assert_eq!(
+            window("main", "sun,fri-mon 22:00-6:00 utc+2").schedule(),
+            "mon,fri,sat,sun 22:00-06:00 UTC+02:00"
+        );
+        assert_eq!(
+            window("main", "mon 09:00-18:00 -05:30").schedule(),
+            "mon 09:00-18:00 UTC-05:30"
+        );
+
+        for value in [
+            "",
+            "mon-fri",
+            "09:00-18:00",
+            "mon-fry 09:00-18:00",
+            "mon 09:00",
+            "mon 09:00-25:00",
+            "mon 09:00-09:00",
+            "mon 09:00-18:00 CET",
+            "mon 09:00-18:00 UTC+15",
+            "mon 09:00-18:00 UTC+02:00 UTC",
+        ] {
+            assert!(MergeWindow::parse_schedule(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn window_opening() {
+        let office_hours = window("main", "mon-fri 09:00-18:00");
+        assert!(!office_hours.is_open(utc(17, 10, 0)));
+        assert!(!office_hours.is_open(utc(19, 8, 59)));
+        assert!(office_hours.is_open(utc(19, 9, 0)));
+        assert!(!office_hours.is_open(utc(19, 18, 0)));
+        assert_eq!(
+            office_hours.next_opening(utc(17, 10, 0)),
+            Some(utc(19, 9, 0))
+        );
+        assert_eq!(
+            office_hours.next_opening(utc(19, 9, 0)),
+            Some(utc(20, 9, 0))
+        );
+
+        let nights = window("main", "fri 22:00-06:00");
+        assert!(nights.is_open(utc(16, 23, 0)));
+        assert!(nights.is_open(utc(17, 5, 59)));
+        assert!(!nights.is_open(utc(17, 6, 0)));
+        assert_eq!(nights.next_opening(utc(17, 6, 0)), Some(utc(23, 22, 0)));
+
+        // 09:00-18:00 at UTC+02:00 is 07:00-16:00 UTC
+        let paris = window("main", "mon-fri 09:00-18:00 UTC+02:00");
+        assert!(!paris.is_open(utc(19, 6, 59)));
+        assert!(paris.is_open(utc(19, 7, 0)));
+        assert!(!paris.is_open(utc(19, 16, 0)));
+        assert_eq!(paris.next_opening(utc(17, 10, 0)), Some(utc(19, 7, 0)));
+
+        // Monday 01:00 UTC is still Sunday at UTC-05:00
+        let sundays = window("main", "sun 20:00-23:00 UTC-05:00");
+        assert!(sundays.is_open(utc(19, 1, 0)));
+        assert!(!sundays.is_open(utc(18, 21, 0)));
+        assert_eq!(sundays.next_opening(utc(17, 10, 0)), Some(utc(19, 1, 0)));
+    }
+
+    #[test]
+    fn resolve() {
+        let now = utc(17, 10, 0);
+        let freezes = vec![
+            MergeFreeze {
+                base_branch: "main".into(),
+                until: utc(18, 0, 0),
+                reason: Some("Release".into()),
+                ..Default::default()
+            },
+            MergeFreeze {
+                base_branch: "*".into(),
+                until: utc(17, 12, 0),
+                ..Default::default()
+            },
+            MergeFreeze {
+                base_branch: "release/*".into(),
+                until: utc(17, 9, 0),
+                ..Default::default()
+            },
+        ];
+        let windows = vec![
+            window("main", "mon-fri 09:00-18:00"),
+            window("release/*", "sat 09:00-12:00"),
+            window("release/*", "mon 08:00-12:00"),
+        ];
+
+        let frozen = FrozenReason::resolve(&freezes, &windows, "main", now).unwrap();
+        assert_eq!(frozen.retry_at(), Some(utc(18, 0, 0)));
+        assert_eq!(
+            frozen.to_string(),
+            "Merges are frozen until 2026-10-18 00:00 UTC (Release)"
+        );
+        assert_eq!(
+            FrozenReason::resolve(&freezes, &windows, "dev", now),
+            Some(FrozenReason::Freeze {
+                until: utc(17, 12, 0),
+                reason: None
+            })
+        );
+
+        assert_eq!(FrozenReason::resolve(&[], &windows, "dev", now), None);
+        assert_eq!(FrozenReason::resolve(&[], &windows, "release/1", now), None);
+
+        let frozen = FrozenReason::resolve(&[], &windows, "release/1", utc(17, 12, 0)).unwrap();
+        assert_eq!(frozen.retry_at(), Some(utc(19, 8, 0)));
+        assert_eq!(
+            frozen.to_string(),
+            "Outside of merge windows, next opening on 2026-10-19 08:00 UTC"
+        );
+    }
+}
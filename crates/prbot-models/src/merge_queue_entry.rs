@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+use crate::Repository;
+
+#[derive(Debug, Error)]
+pub enum MergeQueueStatusError {
+    /// Unknown merge queue status.
+    #[error("Unknown merge queue status: {}", status)]
+    UnknownMergeQueueStatus { status: String },
+}
+
+/// Merge queue entry status.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Copy, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeQueueStatus {
+    /// Waiting for a batch.
+    #[default]
+    Queued,
+    /// Tested in the current batch.
+    Testing,
+    /// Part of a failed batch, waiting for bisection.
+    Suspect,
+    /// Failed alone, removed from the next batches.
+    Failed,
+}
+
+impl MergeQueueStatus {
+    /// Convert merge queue status to static str.
+    pub fn to_str(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl Display for MergeQueueStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+impl TryFrom<&str> for MergeQueueStatus {
+    type Error = MergeQueueStatusError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "queued" => Ok(Self::Queued),
+            "testing" => Ok(Self::Testing),
+            "suspect" => Ok(Self::Suspect),
+            "failed" => Ok(Self::Failed),
+            e => Err(MergeQueueStatusError::UnknownMergeQueueStatus {
+                status: e.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<MergeQueueStatus> for &'static str {
+    fn from(status: MergeQueueStatus) -> Self {
+        match status {
+            MergeQueueStatus::Queued => "queued",
+            MergeQueueStatus::Testing => "testing",
+            MergeQueueStatus::Suspect => "suspect",
+            MergeQueueStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SmartDefault)]
+pub struct MergeQueueEntry {
+    pub id: u64,
+    pub repository_id: u64,
+    pub pull_request_number: u64,
+    pub base_branch: String,
+    pub status: MergeQueueStatus,
+    pub staging_sha: Option<String>,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub queued_at: OffsetDateTime,
+}
+
+impl MergeQueueEntry {
+    pub fn with_repository(mut self, repository: &Repository) -> Self {
+        self.repository_id = repository.id;
+        self
+    }
+
+    /// Check if the entry is waiting to be merged.
+    pub fn is_active(&self) -> bool {
+        self.status != MergeQueueStatus::Failed
+    }
+}
@@ -8,4 +8,25 @@ pub struct MergeRule {
     pub base_branch: RuleBranch,
     pub head_branch: RuleBranch,
     pub strategy: MergeStrategy,
+    /// Merge commit title template, overriding the repository template.
+    #[serde(default)]
+    pub commit_title_template: Option<String>,
+    /// Merge commit message template, overriding the repository template.
+    #[serde(default)]
+    pub commit_message_template: Option<String>,
+}
+
+impl MergeRule {
+    /// Find the rule matching branches with the most specific base branch,
+    /// then the most specific head branch.
+    pub fn find_most_specific<'a>(
+        rules: &'a [MergeRule],
+        base_branch: &str,
+        head_branch: &str,
+    ) -> Option<&'a MergeRule> {
+        rules
+            .iter()
+            .filter(|r| r.base_branch.matches(base_branch) && r.head_branch.matches(head_branch))
+            .max_by(|a, b| (&a.base_branch, &a.head_branch).cmp(&(&b.base_branch, &b.head_branch)))
+    }
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 
 use crate::{MergeStrategy, QaStatus, Repository};
 
@@ -14,6 +15,12 @@ pub struct PullRequest {
     pub automerge: bool,
     pub locked: bool,
     pub strategy_override: Option<MergeStrategy>,
+    /// Stale reminders are paused until this date.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub snoozed_until: Option<OffsetDateTime>,
+    /// Last stale reminder date.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub stale_reminded_at: Option<OffsetDateTime>,
 }
 
 impl PullRequest {
@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+#[derive(Debug, Error)]
+pub enum PullRequestEventKindError {
+    /// Unknown pull request event kind.
+    #[error("Unknown pull request event kind: {}", kind)]
+    UnknownPullRequestEventKind { kind: String },
+}
+
+/// Pull request timeline event kind.
+///
+/// Step changes are stored as step transitions.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Copy, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PullRequestEventKind {
+    /// Pull request opened.
+    #[default]
+    Opened,
+    /// QA status changed, with the new status as details.
+    QaStatusChanged,
+    /// Review submitted, with the review state as details.
+    Reviewed,
+    /// Bot command executed, with the command as details.
+    Command,
+    /// Pull request merged.
+    Merged,
+    /// Pull request closed without being merged.
+    Closed,
+    /// Pull request reopened.
+    Reopened,
+    /// Reviewer automatically assigned, with the assignment strategy as details.
+    ReviewerAssigned,
+}
+
+impl PullRequestEventKind {
+    /// Convert kind to static str.
+    pub fn to_str(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl Display for PullRequestEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+impl TryFrom<&str> for PullRequestEventKind {
+    type Error = PullRequestEventKindError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "opened" => Ok(Self::Opened),
+            "qa_status_changed" => Ok(Self::QaStatusChanged),
+            "reviewed" => Ok(Self::Reviewed),
+            "command" => Ok(Self::Command),
+            "merged" => Ok(Self::Merged),
+            "closed" => Ok(Self::Closed),
+            "reopened" => Ok(Self::Reopened),
+            "reviewer_assigned" => Ok(Self::ReviewerAssigned),
+            e => Err(PullRequestEventKindError::UnknownPullRequestEventKind {
+                kind: e.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<PullRequestEventKind> for &'static str {
+    fn from(kind: PullRequestEventKind) -> Self {
+        match kind {
+            PullRequestEventKind::Opened => "opened",
+            PullRequestEventKind::QaStatusChanged => "qa_status_changed",
+            PullRequestEventKind::Reviewed => "reviewed",
+            PullRequestEventKind::Command => "command",
+            PullRequestEventKind::Merged => "merged",
+            PullRequestEventKind::Closed => "closed",
+            PullRequestEventKind::Reopened => "reopened",
+            PullRequestEventKind::ReviewerAssigned => "reviewer_assigned",
+        }
+    }
+}
+
+/// Pull request timeline event.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SmartDefault)]
+pub struct PullRequestEvent {
+    pub id: u64,
+    pub pull_request_id: u64,
+    pub kind: PullRequestEventKind,
+    pub author: Option<String>,
+    pub details: String,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
@@ -1,8 +1,10 @@
-use std::str::FromStr;
+use std::{collections::HashMap, fmt::Display, str::FromStr};
 
+use glob::Pattern;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
-use crate::RuleBranch;
+use crate::{MergeStrategy, RuleBranch};
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PullRequestRule {
@@ -10,6 +12,73 @@ pub struct PullRequestRule {
     pub name: String,
     pub conditions: Vec<RuleCondition>,
     pub actions: Vec<RuleAction>,
+    /// Higher priority rules win conflicting actions.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// Rule action, overridden if a higher priority rule sets the same setting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedRuleAction<'a> {
+    pub rule: &'a PullRequestRule,
+    pub action: &'a RuleAction,
+    pub overridden_by: Option<&'a str>,
+}
+
+impl PullRequestRule {
+    /// Sort rules by decreasing priority, then by decreasing branch specificity, then by name.
+    pub fn sort_by_priority(rules: &mut [PullRequestRule]) {
+        rules.sort_by(|a, b| {
+            b.priority
+                .cmp(&a.priority)
+                .then_with(|| b.branch_specificity().cmp(&a.branch_specificity()))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+    }
+
+    /// Most specific base and head branch conditions, at the top level.
+    ///
+    /// Rules without branch conditions apply to any branch.
+    pub fn branch_specificity(&self) -> (u8, u8) {
+        self.conditions
+            .iter()
+            .fold((0, 0), |(base, head), condition| match condition {
+                RuleCondition::BaseBranch(b) => (base.max(b.specificity()), head),
+                RuleCondition::HeadBranch(b) => (base, head.max(b.specificity())),
+                _ => (base, head),
+            })
+    }
+
+    /// Resolve actions from rules sorted by priority.
+    ///
+    /// Only the first action of each setting wins, other actions are cumulative.
+    pub fn resolve_actions(rules: &[PullRequestRule]) -> Vec<ResolvedRuleAction<'_>> {
+        let mut winners = HashMap::new();
+        let mut resolved = vec![];
+
+        for rule in rules {
+            for action in &rule.actions {
+                let overridden_by = match action.setting() {
+                    Some(setting) => match winners.get(setting) {
+                        Some(winner) => Some(*winner),
+                        None => {
+                            winners.insert(setting, rule.name.as_str());
+                            None
+                        }
+                    },
+                    None => None,
+                };
+
+                resolved.push(ResolvedRuleAction {
+                    rule,
+                    action,
+                    overridden_by,
+                });
+            }
+        }
+
+        resolved
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -19,6 +88,24 @@ pub enum RuleAction {
     SetQaEnabled(bool),
     SetChecksEnabled(bool),
     SetNeededReviewers(u64),
+    /// Add labels, if missing.
+    AddLabels(Vec<String>),
+    /// Remove labels, if present.
+    RemoveLabels(Vec<String>),
+    /// Request reviews from users not already requested and who did not review yet.
+    RequestReviewers(Vec<String>),
+    /// Add required reviewers, if not already required.
+    RequestRequiredReviewers(Vec<String>),
+    /// Override the merge strategy.
+    SetMergeStrategy(MergeStrategy),
+    /// Lock the pull request with a reason, if not already locked.
+    Lock(String),
+    /// Post a comment once per rule.
+    ///
+    /// Supports `{author}`, `{title}`, `{number}`, `{base}`, `{head}` and `{rule}` placeholders.
+    PostComment(String),
+    /// Override the repository reviewer pool for automatic reviewer assignment.
+    SetReviewerPool(Vec<String>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -27,6 +114,34 @@ pub enum RuleCondition {
     BaseBranch(RuleBranch),
     HeadBranch(RuleBranch),
     Author(String),
+    /// All conditions match.
+    All(Vec<RuleCondition>),
+    /// At least one condition matches.
+    Any(Vec<RuleCondition>),
+    /// Condition does not match.
+    Not(Box<RuleCondition>),
+    /// Label is set on the pull request.
+    Label(String),
+    /// Title matches a regex.
+    TitleMatches(String),
+    /// Pull request draft status.
+    Draft(bool),
+    /// Author is a member of a team (`team` from the repository owner, or `org/team`).
+    AuthorTeam(String),
+    /// At least one changed file matches a glob pattern.
+    ChangedFiles(String),
+    /// Diff size (added and deleted lines) is at most a value.
+    MaxDiffSize(u64),
+    /// Diff size (added and deleted lines) is at least a value.
+    MinDiffSize(u64),
+    /// Author is a bot account (login ending with `[bot]`).
+    AuthorIsBot(bool),
+}
+
+impl Display for RuleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&serde_json::to_string(self).map_err(|_| std::fmt::Error)?)
+    }
 }
 
 impl FromStr for RuleAction {
@@ -37,6 +152,72 @@ impl FromStr for RuleAction {
     }
 }
 
+impl RuleAction {
+    /// Setting changed by this action, if only one value can win.
+    pub fn setting(&self) -> Option<&'static str> {
+        match self {
+            Self::SetAutomerge(_) => Some("automerge"),
+            Self::SetQaEnabled(_) => Some("qa"),
+            Self::SetChecksEnabled(_) => Some("checks"),
+            Self::SetNeededReviewers(_) => Some("needed_reviewers"),
+            Self::SetMergeStrategy(_) => Some("merge_strategy"),
+            Self::Lock(_) => Some("lock"),
+            Self::SetReviewerPool(_) => Some("reviewer_pool"),
+            Self::AddLabels(_)
+            | Self::RemoveLabels(_)
+            | Self::RequestReviewers(_)
+            | Self::RequestRequiredReviewers(_)
+            | Self::PostComment(_) => None,
+        }
+    }
+}
+
+impl RuleCondition {
+    /// Validate regexes and glob patterns, in nested conditions too.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut result = Ok(());
+        self.walk(&mut |condition| {
+            if result.is_err() {
+                return;
+            }
+
+            result = match condition {
+                Self::BaseBranch(branch) | Self::HeadBranch(branch) => branch.validate(),
+                Self::TitleMatches(regex) => Regex::new(regex)
+                    .map(|_| ())
+                    .map_err(|e| format!("invalid title regex '{regex}': {e}")),
+                Self::ChangedFiles(pattern) => Pattern::new(pattern)
+                    .map(|_| ())
+                    .map_err(|e| format!("invalid changed files pattern '{pattern}': {e}")),
+                _ => Ok(()),
+            };
+        });
+
+        result
+    }
+
+    /// Visit this condition and its nested conditions.
+    pub fn walk<'a>(&'a self, f: &mut impl FnMut(&'a RuleCondition)) {
+        f(self);
+
+        match self {
+            Self::All(conditions) | Self::Any(conditions) => {
+                for condition in conditions {
+                    condition.walk(f);
+                }
+            }
+            Self::Not(condition) => condition.walk(f),
+            _ => (),
+        }
+    }
+}
+
+impl Display for RuleCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&serde_json::to_string(self).map_err(|_| std::fmt::Error)?)
+    }
+}
+
 impl FromStr for RuleCondition {
     type Err = serde_json::Error;
 
@@ -49,7 +230,7 @@ impl FromStr for RuleCondition {
 mod tests {
     use std::str::FromStr;
 
-    use crate::{RuleAction, RuleBranch, RuleCondition};
+    use crate::{MergeStrategy, PullRequestRule, RuleAction, RuleBranch, RuleCondition};
 
     #[test]
     fn parse_rule_condition() {
@@ -64,6 +245,90 @@ mod tests {
 
         let rule = RuleCondition::from_str(r#"{"base_branch": "*"}"#).unwrap();
         assert_eq!(rule, RuleCondition::BaseBranch(RuleBranch::Wildcard));
+
+        let rule = RuleCondition::from_str(r#"{"base_branch": "release/*"}"#).unwrap();
+        assert_eq!(
+            rule,
+            RuleCondition::BaseBranch(RuleBranch::Glob("release/*".into()))
+        );
+
+        let rule = RuleCondition::from_str(r#"{"head_branch": "re:^renovate/"}"#).unwrap();
+        assert_eq!(
+            rule,
+            RuleCondition::HeadBranch(RuleBranch::Regex("^renovate/".into()))
+        );
+        assert_eq!(rule.to_string(), r#"{"head_branch":"re:^renovate/"}"#);
+
+        let rule = RuleCondition::from_str(
+            r#"{"all": [{"label": "deps"}, {"not": {"draft": true}}, {"any": [{"author_is_bot": true}, {"author_team": "org/team"}]}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            rule,
+            RuleCondition::All(vec![
+                RuleCondition::Label("deps".into()),
+                RuleCondition::Not(Box::new(RuleCondition::Draft(true))),
+                RuleCondition::Any(vec![
+                    RuleCondition::AuthorIsBot(true),
+                    RuleCondition::AuthorTeam("org/team".into())
+                ])
+            ])
+        );
+
+        let rule = RuleCondition::from_str(r#"{"changed_files": "docs/**"}"#).unwrap();
+        assert_eq!(rule, RuleCondition::ChangedFiles("docs/**".into()));
+
+        let rule = RuleCondition::from_str(r#"{"max_diff_size": 100}"#).unwrap();
+        assert_eq!(rule, RuleCondition::MaxDiffSize(100));
+        assert_eq!(rule.to_string(), r#"{"max_diff_size":100}"#);
+    }
+
+    #[test]
+    fn validate_rule_condition() {
+        assert!(RuleCondition::TitleMatches("^feat".into())
+            .validate()
+            .is_ok());
+        assert!(RuleCondition::ChangedFiles("src/**/*.rs".into())
+            .validate()
+            .is_ok());
+
+        assert!(
+            RuleCondition::Not(Box::new(RuleCondition::TitleMatches("(".into())))
+                .validate()
+                .is_err()
+        );
+        assert!(RuleCondition::Any(vec![
+            RuleCondition::Label("a".into()),
+            RuleCondition::ChangedFiles("[".into())
+        ])
+        .validate()
+        .is_err());
+        assert!(RuleCondition::HeadBranch(RuleBranch::Regex("(".into()))
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn sort_by_branch_specificity() {
+        let rule = |name: &str, branch: RuleBranch| PullRequestRule {
+            name: name.into(),
+            conditions: vec![RuleCondition::BaseBranch(branch)],
+            ..Default::default()
+        };
+        let mut rules = vec![
+            rule("A", RuleBranch::Wildcard),
+            rule("B", RuleBranch::Glob("release/*".into())),
+            rule("C", RuleBranch::Named("release/1.0".into())),
+            rule("D", RuleBranch::Regex("^release/".into())),
+            PullRequestRule {
+                priority: 1,
+                ..rule("E", RuleBranch::Wildcard)
+            },
+        ];
+        PullRequestRule::sort_by_priority(&mut rules);
+
+        let names: Vec<_> = rules.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["E", "C", "D", "B", "A"]);
     }
 
     #[test]
@@ -73,5 +338,55 @@ mod tests {
 
         let rule = RuleAction::from_str(r#"{"set_needed_reviewers": 0}"#).unwrap();
         assert_eq!(rule, RuleAction::SetNeededReviewers(0));
+
+        let rule = RuleAction::from_str(r#"{"add_labels": ["deps"]}"#).unwrap();
+        assert_eq!(rule, RuleAction::AddLabels(vec!["deps".into()]));
+
+        let rule = RuleAction::from_str(r#"{"set_merge_strategy": "squash"}"#).unwrap();
+        assert_eq!(rule, RuleAction::SetMergeStrategy(MergeStrategy::Squash));
+
+        let rule = RuleAction::from_str(r#"{"lock": "Release freeze"}"#).unwrap();
+        assert_eq!(rule, RuleAction::Lock("Release freeze".into()));
+    }
+
+    #[test]
+    fn resolve_actions() {
+        let mut rules = vec![
+            PullRequestRule {
+                name: "Low".into(),
+                actions: vec![
+                    RuleAction::SetNeededReviewers(1),
+                    RuleAction::AddLabels(vec!["a".into()]),
+                ],
+                ..Default::default()
+            },
+            PullRequestRule {
+                name: "High".into(),
+                priority: 10,
+                actions: vec![
+                    RuleAction::SetNeededReviewers(2),
+                    RuleAction::AddLabels(vec!["b".into()]),
+                ],
+                ..Default::default()
+            },
+        ];
+
+        PullRequestRule::sort_by_priority(&mut rules);
+        assert_eq!(rules[0].name, "High");
+
+        let resolved = PullRequestRule::resolve_actions(&rules);
+        let overrides: Vec<_> = resolved
+            .iter()
+            .map(|r| (r.rule.name.as_str(), r.overridden_by))
+            .collect();
+        assert_eq!(
+            overrides,
+            vec![
+                ("High", None),
+                ("High", None),
+                ("Low", Some("High")),
+                ("Low", None)
+            ]
+        );
     }
 }
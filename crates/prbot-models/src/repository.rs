@@ -1,7 +1,11 @@
+use glob::Pattern;
 use prbot_config::Config;
 use serde::{Deserialize, Serialize};
 
-use crate::{MergeStrategy, RepositoryPath};
+use crate::{
+    MergeStrategy, RepositoryPath, ReviewerAssignmentStrategy, StaleApprovalPolicy,
+    DEFAULT_MERGE_COMMIT_TITLE_TEMPLATE,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Repository {
@@ -15,6 +19,66 @@ pub struct Repository {
     pub default_automerge: bool,
     pub default_enable_qa: bool,
     pub default_enable_checks: bool,
+    /// App slugs whose check runs are taken into account.
+    #[serde(default = "default_ci_app_slugs")]
+    pub ci_app_slugs: Vec<String>,
+    /// Required check name patterns.
+    #[serde(default)]
+    pub required_checks: Vec<String>,
+    /// Optional check name patterns, ignored in the checks status.
+    #[serde(default)]
+    pub optional_checks: Vec<String>,
+    /// Use the GitHub native merge queue to merge pull requests.
+    #[serde(default)]
+    pub native_merge_queue: bool,
+    /// Blob SHA of the last applied configuration file.
+    #[serde(default)]
+    pub config_sha: String,
+    /// Merge commit title template, see [`crate::MergeCommitVariables`].
+    #[serde(default = "default_merge_commit_title_template")]
+    pub merge_commit_title_template: String,
+    /// Merge commit message template, see [`crate::MergeCommitVariables`].
+    #[serde(default)]
+    pub merge_commit_message_template: String,
+    /// Normalize merge commit titles following the Conventional Commits format.
+    #[serde(default)]
+    pub conventional_commits: bool,
+    /// Days after which a pull request is stale in its current step (0 to disable).
+    #[serde(default)]
+    pub stale_threshold_days: u64,
+    /// Convert stale pull requests to drafts.
+    #[serde(default)]
+    pub stale_convert_to_draft: bool,
+    /// Register code owners of changed files as required reviewers.
+    #[serde(default)]
+    pub codeowners_reviewers: bool,
+    /// Reviewers automatically requested on opened or ready pull requests.
+    #[serde(default)]
+    pub reviewer_pool: Vec<String>,
+    /// Reviewers picked from the pool for each pull request.
+    #[serde(default = "default_reviewer_pool_count")]
+    pub reviewer_pool_count: u64,
+    /// How reviewers are picked from the pool.
+    #[serde(default)]
+    pub reviewer_assignment_strategy: ReviewerAssignmentStrategy,
+    /// Round-robin position in the reviewer pool.
+    #[serde(default)]
+    pub reviewer_pool_cursor: u64,
+    /// What happens to approvals when new commits are pushed.
+    #[serde(default)]
+    pub stale_approval_policy: StaleApprovalPolicy,
+}
+
+fn default_ci_app_slugs() -> Vec<String> {
+    vec!["github-actions".into()]
+}
+
+fn default_reviewer_pool_count() -> u64 {
+    1
+}
+
+fn default_merge_commit_title_template() -> String {
+    DEFAULT_MERGE_COMMIT_TITLE_TEMPLATE.into()
 }
 
 impl Default for Repository {
@@ -30,6 +94,22 @@ impl Default for Repository {
             default_automerge: false,
             default_enable_qa: false,
             default_enable_checks: true,
+            ci_app_slugs: default_ci_app_slugs(),
+            required_checks: vec![],
+            optional_checks: vec![],
+            native_merge_queue: false,
+            config_sha: String::new(),
+            merge_commit_title_template: default_merge_commit_title_template(),
+            merge_commit_message_template: String::new(),
+            conventional_commits: false,
+            stale_threshold_days: 0,
+            stale_convert_to_draft: false,
+            codeowners_reviewers: false,
+            reviewer_pool: vec![],
+            reviewer_pool_count: default_reviewer_pool_count(),
+            reviewer_assignment_strategy: ReviewerAssignmentStrategy::RoundRobin,
+            reviewer_pool_cursor: 0,
+            stale_approval_policy: StaleApprovalPolicy::Keep,
         }
     }
 }
@@ -39,6 +119,32 @@ impl Repository {
         RepositoryPath::new_from_components(&self.owner, &self.name)
     }
 
+    /// Check if check runs from an app should be taken into account.
+    pub fn is_ci_app(&self, slug: &str) -> bool {
+        matches_any_pattern(&self.ci_app_slugs, slug)
+    }
+
+    /// Check if a check name is optional.
+    pub fn is_optional_check(&self, name: &str) -> bool {
+        matches_any_pattern(&self.optional_checks, name)
+    }
+
+    /// Check if a check name matches a pattern.
+    ///
+    /// Patterns are glob patterns, or exact names if the pattern is invalid.
+    pub fn check_matches_pattern(pattern: &str, name: &str) -> bool {
+        Pattern::new(pattern)
+            .map(|p| p.matches(name))
+            .unwrap_or_else(|_| pattern == name)
+    }
+
+    /// Validate a check name pattern.
+    pub fn validate_check_pattern(pattern: &str) -> Result<(), String> {
+        Pattern::new(pattern)
+            .map(|_| ())
+            .map_err(|e| format!("Invalid pattern '{pattern}': {e}"))
+    }
+
     pub fn with_config(mut self, config: &Config) -> Self {
         self.default_strategy = (&config.default_merge_strategy)
             .try_into()
@@ -49,3 +155,37 @@ impl Repository {
         self
     }
 }
+
+fn matches_any_pattern(patterns: &[String], value: &str) -> bool {
+    patterns
+        .iter()
+        .any(|p| Repository::check_matches_pattern(p, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_patterns() {
+        let repo = Repository {
+            ci_app_slugs: vec!["github-actions".into(), "circleci-*".into()],
+            optional_checks: vec!["lint*".into(), "docs".into()],
+            ..Default::default()
+        };
+
+        assert!(repo.is_ci_app("github-actions"));
+        assert!(repo.is_ci_app("circleci-checks"));
+        assert!(!repo.is_ci_app("dependabot"));
+
+        assert!(repo.is_optional_check("lint"));
+        assert!(repo.is_optional_check("lint (nightly)"));
+        assert!(repo.is_optional_check("docs"));
+        assert!(!repo.is_optional_check("test"));
+
+        // Invalid patterns are matched exactly
+        assert!(Repository::check_matches_pattern("test [", "test ["));
+        assert!(Repository::validate_check_pattern("test [").is_err());
+        assert!(Repository::validate_check_pattern("test (*)").is_ok());
+    }
+}
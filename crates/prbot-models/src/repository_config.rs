@@ -0,0 +1,437 @@
+//! Repository configuration file.
+
+use std::collections::HashSet;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    MergeRule, MergeStrategy, PullRequestRule, Repository, ReviewerAssignmentStrategy, RuleAction,
+    RuleBranch, RuleCondition, StaleApprovalPolicy,
+};
+
+/// Configuration file paths, looked up in order on the default branch.
+pub const REPOSITORY_CONFIG_PATHS: [&str; 3] = [
+    ".github/prbot.toml",
+    ".github/prbot.yml",
+    ".github/prbot.yaml",
+];
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum RepositoryConfigError {
+    /// Unsupported file format.
+    #[error("Unsupported configuration file format: {}", path)]
+    UnsupportedFormat { path: String },
+    /// Parse error.
+    #[error("Could not parse {}: {}", path, message)]
+    ParseError { path: String, message: String },
+    /// Validation error.
+    #[error("Invalid configuration in {}: {}", path, message)]
+    ValidationError { path: String, message: String },
+}
+
+/// Repository configuration, read from a file in the repository.
+///
+/// Missing keys keep the current repository values.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RepositoryConfig {
+    pub pr_title_validation_regex: Option<String>,
+    pub default_strategy: Option<MergeStrategy>,
+    pub default_needed_reviewers_count: Option<u64>,
+    pub default_automerge: Option<bool>,
+    pub default_enable_qa: Option<bool>,
+    pub default_enable_checks: Option<bool>,
+    pub merge_commit_title_template: Option<String>,
+    pub merge_commit_message_template: Option<String>,
+    pub conventional_commits: Option<bool>,
+    pub stale_threshold_days: Option<u64>,
+    pub stale_convert_to_draft: Option<bool>,
+    pub codeowners_reviewers: Option<bool>,
+    pub reviewer_pool: Option<Vec<String>>,
+    pub reviewer_pool_count: Option<u64>,
+    pub reviewer_assignment_strategy: Option<ReviewerAssignmentStrategy>,
+    pub stale_approval_policy: Option<StaleApprovalPolicy>,
+    /// Replaces all merge rules when set.
+    pub merge_rules: Option<Vec<MergeRuleConfig>>,
+    /// Replaces all pull request rules when set.
+    pub pull_request_rules: Option<Vec<PullRequestRuleConfig>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MergeRuleConfig {
+    pub base: RuleBranch,
+    pub head: RuleBranch,
+    pub strategy: MergeStrategy,
+    pub commit_title_template: Option<String>,
+    pub commit_message_template: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct PullRequestRuleConfig {
+    pub name: String,
+    #[serde(default)]
+    pub conditions: Vec<RuleCondition>,
+    #[serde(default)]
+    pub actions: Vec<RuleAction>,
+    #[serde(default)]
+    pub priority: i32,
+}
+
+impl RepositoryConfig {
+    /// Parse and validate a configuration file, using its extension to get its format.
+    pub fn from_file(path: &str, content: &str) -> Result<Self, RepositoryConfigError> {
+        let parse_error = |message: String| RepositoryConfigError::ParseError {
+            path: path.into(),
+            message,
+        };
+
+        let config: Self = if path.ends_with(".toml") {
+            toml::from_str(content).map_err(|e| parse_error(e.message().into()))?
+        } else if path.ends_with(".yml") || path.ends_with(".yaml") {
+            // An empty YAML file is a null document
+            if content.trim().is_empty() {
+                Self::default()
+            } else {
+                // Enums are written as single-key maps, like in TOML
+                serde_yaml::with::singleton_map_recursive::deserialize(
+                    serde_yaml::Deserializer::from_str(content),
+                )
+                .map_err(|e| parse_error(e.to_string()))?
+            }
+        } else {
+            return Err(RepositoryConfigError::UnsupportedFormat { path: path.into() });
+        };
+
+        config
+            .validate()
+            .map_err(|message| RepositoryConfigError::ValidationError {
+                path: path.into(),
+                message,
+            })?;
+
+        Ok(config)
+    }
+
+    /// Validate values which cannot be checked while parsing.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(regex) = &self.pr_title_validation_regex {
+            Regex::new(regex).map_err(|e| format!("invalid PR title regex: {e}"))?;
+        }
+
+        let mut merge_rules = HashSet::new();
+        for rule in self.merge_rules.iter().flatten() {
+            if rule.base == RuleBranch::Wildcard && rule.head == RuleBranch::Wildcard {
+                return Err(
+                    "merge rule '* -> *' is not allowed, use 'default_strategy' instead".into(),
+                );
+            }
+
+            rule.base.validate()?;
+            rule.head.validate()?;
+
+            if !merge_rules.insert((&rule.base, &rule.head)) {
+                return Err(format!(
+                    "duplicate merge rule '{} -> {}'",
+                    rule.head, rule.base
+                ));
+            }
+        }
+
+        let mut rule_names = HashSet::new();
+        for rule in self.pull_request_rules.iter().flatten() {
+            if rule.name.trim().is_empty() {
+                return Err("pull request rule names cannot be empty".into());
+            }
+
+            if !rule_names.insert(&rule.name) {
+                return Err(format!("duplicate pull request rule '{}'", rule.name));
+            }
+
+            for condition in &rule.conditions {
+                condition
+                    .validate()
+                    .map_err(|e| format!("pull request rule '{}': {e}", rule.name))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply repository values, and remember the configuration file SHA.
+    pub fn apply_to_repository(&self, mut repository: Repository, sha: &str) -> Repository {
+        if let Some(value) = &self.pr_title_validation_regex {
+            repository.pr_title_validation_regex.clone_from(value);
+        }
+        if let Some(value) = self.default_strategy {
+            repository.default_strategy = value;
+        }
+        if let Some(value) = self.default_needed_reviewers_count {
+            repository.default_needed_reviewers_count = value;
+        }
+        if let Some(value) = self.default_automerge {
+            repository.default_automerge = value;
+        }
+        if let Some(value) = self.default_enable_qa {
+            repository.default_enable_qa = value;
+        }
+        if let Some(value) = self.default_enable_checks {
+            repository.default_enable_checks = value;
+        }
+        if let Some(value) = &self.merge_commit_title_template {
+            repository.merge_commit_title_template.clone_from(value);
+        }
+        if let Some(value) = &self.merge_commit_message_template {
+            repository.merge_commit_message_template.clone_from(value);
+        }
+        if let Some(value) = self.conventional_commits {
+            repository.conventional_commits = value;
+        }
+        if let Some(value) = self.stale_threshold_days {
+            repository.stale_threshold_days = value;
+        }
+        if let Some(value) = self.stale_convert_to_draft {
+            repository.stale_convert_to_draft = value;
+        }
+        if let Some(value) = self.codeowners_reviewers {
+            repository.codeowners_reviewers = value;
+        }
+        if let Some(value) = &self.reviewer_pool {
+            repository.reviewer_pool.clone_from(value);
+        }
+        if let Some(value) = self.reviewer_pool_count {
+            repository.reviewer_pool_count = value;
+        }
+        if let Some(value) = self.reviewer_assignment_strategy {
+            repository.reviewer_assignment_strategy = value;
+        }
+        if let Some(value) = self.stale_approval_policy {
+            repository.stale_approval_policy = value;
+        }
+
+        repository.config_sha = sha.into();
+        repository
+    }
+
+    /// Get merge rules for a repository, if set.
+    pub fn merge_rules(&self, repository: &Repository) -> Option<Vec<MergeRule>> {
+        self.merge_rules.as_ref().map(|rules| {
+            rules
+                .iter()
+                .map(|rule| MergeRule {
+                    repository_id: repository.id,
+                    base_branch: rule.base.clone(),
+                    head_branch: rule.head.clone(),
+                    strategy: rule.strategy,
+                    commit_title_template: rule.commit_title_template.clone(),
+                    commit_message_template: rule.commit_message_template.clone(),
+                })
+                .collect()
+        })
+    }
+
+    /// Get pull request rules for a repository, if set.
+    pub fn pull_request_rules(&self, repository: &Repository) -> Option<Vec<PullRequestRule>> {
+        self.pull_request_rules.as_ref().map(|rules| {
+            rules
+                .iter()
+                .map(|rule| PullRequestRule {
+                    repository_id: repository.id,
+                    name: rule.name.clone(),
+                    conditions: rule.conditions.clone(),
+                    actions: rule.actions.clone(),
+                    priority: rule.priority,
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_toml_file() {
+        let config = RepositoryConfig::from_file(
+            ".github/prbot.toml",
+            r#"
+            pr_title_validation_regex = "^(feat|fix)"
+            default_strategy = "squash"
+            default_needed_reviewers_count = 2
+            merge_commit_message_template = "{body}"
+            conventional_commits = true
+            stale_threshold_days = 7
+            stale_approval_policy = "invalidate_all"
+
+            [[merge_rules]]
+            base = "main"
+            head = "*"
+            strategy = "merge"
+            commit_title_template = "{title}"
+
+            [[pull_request_rules]]
+            name = "Dependencies"
+            conditions = [{ author = "dependabot[bot]" }]
+            actions = [{ set_automerge = true }, { set_qa_enabled = false }]
+            priority = 10
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            RepositoryConfig {
+                pr_title_validation_regex: Some("^(feat|fix)".into()),
+                default_strategy: Some(MergeStrategy::Squash),
+                default_needed_reviewers_count: Some(2),
+                merge_commit_message_template: Some("{body}".into()),
+                conventional_commits: Some(true),
+                stale_threshold_days: Some(7),
+                stale_approval_policy: Some(StaleApprovalPolicy::InvalidateAll),
+                merge_rules: Some(vec![MergeRuleConfig {
+                    base: RuleBranch::Named("main".into()),
+                    head: RuleBranch::Wildcard,
+                    strategy: MergeStrategy::Merge,
+                    commit_title_template: Some("{title}".into()),
+                    commit_message_template: None,
+                }]),
+                pull_request_rules: Some(vec![PullRequestRuleConfig {
+                    name: "Dependencies".into(),
+                    conditions: vec![RuleCondition::Author("dependabot[bot]".into())],
+                    actions: vec![
+                        RuleAction::SetAutomerge(true),
+                        RuleAction::SetQaEnabled(false)
+                    ],
+                    priority: 10,
+                }]),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn from_yaml_file() {
+        let config = RepositoryConfig::from_file(
+            ".github/prbot.yml",
+            r#"
+default_enable_qa: true
+reviewer_pool: [alice, bob]
+reviewer_assignment_strategy: least_load
+pull_request_rules:
+  - name: Release
+    conditions:
+      - base_branch: main
+    actions:
+      - set_needed_reviewers: 2
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            RepositoryConfig {
+                default_enable_qa: Some(true),
+                reviewer_pool: Some(vec!["alice".into(), "bob".into()]),
+                reviewer_assignment_strategy: Some(ReviewerAssignmentStrategy::LeastLoad),
+                pull_request_rules: Some(vec![PullRequestRuleConfig {
+                    name: "Release".into(),
+                    conditions: vec![RuleCondition::BaseBranch(RuleBranch::Named("main".into()))],
+                    actions: vec![RuleAction::SetNeededReviewers(2)],
+                    priority: 0,
+                }]),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            RepositoryConfig::from_file(".github/prbot.yaml", "").unwrap(),
+            RepositoryConfig::default()
+        );
+    }
+
+    #[test]
+    fn from_file_errors() {
+        assert!(matches!(
+            RepositoryConfig::from_file(".github/prbot.json", "{}"),
+            Err(RepositoryConfigError::UnsupportedFormat { .. })
+        ));
+        assert!(matches!(
+            RepositoryConfig::from_file(".github/prbot.toml", "unknown = 1"),
+            Err(RepositoryConfigError::ParseError { .. })
+        ));
+        assert!(matches!(
+            RepositoryConfig::from_file(".github/prbot.toml", "default_strategy = \"fast\""),
+            Err(RepositoryConfigError::ParseError { .. })
+        ));
+        assert_eq!(
+            RepositoryConfig::from_file(".github/prbot.toml", "pr_title_validation_regex = \"(\""),
+            Err(RepositoryConfigError::ValidationError {
+                path: ".github/prbot.toml".into(),
+                message: "invalid PR title regex: regex parse error:\n    (\n    ^\nerror: unclosed group".into()
+            })
+        );
+        assert!(matches!(
+            RepositoryConfig::from_file(
+                ".github/prbot.toml",
+                r#"
+                [[merge_rules]]
+                base = "*"
+                head = "*"
+                strategy = "merge"
+                "#
+            ),
+            Err(RepositoryConfigError::ValidationError { .. })
+        ));
+        assert!(matches!(
+            RepositoryConfig::from_file(
+                ".github/prbot.toml",
+                r#"
+                [[merge_rules]]
+                base = "re:("
+                head = "*"
+                strategy = "merge"
+                "#
+            ),
+            Err(RepositoryConfigError::ValidationError { .. })
+        ));
+        assert!(matches!(
+            RepositoryConfig::from_file(
+                ".github/prbot.toml",
+                r#"
+                [[pull_request_rules]]
+                name = "A"
+
+                [[pull_request_rules]]
+                name = "A"
+                "#
+            ),
+            Err(RepositoryConfigError::ValidationError { .. })
+        ));
+    }
+
+    #[test]
+    fn apply_to_repository() {
+        let config = RepositoryConfig {
+            default_strategy: Some(MergeStrategy::Rebase),
+            default_automerge: Some(true),
+            ..Default::default()
+        };
+
+        let repository = config.apply_to_repository(
+            Repository {
+                default_needed_reviewers_count: 3,
+                ..Default::default()
+            },
+            "abcdef",
+        );
+
+        assert_eq!(repository.default_strategy, MergeStrategy::Rebase);
+        assert!(repository.default_automerge);
+        assert_eq!(repository.default_needed_reviewers_count, 3);
+        assert_eq!(repository.config_sha, "abcdef");
+    }
+}
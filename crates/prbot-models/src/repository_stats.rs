@@ -0,0 +1,321 @@
+//! Pull request lifecycle statistics.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use crate::{PullRequestEvent, PullRequestEventKind, StepLabel, StepTransition};
+
+/// Timeline of a pull request: step transitions and events, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PullRequestTimeline {
+    pub transitions: Vec<StepTransition>,
+    pub events: Vec<PullRequestEvent>,
+}
+
+impl PullRequestTimeline {
+    fn first_event_at(&self, kind: PullRequestEventKind) -> Option<OffsetDateTime> {
+        self.events
+            .iter()
+            .filter(|e| e.kind == kind)
+            .map(|e| e.created_at)
+            .min()
+    }
+
+    /// Opening date, or first known step date for pull requests opened before timelines.
+    pub fn opened_at(&self) -> Option<OffsetDateTime> {
+        self.first_event_at(PullRequestEventKind::Opened)
+            .or_else(|| self.transitions.first().map(|t| t.created_at))
+    }
+
+    /// First review date.
+    pub fn first_review_at(&self) -> Option<OffsetDateTime> {
+        self.first_event_at(PullRequestEventKind::Reviewed)
+    }
+
+    /// Merge date.
+    pub fn merged_at(&self) -> Option<OffsetDateTime> {
+        self.first_event_at(PullRequestEventKind::Merged)
+    }
+
+    /// Is the pull request still open (not merged, and not closed since its last reopening)?
+    pub fn is_open(&self) -> bool {
+        !self
+            .events
+            .iter()
+            .rev()
+            .find(|e| {
+                matches!(
+                    e.kind,
+                    PullRequestEventKind::Merged
+                        | PullRequestEventKind::Closed
+                        | PullRequestEventKind::Reopened
+                )
+            })
+            .is_some_and(|e| e.kind != PullRequestEventKind::Reopened)
+    }
+
+    /// Automatically assigned reviewers.
+    pub fn assigned_reviewers(&self) -> Vec<&str> {
+        self.events
+            .iter()
+            .filter(|e| e.kind == PullRequestEventKind::ReviewerAssigned)
+            .filter_map(|e| e.author.as_deref())
+            .collect()
+    }
+
+    /// Did a user submit a review?
+    pub fn reviewed_by(&self, username: &str) -> bool {
+        self.events.iter().any(|e| {
+            e.kind == PullRequestEventKind::Reviewed && e.author.as_deref() == Some(username)
+        })
+    }
+
+    /// Time spent in each finished step.
+    ///
+    /// The current step is only counted once the pull request is merged.
+    pub fn step_durations(&self) -> Vec<(StepLabel, Duration)> {
+        let ends = self
+            .transitions
+            .iter()
+            .skip(1)
+            .map(|t| Some(t.created_at))
+            .chain([self.merged_at()]);
+
+        self.transitions
+            .iter()
+            .zip(ends)
+            .filter_map(|(transition, end)| {
+                end.map(|end| (transition.step, end - transition.created_at))
+            })
+            .collect()
+    }
+}
+
+/// Duration aggregates, in seconds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DurationStats {
+    pub count: u64,
+    pub mean_seconds: i64,
+    pub median_seconds: i64,
+    pub p90_seconds: i64,
+}
+
+impl DurationStats {
+    /// Aggregate durations.
+    pub fn from_durations(durations: impl IntoIterator<Item = Duration>) -> Self {
+        let mut seconds: Vec<i64> = durations.into_iter().map(|d| d.whole_seconds()).collect();
+        if seconds.is_empty() {
+            return Self::default();
+        }
+
+        seconds.sort_unstable();
+        let count = seconds.len();
+        let percentile = |p: usize| seconds[(count * p).div_ceil(100).max(1) - 1];
+
+        Self {
+            count: count as u64,
+            mean_seconds: seconds.iter().sum::<i64>() / count as i64,
+            median_seconds: percentile(50),
+            p90_seconds: percentile(90),
+        }
+    }
+}
+
+/// Pull request lifecycle statistics for a repository.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryStats {
+    pub repository_path: String,
+    /// Only pull requests opened since this date are counted.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub since: Option<OffsetDateTime>,
+    pub pull_requests_count: u64,
+    pub merged_count: u64,
+    pub time_to_first_review: DurationStats,
+    pub time_in_qa: DurationStats,
+    pub time_to_merge: DurationStats,
+    /// Time spent in each step, by step label.
+    pub step_durations: BTreeMap<String, DurationStats>,
+}
+
+impl RepositoryStats {
+    /// Compute statistics from pull request timelines.
+    pub fn compute(
+        repository_path: &str,
+        since: Option<OffsetDateTime>,
+        timelines: &[PullRequestTimeline],
+    ) -> Self {
+        let timelines: Vec<_> = timelines
+            .iter()
+            .filter_map(|t| t.opened_at().map(|opened_at| (t, opened_at)))
+            .filter(|(_, opened_at)| since.map_or(true, |since| *opened_at >= since))
+            .collect();
+
+        let mut time_to_first_review = vec![];
+        let mut time_in_qa = vec![];
+        let mut time_to_merge = vec![];
+        let mut step_durations: BTreeMap<&str, Vec<Duration>> = BTreeMap::new();
+
+        for (timeline, opened_at) in &timelines {
+            if let Some(reviewed_at) = timeline.first_review_at() {
+                time_to_first_review.push(reviewed_at - *opened_at);
+            }
+            if let Some(merged_at) = timeline.merged_at() {
+                time_to_merge.push(merged_at - *opened_at);
+            }
+
+            let mut qa_duration = None;
+            for (step, duration) in timeline.step_durations() {
+                if step == StepLabel::AwaitingQa {
+                    *qa_duration.get_or_insert(Duration::ZERO) += duration;
+                }
+                step_durations
+                    .entry(step.to_str())
+                    .or_default()
+                    .push(duration);
+            }
+            time_in_qa.extend(qa_duration);
+        }
+
+        Self {
+            repository_path: repository_path.into(),
+            since,
+            pull_requests_count: timelines.len() as u64,
+            merged_count: time_to_merge.len() as u64,
+            time_to_first_review: DurationStats::from_durations(time_to_first_review),
+            time_in_qa: DurationStats::from_durations(time_in_qa),
+            time_to_merge: DurationStats::from_durations(time_to_merge),
+            step_durations: step_durations
+                .into_iter()
+                .map(|(step, durations)| (step.into(), DurationStats::from_durations(durations)))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+
+    fn utc(day: u8, hour: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2026, Month::October, day)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    fn event(kind: PullRequestEventKind, created_at: OffsetDateTime) -> PullRequestEvent {
+        PullRequestEvent {
+            kind,
+            created_at,
+            ..Default::default()
+        }
+    }
+
+    fn transition(step: StepLabel, created_at: OffsetDateTime) -> StepTransition {
+        StepTransition {
+            step,
+            created_at,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn duration_stats() {
+        assert_eq!(DurationStats::from_durations([]), DurationStats::default());
+        assert_eq!(
+            DurationStats::from_durations((1..=10).map(Duration::hours)),
+            DurationStats {
+                count: 10,
+                mean_seconds: 19_800,
+                median_seconds: 18_000,
+                p90_seconds: 32_400,
+            }
+        );
+    }
+
+    #[test]
+    fn compute() {
+        let merged = PullRequestTimeline {
+            transitions: vec![
+                transition(StepLabel::AwaitingReview, utc(10, 10)),
+                transition(StepLabel::AwaitingQa, utc(10, 14)),
+                transition(StepLabel::AwaitingMerge, utc(11, 10)),
+            ],
+            events: vec![
+                event(PullRequestEventKind::Opened, utc(10, 10)),
+                event(PullRequestEventKind::Reviewed, utc(10, 12)),
+                event(PullRequestEventKind::Reviewed, utc(10, 13)),
+                event(PullRequestEventKind::Merged, utc(11, 12)),
+            ],
+        };
+        let open = PullRequestTimeline {
+            transitions: vec![transition(StepLabel::AwaitingReview, utc(12, 10))],
+            events: vec![event(PullRequestEventKind::Opened, utc(12, 10))],
+        };
+        let old = PullRequestTimeline {
+            transitions: vec![transition(StepLabel::AwaitingReview, utc(1, 10))],
+            events: vec![],
+        };
+
+        let stats = RepositoryStats::compute(
+            "me/repo",
+            Some(utc(5, 0)),
+            &[merged, open, old, PullRequestTimeline::default()],
+        );
+        assert_eq!(stats.pull_requests_count, 2);
+        assert_eq!(stats.merged_count, 1);
+        assert_eq!(stats.time_to_first_review.count, 1);
+        assert_eq!(stats.time_to_first_review.mean_seconds, 2 * 3600);
+        assert_eq!(stats.time_in_qa.mean_seconds, 20 * 3600);
+        assert_eq!(stats.time_to_merge.mean_seconds, 26 * 3600);
+        assert_eq!(
+            stats.step_durations.keys().collect::<Vec<_>>(),
+            vec![
+                "step/awaiting-merge",
+                "step/awaiting-qa",
+                "step/awaiting-review"
+            ]
+        );
+        assert_eq!(
+            stats.step_durations["step/awaiting-merge"].mean_seconds,
+            2 * 3600
+        );
+    }
+
+    #[test]
+    fn open_and_assigned_reviewers() {
+        let author_event = |kind, author: &str, created_at| PullRequestEvent {
+            author: Some(author.into()),
+            ..event(kind, created_at)
+        };
+
+        let mut timeline = PullRequestTimeline {
+            transitions: vec![],
+            events: vec![
+                event(PullRequestEventKind::Opened, utc(10, 10)),
+                author_event(PullRequestEventKind::ReviewerAssigned, "alice", utc(10, 10)),
+                author_event(PullRequestEventKind::ReviewerAssigned, "bob", utc(10, 10)),
+                author_event(PullRequestEventKind::Reviewed, "bob", utc(10, 12)),
+            ],
+        };
+        assert!(timeline.is_open());
+        assert_eq!(timeline.assigned_reviewers(), vec!["alice", "bob"]);
+        assert!(!timeline.reviewed_by("alice"));
+        assert!(timeline.reviewed_by("bob"));
+
+        timeline
+            .events
+            .push(event(PullRequestEventKind::Closed, utc(10, 14)));
+        assert!(!timeline.is_open());
+
+        timeline
+            .events
+            .push(event(PullRequestEventKind::Reopened, utc(10, 15)));
+        assert!(timeline.is_open());
+    }
+}
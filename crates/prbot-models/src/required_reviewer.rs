@@ -3,5 +3,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RequiredReviewer {
     pub pull_request_id: u64,
+    /// Username, or `org/team` team name.
     pub username: String,
 }
+
+impl RequiredReviewer {
+    /// Get the organization and team slug, if the reviewer is a team.
+    pub fn team(&self) -> Option<(&str, &str)> {
+        self.username.split_once('/')
+    }
+}
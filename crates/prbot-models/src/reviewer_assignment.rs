@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+#[derive(Debug, Error)]
+pub enum ReviewerAssignmentStrategyError {
+    /// Unknown reviewer assignment strategy.
+    #[error("Unknown reviewer assignment strategy: {}", strategy)]
+    UnknownReviewerAssignmentStrategy { strategy: String },
+}
+
+/// How reviewers are picked from a reviewer pool.
+#[derive(Debug, Serialize, Default, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewerAssignmentStrategy {
+    /// Pick reviewers in turn.
+    #[default]
+    RoundRobin,
+    /// Pick reviewers with the fewest open assigned reviews.
+    LeastLoad,
+}
+
+impl std::fmt::Display for ReviewerAssignmentStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            Self::RoundRobin => "round_robin",
+            Self::LeastLoad => "least_load",
+        };
+
+        f.write_str(value)
+    }
+}
+
+impl FromStr for ReviewerAssignmentStrategy {
+    type Err = ReviewerAssignmentStrategyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<&str> for ReviewerAssignmentStrategy {
+    type Error = ReviewerAssignmentStrategyError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "round_robin" | "round-robin" => Ok(Self::RoundRobin),
+            "least_load" | "least-load" => Ok(Self::LeastLoad),
+            other => Err(
+                ReviewerAssignmentStrategyError::UnknownReviewerAssignmentStrategy {
+                    strategy: other.into(),
+                },
+            ),
+        }
+    }
+}
+
+/// User marked as away, skipped by automatic reviewer assignment.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SmartDefault)]
+pub struct AwayUser {
+    pub username: String,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_strategy() {
+        assert_eq!(
+            ReviewerAssignmentStrategy::from_str("round-robin").unwrap(),
+            ReviewerAssignmentStrategy::RoundRobin
+        );
+        assert_eq!(
+            ReviewerAssignmentStrategy::from_str("least_load").unwrap(),
+            ReviewerAssignmentStrategy::LeastLoad
+        );
+        assert!(ReviewerAssignmentStrategy::from_str("random").is_err());
+        assert_eq!(
+            ReviewerAssignmentStrategy::LeastLoad.to_string(),
+            "least_load"
+        );
+    }
+}
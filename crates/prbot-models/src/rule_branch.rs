@@ -1,14 +1,31 @@
 //! Rule branch.
 
-use std::{convert::Infallible, str::FromStr};
+use std::str::FromStr;
 
+use glob::Pattern;
+use regex::Regex;
 use serde::{de::Visitor, Deserialize, Serialize};
 
+/// Prefix used for regex branches.
+const REGEX_PREFIX: &str = "re:";
+
 /// Rule branch.
+///
+/// Stored as a plain string: `*` is the wildcard, a `re:` prefix marks a
+/// regex, and a name containing `*`, `?` or `[` is a glob pattern.
+/// Git forbids these characters in branch names, so existing names are
+/// never reinterpreted.
+///
+/// When several rules match the same branch, the most specific one wins,
+/// in this order: named, regex, glob, then wildcard.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
 pub enum RuleBranch {
     /// Named.
     Named(String),
+    /// Glob pattern, like `release/*`.
+    Glob(String),
+    /// Regex, like `re:^release/v\d+$`.
+    Regex(String),
     /// Wildcard.
     #[default]
     Wildcard,
@@ -22,12 +39,9 @@ impl PartialOrd for RuleBranch {
 
 impl Ord for RuleBranch {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match (self, other) {
-            (Self::Wildcard, Self::Named(_)) => std::cmp::Ordering::Less,
-            (Self::Named(_), Self::Wildcard) => std::cmp::Ordering::Greater,
-            (Self::Wildcard, Self::Wildcard) => std::cmp::Ordering::Equal,
-            (Self::Named(s1), Self::Named(s2)) => s1.cmp(s2),
-        }
+        self.specificity()
+            .cmp(&other.specificity())
+            .then_with(|| self.name().cmp(&other.name()))
     }
 }
 
@@ -68,18 +82,25 @@ impl<'de> Deserialize<'de> for RuleBranch {
 
 impl From<&str> for RuleBranch {
     fn from(value: &str) -> Self {
-        match value {
-            "*" => Self::Wildcard,
-            n => Self::Named(n.into()),
+        if value == "*" {
+            Self::Wildcard
+        } else if let Some(regex) = value.strip_prefix(REGEX_PREFIX) {
+            Self::Regex(regex.into())
+        } else if value.contains(['*', '?', '[']) {
+            Self::Glob(value.into())
+        } else {
+            Self::Named(value.into())
         }
     }
 }
 
 impl FromStr for RuleBranch {
-    type Err = Infallible;
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::from(s))
+        let branch = Self::from(s);
+        branch.validate()?;
+        Ok(branch)
     }
 }
 
@@ -87,10 +108,50 @@ impl RuleBranch {
     /// Get branch name.
     pub fn name(&self) -> String {
         match self {
-            RuleBranch::Named(s) => s.clone(),
+            RuleBranch::Named(s) | RuleBranch::Glob(s) => s.clone(),
+            RuleBranch::Regex(s) => format!("{REGEX_PREFIX}{s}"),
             RuleBranch::Wildcard => "*".into(),
         }
     }
+
+    /// Specificity, higher is more specific.
+    pub fn specificity(&self) -> u8 {
+        match self {
+            RuleBranch::Named(_) => 3,
+            RuleBranch::Regex(_) => 2,
+            RuleBranch::Glob(_) => 1,
+            RuleBranch::Wildcard => 0,
+        }
+    }
+
+    /// Check if a branch matches.
+    ///
+    /// Invalid patterns never match.
+    pub fn matches(&self, branch: &str) -> bool {
+        match self {
+            RuleBranch::Named(s) => s == branch,
+            RuleBranch::Glob(pattern) => Pattern::new(pattern)
+                .map(|p| p.matches(branch))
+                .unwrap_or(false),
+            RuleBranch::Regex(regex) => Regex::new(regex)
+                .map(|r| r.is_match(branch))
+                .unwrap_or(false),
+            RuleBranch::Wildcard => true,
+        }
+    }
+
+    /// Validate glob patterns and regexes.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            RuleBranch::Glob(pattern) => Pattern::new(pattern)
+                .map(|_| ())
+                .map_err(|e| format!("invalid branch pattern '{pattern}': {e}")),
+            RuleBranch::Regex(regex) => Regex::new(regex)
+                .map(|_| ())
+                .map_err(|e| format!("invalid branch regex '{regex}': {e}")),
+            RuleBranch::Named(_) | RuleBranch::Wildcard => Ok(()),
+        }
+    }
 }
 
 impl std::fmt::Display for RuleBranch {
@@ -98,3 +159,58 @@ impl std::fmt::Display for RuleBranch {
         f.write_str(&self.name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rule_branch() {
+        assert_eq!(RuleBranch::from("*"), RuleBranch::Wildcard);
+        assert_eq!(RuleBranch::from("main"), RuleBranch::Named("main".into()));
+        assert_eq!(
+            RuleBranch::from("release/*"),
+            RuleBranch::Glob("release/*".into())
+        );
+        assert_eq!(
+            RuleBranch::from("re:^v\\d+$"),
+            RuleBranch::Regex("^v\\d+$".into())
+        );
+        assert_eq!(RuleBranch::from("re:^v\\d+$").to_string(), "re:^v\\d+$");
+        assert!("re:(".parse::<RuleBranch>().is_err());
+        assert!("release/[".parse::<RuleBranch>().is_err());
+    }
+
+    #[test]
+    fn match_rule_branch() {
+        assert!(RuleBranch::Named("main".into()).matches("main"));
+        assert!(!RuleBranch::Named("main".into()).matches("main2"));
+        assert!(RuleBranch::Glob("release/*".into()).matches("release/1.0"));
+        assert!(!RuleBranch::Glob("release/*".into()).matches("main"));
+        assert!(RuleBranch::Regex("^v\\d+$".into()).matches("v12"));
+        assert!(!RuleBranch::Regex("^v\\d+$".into()).matches("vx"));
+        assert!(!RuleBranch::Regex("(".into()).matches("("));
+        assert!(RuleBranch::Wildcard.matches("anything"));
+    }
+
+    #[test]
+    fn order_rule_branch() {
+        let mut branches = vec![
+            RuleBranch::Named("main".into()),
+            RuleBranch::Wildcard,
+            RuleBranch::Regex(".*".into()),
+            RuleBranch::Glob("m*".into()),
+        ];
+        branches.sort();
+
+        assert_eq!(
+            branches,
+            vec![
+                RuleBranch::Wildcard,
+                RuleBranch::Glob("m*".into()),
+                RuleBranch::Regex(".*".into()),
+                RuleBranch::Named("main".into()),
+            ]
+        );
+    }
+}
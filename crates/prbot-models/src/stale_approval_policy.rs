@@ -0,0 +1,83 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum StaleApprovalPolicyError {
+    /// Unknown stale approval policy.
+    #[error("Unknown stale approval policy: {}", policy)]
+    UnknownStaleApprovalPolicy { policy: String },
+}
+
+/// What happens to approvals when new commits are pushed.
+#[derive(Debug, Serialize, Default, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StaleApprovalPolicy {
+    /// Approvals stay valid.
+    #[default]
+    Keep,
+    /// Approvals of older commits are invalidated.
+    InvalidateAll,
+    /// Approvals of older commits are invalidated if files of the pull request changed since.
+    InvalidateChangedFiles,
+}
+
+impl std::fmt::Display for StaleApprovalPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            Self::Keep => "keep",
+            Self::InvalidateAll => "invalidate_all",
+            Self::InvalidateChangedFiles => "invalidate_changed_files",
+        };
+
+        f.write_str(value)
+    }
+}
+
+impl FromStr for StaleApprovalPolicy {
+    type Err = StaleApprovalPolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl TryFrom<&str> for StaleApprovalPolicy {
+    type Error = StaleApprovalPolicyError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "keep" => Ok(Self::Keep),
+            "invalidate_all" | "invalidate-all" => Ok(Self::InvalidateAll),
+            "invalidate_changed_files" | "invalidate-changed-files" => {
+                Ok(Self::InvalidateChangedFiles)
+            }
+            other => Err(StaleApprovalPolicyError::UnknownStaleApprovalPolicy {
+                policy: other.into(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_policy() {
+        assert_eq!(
+            StaleApprovalPolicy::from_str("keep").unwrap(),
+            StaleApprovalPolicy::Keep
+        );
+        assert_eq!(
+            StaleApprovalPolicy::from_str("invalidate-changed-files").unwrap(),
+            StaleApprovalPolicy::InvalidateChangedFiles
+        );
+        assert!(StaleApprovalPolicy::from_str("dismiss").is_err());
+        assert_eq!(
+            StaleApprovalPolicy::InvalidateAll.to_string(),
+            "invalidate_all"
+        );
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::{convert::TryFrom, fmt::Display};
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Type error.
@@ -14,7 +15,8 @@ pub enum StepLabelError {
 }
 
 /// Step label.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum StepLabel {
     /// Work in progress.
     Wip,
@@ -26,10 +28,14 @@ pub enum StepLabel {
     AwaitingReview,
     /// Awaiting required review.
     AwaitingRequiredReview,
+    /// Awaiting review, after approvals were invalidated by new commits.
+    StaleApproval,
     /// Awaiting QA.
     AwaitingQa,
     /// Locked
     Locked,
+    /// Frozen, by a merge freeze or outside of merge windows.
+    Frozen,
     /// Awaiting merge.
     AwaitingMerge,
 }
@@ -39,6 +45,19 @@ impl StepLabel {
     pub fn to_str(self) -> &'static str {
         self.into()
     }
+
+    /// Check if a pull request can become stale in this step.
+    pub fn can_be_stale(self) -> bool {
+        matches!(
+            self,
+            Self::AwaitingChanges
+                | Self::AwaitingChecks
+                | Self::AwaitingReview
+                | Self::AwaitingRequiredReview
+                | Self::StaleApproval
+                | Self::AwaitingQa
+        )
+    }
 }
 
 impl Display for StepLabel {
@@ -57,9 +76,11 @@ impl TryFrom<&str> for StepLabel {
             "step/awaiting-changes" => Ok(Self::AwaitingChanges),
             "step/awaiting-review" => Ok(Self::AwaitingReview),
             "step/awaiting-required-review" => Ok(Self::AwaitingRequiredReview),
+            "step/stale-approval" => Ok(Self::StaleApproval),
             "step/awaiting-qa" => Ok(Self::AwaitingQa),
             "step/awaiting-merge" => Ok(Self::AwaitingMerge),
             "step/locked" => Ok(Self::Locked),
+            "step/frozen" => Ok(Self::Frozen),
             name => Err(StepLabelError::UnknownStepLabel {
                 label: name.to_string(),
             }),
@@ -83,9 +104,11 @@ impl From<StepLabel> for &'static str {
             StepLabel::AwaitingChanges => "step/awaiting-changes",
             StepLabel::AwaitingReview => "step/awaiting-review",
             StepLabel::AwaitingRequiredReview => "step/awaiting-required-review",
+            StepLabel::StaleApproval => "step/stale-approval",
             StepLabel::AwaitingQa => "step/awaiting-qa",
             StepLabel::AwaitingMerge => "step/awaiting-merge",
             StepLabel::Locked => "step/locked",
+            StepLabel::Frozen => "step/frozen",
         }
     }
 }
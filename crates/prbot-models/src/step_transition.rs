@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use time::OffsetDateTime;
+
+use crate::StepLabel;
+
+/// Step transition: a pull request entering a step.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SmartDefault)]
+pub struct StepTransition {
+    pub id: u64,
+    pub pull_request_id: u64,
+    #[default(StepLabel::Wip)]
+    pub step: StepLabel,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
@@ -0,0 +1,78 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use thiserror::Error;
+use time::OffsetDateTime;
+
+#[derive(Debug, Error)]
+pub enum WebhookDeliveryOutcomeError {
+    /// Unknown webhook delivery outcome.
+    #[error("Unknown webhook delivery outcome: {}", outcome)]
+    UnknownWebhookDeliveryOutcome { outcome: String },
+}
+
+/// Webhook delivery processing outcome.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Copy, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryOutcome {
+    /// Received, not yet processed.
+    #[default]
+    Pending,
+    /// Processed without error.
+    Success,
+    /// Processing failed.
+    Failure,
+}
+
+impl WebhookDeliveryOutcome {
+    /// Convert outcome to static str.
+    pub fn to_str(self) -> &'static str {
+        self.into()
+    }
+}
+
+impl Display for WebhookDeliveryOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+impl TryFrom<&str> for WebhookDeliveryOutcome {
+    type Error = WebhookDeliveryOutcomeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "success" => Ok(Self::Success),
+            "failure" => Ok(Self::Failure),
+            e => Err(WebhookDeliveryOutcomeError::UnknownWebhookDeliveryOutcome {
+                outcome: e.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<WebhookDeliveryOutcome> for &'static str {
+    fn from(outcome: WebhookDeliveryOutcome) -> Self {
+        match outcome {
+            WebhookDeliveryOutcome::Pending => "pending",
+            WebhookDeliveryOutcome::Success => "success",
+            WebhookDeliveryOutcome::Failure => "failure",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, SmartDefault)]
+pub struct WebhookDelivery {
+    pub id: u64,
+    pub delivery_id: String,
+    pub event_type: String,
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+    pub outcome: WebhookDeliveryOutcome,
+    pub error: Option<String>,
+    #[default(OffsetDateTime::now_utc())]
+    #[serde(with = "time::serde::rfc3339")]
+    pub received_at: OffsetDateTime,
+}
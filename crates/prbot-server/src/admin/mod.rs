@@ -0,0 +1,5 @@
+//! Admin handlers.
+
+pub mod stats;
+pub mod validator;
+pub mod webhooks;
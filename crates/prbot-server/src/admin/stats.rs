@@ -0,0 +1,61 @@
+//! Admin statistics handlers.
+
+use actix_web::{web, HttpResponse, Result};
+use prbot_core::use_cases::stats::ComputeRepositoryStatsInterface;
+use prbot_models::{parse_since, RepositoryPath};
+use serde::Deserialize;
+use shaku::HasComponent;
+use time::OffsetDateTime;
+
+use crate::{server::AppContext, ServerError};
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct StatsQuery {
+    since: Option<String>,
+}
+
+#[tracing::instrument(skip(ctx), ret)]
+pub(crate) async fn repository_stats(
+    ctx: web::Data<AppContext>,
+    path: web::Path<(String, String)>,
+    query: web::Query<StatsQuery>,
+) -> Result<HttpResponse> {
+    let (owner, name) = path.into_inner();
+    let since = match query
+        .since
+        .as_deref()
+        .map(|value| parse_since(value, OffsetDateTime::now_utc()))
+        .transpose()
+    {
+        Ok(since) => since,
+        Err(e) => {
+            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": e
+            })))
+        }
+    };
+
+    if ctx
+        .db_service
+        .repositories_get(&owner, &name)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?
+        .is_none()
+    {
+        return Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("Unknown repository: {owner}/{name}")
+        })));
+    }
+
+    let compute_stats: &dyn ComputeRepositoryStatsInterface = ctx.core_module.resolve_ref();
+    let stats = compute_stats
+        .run(
+            &ctx.as_core_context(),
+            &RepositoryPath::new_from_components(&owner, &name),
+            since,
+        )
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })?;
+
+    Ok(HttpResponse::Ok().json(stats))
+}
@@ -0,0 +1,35 @@
+//! Admin API validator.
+
+use actix_web::{dev::ServiceRequest, http::StatusCode, web, Error, ResponseError};
+use actix_web_httpauth::extractors::bearer::BearerAuth;
+use thiserror::Error;
+
+use crate::server::AppContext;
+
+/// Validation error.
+#[derive(Debug, Error)]
+pub enum AdminValidationError {
+    #[error("Invalid admin token.")]
+    InvalidToken,
+}
+
+impl ResponseError for AdminValidationError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNAUTHORIZED
+    }
+}
+
+/// Admin token validator.
+pub async fn admin_auth_validator(
+    req: ServiceRequest,
+    credentials: BearerAuth,
+) -> Result<ServiceRequest, (Error, ServiceRequest)> {
+    let ctx = req.app_data::<web::Data<AppContext>>().unwrap();
+    let admin_token = &ctx.config.server.admin_token;
+
+    if !admin_token.is_empty() && credentials.token() == admin_token {
+        Ok(req)
+    } else {
+        Err((AdminValidationError::InvalidToken.into(), req))
+    }
+}
@@ -0,0 +1,20 @@
+//! Admin webhook handlers.
+
+use actix_web::{web, HttpResponse, Result};
+
+use crate::{server::AppContext, webhook::replay_webhook_delivery};
+
+#[tracing::instrument(skip(ctx), ret)]
+pub(crate) async fn replay_delivery(
+    ctx: web::Data<AppContext>,
+    delivery_id: web::Path<String>,
+) -> Result<HttpResponse> {
+    let delivery = replay_webhook_delivery(ctx.into_inner(), &delivery_id).await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "delivery_id": delivery.delivery_id,
+        "event_type": delivery.event_type,
+        "outcome": delivery.outcome,
+        "error": delivery.error,
+    })))
+}
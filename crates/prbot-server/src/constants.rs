@@ -1,5 +1,7 @@
 //! Webhook constants.
 
+/// GitHub delivery header.
+pub const GITHUB_DELIVERY_HEADER: &str = "X-GitHub-Delivery";
 /// GitHub event header.
 pub const GITHUB_EVENT_HEADER: &str = "X-GitHub-Event";
 /// GitHub signature header.
@@ -2,6 +2,7 @@
 
 use actix_http::StatusCode;
 use actix_web::ResponseError;
+use prbot_models::JobKind;
 use thiserror::Error;
 
 use crate::event_type::EventType;
@@ -16,6 +17,12 @@ pub enum ServerError {
         source: serde_json::Error,
     },
 
+    #[error("Unsupported event type '{event_type}'.")]
+    UnsupportedEventType { event_type: String },
+
+    #[error("Invalid payload for job '{kind}': {message}")]
+    InvalidJobPayload { kind: JobKind, message: String },
+
     #[error("Missing webhook signature.")]
     MissingWebhookSignature,
 
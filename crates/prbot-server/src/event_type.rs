@@ -14,16 +14,26 @@ pub enum EventTypeError {
 /// Event type.
 #[derive(Debug, Clone, Copy)]
 pub enum EventType {
+    /// Check run event.
+    CheckRun,
     /// Check suite event.
     CheckSuite,
     /// Issue comment event.
     IssueComment,
+    /// Merge group event.
+    MergeGroup,
     /// Ping event.
     Ping,
     /// Pull request event.
     PullRequest,
     /// Pull request review event.
     PullRequestReview,
+    /// Push event.
+    Push,
+    /// Commit status event.
+    Status,
+    /// Workflow run event.
+    WorkflowRun,
 }
 
 impl EventType {
@@ -44,11 +54,16 @@ impl TryFrom<&str> for EventType {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
+            "check_run" => Ok(Self::CheckRun),
             "check_suite" => Ok(Self::CheckSuite),
             "issue_comment" => Ok(Self::IssueComment),
+            "merge_group" => Ok(Self::MergeGroup),
             "ping" => Ok(Self::Ping),
             "pull_request" => Ok(Self::PullRequest),
             "pull_request_review" => Ok(Self::PullRequestReview),
+            "push" => Ok(Self::Push),
+            "status" => Ok(Self::Status),
+            "workflow_run" => Ok(Self::WorkflowRun),
             name => Err(EventTypeError::UnsupportedEvent {
                 event: name.to_owned(),
             }),
@@ -59,11 +74,16 @@ impl TryFrom<&str> for EventType {
 impl From<EventType> for &'static str {
     fn from(event_type: EventType) -> Self {
         match event_type {
+            EventType::CheckRun => "check_run",
             EventType::CheckSuite => "check_suite",
             EventType::IssueComment => "issue_comment",
+            EventType::MergeGroup => "merge_group",
             EventType::Ping => "ping",
             EventType::PullRequest => "pull_request",
             EventType::PullRequestReview => "pull_request_review",
+            EventType::Push => "push",
+            EventType::Status => "status",
+            EventType::WorkflowRun => "workflow_run",
         }
     }
 }
@@ -1,16 +1,19 @@
 //! External status handlers.
 
-use std::str::FromStr;
+use std::{str::FromStr, sync::Arc};
 
 use actix_web::{web, HttpResponse, Result};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
 use prbot_core::use_cases::status::SetPullRequestQaStatusInterface;
-use prbot_models::{QaStatus, RepositoryPath};
+use prbot_models::{JobKind, QaStatus, RepositoryPath};
 use prbot_sentry::sentry;
 use serde::{Deserialize, Serialize};
 use shaku::HasComponent;
 
-use crate::{external::validator::extract_account_from_auth, server::AppContext};
+use crate::{
+    external::validator::extract_account_from_auth, jobs::enqueue_job, server::AppContext,
+    Result as ServerResult, ServerError,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub(crate) struct QaStatusJson {
@@ -20,6 +23,16 @@ pub(crate) struct QaStatusJson {
     status: Option<bool>,
 }
 
+/// QA status job payload.
+#[derive(Debug, Serialize, Deserialize)]
+struct QaStatusJobPayload {
+    account: String,
+    repository_path: String,
+    pull_request_numbers: Vec<u64>,
+    author: String,
+    status: QaStatus,
+}
+
 #[tracing::instrument(skip_all, fields(
     repository_path = data.repository_path,
     pull_request_numbers = ?data.pull_request_numbers,
@@ -42,36 +55,68 @@ pub(crate) async fn set_qa_status(
         }));
     });
 
-    // TODO: CAN EXPLODE
-    let repo_path = RepositoryPath::from_str(&data.repository_path).unwrap();
+    if let Err(e) = RepositoryPath::from_str(&data.repository_path) {
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": e.to_string()
+        })));
+    }
+
+    let data = data.into_inner();
     let status = match data.status {
         None => QaStatus::Waiting,
         Some(true) => QaStatus::Pass,
         Some(false) => QaStatus::Fail,
     };
 
-    tokio::spawn(async move {
-        sentry::configure_scope(|scope| {
-            scope.set_user(Some(sentry::User {
-                username: Some(target_account.username.clone()),
-                ..sentry::User::default()
-            }));
-        });
-
-        let set_pull_request_qa_status: &dyn SetPullRequestQaStatusInterface =
-            ctx.core_module.resolve_ref();
-        set_pull_request_qa_status
-            .run(
-                &ctx.as_core_context(),
-                &target_account,
-                repo_path,
-                &data.pull_request_numbers,
-                &data.author,
-                status,
-            )
-            .await
-            .unwrap()
-    });
+    let payload = serde_json::to_string(&QaStatusJobPayload {
+        account: target_account.username,
+        repository_path: data.repository_path,
+        pull_request_numbers: data.pull_request_numbers,
+        author: data.author,
+        status,
+    })
+    .map_err(actix_web::error::ErrorInternalServerError)?;
+
+    enqueue_job(&ctx, JobKind::SetPullRequestQaStatus, payload, None).await?;
 
     Ok(HttpResponse::Accepted().body("Set QA status."))
 }
+
+/// Run a QA status job.
+pub(crate) async fn set_qa_status_job(ctx: Arc<AppContext>, payload: &str) -> ServerResult<()> {
+    let invalid_payload = |message: String| ServerError::InvalidJobPayload {
+        kind: JobKind::SetPullRequestQaStatus,
+        message,
+    };
+
+    let payload: QaStatusJobPayload =
+        serde_json::from_str(payload).map_err(|e| invalid_payload(e.to_string()))?;
+    let repo_path = RepositoryPath::from_str(&payload.repository_path)
+        .map_err(|e| invalid_payload(e.to_string()))?;
+    let target_account = ctx
+        .db_service
+        .external_accounts_get_expect(&payload.account)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?;
+
+    sentry::configure_scope(|scope| {
+        scope.set_user(Some(sentry::User {
+            username: Some(target_account.username.clone()),
+            ..sentry::User::default()
+        }));
+    });
+
+    let set_pull_request_qa_status: &dyn SetPullRequestQaStatusInterface =
+        ctx.core_module.resolve_ref();
+    set_pull_request_qa_status
+        .run(
+            &ctx.as_core_context(),
+            &target_account,
+            repo_path,
+            &payload.pull_request_numbers,
+            &payload.author,
+            payload.status,
+        )
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })
+}
@@ -7,7 +7,8 @@ use prbot_ghapi_interface::{
     gif::GifResponse,
     review::GhReviewApi,
     types::{
-        GhCheckRun, GhCommitStatus, GhCommitStatusState, GhMergeStrategy, GhPullRequest,
+        GhCheckRun, GhCommitStatus, GhCommitStatusState, GhFileContent, GhGitCommit, GhGitRef,
+        GhIssueComment, GhMergeStrategy, GhPullCommit, GhPullRequest, GhPullRequestShort,
         GhReactionType, GhUserPermission,
     },
     ApiService, Result,
@@ -102,6 +103,16 @@ impl ApiService for MetricsApiService {
             .await
     }
 
+    async fn comments_list(
+        &self,
+        owner: &str,
+        name: &str,
+        issue_number: u64,
+    ) -> Result<Vec<GhIssueComment>> {
+        GITHUB_API_CALLS.inc();
+        self.inner.comments_list(owner, name, issue_number).await
+    }
+
     async fn comments_update(
         &self,
         owner: &str,
@@ -138,6 +149,11 @@ impl ApiService for MetricsApiService {
         self.inner.pulls_get(owner, name, number).await
     }
 
+    async fn pulls_list(&self, owner: &str, name: &str) -> Result<Vec<GhPullRequest>> {
+        GITHUB_API_CALLS.inc();
+        self.inner.pulls_list(owner, name).await
+    }
+
     async fn pulls_merge(
         &self,
         owner: &str,
@@ -160,6 +176,31 @@ impl ApiService for MetricsApiService {
             .await
     }
 
+    async fn pulls_create(
+        &self,
+        owner: &str,
+        name: &str,
+        title: &str,
+        body: &str,
+        head: &str,
+        base: &str,
+    ) -> Result<GhPullRequest> {
+        GITHUB_API_CALLS.inc();
+        self.inner
+            .pulls_create(owner, name, title, body, head, base)
+            .await
+    }
+
+    async fn pulls_enqueue(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        GITHUB_API_CALLS.inc();
+        self.inner.pulls_enqueue(owner, name, number).await
+    }
+
+    async fn pulls_convert_to_draft(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        GITHUB_API_CALLS.inc();
+        self.inner.pulls_convert_to_draft(owner, name, number).await
+    }
+
     async fn pull_reviewer_requests_add(
         &self,
         owner: &str,
@@ -196,6 +237,44 @@ impl ApiService for MetricsApiService {
         self.inner.pull_reviews_list(owner, name, number).await
     }
 
+    async fn pull_files_list(&self, owner: &str, name: &str, number: u64) -> Result<Vec<String>> {
+        GITHUB_API_CALLS.inc();
+        self.inner.pull_files_list(owner, name, number).await
+    }
+
+    async fn pull_commits_list(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<GhPullCommit>> {
+        GITHUB_API_CALLS.inc();
+        self.inner.pull_commits_list(owner, name, number).await
+    }
+
+    async fn commit_pulls_list(
+        &self,
+        owner: &str,
+        name: &str,
+        sha: &str,
+    ) -> Result<Vec<GhPullRequestShort>> {
+        GITHUB_API_CALLS.inc();
+        self.inner.commit_pulls_list(owner, name, sha).await
+    }
+
+    async fn commits_compare_files(
+        &self,
+        owner: &str,
+        name: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<String>> {
+        GITHUB_API_CALLS.inc();
+        self.inner
+            .commits_compare_files(owner, name, base, head)
+            .await
+    }
+
     async fn commit_statuses_combined(
         &self,
         owner: &str,
@@ -223,6 +302,112 @@ impl ApiService for MetricsApiService {
             .await
     }
 
+    async fn git_refs_get(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+    ) -> Result<Option<GhGitRef>> {
+        GITHUB_API_CALLS.inc();
+        self.inner.git_refs_get(owner, name, reference).await
+    }
+
+    async fn git_refs_create(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+        sha: &str,
+    ) -> Result<()> {
+        GITHUB_API_CALLS.inc();
+        self.inner
+            .git_refs_create(owner, name, reference, sha)
+            .await
+    }
+
+    async fn git_refs_update(
+        &self,
+        owner: &str,
+        name: &str,
+        reference: &str,
+        sha: &str,
+        force: bool,
+    ) -> Result<()> {
+        GITHUB_API_CALLS.inc();
+        self.inner
+            .git_refs_update(owner, name, reference, sha, force)
+            .await
+    }
+
+    async fn git_refs_delete(&self, owner: &str, name: &str, reference: &str) -> Result<()> {
+        GITHUB_API_CALLS.inc();
+        self.inner.git_refs_delete(owner, name, reference).await
+    }
+
+    async fn git_commits_get(&self, owner: &str, name: &str, sha: &str) -> Result<GhGitCommit> {
+        GITHUB_API_CALLS.inc();
+        self.inner.git_commits_get(owner, name, sha).await
+    }
+
+    async fn git_commits_create(
+        &self,
+        owner: &str,
+        name: &str,
+        message: &str,
+        tree: &str,
+        parents: &[String],
+    ) -> Result<GhGitCommit> {
+        GITHUB_API_CALLS.inc();
+        self.inner
+            .git_commits_create(owner, name, message, tree, parents)
+            .await
+    }
+
+    async fn repository_merges_create(
+        &self,
+        owner: &str,
+        name: &str,
+        base: &str,
+        head: &str,
+        commit_message: &str,
+    ) -> Result<Option<String>> {
+        GITHUB_API_CALLS.inc();
+        self.inner
+            .repository_merges_create(owner, name, base, head, commit_message)
+            .await
+    }
+
+    async fn repository_contents_get<'a>(
+        &self,
+        owner: &str,
+        name: &str,
+        path: &str,
+        reference: Option<&'a str>,
+    ) -> Result<Option<GhFileContent>> {
+        GITHUB_API_CALLS.inc();
+        self.inner
+            .repository_contents_get(owner, name, path, reference)
+            .await
+    }
+
+    async fn team_members_list(&self, org: &str, team_slug: &str) -> Result<Vec<String>> {
+        GITHUB_API_CALLS.inc();
+        self.inner.team_members_list(org, team_slug).await
+    }
+
+    async fn team_permissions_get(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        name: &str,
+    ) -> Result<GhUserPermission> {
+        GITHUB_API_CALLS.inc();
+        self.inner
+            .team_permissions_get(org, team_slug, owner, name)
+            .await
+    }
+
     async fn gif_search(&self, api_key: &str, search: &str) -> Result<GifResponse> {
         TENOR_API_CALLS.inc();
         self.inner.gif_search(api_key, search).await
@@ -0,0 +1,449 @@
+//! Background jobs.
+
+use std::{sync::Arc, time::Duration as StdDuration};
+
+use prbot_core::use_cases::pulls::SynchronizePullRequestAndUpdateStatusInterface;
+use prbot_models::{Job, JobKind, JobStatus, PullRequestHandle};
+use shaku::HasComponent;
+use time::{Duration, OffsetDateTime};
+use tracing::{error, info, warn};
+
+use crate::{
+    external::status::set_qa_status_job,
+    metrics::{JOBS_DEAD_LETTERED, JOBS_FAILED, JOBS_SUCCEEDED},
+    server::AppContext,
+    webhook::{
+        checks::{
+            check_run_event, check_suite_event, merge_group_event, parse_check_run_event,
+            parse_check_suite_event, parse_merge_group_event, parse_status_event,
+            parse_workflow_run_event, status_event, workflow_run_event,
+        },
+        deliveries::set_webhook_delivery_outcome,
+        issues::{issue_comment_event, parse_issue_comment_event},
+        pulls::{parse_pull_request_event, pull_request_event},
+        pushes::{parse_push_event, push_event},
+        reviews::{parse_review_event, review_event},
+    },
+    Result, ServerError,
+};
+
+const JOBS_POLL_INTERVAL: StdDuration = StdDuration::from_secs(1);
+const JOBS_MAX_BACKOFF: Duration = Duration::hours(1);
+
+/// Enqueue a new job, to be run as soon as possible.
+///
+/// A webhook delivery is enqueued once: sending it again returns its existing job.
+pub(crate) async fn enqueue_job(
+    ctx: &AppContext,
+    kind: JobKind,
+    payload: String,
+    delivery_id: Option<String>,
+) -> Result<Job> {
+    ctx.db_service
+        .jobs_create(Job {
+            kind,
+            payload,
+            delivery_id,
+            max_attempts: ctx.config.server.jobs_max_attempts,
+            ..Default::default()
+        })
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })
+}
+
+/// Execute a job payload, without touching its state.
+pub(crate) async fn execute_job(ctx: Arc<AppContext>, kind: JobKind, payload: &str) -> Result<()> {
+    match kind {
+        JobKind::ProcessPullRequestEvent => {
+            pull_request_event(ctx, parse_pull_request_event(payload)?).await
+        }
+        JobKind::HandleIssueCommentEvent => {
+            issue_comment_event(ctx, parse_issue_comment_event(payload)?).await
+        }
+        JobKind::HandleCheckRunEvent => check_run_event(ctx, parse_check_run_event(payload)?).await,
+        JobKind::HandleCheckSuiteEvent => {
+            check_suite_event(ctx, parse_check_suite_event(payload)?).await
+        }
+        JobKind::HandleWorkflowRunEvent => {
+            workflow_run_event(ctx, parse_workflow_run_event(payload)?).await
+        }
+        JobKind::HandleStatusEvent => status_event(ctx, parse_status_event(payload)?).await,
+        JobKind::HandleMergeGroupEvent => {
+            merge_group_event(ctx, parse_merge_group_event(payload)?).await
+        }
+        JobKind::HandleReviewEvent => review_event(ctx, parse_review_event(payload)?).await,
+        JobKind::HandlePushEvent => push_event(ctx, parse_push_event(payload)?).await,
+        JobKind::SetPullRequestQaStatus => set_qa_status_job(ctx, payload).await,
+        JobKind::RetryPullRequestMerge => retry_pull_request_merge_job(ctx, payload).await,
+    }
+}
+
+/// Run a merge retry job, by synchronizing the pull request again.
+async fn retry_pull_request_merge_job(ctx: Arc<AppContext>, payload: &str) -> Result<()> {
+    let pr_handle =
+        PullRequestHandle::try_from(payload).map_err(|e| ServerError::InvalidJobPayload {
+            kind: JobKind::RetryPullRequestMerge,
+            message: e.to_string(),
+        })?;
+
+    let uc: &dyn SynchronizePullRequestAndUpdateStatusInterface = ctx.core_module.resolve_ref();
+    uc.run(&ctx.as_core_context(), &pr_handle)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })
+}
+
+/// Delay before the next attempt of a job which failed `attempts` times.
+pub(crate) fn backoff_delay(base: u64, attempts: u64) -> Duration {
+    let exponent = attempts.saturating_sub(1).min(16) as u32;
+    let delay = Duration::seconds(base.saturating_mul(2u64.pow(exponent)) as i64);
+    delay.min(JOBS_MAX_BACKOFF)
+}
+
+/// Build a worker identifier, unique across server replicas.
+fn job_worker_id(instance_id: &str, index: u64) -> String {
+    format!("{instance_id}/{index}")
+}
+
+/// Renew the lease of a running job, until the job completes.
+async fn renew_job_lease(ctx: &AppContext, job_id: u64, worker_id: &str) {
+    let lease = ctx.config.server.jobs_lease_duration;
+
+    loop {
+        tokio::time::sleep(StdDuration::from_secs((lease / 3).max(1))).await;
+
+        let locked_until = OffsetDateTime::now_utc() + Duration::seconds(lease as i64);
+        match ctx
+            .db_service
+            .jobs_renew_lease(job_id, worker_id, locked_until)
+            .await
+        {
+            Ok(true) => (),
+            Ok(false) => warn!(
+                job_id = job_id,
+                worker_id = worker_id,
+                message = "Job lease was lost, it may be run again"
+            ),
+            Err(e) => warn!(
+                job_id = job_id,
+                worker_id = worker_id,
+                error = %e,
+                message = "Could not renew job lease"
+            ),
+        }
+    }
+}
+
+/// Claim and run the next available job, if any.
+///
+/// The job is leased to the worker while it runs.
+pub(crate) async fn process_next_job(ctx: Arc<AppContext>, worker_id: &str) -> Result<Option<Job>> {
+    let now = OffsetDateTime::now_utc();
+    let locked_until = now + Duration::seconds(ctx.config.server.jobs_lease_duration as i64);
+    let job = match ctx
+        .db_service
+        .jobs_claim_next(now, worker_id, locked_until)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?
+    {
+        Some(job) => job,
+        None => return Ok(None),
+    };
+
+    let result = tokio::select! {
+        result = execute_job(ctx.clone(), job.kind, &job.payload) => result,
+        () = renew_job_lease(&ctx, job.id, worker_id) => unreachable!("lease renewal never ends"),
+    };
+
+    let job = Job {
+        locked_by: None,
+        locked_until: None,
+        ..job
+    };
+    let job = match &result {
+        Ok(()) => {
+            JOBS_SUCCEEDED.inc();
+            Job {
+                status: JobStatus::Succeeded,
+                last_error: None,
+                ..job
+            }
+        }
+        Err(e) if job.attempts >= job.max_attempts => {
+            JOBS_DEAD_LETTERED.inc();
+            error!(
+                job_id = job.id,
+                kind = %job.kind,
+                attempts = job.attempts,
+                error = %e,
+                message = "Job moved to dead-letter state"
+            );
+            Job {
+                status: JobStatus::DeadLetter,
+                last_error: Some(e.to_string()),
+                ..job
+            }
+        }
+        Err(e) => {
+            JOBS_FAILED.inc();
+            warn!(
+                job_id = job.id,
+                kind = %job.kind,
+                attempts = job.attempts,
+                error = %e,
+                message = "Job failed, will be retried"
+            );
+            Job {
+                status: JobStatus::Pending,
+                last_error: Some(e.to_string()),
+                run_at: now + backoff_delay(ctx.config.server.jobs_backoff_base, job.attempts),
+                ..job
+            }
+        }
+    };
+
+    // The lease may have expired and the job claimed again by another worker
+    let job_id = job.id;
+    let Some(job) = ctx
+        .db_service
+        .jobs_finish(job, worker_id)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?
+    else {
+        warn!(
+            job_id = job_id,
+            worker_id = worker_id,
+            message = "Job lease was lost, its outcome is discarded"
+        );
+        return Ok(None);
+    };
+
+    if let Some(delivery_id) = &job.delivery_id {
+        if job.status != JobStatus::Pending {
+            set_webhook_delivery_outcome(&ctx, delivery_id, result.as_ref().err()).await?;
+        }
+    }
+
+    Ok(Some(job))
+}
+
+/// Requeue running jobs whose lease expired, because their worker stopped.
+pub(crate) async fn requeue_expired_jobs(ctx: &AppContext) -> Result<u64> {
+    let count = ctx
+        .db_service
+        .jobs_requeue_expired(OffsetDateTime::now_utc())
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?;
+    if count > 0 {
+        info!(count = count, message = "Requeued interrupted jobs");
+    }
+
+    Ok(count)
+}
+
+/// Spawn background job workers.
+///
+/// Jobs are leased by their worker, so multiple server instances can share
+/// the same database: only jobs whose lease expired are requeued.
+pub(crate) async fn spawn_job_workers(ctx: Arc<AppContext>) -> Result<()> {
+    requeue_expired_jobs(&ctx).await?;
+
+    {
+        let ctx = ctx.clone();
+        let interval = StdDuration::from_secs(ctx.config.server.jobs_lease_duration.max(1));
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if let Err(e) = requeue_expired_jobs(&ctx).await {
+                    error!(error = %e, message = "Could not requeue expired jobs");
+                }
+            }
+        });
+    }
+
+    let instance_id = format!(
+        "{}-{:08x}",
+        std::env::var("HOSTNAME").unwrap_or_else(|_| "prbot".into()),
+        rand::random::<u32>()
+    );
+    for index in 0..ctx.config.server.jobs_workers_count {
+        let ctx = ctx.clone();
+        let worker_id = job_worker_id(&instance_id, index);
+        tokio::spawn(async move {
+            loop {
+                match process_next_job(ctx.clone(), &worker_id).await {
+                    Ok(Some(_)) => continue,
+                    Ok(None) => (),
+                    Err(e) => error!(error = %e, message = "Could not process job"),
+                }
+
+                tokio::time::sleep(JOBS_POLL_INTERVAL).await;
+            }
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use prbot_config::Config;
+    use prbot_core::CoreModule;
+    use prbot_database_memory::MemoryDb;
+    use prbot_ghapi_interface::MockApiService;
+    use prbot_lock_interface::MockLockService;
+    use prbot_models::{Job, JobKind, JobStatus, WebhookDelivery, WebhookDeliveryOutcome};
+    use time::{Duration, OffsetDateTime};
+
+    use super::*;
+
+    fn build_context() -> Arc<AppContext> {
+        Arc::new(AppContext::new_with_adapters(
+            Config::from_env_no_version(),
+            CoreModule::builder().build(),
+            Box::new(MemoryDb::new()),
+            Box::new(MockApiService::new()),
+            Box::new(MockLockService::new()),
+        ))
+    }
+
+    #[test]
+    fn backoff() {
+        assert_eq!(backoff_delay(10, 1), Duration::seconds(10));
+        assert_eq!(backoff_delay(10, 2), Duration::seconds(20));
+        assert_eq!(backoff_delay(10, 4), Duration::seconds(80));
+        assert_eq!(backoff_delay(10, 100), Duration::hours(1));
+    }
+
+    #[tokio::test]
+    async fn enqueue_delivery_once() -> Result<()> {
+        let ctx = build_context();
+        let job = enqueue_job(
+            &ctx,
+            JobKind::HandleIssueCommentEvent,
+            "{}".into(),
+            Some("abcd".into()),
+        )
+        .await?;
+        let redelivered = enqueue_job(
+            &ctx,
+            JobKind::HandleIssueCommentEvent,
+            "{}".into(),
+            Some("abcd".into()),
+        )
+        .await?;
+
+        assert_eq!(redelivered.id, job.id);
+        assert_eq!(ctx.db_service.jobs_list(None).await.unwrap().len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn process_without_jobs() -> Result<()> {
+        assert_eq!(process_next_job(build_context(), "worker").await?, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn process_failing_job() -> Result<()> {
+        let ctx = build_context();
+        ctx.db_service
+            .webhook_deliveries_create(WebhookDelivery {
+                delivery_id: "abcd".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        ctx.db_service
+            .jobs_create(Job {
+                kind: JobKind::SetPullRequestQaStatus,
+                payload: "{}".into(),
+                max_attempts: 2,
+                delivery_id: Some("abcd".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        // First attempt, job is scheduled for retry
+        let job = process_next_job(ctx.clone(), "worker").await?.unwrap();
+        assert_eq!(job.status, JobStatus::Pending);
+        assert_eq!(job.attempts, 1);
+        assert_eq!(job.locked_by, None);
+        assert_eq!(job.locked_until, None);
+        assert!(job.last_error.is_some());
+        assert!(job.run_at > OffsetDateTime::now_utc());
+        assert_eq!(process_next_job(ctx.clone(), "worker").await?, None);
+
+        // Second attempt, job is moved to the dead-letter state
+        ctx.db_service
+            .jobs_update(Job {
+                run_at: OffsetDateTime::now_utc(),
+                ..job
+            })
+            .await
+            .unwrap();
+        let job = process_next_job(ctx.clone(), "worker").await?.unwrap();
+        assert_eq!(job.status, JobStatus::DeadLetter);
+        assert_eq!(job.attempts, 2);
+
+        let delivery = ctx
+            .db_service
+            .webhook_deliveries_get_expect("abcd")
+            .await
+            .unwrap();
+        assert_eq!(delivery.outcome, WebhookDeliveryOutcome::Failure);
+        assert_eq!(delivery.error, job.last_error);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn requeue_expired() -> Result<()> {
+        let ctx = build_context();
+        let now = OffsetDateTime::now_utc();
+        for locked_until in [now - Duration::seconds(1), now + Duration::seconds(60)] {
+            ctx.db_service
+                .jobs_create(Job {
+                    status: JobStatus::Running,
+                    locked_by: Some("other".into()),
+                    locked_until: Some(locked_until),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        // Jobs still leased by another replica are left alone
+        assert_eq!(requeue_expired_jobs(&ctx).await?, 1);
+        assert_eq!(
+            ctx.db_service
+                .jobs_list(Some(JobStatus::Running))
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_invalid_retry_payload() {
+        assert!(matches!(
+            execute_job(
+                build_context(),
+                JobKind::RetryPullRequestMerge,
+                "owner/name"
+            )
+            .await,
+            Err(ServerError::InvalidJobPayload {
+                kind: JobKind::RetryPullRequestMerge,
+                ..
+            })
+        ));
+    }
+}
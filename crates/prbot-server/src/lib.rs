@@ -3,6 +3,7 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+mod admin;
 pub mod constants;
 mod debug;
 pub mod errors;
@@ -10,11 +11,13 @@ mod event_type;
 mod external;
 pub mod ghapi;
 mod health;
+mod jobs;
 mod metrics;
 pub mod middlewares;
 pub mod redis;
+mod scheduler;
 pub mod server;
 pub mod utils;
-mod webhook;
+pub mod webhook;
 
 pub use errors::{Result, ServerError};
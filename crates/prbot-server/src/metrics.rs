@@ -1,6 +1,6 @@
 use actix_web_prom::{PrometheusMetrics, PrometheusMetricsBuilder};
 use lazy_static::lazy_static;
-use prometheus::IntCounter;
+use prometheus::{HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts};
 
 lazy_static! {
     pub static ref GITHUB_API_CALLS: IntCounter =
@@ -8,6 +8,69 @@ lazy_static! {
     pub static ref TENOR_API_CALLS: IntCounter =
         IntCounter::new("tenor_api_calls", "Tenor API calls").unwrap();
     pub static ref REDIS_CALLS: IntCounter = IntCounter::new("redis_calls", "Redis calls").unwrap();
+    pub static ref WEBHOOK_DUPLICATE_DELIVERIES: IntCounter = IntCounter::new(
+        "webhook_duplicate_deliveries",
+        "Webhook deliveries ignored as duplicates"
+    )
+    .unwrap();
+    pub static ref JOBS_SUCCEEDED: IntCounter =
+        IntCounter::new("jobs_succeeded", "Background jobs succeeded").unwrap();
+    pub static ref JOBS_FAILED: IntCounter = IntCounter::new(
+        "jobs_failed",
+        "Background job attempts failed and scheduled for retry"
+    )
+    .unwrap();
+    pub static ref JOBS_DEAD_LETTERED: IntCounter = IntCounter::new(
+        "jobs_dead_lettered",
+        "Background jobs moved to the dead-letter state"
+    )
+    .unwrap();
+    pub static ref SCHEDULER_TASK_RUNS: IntCounterVec = IntCounterVec::new(
+        Opts::new("scheduler_task_runs", "Scheduled task runs"),
+        &["task"]
+    )
+    .unwrap();
+    pub static ref SCHEDULER_TASK_FAILURES: IntCounterVec = IntCounterVec::new(
+        Opts::new("scheduler_task_failures", "Scheduled task runs failed"),
+        &["task"]
+    )
+    .unwrap();
+    pub static ref SCHEDULER_TASK_ITEMS: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "scheduler_task_items",
+            "Items (pull requests, deliveries) processed by scheduled tasks"
+        ),
+        &["task"]
+    )
+    .unwrap();
+    pub static ref SCHEDULER_TASK_DURATION: HistogramVec = HistogramVec::new(
+        HistogramOpts::new(
+            "scheduler_task_duration_seconds",
+            "Scheduled task run duration"
+        ),
+        &["task"]
+    )
+    .unwrap();
+    pub static ref PULL_REQUEST_STEP_DURATION: HistogramVec = HistogramVec::new(
+        HistogramOpts::new(
+            "pull_request_step_duration_seconds",
+            "Time spent by merged pull requests in each step"
+        )
+        .buckets(vec![
+            60.0,
+            300.0,
+            900.0,
+            3600.0,
+            4.0 * 3600.0,
+            12.0 * 3600.0,
+            86400.0,
+            3.0 * 86400.0,
+            7.0 * 86400.0,
+            30.0 * 86400.0,
+        ]),
+        &["step"]
+    )
+    .unwrap();
 }
 
 pub(crate) fn build_metrics_handler() -> PrometheusMetrics {
@@ -31,6 +94,42 @@ pub(crate) fn build_metrics_handler() -> PrometheusMetrics {
         .register(Box::new(REDIS_CALLS.clone()))
         .unwrap();
     prometheus
+        .registry
+        .register(Box::new(WEBHOOK_DUPLICATE_DELIVERIES.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(JOBS_SUCCEEDED.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(JOBS_FAILED.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(JOBS_DEAD_LETTERED.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(SCHEDULER_TASK_RUNS.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(SCHEDULER_TASK_FAILURES.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(SCHEDULER_TASK_ITEMS.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(SCHEDULER_TASK_DURATION.clone()))
+        .unwrap();
+    prometheus
+        .registry
+        .register(Box::new(PULL_REQUEST_STEP_DURATION.clone()))
+        .unwrap();
+    prometheus
 }
 
 #[cfg(unix)]
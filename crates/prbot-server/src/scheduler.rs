@@ -0,0 +1,319 @@
+//! Periodic tasks scheduler.
+//!
+//! Each task runs in its own loop on every server replica, but only one
+//! replica runs it per interval, using a marker stored in the lock service.
+
+use std::{sync::Arc, time::Duration as StdDuration};
+
+use prbot_config::Config;
+use prbot_core::use_cases::pulls::{
+    PurgeClosedPullRequestsInterface, RemindStalePullRequestsInterface,
+    SynchronizeOpenPullRequestsInterface,
+};
+use prbot_lock_interface::{LockService, LockStatus};
+use shaku::HasComponent;
+use time::{Duration, OffsetDateTime};
+use tracing::{error, info};
+
+use crate::{
+    metrics::{
+        SCHEDULER_TASK_DURATION, SCHEDULER_TASK_FAILURES, SCHEDULER_TASK_ITEMS, SCHEDULER_TASK_RUNS,
+    },
+    server::AppContext,
+    webhook::purge_webhook_deliveries,
+    Result, ServerError,
+};
+
+/// Periodic task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScheduledTask {
+    /// Synchronize open pull requests, in case webhooks were missed.
+    SynchronizePullRequests,
+    /// Synchronize automerge pull requests, to retry blocked merges.
+    RetryAutomerges,
+    /// Delete closed pull requests after their retention period.
+    PurgeClosedPullRequests,
+    /// Delete webhook deliveries after their retention period.
+    PurgeWebhookDeliveries,
+    /// Remind reviewers of pull requests stuck in the same step.
+    RemindStalePullRequests,
+}
+
+impl ScheduledTask {
+    /// All tasks.
+    pub const ALL: [Self; 5] = [
+        Self::SynchronizePullRequests,
+        Self::RetryAutomerges,
+        Self::PurgeClosedPullRequests,
+        Self::PurgeWebhookDeliveries,
+        Self::RemindStalePullRequests,
+    ];
+
+    /// Task name, used in metrics and lock keys.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SynchronizePullRequests => "synchronize_pull_requests",
+            Self::RetryAutomerges => "retry_automerges",
+            Self::PurgeClosedPullRequests => "purge_closed_pull_requests",
+            Self::PurgeWebhookDeliveries => "purge_webhook_deliveries",
+            Self::RemindStalePullRequests => "remind_stale_pull_requests",
+        }
+    }
+
+    /// Task interval, if enabled.
+    pub fn interval(&self, config: &Config) -> Option<StdDuration> {
+        let seconds = match self {
+            Self::SynchronizePullRequests => config.server.scheduler_sync_interval,
+            Self::RetryAutomerges => config.server.scheduler_automerge_interval,
+            Self::PurgeClosedPullRequests => config.server.scheduler_purge_interval,
+            Self::PurgeWebhookDeliveries => {
+                config.server.scheduler_webhook_deliveries_purge_interval
+            }
+            Self::RemindStalePullRequests => config.server.scheduler_stale_interval,
+        };
+
+        (seconds > 0).then(|| StdDuration::from_secs(seconds))
+    }
+}
+
+/// Run a task, and return how many items were processed.
+pub(crate) async fn run_task(ctx: &AppContext, task: ScheduledTask) -> Result<u64> {
+    let core_ctx = ctx.as_core_context();
+
+    let count = match task {
+        ScheduledTask::SynchronizePullRequests | ScheduledTask::RetryAutomerges => {
+            let uc: &dyn SynchronizeOpenPullRequestsInterface = ctx.core_module.resolve_ref();
+            uc.run(&core_ctx, task == ScheduledTask::RetryAutomerges)
+                .await
+                .map_err(|e| ServerError::DomainError { source: e })? as u64
+        }
+        ScheduledTask::PurgeClosedPullRequests => {
+            let closed_before = OffsetDateTime::now_utc()
+                - Duration::days(ctx.config.server.scheduler_purge_closed_after_days as i64);
+            let uc: &dyn PurgeClosedPullRequestsInterface = ctx.core_module.resolve_ref();
+            uc.run(&core_ctx, closed_before)
+                .await
+                .map_err(|e| ServerError::DomainError { source: e })? as u64
+        }
+        ScheduledTask::PurgeWebhookDeliveries => {
+            purge_webhook_deliveries(
+                ctx.db_service.as_ref(),
+                ctx.config.server.webhook_deliveries_retention_days,
+            )
+            .await?
+        }
+        ScheduledTask::RemindStalePullRequests => {
+            let uc: &dyn RemindStalePullRequestsInterface = ctx.core_module.resolve_ref();
+            uc.run(&core_ctx, OffsetDateTime::now_utc())
+                .await
+                .map_err(|e| ServerError::DomainError { source: e })? as u64
+        }
+    };
+
+    Ok(count)
+}
+
+/// Claim the current run of a task.
+///
+/// Returns `false` if the task already ran during the last interval,
+/// or if another replica is claiming it.
+pub(crate) async fn claim_task_run(
+    lock_service: &dyn LockService,
+    task: ScheduledTask,
+    interval: StdDuration,
+) -> Result<bool> {
+    let key = format!("scheduler:{}", task.name());
+    let lock = match lock_service
+        .try_lock_resource(&key)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?
+    {
+        LockStatus::SuccessfullyLocked(lock) => lock,
+        LockStatus::AlreadyLocked => return Ok(false),
+    };
+
+    let marker_key = format!("{key}:last-run");
+    let result = match lock_service.get_resource(&marker_key).await {
+        Ok(Some(_)) => Ok(false),
+        Ok(None) => lock_service
+            .set_resource(
+                &marker_key,
+                &OffsetDateTime::now_utc().unix_timestamp().to_string(),
+                interval,
+            )
+            .await
+            .map(|()| true),
+        Err(e) => Err(e),
+    };
+
+    lock.release()
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?;
+    result.map_err(|e| ServerError::DomainError { source: e.into() })
+}
+
+/// Run a task if it was not already run during the last interval.
+async fn run_task_if_due(ctx: &AppContext, task: ScheduledTask, interval: StdDuration) {
+    match claim_task_run(ctx.lock_service.as_ref(), task, interval).await {
+        Ok(true) => (),
+        Ok(false) => return,
+        Err(e) => {
+            error!(task = task.name(), error = %e, message = "Could not claim scheduled task");
+            return;
+        }
+    }
+
+    SCHEDULER_TASK_RUNS.with_label_values(&[task.name()]).inc();
+    let timer = SCHEDULER_TASK_DURATION
+        .with_label_values(&[task.name()])
+        .start_timer();
+    let result = run_task(ctx, task).await;
+    timer.observe_duration();
+
+    match result {
+        Ok(count) => {
+            SCHEDULER_TASK_ITEMS
+                .with_label_values(&[task.name()])
+                .inc_by(count);
+            info!(
+                task = task.name(),
+                count = count,
+                message = "Ran scheduled task"
+            );
+        }
+        Err(e) => {
+            SCHEDULER_TASK_FAILURES
+                .with_label_values(&[task.name()])
+                .inc();
+            error!(task = task.name(), error = %e, message = "Scheduled task failed");
+        }
+    }
+}
+
+/// Spawn one loop per enabled periodic task.
+pub(crate) fn spawn_scheduler(ctx: Arc<AppContext>) {
+    for task in ScheduledTask::ALL {
+        let Some(interval) = task.interval(&ctx.config) else {
+            continue;
+        };
+
+        let ctx = ctx.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                run_task_if_due(&ctx, task, interval).await;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_lock_interface::{LockInstance, MockLockService};
+
+    use super::*;
+
+    #[test]
+    fn interval() {
+        let mut config = Config::from_env_no_version();
+        config.server.scheduler_sync_interval = 60;
+        config.server.scheduler_purge_interval = 0;
+
+        assert_eq!(
+            ScheduledTask::SynchronizePullRequests.interval(&config),
+            Some(StdDuration::from_secs(60))
+        );
+        assert_eq!(
+            ScheduledTask::PurgeClosedPullRequests.interval(&config),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn claim_due_task() -> Result<()> {
+        let mut lock_service = MockLockService::new();
+        lock_service
+            .expect_try_lock_resource()
+            .once()
+            .withf(|name| name == "scheduler:retry_automerges")
+            .return_once(|name| {
+                Ok(LockStatus::SuccessfullyLocked(LockInstance::new_dummy(
+                    name,
+                )))
+            });
+        lock_service
+            .expect_get_resource()
+            .once()
+            .withf(|name| name == "scheduler:retry_automerges:last-run")
+            .return_once(|_| Ok(None));
+        lock_service
+            .expect_set_resource()
+            .once()
+            .withf(|name, _, timeout| {
+                name == "scheduler:retry_automerges:last-run" && timeout.as_secs() == 300
+            })
+            .return_once(|_, _, _| Ok(()));
+
+        assert!(
+            claim_task_run(
+                &lock_service,
+                ScheduledTask::RetryAutomerges,
+                StdDuration::from_secs(300)
+            )
+            .await?
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn claim_already_run_task() -> Result<()> {
+        let mut lock_service = MockLockService::new();
+        lock_service
+            .expect_try_lock_resource()
+            .once()
+            .return_once(|name| {
+                Ok(LockStatus::SuccessfullyLocked(LockInstance::new_dummy(
+                    name,
+                )))
+            });
+        lock_service
+            .expect_get_resource()
+            .once()
+            .return_once(|_| Ok(Some("1".into())));
+        lock_service.expect_set_resource().never();
+
+        assert!(
+            !claim_task_run(
+                &lock_service,
+                ScheduledTask::RetryAutomerges,
+                StdDuration::from_secs(300)
+            )
+            .await?
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn claim_locked_task() -> Result<()> {
+        let mut lock_service = MockLockService::new();
+        lock_service
+            .expect_try_lock_resource()
+            .once()
+            .return_once(|_| Ok(LockStatus::AlreadyLocked));
+        lock_service.expect_get_resource().never();
+
+        assert!(
+            !claim_task_run(
+                &lock_service,
+                ScheduledTask::RetryAutomerges,
+                StdDuration::from_secs(300)
+            )
+            .await?
+        );
+
+        Ok(())
+    }
+}
@@ -20,13 +20,16 @@ use sentry_actix::Sentry;
 use tracing::info;
 
 use crate::{
+    admin::{stats::repository_stats, validator::admin_auth_validator, webhooks::replay_delivery},
     debug::configure_debug_handlers,
     external::{status::set_qa_status, validator::jwt_auth_validator},
     ghapi::MetricsApiService,
     health::health_check_route,
+    jobs::spawn_job_workers,
     metrics::build_metrics_handler,
     middlewares::VerifySignature,
     redis::MetricsRedisService,
+    scheduler::spawn_scheduler,
     webhook::configure_webhook_handlers,
     Result, ServerError,
 };
@@ -134,6 +137,21 @@ pub fn build_actix_app(
             .into()
         }));
 
+    if !context.config.server.admin_token.is_empty() {
+        app = app.service(
+            web::scope("/admin")
+                .wrap(HttpAuthentication::bearer(admin_auth_validator))
+                .route(
+                    "/webhook-deliveries/{delivery_id}/replay",
+                    web::post().to(replay_delivery),
+                )
+                .route(
+                    "/repositories/{owner}/{name}/stats",
+                    web::get().to(repository_stats),
+                ),
+        );
+    }
+
     if context.config.test_debug_mode {
         app = app.service(web::scope("/debug").configure(configure_debug_handlers));
     }
@@ -162,6 +180,10 @@ async fn run_bot_server_internal(ip_with_port: String, context: AppContext) -> R
     let context = Data::new(context);
     let cloned_context = context.clone();
 
+    spawn_job_workers(context.clone().into_inner()).await?;
+
+    spawn_scheduler(context.clone().into_inner());
+
     let mut server = HttpServer::new(move || build_actix_app(context.clone()));
 
     if let Some(workers) = cloned_context.config.server.workers_count {
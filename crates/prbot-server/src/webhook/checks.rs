@@ -2,32 +2,91 @@
 
 use std::sync::Arc;
 
-use actix_web::HttpResponse;
-use prbot_core::use_cases::checks::HandleCheckSuiteEventInterface;
-use prbot_ghapi_interface::types::GhCheckSuiteEvent;
+use prbot_core::use_cases::checks::{
+    HandleCheckRunEventInterface, HandleCheckSuiteEventInterface, HandleMergeGroupEventInterface,
+    HandleStatusEventInterface, HandleWorkflowRunEventInterface,
+};
+use prbot_ghapi_interface::types::{
+    GhCheckRunEvent, GhCheckSuiteEvent, GhMergeGroupEvent, GhStatusEvent, GhWorkflowRunEvent,
+};
 use shaku::HasComponent;
 
 use super::parse_event_type;
 use crate::{event_type::EventType, server::AppContext, Result, ServerError};
 
+pub(crate) fn parse_check_run_event(body: &str) -> Result<GhCheckRunEvent> {
+    parse_event_type(EventType::CheckRun, body)
+}
+
 pub(crate) fn parse_check_suite_event(body: &str) -> Result<GhCheckSuiteEvent> {
     parse_event_type(EventType::CheckSuite, body)
 }
 
+pub(crate) fn parse_merge_group_event(body: &str) -> Result<GhMergeGroupEvent> {
+    parse_event_type(EventType::MergeGroup, body)
+}
+
+pub(crate) fn parse_status_event(body: &str) -> Result<GhStatusEvent> {
+    parse_event_type(EventType::Status, body)
+}
+
+pub(crate) fn parse_workflow_run_event(body: &str) -> Result<GhWorkflowRunEvent> {
+    parse_event_type(EventType::WorkflowRun, body)
+}
+
+pub(crate) async fn check_run_event(ctx: Arc<AppContext>, event: GhCheckRunEvent) -> Result<()> {
+    let ctx = ctx.as_core_context();
+    let handle_check_run_event: &dyn HandleCheckRunEventInterface = ctx.core_module.resolve_ref();
+    handle_check_run_event
+        .run(&ctx, event)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })
+}
+
 pub(crate) async fn check_suite_event(
     ctx: Arc<AppContext>,
     event: GhCheckSuiteEvent,
-) -> Result<HttpResponse> {
-    tokio::spawn(async move {
-        let ctx = ctx.as_core_context();
-        let handle_check_suite_event: &dyn HandleCheckSuiteEventInterface =
-            ctx.core_module.resolve_ref();
-        handle_check_suite_event
-            .run(&ctx, event)
-            .await
-            .map_err(|e| ServerError::DomainError { source: e })
-            .unwrap()
-    });
-
-    Ok(HttpResponse::Accepted().body("Check suite."))
+) -> Result<()> {
+    let ctx = ctx.as_core_context();
+    let handle_check_suite_event: &dyn HandleCheckSuiteEventInterface =
+        ctx.core_module.resolve_ref();
+    handle_check_suite_event
+        .run(&ctx, event)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })
+}
+
+pub(crate) async fn merge_group_event(
+    ctx: Arc<AppContext>,
+    event: GhMergeGroupEvent,
+) -> Result<()> {
+    let ctx = ctx.as_core_context();
+    let handle_merge_group_event: &dyn HandleMergeGroupEventInterface =
+        ctx.core_module.resolve_ref();
+    handle_merge_group_event
+        .run(&ctx, event)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })
+}
+
+pub(crate) async fn status_event(ctx: Arc<AppContext>, event: GhStatusEvent) -> Result<()> {
+    let ctx = ctx.as_core_context();
+    let handle_status_event: &dyn HandleStatusEventInterface = ctx.core_module.resolve_ref();
+    handle_status_event
+        .run(&ctx, event)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })
+}
+
+pub(crate) async fn workflow_run_event(
+    ctx: Arc<AppContext>,
+    event: GhWorkflowRunEvent,
+) -> Result<()> {
+    let ctx = ctx.as_core_context();
+    let handle_workflow_run_event: &dyn HandleWorkflowRunEventInterface =
+        ctx.core_module.resolve_ref();
+    handle_workflow_run_event
+        .run(&ctx, event)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })
 }
@@ -0,0 +1,177 @@
+//! Webhook deliveries.
+
+use std::{sync::Arc, time::Duration as StdDuration};
+
+use actix_web::HttpRequest;
+use prbot_database_interface::DbService;
+use prbot_lock_interface::{LockInstance, LockService, LockStatus};
+use prbot_models::{WebhookDelivery, WebhookDeliveryOutcome};
+use time::{Duration, OffsetDateTime};
+
+use super::parse_event;
+use crate::{event_type::EventType, jobs::execute_job, server::AppContext, Result, ServerError};
+
+fn delivery_marker_key(delivery_id: &str) -> String {
+    format!("webhook-delivery:{delivery_id}")
+}
+
+/// Claim a delivery before handling it.
+///
+/// Returns `None` if the delivery was already handled, or is being handled
+/// by another replica.
+pub(crate) async fn claim_delivery<'a>(
+    lock_service: &'a dyn LockService,
+    delivery_id: &str,
+) -> Result<Option<LockInstance<'a>>> {
+    let key = delivery_marker_key(delivery_id);
+    let lock = match lock_service
+        .try_lock_resource(&format!("{key}:lock"))
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?
+    {
+        LockStatus::SuccessfullyLocked(lock) => lock,
+        LockStatus::AlreadyLocked => return Ok(None),
+    };
+
+    match lock_service.has_resource(&key).await {
+        Ok(false) => Ok(Some(lock)),
+        Ok(true) => {
+            lock.release()
+                .await
+                .map_err(|e| ServerError::DomainError { source: e.into() })?;
+            Ok(None)
+        }
+        Err(e) => {
+            lock.release()
+                .await
+                .map_err(|e| ServerError::DomainError { source: e.into() })?;
+            Err(ServerError::DomainError { source: e.into() })
+        }
+    }
+}
+
+/// Release a delivery claim.
+///
+/// The delivery is only marked as handled if it was, so a failed delivery can be sent again.
+pub(crate) async fn release_delivery_claim(
+    lock_service: &dyn LockService,
+    lock: LockInstance<'_>,
+    delivery_id: &str,
+    handled: bool,
+    ttl: u64,
+) -> Result<()> {
+    let result = if handled {
+        lock_service
+            .set_resource(
+                &delivery_marker_key(delivery_id),
+                "1",
+                StdDuration::from_secs(ttl),
+            )
+            .await
+    } else {
+        Ok(())
+    };
+
+    lock.release()
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?;
+    result.map_err(|e| ServerError::DomainError { source: e.into() })
+}
+
+/// Store an incoming webhook delivery.
+///
+/// A delivery already known (e.g. a redelivery from GitHub) is kept as-is.
+pub(crate) async fn record_webhook_delivery(
+    ctx: &AppContext,
+    delivery_id: &str,
+    event_type: EventType,
+    req: &HttpRequest,
+    body: &str,
+) -> Result<()> {
+    if ctx
+        .db_service
+        .webhook_deliveries_get(delivery_id)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let headers = req
+        .headers()
+        .iter()
+        .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.to_string(), v.to_string())))
+        .collect();
+
+    ctx.db_service
+        .webhook_deliveries_create(WebhookDelivery {
+            delivery_id: delivery_id.into(),
+            event_type: event_type.to_str().into(),
+            headers,
+            body: body.into(),
+            ..Default::default()
+        })
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?;
+
+    Ok(())
+}
+
+/// Store the processing outcome of a webhook delivery.
+pub(crate) async fn set_webhook_delivery_outcome(
+    ctx: &AppContext,
+    delivery_id: &str,
+    error: Option<&ServerError>,
+) -> Result<WebhookDelivery> {
+    let outcome = if error.is_some() {
+        WebhookDeliveryOutcome::Failure
+    } else {
+        WebhookDeliveryOutcome::Success
+    };
+
+    ctx.db_service
+        .webhook_deliveries_set_outcome(delivery_id, outcome, error.map(ToString::to_string))
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })
+}
+
+/// Replay a stored webhook delivery, processing it directly instead of enqueuing a job.
+pub async fn replay_webhook_delivery(
+    ctx: Arc<AppContext>,
+    delivery_id: &str,
+) -> Result<WebhookDelivery> {
+    let delivery = ctx
+        .db_service
+        .webhook_deliveries_get_expect(delivery_id)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })?;
+
+    let event_type = EventType::try_from(&delivery.event_type[..]).map_err(|_| {
+        ServerError::UnsupportedEventType {
+            event_type: delivery.event_type.clone(),
+        }
+    })?;
+
+    let kind = parse_event(event_type, &delivery.body).map(|(_, kind)| kind);
+    let result = match kind {
+        Ok(Some(kind)) => execute_job(ctx.clone(), kind, &delivery.body).await,
+        Ok(None) => Ok(()),
+        Err(e) => Err(e),
+    };
+
+    set_webhook_delivery_outcome(&ctx, delivery_id, result.as_ref().err()).await
+}
+
+/// Purge webhook deliveries older than the retention period.
+pub async fn purge_webhook_deliveries(
+    db_service: &dyn DbService,
+    retention_days: u64,
+) -> Result<u64> {
+    let received_before = OffsetDateTime::now_utc() - Duration::days(retention_days as i64);
+
+    db_service
+        .webhook_deliveries_purge(received_before)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e.into() })
+}
@@ -2,13 +2,12 @@
 
 use std::sync::Arc;
 
-use actix_web::HttpResponse;
 use prbot_core::use_cases::comments::HandleIssueCommentEventInterface;
 use prbot_ghapi_interface::types::GhIssueCommentEvent;
 use shaku::HasComponent;
 
 use super::parse_event_type;
-use crate::{event_type::EventType, server::AppContext, Result};
+use crate::{event_type::EventType, server::AppContext, Result, ServerError};
 
 pub(crate) fn parse_issue_comment_event(body: &str) -> Result<GhIssueCommentEvent> {
     parse_event_type(EventType::IssueComment, body)
@@ -17,13 +16,12 @@ pub(crate) fn parse_issue_comment_event(body: &str) -> Result<GhIssueCommentEven
 pub(crate) async fn issue_comment_event(
     ctx: Arc<AppContext>,
     event: GhIssueCommentEvent,
-) -> Result<HttpResponse> {
-    tokio::spawn(async move {
-        let ctx = ctx.as_core_context();
-        let handle_issue_comment_event: &dyn HandleIssueCommentEventInterface =
-            ctx.core_module.resolve_ref();
-        handle_issue_comment_event.run(&ctx, event).await.unwrap();
-    });
-
-    Ok(HttpResponse::Accepted().body("Issue comment."))
+) -> Result<()> {
+    let ctx = ctx.as_core_context();
+    let handle_issue_comment_event: &dyn HandleIssueCommentEventInterface =
+        ctx.core_module.resolve_ref();
+    handle_issue_comment_event
+        .run(&ctx, event)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })
 }
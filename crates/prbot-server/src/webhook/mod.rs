@@ -1,47 +1,117 @@
 //! Webhook handlers.
 
-mod checks;
-mod issues;
+pub(crate) mod checks;
+pub(crate) mod deliveries;
+pub(crate) mod issues;
 mod ping;
-mod pulls;
-mod reviews;
+pub(crate) mod pulls;
+pub(crate) mod pushes;
+pub(crate) mod reviews;
 
 #[cfg(test)]
 mod tests;
 
-use std::{convert::TryFrom, sync::Arc};
+use std::convert::TryFrom;
 
 use actix_web::{web, HttpRequest, HttpResponse, Result as ActixResult};
+use prbot_models::JobKind;
 use serde::Deserialize;
 
+pub use self::deliveries::{purge_webhook_deliveries, replay_webhook_delivery};
 use self::{
-    checks::parse_check_suite_event, issues::parse_issue_comment_event, ping::parse_ping_event,
-    pulls::parse_pull_request_event, reviews::parse_review_event,
+    checks::{
+        parse_check_run_event, parse_check_suite_event, parse_merge_group_event,
+        parse_status_event, parse_workflow_run_event,
+    },
+    deliveries::{
+        claim_delivery, record_webhook_delivery, release_delivery_claim,
+        set_webhook_delivery_outcome,
+    },
+    issues::parse_issue_comment_event,
+    ping::parse_ping_event,
+    pulls::parse_pull_request_event,
+    pushes::parse_push_event,
+    reviews::parse_review_event,
 };
 use crate::{
-    constants::GITHUB_EVENT_HEADER, event_type::EventType, server::AppContext,
-    utils::convert_payload_to_string, Result, ServerError,
+    constants::{GITHUB_DELIVERY_HEADER, GITHUB_EVENT_HEADER},
+    event_type::EventType,
+    jobs::enqueue_job,
+    metrics::WEBHOOK_DUPLICATE_DELIVERIES,
+    server::AppContext,
+    utils::convert_payload_to_string,
+    Result, ServerError,
 };
 
+/// Validate an event payload, and get the job kind to process it, if any.
 #[tracing::instrument(skip_all, fields(event_type))]
-async fn parse_event(
-    ctx: Arc<AppContext>,
-    event_type: EventType,
-    body: &str,
-) -> Result<HttpResponse> {
-    match event_type {
+fn parse_event(event_type: EventType, body: &str) -> Result<(HttpResponse, Option<JobKind>)> {
+    Ok(match event_type {
+        EventType::CheckRun => {
+            parse_check_run_event(body)?;
+            (
+                HttpResponse::Accepted().body("Check run."),
+                Some(JobKind::HandleCheckRunEvent),
+            )
+        }
         EventType::CheckSuite => {
-            checks::check_suite_event(ctx, parse_check_suite_event(body)?).await
+            parse_check_suite_event(body)?;
+            (
+                HttpResponse::Accepted().body("Check suite."),
+                Some(JobKind::HandleCheckSuiteEvent),
+            )
         }
         EventType::IssueComment => {
-            issues::issue_comment_event(ctx, parse_issue_comment_event(body)?).await
+            parse_issue_comment_event(body)?;
+            (
+                HttpResponse::Accepted().body("Issue comment."),
+                Some(JobKind::HandleIssueCommentEvent),
+            )
+        }
+        EventType::MergeGroup => {
+            parse_merge_group_event(body)?;
+            (
+                HttpResponse::Accepted().body("Merge group."),
+                Some(JobKind::HandleMergeGroupEvent),
+            )
         }
-        EventType::Ping => Ok(ping::ping_event(parse_ping_event(body)?)),
+        EventType::Ping => (ping::ping_event(parse_ping_event(body)?), None),
         EventType::PullRequest => {
-            pulls::pull_request_event(ctx, parse_pull_request_event(body)?).await
+            parse_pull_request_event(body)?;
+            (
+                HttpResponse::Accepted().body("Pull request."),
+                Some(JobKind::ProcessPullRequestEvent),
+            )
         }
-        EventType::PullRequestReview => reviews::review_event(ctx, parse_review_event(body)?).await,
-    }
+        EventType::PullRequestReview => {
+            parse_review_event(body)?;
+            (
+                HttpResponse::Accepted().body("Pull request review."),
+                Some(JobKind::HandleReviewEvent),
+            )
+        }
+        EventType::Push => {
+            parse_push_event(body)?;
+            (
+                HttpResponse::Accepted().body("Push."),
+                Some(JobKind::HandlePushEvent),
+            )
+        }
+        EventType::Status => {
+            parse_status_event(body)?;
+            (
+                HttpResponse::Accepted().body("Commit status."),
+                Some(JobKind::HandleStatusEvent),
+            )
+        }
+        EventType::WorkflowRun => {
+            parse_workflow_run_event(body)?;
+            (
+                HttpResponse::Accepted().body("Workflow run."),
+                Some(JobKind::HandleWorkflowRunEvent),
+            )
+        }
+    })
 }
 
 fn parse_event_type<'de, T>(event_type: EventType, body: &'de str) -> Result<T>
@@ -61,6 +131,47 @@ fn extract_event_from_request(req: &HttpRequest) -> Option<EventType> {
         .and_then(|x| EventType::try_from(x).ok())
 }
 
+fn extract_delivery_id_from_request(req: &HttpRequest) -> Option<String> {
+    req.headers()
+        .get(GITHUB_DELIVERY_HEADER)
+        .and_then(|x| x.to_str().ok())
+        .map(ToOwned::to_owned)
+}
+
+/// Record a delivery, and enqueue its processing job.
+async fn handle_delivery(
+    ctx: &AppContext,
+    delivery_id: Option<&str>,
+    event_type: EventType,
+    req: &HttpRequest,
+    body: String,
+) -> Result<HttpResponse> {
+    if let Some(delivery_id) = delivery_id {
+        record_webhook_delivery(ctx, delivery_id, event_type, req, &body).await?;
+    }
+
+    match parse_event(event_type, &body) {
+        Ok((response, Some(kind))) => {
+            enqueue_job(ctx, kind, body, delivery_id.map(Into::into)).await?;
+            Ok(response)
+        }
+        Ok((response, None)) => {
+            if let Some(delivery_id) = delivery_id {
+                set_webhook_delivery_outcome(ctx, delivery_id, None).await?;
+            }
+
+            Ok(response)
+        }
+        Err(e) => {
+            if let Some(delivery_id) = delivery_id {
+                set_webhook_delivery_outcome(ctx, delivery_id, Some(&e)).await?;
+            }
+
+            Err(e)
+        }
+    }
+}
+
 #[tracing::instrument(skip_all)]
 pub(crate) async fn event_handler(
     req: HttpRequest,
@@ -70,9 +181,36 @@ pub(crate) async fn event_handler(
     // Route event depending on header
     if let Some(event_type) = extract_event_from_request(&req) {
         if let Ok(body) = convert_payload_to_string(&mut payload).await {
-            parse_event(ctx.into_inner(), event_type, &body)
-                .await
-                .map_err(Into::into)
+            let ctx = ctx.into_inner();
+            let delivery_id = extract_delivery_id_from_request(&req);
+            let claim = match &delivery_id {
+                Some(delivery_id) => {
+                    match claim_delivery(ctx.lock_service.as_ref(), delivery_id).await? {
+                        Some(lock) => Some(lock),
+                        None => {
+                            WEBHOOK_DUPLICATE_DELIVERIES.inc();
+                            return Ok(HttpResponse::Ok().body("Already processed."));
+                        }
+                    }
+                }
+                None => None,
+            };
+
+            let result =
+                handle_delivery(&ctx, delivery_id.as_deref(), event_type, &req, body).await;
+
+            if let (Some(delivery_id), Some(lock)) = (&delivery_id, claim) {
+                release_delivery_claim(
+                    ctx.lock_service.as_ref(),
+                    lock,
+                    delivery_id,
+                    result.is_ok(),
+                    ctx.config.server.webhook_deduplication_ttl,
+                )
+                .await?;
+            }
+
+            Ok(result?)
         } else {
             let event_type: &str = event_type.into();
             Ok(HttpResponse::BadRequest().json(serde_json::json!({
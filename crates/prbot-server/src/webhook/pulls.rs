@@ -2,13 +2,20 @@
 
 use std::sync::Arc;
 
-use actix_web::HttpResponse;
-use prbot_core::use_cases::pulls::ProcessPullRequestEventInterface;
-use prbot_ghapi_interface::types::GhPullRequestEvent;
+use prbot_core::use_cases::{
+    pulls::ProcessPullRequestEventInterface,
+    stats::{get_pull_request_timeline, has_pull_request_event},
+};
+use prbot_database_interface::DbService;
+use prbot_ghapi_interface::types::{GhPullRequestAction, GhPullRequestEvent};
+use prbot_models::{PullRequestEventKind, PullRequestHandle};
 use shaku::HasComponent;
 
 use super::parse_event_type;
-use crate::{event_type::EventType, server::AppContext, Result, ServerError};
+use crate::{
+    event_type::EventType, metrics::PULL_REQUEST_STEP_DURATION, server::AppContext, Result,
+    ServerError,
+};
 
 pub(crate) fn parse_pull_request_event(body: &str) -> Result<GhPullRequestEvent> {
     parse_event_type(EventType::PullRequest, body)
@@ -23,18 +30,55 @@ pub(crate) fn parse_pull_request_event(body: &str) -> Result<GhPullRequestEvent>
 pub(crate) async fn pull_request_event(
     ctx: Arc<AppContext>,
     event: GhPullRequestEvent,
-) -> Result<HttpResponse> {
-    tokio::spawn(async move {
-        let ctx = ctx.as_core_context();
-
-        let handle_pull_request_event: &dyn ProcessPullRequestEventInterface =
-            ctx.core_module.resolve_ref();
-        handle_pull_request_event
-            .run(&ctx, event)
-            .await
-            .map_err(|e| ServerError::DomainError { source: e })
-            .unwrap();
-    });
-
-    Ok(HttpResponse::Accepted().body("Pull request."))
+) -> Result<()> {
+    let core_ctx = ctx.as_core_context();
+    let merged =
+        event.action == GhPullRequestAction::Closed && event.pull_request.merged_at.is_some();
+    let pr_handle: PullRequestHandle = (
+        event.repository.owner.login.as_str(),
+        event.repository.name.as_str(),
+        event.pull_request.number,
+    )
+        .into();
+
+    // Retried or replayed events must not observe durations twice
+    let newly_merged = merged
+        && !has_pull_request_event(
+            ctx.db_service.as_ref(),
+            &pr_handle,
+            PullRequestEventKind::Merged,
+        )
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })?;
+
+    let handle_pull_request_event: &dyn ProcessPullRequestEventInterface =
+        core_ctx.core_module.resolve_ref();
+    handle_pull_request_event
+        .run(&core_ctx, event)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })?;
+
+    if newly_merged {
+        observe_step_durations(ctx.db_service.as_ref(), &pr_handle).await?;
+    }
+
+    Ok(())
+}
+
+/// Observe the time spent in each step by a merged pull request.
+async fn observe_step_durations(
+    db_service: &dyn DbService,
+    pr_handle: &PullRequestHandle,
+) -> Result<()> {
+    let timeline = get_pull_request_timeline(db_service, pr_handle)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })?;
+
+    for (step, duration) in timeline.step_durations() {
+        PULL_REQUEST_STEP_DURATION
+            .with_label_values(&[step.to_str()])
+            .observe(duration.as_seconds_f64());
+    }
+
+    Ok(())
 }
@@ -0,0 +1,23 @@
+//! Push webhook handlers.
+
+use std::sync::Arc;
+
+use prbot_core::use_cases::repositories::HandlePushEventInterface;
+use prbot_ghapi_interface::types::GhPushEvent;
+use shaku::HasComponent;
+
+use super::parse_event_type;
+use crate::{event_type::EventType, server::AppContext, Result, ServerError};
+
+pub(crate) fn parse_push_event(body: &str) -> Result<GhPushEvent> {
+    parse_event_type(EventType::Push, body)
+}
+
+pub(crate) async fn push_event(ctx: Arc<AppContext>, event: GhPushEvent) -> Result<()> {
+    let ctx = ctx.as_core_context();
+    let handle_push_event: &dyn HandlePushEventInterface = ctx.core_module.resolve_ref();
+    handle_push_event
+        .run(&ctx, event)
+        .await
+        .map_err(|e| ServerError::DomainError { source: e })
+}
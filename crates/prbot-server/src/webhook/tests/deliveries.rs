@@ -0,0 +1,105 @@
+//! Webhook deliveries tests
+
+use prbot_lock_interface::{LockInstance, LockStatus, MockLockService};
+
+use crate::{
+    webhook::deliveries::{claim_delivery, release_delivery_claim},
+    Result as ServerResult,
+};
+
+fn expect_delivery_lock(lock_service: &mut MockLockService, available: bool) {
+    lock_service
+        .expect_try_lock_resource()
+        .once()
+        .withf(|name| name == "webhook-delivery:abcd:lock")
+        .returning(move |name| {
+            Ok(if available {
+                LockStatus::SuccessfullyLocked(LockInstance::new_dummy(name))
+            } else {
+                LockStatus::AlreadyLocked
+            })
+        });
+}
+
+#[tokio::test]
+async fn test_claim_delivery_new() -> ServerResult<()> {
+    let mut lock_service = MockLockService::new();
+    expect_delivery_lock(&mut lock_service, true);
+    lock_service
+        .expect_has_resource()
+        .once()
+        .withf(|name| name == "webhook-delivery:abcd")
+        .return_once(|_| Ok(false));
+    lock_service.expect_set_resource().never();
+
+    assert!(claim_delivery(&lock_service, "abcd").await?.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_delivery_known() -> ServerResult<()> {
+    let mut lock_service = MockLockService::new();
+    expect_delivery_lock(&mut lock_service, true);
+    lock_service
+        .expect_has_resource()
+        .once()
+        .withf(|name| name == "webhook-delivery:abcd")
+        .return_once(|_| Ok(true));
+
+    assert!(claim_delivery(&lock_service, "abcd").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_delivery_in_progress() -> ServerResult<()> {
+    let mut lock_service = MockLockService::new();
+    expect_delivery_lock(&mut lock_service, false);
+    lock_service.expect_has_resource().never();
+
+    assert!(claim_delivery(&lock_service, "abcd").await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_release_delivery_claim_handled() -> ServerResult<()> {
+    let mut lock_service = MockLockService::new();
+    lock_service
+        .expect_set_resource()
+        .once()
+        .withf(|name, value, timeout| {
+            name == "webhook-delivery:abcd" && value == "1" && timeout.as_secs() == 60
+        })
+        .return_once(|_, _, _| Ok(()));
+
+    release_delivery_claim(
+        &lock_service,
+        LockInstance::new_dummy("webhook-delivery:abcd:lock"),
+        "abcd",
+        true,
+        60,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_release_delivery_claim_failed() -> ServerResult<()> {
+    let mut lock_service = MockLockService::new();
+    // A failed delivery can be sent again
+    lock_service.expect_set_resource().never();
+
+    release_delivery_claim(
+        &lock_service,
+        LockInstance::new_dummy("webhook-delivery:abcd:lock"),
+        "abcd",
+        false,
+        60,
+    )
+    .await?;
+
+    Ok(())
+}
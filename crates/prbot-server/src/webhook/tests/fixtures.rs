@@ -1,9 +1,15 @@
 //! Fixtures
 
 pub const PING_EVENT_DATA: &str = include_str!("fixtures/ping_event.json");
+pub const CHECK_RUN_COMPLETED_DATA: &str = include_str!("fixtures/check_run_completed.json");
 pub const CHECK_SUITE_COMPLETED_DATA: &str = include_str!("fixtures/check_suite_completed.json");
 pub const ISSUE_COMMENT_CREATED_DATA: &str = include_str!("fixtures/issue_comment_created.json");
+pub const MERGE_GROUP_CHECKS_REQUESTED_DATA: &str =
+    include_str!("fixtures/merge_group_checks_requested.json");
 pub const PULL_REQUEST_OPENED_DATA: &str = include_str!("fixtures/pull_request_opened.json");
 pub const PULL_REQUEST_LABELED_DATA: &str = include_str!("fixtures/pull_request_labeled.json");
 pub const PULL_REQUEST_REVIEW_SUBMITTED_DATA: &str =
     include_str!("fixtures/pull_request_review_submitted.json");
+pub const PUSH_DEFAULT_BRANCH_DATA: &str = include_str!("fixtures/push_default_branch.json");
+pub const STATUS_FAILURE_DATA: &str = include_str!("fixtures/status_failure.json");
+pub const WORKFLOW_RUN_COMPLETED_DATA: &str = include_str!("fixtures/workflow_run_completed.json");
@@ -1,4 +1,5 @@
 //! Webhook tests
 
+mod deliveries;
 mod fixtures;
 mod webhook;
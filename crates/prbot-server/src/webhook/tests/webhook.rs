@@ -1,11 +1,12 @@
 //! Webhook handler tests
 
 use prbot_ghapi_interface::types::{
-    GhApplication, GhBranch, GhBranchShort, GhCheckConclusion, GhCheckStatus, GhCheckSuite,
-    GhCheckSuiteAction, GhCheckSuiteEvent, GhIssue, GhIssueComment, GhIssueCommentAction,
-    GhIssueCommentEvent, GhIssueState, GhLabel, GhPingEvent, GhPullRequest, GhPullRequestAction,
-    GhPullRequestEvent, GhPullRequestShort, GhPullRequestState, GhRepository, GhReview,
-    GhReviewAction, GhReviewEvent, GhReviewState, GhUser,
+    GhApplication, GhBranch, GhBranchShort, GhCheckConclusion, GhCheckRunAction, GhCheckStatus,
+    GhCheckSuite, GhCheckSuiteAction, GhCheckSuiteEvent, GhCommitStatusState, GhIssue,
+    GhIssueComment, GhIssueCommentAction, GhIssueCommentEvent, GhIssueState, GhLabel,
+    GhMergeGroupAction, GhPingEvent, GhPullRequest, GhPullRequestAction, GhPullRequestEvent,
+    GhPullRequestShort, GhPullRequestState, GhRepository, GhReview, GhReviewAction, GhReviewEvent,
+    GhReviewState, GhUser, GhWorkflowRunAction,
 };
 use pretty_assertions::assert_eq;
 use time::{format_description::well_known::Rfc3339, OffsetDateTime};
@@ -13,8 +14,15 @@ use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 use super::fixtures;
 use crate::{
     webhook::{
-        checks::parse_check_suite_event, issues::parse_issue_comment_event, ping::parse_ping_event,
-        pulls::parse_pull_request_event, reviews::parse_review_event,
+        checks::{
+            parse_check_run_event, parse_check_suite_event, parse_merge_group_event,
+            parse_status_event, parse_workflow_run_event,
+        },
+        issues::parse_issue_comment_event,
+        ping::parse_ping_event,
+        pulls::parse_pull_request_event,
+        pushes::parse_push_event,
+        reviews::parse_review_event,
     },
     Result as ServerResult,
 };
@@ -27,6 +35,7 @@ fn test_ping_event_parsing() -> ServerResult<()> {
             zen: "Favor focus over features.".to_string(),
             hook_id: 12_345_678,
             repository: Some(GhRepository {
+                default_branch: "main".to_string(),
                 name: "test-repo".to_string(),
                 full_name: "Example/test-repo".to_string(),
                 owner: GhUser {
@@ -78,6 +87,7 @@ fn test_check_suite_completed_event_parsing() -> ServerResult<()> {
                     .expect("bad date")
             },
             repository: GhRepository {
+                default_branch: "main".to_string(),
                 name: "test-repo".to_string(),
                 full_name: "Example/test-repo".to_string(),
                 owner: GhUser {
@@ -130,6 +140,7 @@ fn test_issue_comment_created_event_parsing() -> ServerResult<()> {
                 body: "Un autre commentaire de test.".to_string()
             },
             repository: GhRepository {
+                default_branch: "main".to_string(),
                 name: "prbot".to_string(),
                 full_name: "Example/prbot".to_string(),
                 owner: GhUser {
@@ -156,6 +167,7 @@ fn test_pull_request_opened_event_parsing() -> ServerResult<()> {
             action: GhPullRequestAction::Opened,
             number: 1214,
             pull_request: GhPullRequest {
+                node_id: "123456789123456789123456789".to_string(),
                 number: 1214,
                 state: GhPullRequestState::Open,
                 locked: false,
@@ -192,10 +204,14 @@ fn test_pull_request_opened_event_parsing() -> ServerResult<()> {
                 merged: Some(false),
                 mergeable: None,
                 rebaseable: None,
+                merge_commit_sha: None,
+                additions: 28,
+                deletions: 13,
             },
             label: None,
             requested_reviewer: None,
             repository: GhRepository {
+                default_branch: "stable".to_string(),
                 name: "test-repo".to_string(),
                 full_name: "Example/test-repo".to_string(),
                 owner: GhUser {
@@ -222,6 +238,7 @@ fn test_pull_request_labeled_event_parsing() -> ServerResult<()> {
             action: GhPullRequestAction::Labeled,
             number: 1214,
             pull_request: GhPullRequest {
+                node_id: "123456789123456789123456789".to_string(),
                 number: 1214,
                 state: GhPullRequestState::Open,
                 locked: false,
@@ -271,6 +288,9 @@ fn test_pull_request_labeled_event_parsing() -> ServerResult<()> {
                 merged: Some(false),
                 mergeable: Some(true),
                 rebaseable: Some(true),
+                merge_commit_sha: Some("123456789123456789123456789".to_string()),
+                additions: 28,
+                deletions: 13,
             },
             label: Some(GhLabel {
                 name: "step/awaiting-changes".to_string(),
@@ -279,6 +299,7 @@ fn test_pull_request_labeled_event_parsing() -> ServerResult<()> {
             }),
             requested_reviewer: None,
             repository: GhRepository {
+                default_branch: "stable".to_string(),
                 name: "test-repo".to_string(),
                 full_name: "Example/test-repo".to_string(),
                 owner: GhUser {
@@ -310,9 +331,11 @@ fn test_review_submitted_event_parsing() -> ServerResult<()> {
                 submitted_at: Some(
                     OffsetDateTime::parse("2020-11-13T17:25:46Z", &Rfc3339).expect("bad date")
                 ),
-                state: GhReviewState::ChangesRequested
+                state: GhReviewState::ChangesRequested,
+                commit_id: Some("123456789123456789123456789".to_string())
             },
             pull_request: GhPullRequest {
+                node_id: "123456789123456789123456789".to_string(),
                 number: 1206,
                 state: GhPullRequestState::Open,
                 locked: false,
@@ -369,9 +392,13 @@ fn test_review_submitted_event_parsing() -> ServerResult<()> {
                 },
                 merged: None,
                 mergeable: None,
-                rebaseable: None
+                rebaseable: None,
+                merge_commit_sha: Some("123456789123456789123456789".to_string()),
+                additions: 0,
+                deletions: 0,
             },
             repository: GhRepository {
+                default_branch: "stable".to_string(),
                 name: "test-repo".to_string(),
                 full_name: "Example/test-repo".to_string(),
                 owner: GhUser {
@@ -389,3 +416,83 @@ fn test_review_submitted_event_parsing() -> ServerResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_check_run_completed_event_parsing() -> ServerResult<()> {
+    let event = parse_check_run_event(fixtures::CHECK_RUN_COMPLETED_DATA)?;
+
+    assert_eq!(event.action, GhCheckRunAction::Completed);
+    assert_eq!(event.check_run.name, "lint");
+    assert_eq!(event.check_run.head_sha, "12345678123456781234567812345678");
+    assert_eq!(event.check_run.status, GhCheckStatus::Completed);
+    assert_eq!(event.check_run.conclusion, Some(GhCheckConclusion::Failure));
+    assert_eq!(event.check_run.app.slug, "github-actions");
+    assert_eq!(event.check_run.pull_requests[0].number, 1214);
+    assert_eq!(
+        event.check_run.completed_at,
+        Some(OffsetDateTime::parse("2020-11-13T17:38:12Z", &Rfc3339).expect("bad date"))
+    );
+    assert_eq!(event.repository.full_name, "Example/test-repo");
+
+    Ok(())
+}
+
+#[test]
+fn test_workflow_run_completed_event_parsing() -> ServerResult<()> {
+    let event = parse_workflow_run_event(fixtures::WORKFLOW_RUN_COMPLETED_DATA)?;
+
+    assert_eq!(event.action, GhWorkflowRunAction::Completed);
+    assert_eq!(event.workflow_run.name, "CI");
+    assert_eq!(event.workflow_run.head_branch, "head-branch");
+    assert_eq!(event.workflow_run.status, GhCheckStatus::Completed);
+    assert_eq!(
+        event.workflow_run.conclusion,
+        Some(GhCheckConclusion::Failure)
+    );
+    assert_eq!(event.workflow_run.pull_requests[0].number, 1214);
+    assert_eq!(event.repository.full_name, "Example/test-repo");
+
+    Ok(())
+}
+
+#[test]
+fn test_status_failure_event_parsing() -> ServerResult<()> {
+    let event = parse_status_event(fixtures::STATUS_FAILURE_DATA)?;
+
+    assert_eq!(event.sha, "6dcb09b5b57875f334f61aebed695e2e4193db5e");
+    assert_eq!(event.context, "ci/jenkins");
+    assert_eq!(event.state, GhCommitStatusState::Failure);
+    assert_eq!(event.description, Some("Build failed".into()));
+    assert_eq!(event.repository.full_name, "Example/test-repo");
+
+    Ok(())
+}
+
+#[test]
+fn test_merge_group_checks_requested_event_parsing() -> ServerResult<()> {
+    let event = parse_merge_group_event(fixtures::MERGE_GROUP_CHECKS_REQUESTED_DATA)?;
+
+    assert_eq!(event.action, GhMergeGroupAction::ChecksRequested);
+    assert_eq!(
+        event.merge_group.head_sha,
+        "ec26c3e57ca3a959ca5aad62de7213c562f8c821"
+    );
+    assert_eq!(event.merge_group.base_ref, "refs/heads/main");
+    assert_eq!(event.merge_group.pull_request_number(), Some(1214));
+    assert_eq!(event.repository.full_name, "Example/test-repo");
+
+    Ok(())
+}
+
+#[test]
+fn test_push_event_parsing() -> ServerResult<()> {
+    let event = parse_push_event(fixtures::PUSH_DEFAULT_BRANCH_DATA)?;
+
+    assert_eq!(event.reference, "refs/heads/main");
+    assert_eq!(event.after, "ec26c3e57ca3a959ca5aad62de7213c562f8c821");
+    assert!(!event.deleted);
+    assert_eq!(event.repository.default_branch, "main");
+    assert!(event.is_default_branch_update());
+
+    Ok(())
+}
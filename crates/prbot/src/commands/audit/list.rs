@@ -0,0 +1,136 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_models::{parse_since, RepositoryPath};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{
+    commands::{Command, CommandContext},
+    Result,
+};
+
+/// List audit entries, oldest first
+#[derive(Parser)]
+pub(crate) struct AuditListCommand {
+    /// Only list entries on a repository (e.g. `MyOrganization/my-project`)
+    #[arg(long)]
+    repo: Option<RepositoryPath>,
+    /// Only list entries made by an actor
+    #[arg(long)]
+    actor: Option<String>,
+    /// Only list entries since a duration (`30d`, `2w`), a date (`2026-10-01`) or a RFC 3339 datetime
+    #[arg(long)]
+    since: Option<String>,
+}
+
+#[async_trait]
+impl Command for AuditListCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let since = self
+            .since
+            .as_deref()
+            .map(|value| parse_since(value, OffsetDateTime::now_utc()))
+            .transpose()
+            .map_err(|e| anyhow!(e))?;
+
+        let repository_path = self.repo.map(|path| path.full_name());
+        let entries = ctx
+            .db_service
+            .audit_entries_list(repository_path.as_deref(), self.actor.as_deref(), since)
+            .await?;
+
+        let mut writer = ctx.writer.write().await;
+        if entries.is_empty() {
+            writeln!(writer, "No audit entry found.")?;
+        } else {
+            for entry in entries {
+                write!(
+                    writer,
+                    "- {} [{}] {} by '{}' on '{}'",
+                    entry.created_at.format(&Rfc3339)?,
+                    entry.source,
+                    entry.action,
+                    entry.actor,
+                    entry.target
+                )?;
+
+                match (entry.old_value, entry.new_value) {
+                    (Some(old), Some(new)) => writeln!(writer, ": '{}' -> '{}'", old, new)?,
+                    (Some(old), None) => writeln!(writer, ": was '{}'", old)?,
+                    (None, Some(new)) => writeln!(writer, ": '{}'", new)?,
+                    (None, None) => writeln!(writer)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::{AuditEntry, AuditSource};
+    use time::OffsetDateTime;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .audit_entries_create(AuditEntry {
+                source: AuditSource::Comment,
+                actor: "me".into(),
+                action: "set_default_automerge".into(),
+                target: "owner/name".into(),
+                repository_path: Some("owner/name".into()),
+                old_value: Some("false".into()),
+                new_value: Some("true".into()),
+                created_at: OffsetDateTime::from_unix_timestamp(0)?,
+                ..Default::default()
+            })
+            .await?;
+        ctx.db_service
+            .audit_entries_create(AuditEntry {
+                source: AuditSource::Cli,
+                actor: "admin".into(),
+                action: "add_external_account".into(),
+                target: "ext".into(),
+                created_at: OffsetDateTime::from_unix_timestamp(3600)?,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            test_command(ctx, &["audit", "list", "--repo", "owner/name", "--actor", "me"]).await,
+            "- 1970-01-01T00:00:00Z [comment] set_default_automerge by 'me' on 'owner/name': 'false' -> 'true'\n"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn run_since() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .audit_entries_create(AuditEntry {
+                source: AuditSource::Cli,
+                actor: "admin".into(),
+                action: "add_external_account".into(),
+                target: "ext".into(),
+                created_at: OffsetDateTime::from_unix_timestamp(0)?,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            test_command(ctx, &["audit", "list", "--since", "2000-01-01"]).await,
+            "No audit entry found.\n"
+        );
+
+        Ok(())
+    }
+}
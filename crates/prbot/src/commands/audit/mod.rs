@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+
+use super::{Command, CommandContext};
+use crate::Result;
+
+mod list;
+
+use self::list::AuditListCommand;
+
+/// Browse the audit log of configuration and admin changes
+#[derive(Parser)]
+pub(crate) struct AuditCommand {
+    #[clap(subcommand)]
+    inner: AuditSubCommand,
+}
+
+#[async_trait]
+impl Command for AuditCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        self.inner.execute(ctx).await
+    }
+}
+
+#[derive(Subcommand)]
+pub(crate) enum AuditSubCommand {
+    List(AuditListCommand),
+}
+
+#[async_trait]
+impl Command for AuditSubCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        match self {
+            Self::List(sub) => sub.execute(ctx).await,
+        }
+    }
+}
@@ -13,7 +13,7 @@ pub(crate) struct AuthAdminAddCommand {
 impl AuthAdminAddCommand {
     pub async fn run(self, ctx: CommandContext) -> Result<()> {
         AddAdminRight
-            .run(&ctx.as_core_context(), &self.username)
+            .run(&ctx.as_core_context(), &ctx.audit_actor(), &self.username)
             .await?;
 
         writeln!(
@@ -13,7 +13,7 @@ pub(crate) struct AuthExternalAccountAddCommand {
 impl AuthExternalAccountAddCommand {
     pub async fn run(self, ctx: CommandContext) -> Result<()> {
         AddExternalAccount
-            .run(&ctx.as_core_context(), &self.username)
+            .run(&ctx.as_core_context(), &ctx.audit_actor(), &self.username)
             .await?;
 
         writeln!(
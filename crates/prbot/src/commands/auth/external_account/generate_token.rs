@@ -13,7 +13,7 @@ pub(crate) struct AuthExternalAccountGenerateTokenCommand {
 impl AuthExternalAccountGenerateTokenCommand {
     pub async fn run(self, ctx: CommandContext) -> Result<()> {
         let token = GenerateExternalAccountToken
-            .run(&ctx.as_core_context(), &self.username)
+            .run(&ctx.as_core_context(), &ctx.audit_actor(), &self.username)
             .await?;
 
         writeln!(ctx.writer.write().await, "{}", token)?;
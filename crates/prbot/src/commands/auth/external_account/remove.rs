@@ -13,7 +13,7 @@ pub(crate) struct AuthExternalAccountRemoveCommand {
 impl AuthExternalAccountRemoveCommand {
     pub async fn run(self, ctx: CommandContext) -> Result<()> {
         RemoveExternalAccount
-            .run(&ctx.as_core_context(), &self.username)
+            .run(&ctx.as_core_context(), &ctx.audit_actor(), &self.username)
             .await?;
 
         writeln!(
@@ -13,7 +13,7 @@ pub(crate) struct AuthExternalAccountRemoveAllRightsCommand {
 impl AuthExternalAccountRemoveAllRightsCommand {
     pub async fn run(self, ctx: CommandContext) -> Result<()> {
         RemoveAllExternalAccountRights
-            .run(&ctx.as_core_context(), &self.username)
+            .run(&ctx.as_core_context(), &ctx.audit_actor(), &self.username)
             .await?;
 
         writeln!(
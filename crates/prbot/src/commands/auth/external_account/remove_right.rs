@@ -18,6 +18,7 @@ impl AuthExternalAccountRemoveRightCommand {
         RemoveExternalAccountRight
             .run(
                 &ctx.as_core_context(),
+                &ctx.audit_actor(),
                 &self.repository_path,
                 &self.username,
             )
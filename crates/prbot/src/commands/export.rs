@@ -41,8 +41,8 @@ mod tests {
     use prbot_database_interface::{DbService, Exchanger};
     use prbot_database_memory::MemoryDb;
     use prbot_models::{
-        Account, ExternalAccount, ExternalAccountRight, MergeRule, PullRequest, Repository,
-        RequiredReviewer,
+        Account, AuditEntry, ExternalAccount, ExternalAccountRight, MergeRule, PullRequest,
+        Repository, RequiredReviewer,
     };
 
     #[tokio::test]
@@ -101,6 +101,15 @@ mod tests {
         })
         .await
         .unwrap();
+        db.audit_entries_create(AuditEntry {
+            actor: "me".into(),
+            action: "set_default_automerge".into(),
+            target: "owner/name".into(),
+            repository_path: Some("owner/name".into()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
 
         let mut s = Vec::new();
         {
@@ -110,5 +119,8 @@ mod tests {
 
         let cursor = Cursor::new(&s);
         Exchanger::import_from_json(&db, cursor).await.unwrap();
+
+        // Known audit entries are not imported twice
+        assert_eq!(db.audit_entries_all().await.unwrap().len(), 1);
     }
 }
@@ -0,0 +1,57 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use clap::Parser;
+
+use crate::{
+    commands::{Command, CommandContext},
+    Result,
+};
+
+/// Cancel a pending background job
+#[derive(Parser)]
+pub(crate) struct JobCancelCommand {
+    /// Job ID
+    id: u64,
+}
+
+#[async_trait]
+impl Command for JobCancelCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        // Cancel atomically, as a worker can claim the job at any time
+        if !ctx.db_service.jobs_cancel(self.id).await? {
+            let job = ctx.db_service.jobs_get_expect(self.id).await?;
+            return Err(anyhow!(
+                "Job #{} cannot be cancelled (status: {})",
+                job.id,
+                job.status
+            ));
+        }
+
+        writeln!(ctx.writer.write().await, "Job #{} cancelled.", self.id)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::Job;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service.jobs_create(Job::default()).await?;
+
+        assert_eq!(
+            test_command(ctx, &["jobs", "cancel", "1"]).await,
+            "Job #1 cancelled.\n"
+        );
+
+        Ok(())
+    }
+}
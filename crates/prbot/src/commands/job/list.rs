@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_models::JobStatus;
+use time::format_description::well_known::Rfc3339;
+
+use crate::{
+    commands::{Command, CommandContext},
+    Result,
+};
+
+/// List background jobs
+#[derive(Parser)]
+pub(crate) struct JobListCommand {
+    /// Only list jobs with this status (pending, running, succeeded, dead_letter, cancelled)
+    #[clap(short, long)]
+    status: Option<JobStatus>,
+}
+
+#[async_trait]
+impl Command for JobListCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let jobs = ctx.db_service.jobs_list(self.status).await?;
+        if jobs.is_empty() {
+            writeln!(ctx.writer.write().await, "No job found.")?;
+        } else {
+            for job in jobs {
+                writeln!(
+                    ctx.writer.write().await,
+                    "- #{} [{}] {} (attempts: {}/{}, run at: {})",
+                    job.id,
+                    job.status,
+                    job.kind,
+                    job.attempts,
+                    job.max_attempts,
+                    job.run_at.format(&Rfc3339)?
+                )?;
+
+                if let Some(error) = job.last_error {
+                    writeln!(ctx.writer.write().await, "  Last error: {}", error)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::{Job, JobKind, JobStatus};
+    use time::OffsetDateTime;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .jobs_create(Job {
+                kind: JobKind::HandleReviewEvent,
+                run_at: OffsetDateTime::from_unix_timestamp(0)?,
+                ..Default::default()
+            })
+            .await?;
+        ctx.db_service
+            .jobs_create(Job {
+                kind: JobKind::ProcessPullRequestEvent,
+                status: JobStatus::DeadLetter,
+                attempts: 5,
+                last_error: Some("Oops".into()),
+                run_at: OffsetDateTime::from_unix_timestamp(0)?,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            test_command(ctx, &["jobs", "list", "--status", "dead_letter"]).await,
+            "- #2 [dead_letter] process_pull_request_event (attempts: 5/5, run at: 1970-01-01T00:00:00Z)\n  Last error: Oops\n"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn run_empty() {
+        let ctx = CommandContextTest::new();
+
+        assert_eq!(
+            test_command(ctx, &["jobs", "list"]).await,
+            "No job found.\n"
+        );
+    }
+}
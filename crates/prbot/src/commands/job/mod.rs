@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+
+use super::{Command, CommandContext};
+use crate::Result;
+
+mod cancel;
+mod list;
+mod retry;
+
+use self::{cancel::JobCancelCommand, list::JobListCommand, retry::JobRetryCommand};
+
+/// Manage background jobs
+#[derive(Parser)]
+pub(crate) struct JobCommand {
+    #[clap(subcommand)]
+    inner: JobSubCommand,
+}
+
+#[async_trait]
+impl Command for JobCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        self.inner.execute(ctx).await
+    }
+}
+
+#[derive(Subcommand)]
+pub(crate) enum JobSubCommand {
+    List(JobListCommand),
+    Retry(JobRetryCommand),
+    Cancel(JobCancelCommand),
+}
+
+#[async_trait]
+impl Command for JobSubCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        match self {
+            Self::List(sub) => sub.execute(ctx).await,
+            Self::Retry(sub) => sub.execute(ctx).await,
+            Self::Cancel(sub) => sub.execute(ctx).await,
+        }
+    }
+}
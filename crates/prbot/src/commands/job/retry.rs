@@ -0,0 +1,77 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_models::{Job, JobStatus};
+use time::OffsetDateTime;
+
+use crate::{
+    commands::{Command, CommandContext},
+    Result,
+};
+
+/// Retry a dead-letter or cancelled background job
+#[derive(Parser)]
+pub(crate) struct JobRetryCommand {
+    /// Job ID
+    id: u64,
+}
+
+#[async_trait]
+impl Command for JobRetryCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let job = ctx.db_service.jobs_get_expect(self.id).await?;
+        if !job.is_retryable() {
+            return Err(anyhow!(
+                "Job #{} cannot be retried (status: {})",
+                job.id,
+                job.status
+            ));
+        }
+
+        ctx.db_service
+            .jobs_update(Job {
+                status: JobStatus::Pending,
+                attempts: 0,
+                run_at: OffsetDateTime::now_utc(),
+                ..job
+            })
+            .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "Job #{} scheduled for retry.",
+            self.id
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::{Job, JobStatus};
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .jobs_create(Job {
+                status: JobStatus::DeadLetter,
+                attempts: 5,
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            test_command(ctx, &["jobs", "retry", "1"]).await,
+            "Job #1 scheduled for retry.\n"
+        );
+
+        Ok(())
+    }
+}
@@ -9,24 +9,30 @@ use prbot_core::{CoreContext, CoreModule};
 use prbot_database_interface::DbService;
 use prbot_ghapi_interface::ApiService;
 use prbot_lock_interface::LockService;
+use prbot_models::{AuditActor, AuditSource};
 use tokio::sync::RwLock;
 
 use self::{
-    auth::AuthCommand, debug::DebugCommand, export::ExportCommand, import::ImportCommand,
-    pull_request::PullRequestCommand, repository::RepositoryCommand, server::ServerCommand,
-    ui::UiCommand, utils::UtilsCommand,
+    audit::AuditCommand, auth::AuthCommand, debug::DebugCommand, export::ExportCommand,
+    import::ImportCommand, job::JobCommand, pull_request::PullRequestCommand,
+    repository::RepositoryCommand, server::ServerCommand, stats::StatsCommand, ui::UiCommand,
+    utils::UtilsCommand, webhook::WebhookCommand,
 };
 use crate::Result;
 
+mod audit;
 mod auth;
 mod debug;
 mod export;
 mod import;
+mod job;
 mod pull_request;
 mod repository;
 mod server;
+mod stats;
 mod ui;
 mod utils;
+mod webhook;
 
 pub(crate) struct CommandContext {
     pub config: Config,
@@ -47,6 +53,12 @@ impl CommandContext {
             lock_service: self.lock_service.as_ref(),
         }
     }
+
+    /// Audit actor for CLI changes, using the current system user.
+    pub fn audit_actor(&self) -> AuditActor {
+        let username = std::env::var("USER").unwrap_or_else(|_| "cli".into());
+        AuditActor::new(AuditSource::Cli, &username)
+    }
 }
 
 #[async_trait]
@@ -65,7 +77,11 @@ pub(crate) enum SubCommand {
     PullRequests(PullRequestCommand),
     Repositories(RepositoryCommand),
     Auth(AuthCommand),
+    Audit(AuditCommand),
     Debug(DebugCommand),
+    Webhooks(WebhookCommand),
+    Jobs(JobCommand),
+    Stats(StatsCommand),
 }
 
 #[async_trait]
@@ -79,8 +95,12 @@ impl Command for SubCommand {
             Self::Utils(sub) => sub.execute(ctx).await,
             Self::PullRequests(sub) => sub.execute(ctx).await,
             Self::Auth(sub) => sub.execute(ctx).await,
+            Self::Audit(sub) => sub.execute(ctx).await,
             Self::Repositories(sub) => sub.execute(ctx).await,
             Self::Debug(sub) => sub.execute(ctx).await,
+            Self::Webhooks(sub) => sub.execute(ctx).await,
+            Self::Jobs(sub) => sub.execute(ctx).await,
+            Self::Stats(sub) => sub.execute(ctx).await,
         }
     }
 }
@@ -56,7 +56,7 @@ impl Command for PullRequestApplyPullRequestRulesCommand {
         let handle = PullRequestHandle::new(self.repository_path.clone(), self.number);
         let apply_rules: &dyn ApplyPullRequestRulesInterface = ctx.core_module.resolve_ref();
         apply_rules
-            .run(&ctx.as_core_context(), &handle, rules)
+            .run(&ctx.as_core_context(), &handle, &upstream_pr, rules)
             .await?;
 
         Ok(())
@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::pulls::{ExplainPullRequestRulesInterface, RuleEvaluation};
+use prbot_models::RepositoryPath;
+use shaku::HasComponent;
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Explain which pull request rules match a pull request, and which actions win
+#[derive(Parser)]
+pub(crate) struct PullRequestExplainCommand {
+    /// Repository path (e.g. 'MyOrganization/my-project')
+    repository_path: RepositoryPath,
+
+    /// Pull request number
+    number: u64,
+}
+
+#[async_trait]
+impl Command for PullRequestExplainCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+
+        let _pr =
+            CliDbExt::get_existing_pull_request(ctx.db_service.as_ref(), owner, name, self.number)
+                .await?;
+
+        let upstream_pr = ctx.api_service.pulls_get(owner, name, self.number).await?;
+
+        let explain_rules: &dyn ExplainPullRequestRulesInterface = ctx.core_module.resolve_ref();
+        let explanation = explain_rules
+            .run(&ctx.as_core_context(), &self.repository_path, &upstream_pr)
+            .await?;
+
+        let mut writer = ctx.writer.write().await;
+        if explanation.rules.is_empty() {
+            writeln!(writer, "No pull request rule.")?;
+            return Ok(());
+        }
+
+        writeln!(writer, "Rules:")?;
+        for rule in &explanation.rules {
+            let result = match &rule.evaluation {
+                RuleEvaluation::Matched => "matched".into(),
+                RuleEvaluation::Failed { conditions } => format!(
+                    "not matched (failed: {})",
+                    conditions
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                RuleEvaluation::Ignored => "ignored, no conditions or actions".into(),
+            };
+
+            writeln!(
+                writer,
+                "  - {} (priority {}): {}",
+                rule.rule.name, rule.rule.priority, result
+            )?;
+        }
+
+        writeln!(writer, "Actions:")?;
+        if explanation.actions.is_empty() {
+            writeln!(writer, "  None")?;
+        }
+        for action in &explanation.actions {
+            match &action.overridden_by {
+                Some(winner) => writeln!(
+                    writer,
+                    "  - {} from '{}', overridden by '{}'",
+                    action.action, action.rule_name, winner
+                )?,
+                None => writeln!(writer, "  - {} from '{}'", action.action, action.rule_name)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::types::{GhPullRequest, GhUser};
+    use prbot_models::{PullRequest, PullRequestRule, Repository, RuleAction, RuleCondition};
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let mut ctx = CommandContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        ctx.db_service
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                ..Default::default()
+            })
+            .await?;
+
+        ctx.db_service
+            .pull_request_rules_create(PullRequestRule {
+                repository_id: repo.id,
+                name: "Bots".into(),
+                conditions: vec![RuleCondition::Author("bot".into())],
+                actions: vec![RuleAction::SetAutomerge(true)],
+                ..Default::default()
+            })
+            .await?;
+        ctx.db_service
+            .pull_request_rules_create(PullRequestRule {
+                repository_id: repo.id,
+                name: "Others".into(),
+                conditions: vec![RuleCondition::Author("other".into())],
+                actions: vec![RuleAction::SetAutomerge(false)],
+                priority: 1,
+            })
+            .await?;
+
+        ctx.api_service
+            .expect_pulls_get()
+            .once()
+            .return_once(|_, _, _| {
+                Ok(GhPullRequest {
+                    user: GhUser {
+                        login: "bot".into(),
+                    },
+                    ..Default::default()
+                })
+            });
+
+        assert_eq!(
+            test_command(ctx, &["pull-requests", "explain", "owner/name", "1"]).await,
+            "Rules:\n\
+            \x20 - Others (priority 1): not matched (failed: {\"author\":\"other\"})\n\
+            \x20 - Bots (priority 0): matched\n\
+            Actions:\n\
+            \x20 - {\"set_automerge\":true} from 'Bots'\n"
+        );
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use super::{Command, CommandContext};
 use crate::Result;
 
 mod apply_pull_request_rules;
+mod explain;
 mod list;
 mod set_merge_strategy;
 mod show;
@@ -12,8 +13,9 @@ mod sync;
 
 use self::{
     apply_pull_request_rules::PullRequestApplyPullRequestRulesCommand,
-    list::PullRequestListCommand, set_merge_strategy::PullRequestSetMergeStrategyCommand,
-    show::PullRequestShowCommand, sync::PullRequestSyncCommand,
+    explain::PullRequestExplainCommand, list::PullRequestListCommand,
+    set_merge_strategy::PullRequestSetMergeStrategyCommand, show::PullRequestShowCommand,
+    sync::PullRequestSyncCommand,
 };
 
 /// Manage pull requests
@@ -36,6 +38,7 @@ pub(crate) enum PullRequestSubCommand {
     Sync(PullRequestSyncCommand),
     SetMergeStrategy(PullRequestSetMergeStrategyCommand),
     ApplyPullRequestRules(PullRequestApplyPullRequestRulesCommand),
+    Explain(PullRequestExplainCommand),
     List(PullRequestListCommand),
 }
 
@@ -47,6 +50,7 @@ impl Command for PullRequestSubCommand {
             Self::Show(sub) => sub.execute(ctx).await,
             Self::Sync(sub) => sub.execute(ctx).await,
             Self::ApplyPullRequestRules(sub) => sub.execute(ctx).await,
+            Self::Explain(sub) => sub.execute(ctx).await,
             Self::SetMergeStrategy(sub) => sub.execute(ctx).await,
         }
     }
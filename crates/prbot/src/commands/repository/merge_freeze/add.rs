@@ -0,0 +1,107 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_models::{format_datetime, parse_deadline, MergeFreeze, RepositoryPath, RuleBranch};
+use time::OffsetDateTime;
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Freeze merges for a repository, until a date
+#[derive(Parser)]
+pub(crate) struct AddCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Base branch name
+    base_branch: RuleBranch,
+    /// Freeze end, as a duration (`2h`, `3d`), a date (`2026-10-20`) or a RFC 3339 datetime
+    until: String,
+    /// Freeze reason
+    #[arg(long)]
+    reason: Option<String>,
+    /// Freeze author
+    #[arg(long, default_value = "cli")]
+    author: String,
+}
+
+#[async_trait]
+impl Command for AddCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        let now = OffsetDateTime::now_utc();
+        let until = parse_deadline(&self.until, now).map_err(|e| anyhow!(e))?;
+        if until <= now {
+            return Err(anyhow!("The freeze end date must be in the future"));
+        }
+
+        ctx.db_service
+            .merge_freezes_set(
+                MergeFreeze {
+                    base_branch: self.base_branch.clone(),
+                    until,
+                    reason: self.reason,
+                    created_by: self.author,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "Merges frozen until {} for repository '{}' and base branches '{}'.",
+            format_datetime(until),
+            self.repository_path,
+            self.base_branch
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::Repository;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            test_command(
+                ctx,
+                &[
+                    "repositories",
+                    "merge-freezes",
+                    "add",
+                    "owner/name",
+                    "main",
+                    "2099-01-01",
+                    "--reason",
+                    "Release"
+                ]
+            )
+            .await,
+            "Merges frozen until 2099-01-01 00:00 UTC for repository 'owner/name' and base branches 'main'.\n"
+        );
+
+        Ok(())
+    }
+}
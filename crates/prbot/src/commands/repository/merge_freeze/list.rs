@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_models::{format_datetime, RepositoryPath};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// List merge freezes for a repository
+#[derive(Parser)]
+pub(crate) struct ListCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+}
+
+#[async_trait]
+impl Command for ListCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let _repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        let freezes = ctx.db_service.merge_freezes_list(owner, name).await?;
+        if freezes.is_empty() {
+            writeln!(
+                ctx.writer.write().await,
+                "No merge freeze for repository {}.",
+                self.repository_path
+            )?;
+        } else {
+            writeln!(
+                ctx.writer.write().await,
+                "Merge freezes for repository {}:",
+                self.repository_path
+            )?;
+            for freeze in freezes {
+                writeln!(
+                    ctx.writer.write().await,
+                    "- '{}' (base): until {}, by '{}'{}",
+                    freeze.base_branch,
+                    format_datetime(freeze.until),
+                    freeze.created_by,
+                    freeze.reason.map(|r| format!(" ({r})")).unwrap_or_default()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
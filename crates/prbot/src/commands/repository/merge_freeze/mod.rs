@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+
+use self::{add::AddCommand, list::ListCommand, remove::RemoveCommand};
+use crate::{
+    commands::{Command, CommandContext},
+    Result,
+};
+
+mod add;
+mod list;
+mod remove;
+
+/// Commands around merge freezes
+#[derive(Parser)]
+pub(crate) struct MergeFreezeCommand {
+    #[clap(subcommand)]
+    inner: MergeFreezeSubCommand,
+}
+
+#[async_trait]
+impl Command for MergeFreezeCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        self.inner.execute(ctx).await
+    }
+}
+
+#[derive(Subcommand)]
+enum MergeFreezeSubCommand {
+    Add(AddCommand),
+    Remove(RemoveCommand),
+    List(ListCommand),
+}
+
+#[async_trait]
+impl Command for MergeFreezeSubCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        match self {
+            Self::Add(sub) => sub.execute(ctx).await,
+            Self::List(sub) => sub.execute(ctx).await,
+            Self::Remove(sub) => sub.execute(ctx).await,
+        }
+    }
+}
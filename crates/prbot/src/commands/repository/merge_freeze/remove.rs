@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::repositories::UnfreezeMergesInterface;
+use prbot_models::{RepositoryPath, RuleBranch};
+use shaku::HasComponent;
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Remove merge freezes for a repository
+#[derive(Parser)]
+pub(crate) struct RemoveCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Base branch name (all freezes are removed if unset)
+    base_branch: Option<RuleBranch>,
+}
+
+#[async_trait]
+impl Command for RemoveCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        let uc: &dyn UnfreezeMergesInterface = ctx.core_module.resolve_ref();
+        let count = uc
+            .run(&ctx.as_core_context(), &repo, self.base_branch.clone())
+            .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "{} merge freeze(s) removed for repository '{}'.",
+            count,
+            self.repository_path
+        )?;
+
+        Ok(())
+    }
+}
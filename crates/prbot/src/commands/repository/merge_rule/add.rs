@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use clap::Parser;
 use prbot_core::use_cases::repositories::AddMergeRuleInterface;
-use prbot_models::{MergeStrategy, RepositoryPath, RuleBranch};
+use prbot_models::{MergeRule, MergeStrategy, RepositoryPath, RuleBranch};
 use shaku::HasComponent;
 
 use crate::{
@@ -21,6 +21,12 @@ pub(crate) struct AddCommand {
     head_branch: RuleBranch,
     /// Merge strategy
     strategy: MergeStrategy,
+    /// Merge commit title template, overriding the repository template (empty to remove)
+    #[arg(long)]
+    commit_title_template: Option<String>,
+    /// Merge commit message template, overriding the repository template (empty to remove)
+    #[arg(long)]
+    commit_message_template: Option<String>,
 }
 
 #[async_trait]
@@ -33,9 +39,14 @@ impl Command for AddCommand {
         uc.run(
             &ctx.as_core_context(),
             &repo,
-            self.base_branch.clone(),
-            self.head_branch.clone(),
-            self.strategy,
+            MergeRule {
+                repository_id: repo.id,
+                base_branch: self.base_branch.clone(),
+                head_branch: self.head_branch.clone(),
+                strategy: self.strategy,
+                commit_title_template: self.commit_title_template,
+                commit_message_template: self.commit_message_template,
+            },
         )
         .await?;
 
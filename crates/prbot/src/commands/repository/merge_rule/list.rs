@@ -43,6 +43,13 @@ impl Command for ListCommand {
                 rule.head_branch,
                 rule.strategy
             )?;
+
+            if let Some(template) = &rule.commit_title_template {
+                writeln!(ctx.writer.write().await, "  - Commit title: '{template}'")?;
+            }
+            if let Some(template) = &rule.commit_message_template {
+                writeln!(ctx.writer.write().await, "  - Commit message: '{template}'")?;
+            }
         }
 
         Ok(())
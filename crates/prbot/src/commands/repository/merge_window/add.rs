@@ -0,0 +1,97 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_models::{MergeWindow, RepositoryPath, RuleBranch};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Add merge window for a repository
+#[derive(Parser)]
+pub(crate) struct AddCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Base branch name
+    base_branch: RuleBranch,
+    /// Schedule, with days, times and an optional UTC offset (e.g. `mon-fri 09:00-18:00 UTC+02:00`)
+    schedule: String,
+}
+
+#[async_trait]
+impl Command for AddCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        let (days, start_time, end_time, utc_offset) =
+            MergeWindow::parse_schedule(&self.schedule).map_err(|e| anyhow!(e))?;
+        let window = ctx
+            .db_service
+            .merge_windows_create(
+                MergeWindow {
+                    base_branch: self.base_branch.clone(),
+                    days,
+                    start_time,
+                    end_time,
+                    utc_offset,
+                    ..Default::default()
+                }
+                .with_repository(&repo),
+            )
+            .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "Merge window #{} created with '{}' for repository '{}' and base branches '{}'.",
+            window.id,
+            window.schedule(),
+            self.repository_path,
+            self.base_branch
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::Repository;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            test_command(
+                ctx,
+                &[
+                    "repositories",
+                    "merge-windows",
+                    "add",
+                    "owner/name",
+                    "main",
+                    "mon-fri 09:00-18:00 UTC+02:00"
+                ]
+            )
+            .await,
+            "Merge window #1 created with 'mon,tue,wed,thu,fri 09:00-18:00 UTC+02:00' for repository 'owner/name' and base branches 'main'.\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_models::RepositoryPath;
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// List merge windows for a repository
+#[derive(Parser)]
+pub(crate) struct ListCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+}
+
+#[async_trait]
+impl Command for ListCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let _repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        let windows = ctx.db_service.merge_windows_list(owner, name).await?;
+        if windows.is_empty() {
+            writeln!(
+                ctx.writer.write().await,
+                "No merge window for repository {}.",
+                self.repository_path
+            )?;
+        } else {
+            writeln!(
+                ctx.writer.write().await,
+                "Merge windows for repository {}:",
+                self.repository_path
+            )?;
+            for window in windows {
+                writeln!(
+                    ctx.writer.write().await,
+                    "- #{}: '{}' (base): '{}'",
+                    window.id,
+                    window.base_branch,
+                    window.schedule()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
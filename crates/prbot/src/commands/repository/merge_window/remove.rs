@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_models::RepositoryPath;
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Remove merge window for a repository
+#[derive(Parser)]
+pub(crate) struct RemoveCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Merge window ID
+    id: u64,
+}
+
+#[async_trait]
+impl Command for RemoveCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let _repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        let found = ctx
+            .db_service
+            .merge_windows_delete(owner, name, self.id)
+            .await?;
+        if found {
+            writeln!(
+                ctx.writer.write().await,
+                "Merge window #{} for repository '{}' deleted.",
+                self.id,
+                self.repository_path
+            )?;
+        } else {
+            writeln!(
+                ctx.writer.write().await,
+                "Unknown merge window #{} for repository '{}'.",
+                self.id,
+                self.repository_path
+            )?;
+        }
+
+        Ok(())
+    }
+}
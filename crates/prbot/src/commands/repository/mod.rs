@@ -8,26 +8,49 @@ use crate::Result;
 
 mod add;
 mod list;
+mod merge_freeze;
 mod merge_rule;
+mod merge_window;
 mod pull_request_rule;
 mod rename;
+mod set_ci_apps;
+mod set_codeowners_reviewers;
 mod set_default_automerge;
 mod set_default_checks_status;
 mod set_default_qa_status;
 mod set_default_reviewers_count;
 mod set_default_title_regex;
 mod set_manual_interaction;
+mod set_merge_commit_template;
+mod set_native_merge_queue;
+mod set_optional_checks;
+mod set_required_checks;
+mod set_reviewer_pool;
+mod set_stale_approval_policy;
+mod set_stale_settings;
 mod show;
+mod sync_config;
 
 use self::{
-    add::RepositoryAddCommand, list::RepositoryListCommand, merge_rule::MergeRuleCommand,
+    add::RepositoryAddCommand, list::RepositoryListCommand, merge_freeze::MergeFreezeCommand,
+    merge_rule::MergeRuleCommand, merge_window::MergeWindowCommand,
     pull_request_rule::PullRequestRuleCommand, rename::RepositoryRenameCommand,
+    set_ci_apps::RepositorySetCiAppsCommand,
+    set_codeowners_reviewers::RepositorySetCodeOwnersReviewersCommand,
     set_default_automerge::RepositorySetDefaultAutomergeCommand,
     set_default_checks_status::RepositorySetDefaultChecksStatusCommand,
     set_default_qa_status::RepositorySetDefaultQaStatusCommand,
     set_default_reviewers_count::RepositorySetDefaultReviewersCountCommand,
     set_default_title_regex::RepositorySetDefaultTitleRegexCommand,
-    set_manual_interaction::RepositorySetManualInteractionCommand, show::RepositoryShowCommand,
+    set_manual_interaction::RepositorySetManualInteractionCommand,
+    set_merge_commit_template::RepositorySetMergeCommitTemplateCommand,
+    set_native_merge_queue::RepositorySetNativeMergeQueueCommand,
+    set_optional_checks::RepositorySetOptionalChecksCommand,
+    set_required_checks::RepositorySetRequiredChecksCommand,
+    set_reviewer_pool::RepositorySetReviewerPoolCommand,
+    set_stale_approval_policy::RepositorySetStaleApprovalPolicyCommand,
+    set_stale_settings::RepositorySetStaleSettingsCommand, show::RepositoryShowCommand,
+    sync_config::RepositorySyncConfigCommand,
 };
 
 /// Manage repositories
@@ -49,6 +72,8 @@ enum RepositorySubCommand {
     Add(RepositoryAddCommand),
     PullRequestRules(PullRequestRuleCommand),
     MergeRules(MergeRuleCommand),
+    MergeFreezes(MergeFreezeCommand),
+    MergeWindows(MergeWindowCommand),
     SetDefaultTitleRegex(RepositorySetDefaultTitleRegexCommand),
     Show(RepositoryShowCommand),
     SetDefaultReviewersCount(RepositorySetDefaultReviewersCountCommand),
@@ -56,6 +81,16 @@ enum RepositorySubCommand {
     SetDefaultQaStatus(RepositorySetDefaultQaStatusCommand),
     SetDefaultChecksStatus(RepositorySetDefaultChecksStatusCommand),
     SetDefaultAutomerge(RepositorySetDefaultAutomergeCommand),
+    SetCiApps(RepositorySetCiAppsCommand),
+    SetRequiredChecks(RepositorySetRequiredChecksCommand),
+    SetOptionalChecks(RepositorySetOptionalChecksCommand),
+    SetNativeMergeQueue(RepositorySetNativeMergeQueueCommand),
+    SetCodeOwnersReviewers(RepositorySetCodeOwnersReviewersCommand),
+    SetMergeCommitTemplate(RepositorySetMergeCommitTemplateCommand),
+    SetStaleSettings(RepositorySetStaleSettingsCommand),
+    SetReviewerPool(RepositorySetReviewerPoolCommand),
+    SetStaleApprovalPolicy(RepositorySetStaleApprovalPolicyCommand),
+    SyncConfig(RepositorySyncConfigCommand),
     Rename(RepositoryRenameCommand),
     List(RepositoryListCommand),
 }
@@ -69,11 +104,23 @@ impl Command for RepositorySubCommand {
             Self::Show(sub) => sub.execute(ctx).await,
             Self::PullRequestRules(sub) => sub.execute(ctx).await,
             Self::MergeRules(sub) => sub.execute(ctx).await,
+            Self::MergeFreezes(sub) => sub.execute(ctx).await,
+            Self::MergeWindows(sub) => sub.execute(ctx).await,
             Self::SetDefaultReviewersCount(sub) => sub.execute(ctx).await,
             Self::SetManualInteraction(sub) => sub.execute(ctx).await,
             Self::SetDefaultQaStatus(sub) => sub.execute(ctx).await,
             Self::SetDefaultChecksStatus(sub) => sub.execute(ctx).await,
             Self::SetDefaultAutomerge(sub) => sub.execute(ctx).await,
+            Self::SetCiApps(sub) => sub.execute(ctx).await,
+            Self::SetRequiredChecks(sub) => sub.execute(ctx).await,
+            Self::SetOptionalChecks(sub) => sub.execute(ctx).await,
+            Self::SetNativeMergeQueue(sub) => sub.execute(ctx).await,
+            Self::SetCodeOwnersReviewers(sub) => sub.execute(ctx).await,
+            Self::SetMergeCommitTemplate(sub) => sub.execute(ctx).await,
+            Self::SetStaleSettings(sub) => sub.execute(ctx).await,
+            Self::SetReviewerPool(sub) => sub.execute(ctx).await,
+            Self::SetStaleApprovalPolicy(sub) => sub.execute(ctx).await,
+            Self::SyncConfig(sub) => sub.execute(ctx).await,
             Self::Rename(sub) => sub.execute(ctx).await,
             Self::List(sub) => sub.execute(ctx).await,
         }
@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use clap::Parser;
-use prbot_core::use_cases::pulls::AddPullRequestRuleInterface;
-use prbot_models::{RepositoryPath, RuleAction, RuleCondition};
+use prbot_core::use_cases::pulls::{
+    AddPullRequestRuleInterface, SimulatePullRequestRuleInterface, SimulatedPullRequest,
+};
+use prbot_models::{PullRequestRule, RepositoryPath, RuleAction, RuleCondition};
 use shaku::HasComponent;
 
 use crate::{
@@ -23,6 +25,12 @@ pub(crate) struct AddCommand {
     /// Actions
     #[arg(short, long)]
     actions: Vec<RuleAction>,
+    /// Priority, higher priority rules win conflicting actions
+    #[arg(short, long, default_value_t = 0, allow_negative_numbers = true)]
+    priority: i32,
+    /// Show the pull requests the rule would change, without adding it
+    #[arg(long)]
+    dry_run: bool,
 }
 
 #[async_trait]
@@ -31,6 +39,43 @@ impl Command for AddCommand {
         let (owner, name) = self.repository_path.components();
         let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
 
+        if self.dry_run {
+            let simulate: &dyn SimulatePullRequestRuleInterface = ctx.core_module.resolve_ref();
+            let simulated = simulate
+                .run(
+                    &ctx.as_core_context(),
+                    &repo,
+                    PullRequestRule {
+                        repository_id: repo.id,
+                        name: self.name.clone(),
+                        conditions: self.conditions,
+                        actions: self.actions,
+                        priority: self.priority,
+                    },
+                )
+                .await?;
+
+            let mut writer = ctx.writer.write().await;
+            if simulated.is_empty() {
+                writeln!(
+                    writer,
+                    "Pull request rule '{}' would not change any open pull request on repository '{}'",
+                    self.name, self.repository_path
+                )?;
+            } else {
+                writeln!(
+                    writer,
+                    "Pull request rule '{}' would change {} open pull request(s) on repository '{}':",
+                    self.name,
+                    simulated.len(),
+                    self.repository_path
+                )?;
+                write!(writer, "{}", Self::format_table(&simulated))?;
+            }
+
+            return Ok(());
+        }
+
         let uc: &dyn AddPullRequestRuleInterface = ctx.core_module.resolve_ref();
         uc.run(
             &ctx.as_core_context(),
@@ -38,6 +83,7 @@ impl Command for AddCommand {
             self.name.clone(),
             self.conditions,
             self.actions,
+            self.priority,
         )
         .await?;
 
@@ -51,3 +97,129 @@ impl Command for AddCommand {
         Ok(())
     }
 }
+
+impl AddCommand {
+    fn format_table(simulated: &[SimulatedPullRequest]) -> String {
+        let mut rows = vec![[
+            "PR".to_string(),
+            "Title".into(),
+            "Setting".into(),
+            "Before".into(),
+            "After".into(),
+        ]];
+        for pr in simulated {
+            for (i, change) in pr.changes.iter().enumerate() {
+                let (number, title) = if i == 0 {
+                    (format!("#{}", pr.number), pr.title.clone())
+                } else {
+                    (String::new(), String::new())
+                };
+
+                rows.push([
+                    number,
+                    title,
+                    change.setting.clone(),
+                    change.before.clone(),
+                    change.after.clone(),
+                ]);
+            }
+        }
+
+        let mut widths = [0; 5];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut table = String::new();
+        for row in rows {
+            let line = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            table.push_str(line.trim_end());
+            table.push('\n');
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_ghapi_interface::types::{GhPullRequest, GhUser};
+    use prbot_models::{PullRequest, Repository};
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run_dry_run() -> Result<(), Box<dyn Error>> {
+        let mut ctx = CommandContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await?;
+        ctx.db_service
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                ..Default::default()
+            })
+            .await?;
+
+        let pr = GhPullRequest {
+            number: 1,
+            title: "Bump serde".into(),
+            user: GhUser {
+                login: "bot".into(),
+            },
+            ..Default::default()
+        };
+        let listed_pr = pr.clone();
+        ctx.api_service
+            .expect_pulls_list()
+            .once()
+            .return_once(|_, _| Ok(vec![listed_pr]));
+        ctx.api_service
+            .expect_pulls_get()
+            .once()
+            .return_once(|_, _, _| Ok(pr));
+
+        assert_eq!(
+            test_command(
+                ctx,
+                &[
+                    "repositories",
+                    "pull-request-rules",
+                    "add",
+                    "owner/name",
+                    "Bots",
+                    "-c",
+                    r#"{"author": "bot"}"#,
+                    "-a",
+                    r#"{"set_automerge": true}"#,
+                    "-a",
+                    r#"{"add_labels": ["deps"]}"#,
+                    "--dry-run"
+                ]
+            )
+            .await,
+            "Pull request rule 'Bots' would change 1 open pull request(s) on repository 'owner/name':\n\
+            PR  Title       Setting    Before  After\n\
+            #1  Bump serde  automerge  false   true\n\
+            \x20               labels             deps\n"
+        );
+
+        Ok(())
+    }
+}
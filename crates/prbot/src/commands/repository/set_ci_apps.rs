@@ -0,0 +1,57 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, Repository, RepositoryPath};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Set CI app slugs for a repository
+#[derive(Parser)]
+pub(crate) struct RepositorySetCiAppsCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// App slugs whose check runs are used (glob patterns allowed, e.g. `github-actions`)
+    apps: Vec<String>,
+}
+
+#[async_trait]
+impl Command for RepositorySetCiAppsCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        for pattern in &self.apps {
+            Repository::validate_check_pattern(pattern).map_err(|e| anyhow!(e))?;
+        }
+
+        ctx.db_service
+            .repositories_set_ci_app_slugs(owner, name, &self.apps)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_ci_app_slugs",
+                &self.repository_path,
+            )
+            .with_old_value(repo.ci_app_slugs.join(", "))
+            .with_new_value(self.apps.join(", ")),
+        )
+        .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "CI apps set to '{}' for repository {}.",
+            self.apps.join(", "),
+            self.repository_path
+        )?;
+
+        Ok(())
+    }
+}
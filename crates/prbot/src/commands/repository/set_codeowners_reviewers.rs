@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, RepositoryPath};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Set code owners required reviewers for a repository
+#[derive(Parser)]
+pub(crate) struct RepositorySetCodeOwnersReviewersCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Status
+    #[clap(value_parser)]
+    status: bool,
+}
+
+#[async_trait]
+impl Command for RepositorySetCodeOwnersReviewersCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        ctx.db_service
+            .repositories_set_codeowners_reviewers(owner, name, self.status)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_codeowners_reviewers",
+                &self.repository_path,
+            )
+            .with_old_value(repo.codeowners_reviewers)
+            .with_new_value(self.status),
+        )
+        .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "Code owners required reviewers set to '{}' for repository {}.",
+            self.status,
+            self.repository_path
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use clap::Parser;
-use prbot_models::RepositoryPath;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, RepositoryPath};
 
 use crate::{
     commands::{Command, CommandContext},
@@ -21,12 +22,24 @@ pub(crate) struct RepositorySetDefaultReviewersCountCommand {
 impl Command for RepositorySetDefaultReviewersCountCommand {
     async fn execute(self, ctx: CommandContext) -> Result<()> {
         let (owner, name) = self.repository_path.components();
-        let _repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
 
         ctx.db_service
             .repositories_set_default_needed_reviewers_count(owner, name, self.reviewers_count)
             .await?;
 
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_default_needed_reviewers_count",
+                &self.repository_path,
+            )
+            .with_old_value(repo.default_needed_reviewers_count)
+            .with_new_value(self.reviewers_count),
+        )
+        .await?;
+
         writeln!(
             ctx.writer.write().await,
             "Default reviewers count updated to {} for repository {}.",
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use clap::Parser;
-use prbot_models::RepositoryPath;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, RepositoryPath};
 
 use crate::{
     commands::{Command, CommandContext},
@@ -22,12 +23,24 @@ pub(crate) struct RepositorySetManualInteractionCommand {
 impl Command for RepositorySetManualInteractionCommand {
     async fn execute(self, ctx: CommandContext) -> Result<()> {
         let (owner, name) = self.repository_path.components();
-        let _repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
 
         ctx.db_service
             .repositories_set_manual_interaction(owner, name, self.manual_interaction)
             .await?;
 
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_manual_interaction",
+                &self.repository_path,
+            )
+            .with_old_value(repo.manual_interaction)
+            .with_new_value(self.manual_interaction),
+        )
+        .await?;
+
         writeln!(
             ctx.writer.write().await,
             "Manual interaction mode set to '{}' for repository {}.",
@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, Repository, RepositoryPath};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Set merge commit templates for a repository
+#[derive(Parser)]
+pub(crate) struct RepositorySetMergeCommitTemplateCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Commit title template (e.g. `{title} (#{number})`)
+    #[arg(long)]
+    title: Option<String>,
+    /// Commit message template (e.g. `{body}\n\n{co_authors}`)
+    #[arg(long)]
+    message: Option<String>,
+    /// Normalize commit titles following the Conventional Commits format
+    #[arg(long)]
+    conventional_commits: Option<bool>,
+}
+
+#[async_trait]
+impl Command for RepositorySetMergeCommitTemplateCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        // Missing values keep their current value
+        let new_repo = ctx
+            .db_service
+            .repositories_set_merge_commit_settings(
+                owner,
+                name,
+                self.title
+                    .as_deref()
+                    .unwrap_or(&repo.merge_commit_title_template),
+                self.message
+                    .as_deref()
+                    .unwrap_or(&repo.merge_commit_message_template),
+                self.conventional_commits
+                    .unwrap_or(repo.conventional_commits),
+            )
+            .await?;
+
+        let format_settings = |r: &Repository| {
+            format!(
+                "title='{}', message='{}', conventional_commits={}",
+                r.merge_commit_title_template,
+                r.merge_commit_message_template,
+                r.conventional_commits
+            )
+        };
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_merge_commit_settings",
+                &self.repository_path,
+            )
+            .with_old_value(format_settings(&repo))
+            .with_new_value(format_settings(&new_repo)),
+        )
+        .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "Merge commit templates set for repository '{}':\n- Title: '{}'\n- Message: '{}'\n- Conventional Commits: {}",
+            self.repository_path,
+            new_repo.merge_commit_title_template,
+            new_repo.merge_commit_message_template,
+            new_repo.conventional_commits
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_models::Repository;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            test_command(
+                ctx,
+                &[
+                    "repositories",
+                    "set-merge-commit-template",
+                    "owner/name",
+                    "--message",
+                    "{body}",
+                    "--conventional-commits",
+                    "true"
+                ]
+            )
+            .await,
+            "Merge commit templates set for repository 'owner/name':\n\
+            - Title: '{title} (#{number})'\n\
+            - Message: '{body}'\n\
+            - Conventional Commits: true\n"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, RepositoryPath};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Set native merge queue usage for a repository
+#[derive(Parser)]
+pub(crate) struct RepositorySetNativeMergeQueueCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Status
+    #[clap(value_parser)]
+    status: bool,
+}
+
+#[async_trait]
+impl Command for RepositorySetNativeMergeQueueCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        ctx.db_service
+            .repositories_set_native_merge_queue(owner, name, self.status)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_native_merge_queue",
+                &self.repository_path,
+            )
+            .with_old_value(repo.native_merge_queue)
+            .with_new_value(self.status),
+        )
+        .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "Native merge queue usage set to '{}' for repository {}.",
+            self.status,
+            self.repository_path
+        )?;
+
+        Ok(())
+    }
+}
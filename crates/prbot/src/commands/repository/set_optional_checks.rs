@@ -0,0 +1,57 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, Repository, RepositoryPath};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Set optional checks for a repository
+#[derive(Parser)]
+pub(crate) struct RepositorySetOptionalChecksCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Optional check name patterns (glob patterns allowed, empty to unset)
+    patterns: Vec<String>,
+}
+
+#[async_trait]
+impl Command for RepositorySetOptionalChecksCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        for pattern in &self.patterns {
+            Repository::validate_check_pattern(pattern).map_err(|e| anyhow!(e))?;
+        }
+
+        ctx.db_service
+            .repositories_set_optional_checks(owner, name, &self.patterns)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_optional_checks",
+                &self.repository_path,
+            )
+            .with_old_value(repo.optional_checks.join(", "))
+            .with_new_value(self.patterns.join(", ")),
+        )
+        .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "Optional checks set to '{}' for repository {}.",
+            self.patterns.join(", "),
+            self.repository_path
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, RepositoryPath, ReviewerAssignmentStrategy};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Set reviewer pool for automatic reviewer assignment in a repository
+#[derive(Parser)]
+pub(crate) struct RepositorySetReviewerPoolCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Reviewers (none to disable)
+    reviewers: Vec<String>,
+    /// Reviewers to assign per pull request
+    #[arg(long, default_value_t = 1)]
+    count: u64,
+    /// Assignment strategy (`round-robin` or `least-load`)
+    #[arg(long, default_value_t = ReviewerAssignmentStrategy::RoundRobin)]
+    strategy: ReviewerAssignmentStrategy,
+}
+
+#[async_trait]
+impl Command for RepositorySetReviewerPoolCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        ctx.db_service
+            .repositories_set_reviewer_pool(owner, name, &self.reviewers, self.count, self.strategy)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_reviewer_pool",
+                &self.repository_path,
+            )
+            .with_old_value(format!(
+                "reviewers={}, count={}, strategy={}",
+                repo.reviewer_pool.join(","),
+                repo.reviewer_pool_count,
+                repo.reviewer_assignment_strategy
+            ))
+            .with_new_value(format!(
+                "reviewers={}, count={}, strategy={}",
+                self.reviewers.join(","),
+                self.count,
+                self.strategy
+            )),
+        )
+        .await?;
+
+        if self.reviewers.is_empty() {
+            writeln!(
+                ctx.writer.write().await,
+                "Automatic reviewer assignment disabled for repository '{}'.",
+                self.repository_path
+            )?;
+        } else {
+            writeln!(
+                ctx.writer.write().await,
+                "Reviewer pool set for repository '{}':\n- Reviewers: {}\n- Count: {}\n- Strategy: {}",
+                self.repository_path,
+                self.reviewers.join(", "),
+                self.count,
+                self.strategy
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_models::Repository;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            test_command(
+                ctx,
+                &[
+                    "repositories",
+                    "set-reviewer-pool",
+                    "owner/name",
+                    "alice",
+                    "bob",
+                    "--count",
+                    "2",
+                    "--strategy",
+                    "least-load"
+                ]
+            )
+            .await,
+            "Reviewer pool set for repository 'owner/name':\n\
+            - Reviewers: alice, bob\n\
+            - Count: 2\n\
+            - Strategy: least_load\n"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, RepositoryPath, StaleApprovalPolicy};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Set what happens to approvals when new commits are pushed in a repository
+#[derive(Parser)]
+pub(crate) struct RepositorySetStaleApprovalPolicyCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Policy (`keep`, `invalidate-all` or `invalidate-changed-files`)
+    policy: StaleApprovalPolicy,
+}
+
+#[async_trait]
+impl Command for RepositorySetStaleApprovalPolicyCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        ctx.db_service
+            .repositories_set_stale_approval_policy(owner, name, self.policy)
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_stale_approval_policy",
+                &self.repository_path,
+            )
+            .with_old_value(repo.stale_approval_policy)
+            .with_new_value(self.policy),
+        )
+        .await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "Stale approval policy set to '{}' for repository '{}'.",
+            self.policy,
+            self.repository_path
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_models::Repository;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            test_command(
+                ctx,
+                &[
+                    "repositories",
+                    "set-stale-approval-policy",
+                    "owner/name",
+                    "invalidate-all"
+                ]
+            )
+            .await,
+            "Stale approval policy set to 'invalidate_all' for repository 'owner/name'.\n"
+        );
+    }
+}
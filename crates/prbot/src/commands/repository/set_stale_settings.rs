@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::audit::record_audit_entry;
+use prbot_models::{AuditEntry, RepositoryPath};
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Set stale pull requests settings for a repository
+#[derive(Parser)]
+pub(crate) struct RepositorySetStaleSettingsCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Days spent in the same step before a pull request is stale (0 to disable)
+    threshold_days: u64,
+    /// Convert stale pull requests to drafts
+    #[arg(long)]
+    convert_to_draft: bool,
+}
+
+#[async_trait]
+impl Command for RepositorySetStaleSettingsCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        ctx.db_service
+            .repositories_set_stale_settings(
+                owner,
+                name,
+                self.threshold_days,
+                self.convert_to_draft,
+            )
+            .await?;
+
+        record_audit_entry(
+            ctx.db_service.as_ref(),
+            AuditEntry::on_repository(
+                &ctx.audit_actor(),
+                "set_stale_settings",
+                &self.repository_path,
+            )
+            .with_old_value(format!(
+                "threshold_days={}, convert_to_draft={}",
+                repo.stale_threshold_days, repo.stale_convert_to_draft
+            ))
+            .with_new_value(format!(
+                "threshold_days={}, convert_to_draft={}",
+                self.threshold_days, self.convert_to_draft
+            )),
+        )
+        .await?;
+
+        if self.threshold_days == 0 {
+            writeln!(
+                ctx.writer.write().await,
+                "Stale reminders disabled for repository '{}'.",
+                self.repository_path
+            )?;
+        } else {
+            writeln!(
+                ctx.writer.write().await,
+                "Stale settings set for repository '{}':\n- Threshold: {} day(s)\n- Convert to draft: {}",
+                self.repository_path,
+                self.threshold_days,
+                self.convert_to_draft
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prbot_database_interface::DbService;
+    use prbot_models::Repository;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            test_command(
+                ctx,
+                &[
+                    "repositories",
+                    "set-stale-settings",
+                    "owner/name",
+                    "7",
+                    "--convert-to-draft"
+                ]
+            )
+            .await,
+            "Stale settings set for repository 'owner/name':\n\
+            - Threshold: 7 day(s)\n\
+            - Convert to draft: true\n"
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::repositories::{RepositoryConfigSync, SyncRepositoryConfigInterface};
+use prbot_models::RepositoryPath;
+use shaku::HasComponent;
+
+use crate::{
+    commands::{Command, CommandContext},
+    utils::CliDbExt,
+    Result,
+};
+
+/// Apply the configuration file from the repository default branch
+#[derive(Parser)]
+pub(crate) struct RepositorySyncConfigCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+}
+
+#[async_trait]
+impl Command for RepositorySyncConfigCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let (owner, name) = self.repository_path.components();
+        let repo = CliDbExt::get_existing_repository(ctx.db_service.as_ref(), owner, name).await?;
+
+        let sync_uc: &dyn SyncRepositoryConfigInterface = ctx.core_module.resolve_ref();
+        let result = sync_uc.run(&ctx.as_core_context(), &repo).await?;
+
+        let mut writer = ctx.writer.write().await;
+        match result {
+            RepositoryConfigSync::NotFound => writeln!(
+                writer,
+                "No configuration file found for repository {}.",
+                self.repository_path
+            )?,
+            RepositoryConfigSync::Unchanged { path } => writeln!(
+                writer,
+                "Configuration file '{}' already applied for repository {}.",
+                path, self.repository_path
+            )?,
+            RepositoryConfigSync::Applied { path } => writeln!(
+                writer,
+                "Configuration file '{}' applied for repository {}.",
+                path, self.repository_path
+            )?,
+            RepositoryConfigSync::Invalid { error } => writeln!(writer, "{}", error)?,
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_core::use_cases::stats::ComputeRepositoryStatsInterface;
+use prbot_models::{format_datetime, parse_since, DurationStats, RepositoryPath};
+use shaku::HasComponent;
+use time::OffsetDateTime;
+
+use super::{Command, CommandContext};
+use crate::Result;
+
+/// Show pull request lifecycle statistics for a repository
+#[derive(Parser)]
+pub(crate) struct StatsCommand {
+    /// Repository path (e.g. `MyOrganization/my-project`)
+    repository_path: RepositoryPath,
+    /// Only count pull requests opened since a duration (`30d`, `2w`), a date (`2026-10-01`) or a RFC 3339 datetime
+    #[arg(long)]
+    since: Option<String>,
+    /// Output as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[async_trait]
+impl Command for StatsCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let since = self
+            .since
+            .as_deref()
+            .map(|value| parse_since(value, OffsetDateTime::now_utc()))
+            .transpose()
+            .map_err(|e| anyhow!(e))?;
+
+        let compute_stats: &dyn ComputeRepositoryStatsInterface = ctx.core_module.resolve_ref();
+        let stats = compute_stats
+            .run(&ctx.as_core_context(), &self.repository_path, since)
+            .await?;
+
+        let mut writer = ctx.writer.write().await;
+        if self.json {
+            writeln!(writer, "{}", serde_json::to_string_pretty(&stats)?)?;
+            return Ok(());
+        }
+
+        match stats.since {
+            Some(since) => writeln!(
+                writer,
+                "Statistics for repository '{}', since {}:",
+                stats.repository_path,
+                format_datetime(since)
+            )?,
+            None => writeln!(
+                writer,
+                "Statistics for repository '{}':",
+                stats.repository_path
+            )?,
+        }
+        writeln!(
+            writer,
+            "  Pull requests: {} ({} merged)",
+            stats.pull_requests_count, stats.merged_count
+        )?;
+        writeln!(
+            writer,
+            "  Time to first review: {}",
+            format_duration_stats(&stats.time_to_first_review)
+        )?;
+        writeln!(
+            writer,
+            "  Time in QA: {}",
+            format_duration_stats(&stats.time_in_qa)
+        )?;
+        writeln!(
+            writer,
+            "  Time to merge: {}",
+            format_duration_stats(&stats.time_to_merge)
+        )?;
+
+        if !stats.step_durations.is_empty() {
+            writeln!(writer, "  Time in step:")?;
+            for (step, durations) in &stats.step_durations {
+                writeln!(writer, "    {}: {}", step, format_duration_stats(durations))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_duration_stats(stats: &DurationStats) -> String {
+    if stats.count == 0 {
+        return "-".into();
+    }
+
+    format!(
+        "mean {}, median {}, p90 {} ({} values)",
+        format_seconds(stats.mean_seconds),
+        format_seconds(stats.median_seconds),
+        format_seconds(stats.p90_seconds),
+        stats.count
+    )
+}
+
+fn format_seconds(seconds: i64) -> String {
+    let (days, hours, minutes) = (
+        seconds / 86_400,
+        seconds % 86_400 / 3_600,
+        seconds % 3_600 / 60,
+    );
+
+    match (days, hours) {
+        (0, 0) => format!("{minutes}m"),
+        (0, _) => format!("{hours}h{minutes:02}m"),
+        _ => format!("{days}d{hours:02}h"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::{
+        PullRequest, PullRequestEvent, PullRequestEventKind, Repository, StepLabel, StepTransition,
+    };
+    use time::OffsetDateTime;
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        let repo = ctx
+            .db_service
+            .repositories_create(Repository {
+                owner: "owner".into(),
+                name: "name".into(),
+                ..Default::default()
+            })
+            .await?;
+        let pr = ctx
+            .db_service
+            .pull_requests_create(PullRequest {
+                repository_id: repo.id,
+                number: 1,
+                ..Default::default()
+            })
+            .await?;
+
+        let now = OffsetDateTime::now_utc();
+        ctx.db_service
+            .step_transitions_create(StepTransition {
+                pull_request_id: pr.id,
+                step: StepLabel::AwaitingQa,
+                created_at: now - time::Duration::hours(26),
+                ..Default::default()
+            })
+            .await?;
+        for (kind, hours) in [
+            (PullRequestEventKind::Opened, 26),
+            (PullRequestEventKind::Reviewed, 25),
+            (PullRequestEventKind::Merged, 0),
+        ] {
+            ctx.db_service
+                .pull_request_events_create(PullRequestEvent {
+                    pull_request_id: pr.id,
+                    kind,
+                    created_at: now - time::Duration::hours(hours),
+                    ..Default::default()
+                })
+                .await?;
+        }
+
+        assert_eq!(
+            test_command(ctx, &["stats", "owner/name", "--since", "2000-01-01"]).await,
+            "Statistics for repository 'owner/name', since 2000-01-01 00:00 UTC:\n\
+                \x20 Pull requests: 1 (1 merged)\n\
+                \x20 Time to first review: mean 1h00m, median 1h00m, p90 1h00m (1 values)\n\
+                \x20 Time in QA: mean 1d02h, median 1d02h, p90 1d02h (1 values)\n\
+                \x20 Time to merge: mean 1d02h, median 1d02h, p90 1d02h (1 values)\n\
+                \x20 Time in step:\n\
+            \x20   step/awaiting-qa: mean 1d02h, median 1d02h, p90 1d02h (1 values)\n"
+        );
+
+        Ok(())
+    }
+}
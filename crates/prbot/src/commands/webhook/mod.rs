@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+
+use super::{Command, CommandContext};
+use crate::Result;
+
+mod purge;
+mod replay;
+
+use self::{purge::WebhookPurgeCommand, replay::WebhookReplayCommand};
+
+/// Manage webhook deliveries
+#[derive(Parser)]
+pub(crate) struct WebhookCommand {
+    #[clap(subcommand)]
+    inner: WebhookSubCommand,
+}
+
+#[async_trait]
+impl Command for WebhookCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        self.inner.execute(ctx).await
+    }
+}
+
+#[derive(Subcommand)]
+pub(crate) enum WebhookSubCommand {
+    Replay(WebhookReplayCommand),
+    Purge(WebhookPurgeCommand),
+}
+
+#[async_trait]
+impl Command for WebhookSubCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        match self {
+            Self::Replay(sub) => sub.execute(ctx).await,
+            Self::Purge(sub) => sub.execute(ctx).await,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_server::webhook::purge_webhook_deliveries;
+
+use crate::{
+    commands::{Command, CommandContext},
+    Result,
+};
+
+/// Purge webhook deliveries older than the retention period
+#[derive(Parser)]
+pub(crate) struct WebhookPurgeCommand {
+    /// Retention period in days, defaults to the configured one
+    #[clap(short, long)]
+    retention_days: Option<u64>,
+}
+
+#[async_trait]
+impl Command for WebhookPurgeCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let retention_days = self
+            .retention_days
+            .unwrap_or(ctx.config.server.webhook_deliveries_retention_days);
+        let count = purge_webhook_deliveries(ctx.db_service.as_ref(), retention_days).await?;
+
+        writeln!(
+            ctx.writer.write().await,
+            "{} webhook deliveries older than {} days purged.",
+            count,
+            retention_days
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::WebhookDelivery;
+    use time::{Duration, OffsetDateTime};
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .webhook_deliveries_create(WebhookDelivery {
+                delivery_id: "old".into(),
+                received_at: OffsetDateTime::now_utc() - Duration::days(10),
+                ..Default::default()
+            })
+            .await?;
+        ctx.db_service
+            .webhook_deliveries_create(WebhookDelivery {
+                delivery_id: "new".into(),
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            test_command(ctx, &["webhooks", "purge", "--retention-days", "5"]).await,
+            "1 webhook deliveries older than 5 days purged.\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use clap::Parser;
+use prbot_server::{server::AppContext, webhook::replay_webhook_delivery};
+
+use crate::{
+    commands::{Command, CommandContext},
+    Result,
+};
+
+/// Replay a stored webhook delivery
+#[derive(Parser)]
+pub(crate) struct WebhookReplayCommand {
+    /// Delivery ID (from the 'X-GitHub-Delivery' header)
+    delivery_id: String,
+}
+
+#[async_trait]
+impl Command for WebhookReplayCommand {
+    async fn execute(self, ctx: CommandContext) -> Result<()> {
+        let writer = ctx.writer.clone();
+        let context = Arc::new(AppContext::new_with_adapters(
+            ctx.config,
+            ctx.core_module,
+            ctx.db_service,
+            ctx.api_service,
+            ctx.lock_service,
+        ));
+
+        let delivery = replay_webhook_delivery(context, &self.delivery_id).await?;
+
+        writeln!(
+            writer.write().await,
+            "Webhook delivery '{}' ({}) replayed with outcome '{}'.",
+            delivery.delivery_id,
+            delivery.event_type,
+            delivery.outcome
+        )?;
+        if let Some(error) = delivery.error {
+            writeln!(writer.write().await, "Error: {}", error)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use prbot_database_interface::DbService;
+    use prbot_models::{WebhookDelivery, WebhookDeliveryOutcome};
+
+    use crate::testutils::{test_command, CommandContextTest};
+
+    #[tokio::test]
+    async fn run() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .webhook_deliveries_create(WebhookDelivery {
+                delivery_id: "abcd".into(),
+                event_type: "ping".into(),
+                body: r#"{"zen": "Hello.", "hook_id": 1}"#.into(),
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            test_command(ctx, &["webhooks", "replay", "abcd"]).await,
+            "Webhook delivery 'abcd' (ping) replayed with outcome 'success'.\n"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn run_parse_error() -> Result<(), Box<dyn Error>> {
+        let ctx = CommandContextTest::new();
+        ctx.db_service
+            .webhook_deliveries_create(WebhookDelivery {
+                delivery_id: "abcd".into(),
+                event_type: "ping".into(),
+                body: "{}".into(),
+                outcome: WebhookDeliveryOutcome::Success,
+                ..Default::default()
+            })
+            .await?;
+
+        let output = test_command(ctx, &["webhooks", "replay", "abcd"]).await;
+        assert!(output.starts_with(
+            "Webhook delivery 'abcd' (ping) replayed with outcome 'failure'.\nError: "
+        ));
+
+        Ok(())
+    }
+}